@@ -3,7 +3,7 @@
 //!
 //! The MPC-VM then executes the bytecode and performs the [witness extension](https://docs.circom.io/getting-started/computing-the-witness/) in MPC (Multiparty Computation).
 //!
-//! The compiler and the VM are generic over a [`Pairing`](https://docs.rs/ark-ec/latest/ark_ec/pairing/trait.Pairing.html). Currently, we support the curves `bn254` and `bls12-381`.
+//! The compiler and the VM are generic over a [`Pairing`](https://docs.rs/ark-ec/latest/ark_ec/pairing/trait.Pairing.html). Currently, we support the curves `bn254`, `bls12-381` and `bls12-377`.
 //!
 //! The [`CoCircomCompiler`], provides two methods for interacting with circom files
 //!     * [`CoCircomCompiler::parse`] - to parse a circuit
@@ -793,7 +793,7 @@ mod tests {
         )
         .unwrap();
 
-        let plain_vm = parsed.to_plain_vm(VMConfig::default());
+        let plain_vm = parsed.to_plain_vm(VMConfig::default()).unwrap();
         let finalized_witness = plain_vm
             .run_with_flat(
                 to_field_vec!(vec![
@@ -1,9 +1,10 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 
 use ark_ff::PrimeField;
-use eyre::bail;
+use eyre::{Context, bail, eyre};
+use mpc_core::protocols::rep3::yao::bristol_fashion::BristolFashionCircuit;
 
-use crate::mpc::VmCircomWitnessExtension;
+use crate::{mpc::VmCircomWitnessExtension, mpc_vm::VMConfig};
 
 type AcceleratorFunction<F, C> = Box<
     dyn Fn(
@@ -30,7 +31,7 @@ type AcceleratorComponent<F, C> = Box<
 
 #[derive(Debug, Clone)]
 pub struct MpcAcceleratorConfig {
-    /// Whether to use the pre-defined SQRT accelerator
+    /// Whether to use the pre-defined SQRT accelerator. For shared inputs, it obliviously returns 0 for quadratic non-residues, as circom's sqrt function does.
     /// Default: true
     pub(crate) sqrt: bool,
     /// Whether to use the pre-defined NUM2BITS accelerator
@@ -42,6 +43,9 @@ pub struct MpcAcceleratorConfig {
     /// Whether to use the pre-defined ISZERO accelerator
     /// Default: true
     pub(crate) iszero: bool,
+    /// User-supplied Bristol Fashion circuits, registered as function accelerators under the provided function names
+    /// Default: empty
+    pub(crate) bristol_fashion: Vec<(String, PathBuf)>,
 }
 
 impl Default for MpcAcceleratorConfig {
@@ -51,6 +55,7 @@ impl Default for MpcAcceleratorConfig {
             num2bits: true,
             addbits: true,
             iszero: true,
+            bristol_fashion: Vec::new(),
         }
     }
 }
@@ -70,6 +75,20 @@ fn map_env_string_to_bool(value: &str) -> bool {
     }
 }
 
+fn map_env_string_to_bristol_fashion(value: &str) -> eyre::Result<Vec<(String, PathBuf)>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (name, path) = entry.split_once('=').ok_or_else(|| {
+                eyre!("Invalid Bristol Fashion accelerator \"{entry}\", expected <FUNCTION>=<PATH>")
+            })?;
+            Ok((name.trim().to_owned(), PathBuf::from(path.trim())))
+        })
+        .collect()
+}
+
 impl MpcAcceleratorConfig {
     /// Constructs an MpcAcceleratorConfig from the environment variables
     ///
@@ -83,8 +102,12 @@ impl MpcAcceleratorConfig {
     /// - ISZERO
     ///
     /// Possible values for the boolean variables are: "1", "true", "on", "0", "false", "off"
-    pub fn from_env() -> Self {
-        Self {
+    ///
+    /// Additionally, user-supplied Bristol Fashion circuits can be registered as function accelerators with the variable `CIRCOM_MPC_ACCELERATOR_BRISTOL_FASHION`.
+    /// It contains a comma-separated list of `<FUNCTION>=<PATH>` pairs, where `<FUNCTION>` is the name of the circom function as emitted by the compiler (e.g., `sqrt_0`) and `<PATH>` is the path to the circuit file.
+    /// Returns an error if an entry of this list is malformed.
+    pub fn from_env() -> eyre::Result<Self> {
+        Ok(Self {
             sqrt: std::env::var("CIRCOM_MPC_ACCELERATOR_SQRT")
                 .map(|x| map_env_string_to_bool(&x))
                 .unwrap_or(true),
@@ -97,7 +120,22 @@ impl MpcAcceleratorConfig {
             iszero: std::env::var("CIRCOM_MPC_ACCELERATOR_ISZERO")
                 .map(|x| map_env_string_to_bool(&x))
                 .unwrap_or(true),
-        }
+            bristol_fashion: std::env::var("CIRCOM_MPC_ACCELERATOR_BRISTOL_FASHION")
+                .map(|x| map_env_string_to_bristol_fashion(&x))
+                .unwrap_or_else(|_| Ok(Vec::new()))?,
+        })
+    }
+
+    /// Constructs an MpcAcceleratorConfig from the environment variables (see [`Self::from_env`]) and additionally registers the Bristol Fashion circuits of the [`VMConfig`].
+    pub fn from_env_and_vm_config(vm_config: &VMConfig) -> eyre::Result<Self> {
+        let mut config = Self::from_env()?;
+        config.bristol_fashion.extend(
+            vm_config
+                .bristol_fashion
+                .iter()
+                .map(|(name, path)| (name.to_owned(), path.to_owned())),
+        );
+        Ok(config)
     }
 }
 
@@ -117,10 +155,11 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> MpcAccelerator<F, C> {
 
     #[expect(unused)]
     pub fn full() -> Self {
-        Self::from_config(Default::default())
+        Self::from_config(Default::default()).expect("default config has no circuits to load")
     }
 
-    pub fn from_config(config: MpcAcceleratorConfig) -> Self {
+    /// Constructs the accelerator from the config. Returns an error if one of the Bristol Fashion circuits cannot be loaded.
+    pub fn from_config(config: MpcAcceleratorConfig) -> eyre::Result<Self> {
         let mut accelerator = Self::empty();
         if config.sqrt {
             accelerator.register_sqrt();
@@ -134,7 +173,16 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> MpcAccelerator<F, C> {
         if config.iszero {
             accelerator.register_iszero();
         }
-        accelerator
+        for (name, path) in config.bristol_fashion {
+            let circuit = BristolFashionCircuit::from_file(&path).with_context(|| {
+                format!(
+                    "while loading Bristol Fashion circuit {} for accelerator {name}",
+                    path.display()
+                )
+            })?;
+            accelerator.register_bristol_fashion(name, circuit);
+        }
+        Ok(accelerator)
     }
 
     pub fn register_function(
@@ -204,6 +252,21 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> MpcAccelerator<F, C> {
         });
     }
 
+    /// Registers the provided Bristol Fashion circuit as accelerator for the function with the provided name. The arguments of the function are mapped to the input bundles of the circuit, and the output bundles are returned as the result of the function.
+    pub fn register_bristol_fashion(&mut self, name: String, circuit: BristolFashionCircuit) {
+        let num_inputs = circuit.get_input_wires().len();
+        self.register_function(name.clone(), move |protocol, args| {
+            tracing::debug!("calling Bristol Fashion accelerator {name}");
+            if args.len() != num_inputs {
+                bail!(
+                    "Calling Bristol Fashion accelerator {name} with {} arguments, expected {num_inputs}!",
+                    args.len()
+                );
+            }
+            protocol.bristol_fashion(&circuit, args.to_vec())
+        });
+    }
+
     fn register_iszero(&mut self) {
         self.register_component("IsZero".to_string(), |protocol, args, _amount_outputs| {
             tracing::debug!("calling pre-defined IsZero accelerator");
@@ -2,8 +2,10 @@ use crate::mpc_vm::VMConfig;
 use ark_ff::PrimeField;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use eyre::Result;
+use mpc_core::protocols::rep3::yao::bristol_fashion::BristolFashionCircuit;
 use std::fmt;
 
+pub(crate) mod batched_packed_shamir;
 pub(crate) mod batched_plain;
 pub(crate) mod batched_rep3;
 pub(crate) mod plain;
@@ -132,6 +134,15 @@ pub trait VmCircomWitnessExtension<F: PrimeField> {
         b: Vec<Self::VmType>,
     ) -> Result<(Vec<Self::VmType>, Self::VmType)>;
 
+    /// Bristol Fashion accelerator
+    ///
+    /// Executes the provided Bristol Fashion circuit. The i-th argument is interpreted as a little-endian integer and is mapped to the i-th input bundle of the circuit. The output bundles are returned as little-endian integers in the order of the output bundles of the circuit.
+    fn bristol_fashion(
+        &mut self,
+        circuit: &BristolFashionCircuit,
+        args: Vec<Self::VmType>,
+    ) -> Result<Vec<Self::VmType>>;
+
     /// Returns a string representation of the provided value.
     /// **DANGEROUS**: If enabled by the second parameter, the implementation
     /// will open secret values. Otherwise will return the string `secret`.
@@ -0,0 +1,430 @@
+use ark_ff::PrimeField;
+use std::io;
+
+use mpc_core::protocols::{
+    rep3::yao::bristol_fashion::BristolFashionCircuit,
+    shamir::{
+        network::ShamirNetwork,
+        packed::{
+            PackedShamirPreprocessing, PackedShamirPrimeFieldShare, PackedShamirProtocol,
+            arithmetic,
+        },
+    },
+};
+
+use super::{VmCircomWitnessExtension, batched_plain::BatchedCircomPlainVmWitnessExtension};
+
+type ArithmeticShare<F> = PackedShamirPrimeFieldShare<F>;
+
+/// A batched version of the MPC-VM using packed Shamir secret sharing. The witnesses of a batch are packed into the same sharing polynomials, such that `packing` witnesses are processed with the communication of one.
+///
+/// This is a pure MPC improvement and does not use any advanced ZK techniques like folding.
+pub struct BatchedCircomPackedShamirVmWitnessExtension<F: PrimeField, N: ShamirNetwork> {
+    protocol0: PackedShamirProtocol<F, N>,
+    plain: BatchedCircomPlainVmWitnessExtension<F>,
+    batch_size: usize,
+}
+
+impl<F: PrimeField, N: ShamirNetwork> BatchedCircomPackedShamirVmWitnessExtension<F, N> {
+    pub fn from_network(
+        network: N,
+        threshold: usize,
+        packing: usize,
+        batch_size: usize,
+    ) -> eyre::Result<Self> {
+        if batch_size == 0 {
+            eyre::bail!("The batch size must be at least 1");
+        }
+        let preprocessing = PackedShamirPreprocessing::new(threshold, packing, network, 0)?;
+        let protocol0 = PackedShamirProtocol::from(preprocessing);
+        Ok(Self {
+            protocol0,
+            plain: BatchedCircomPlainVmWitnessExtension::new(batch_size),
+            batch_size,
+        })
+    }
+
+    /// Get the underlying network
+    pub fn get_network(self) -> N {
+        self.protocol0.into_network()
+    }
+
+    // Returns the public values if none of the values is shared
+    fn all_public(values: Vec<BatchedPackedShamirVmType<F>>) -> Option<Vec<Vec<F>>> {
+        values
+            .into_iter()
+            .map(|value| match value {
+                BatchedPackedShamirVmType::Public(public) => Some(public),
+                BatchedPackedShamirVmType::Arithmetic(_) => None,
+            })
+            .collect()
+    }
+
+    // Evaluates an operation on public values with the plain VM, shared values are not supported
+    fn public_unary(
+        &mut self,
+        a: BatchedPackedShamirVmType<F>,
+        op: &str,
+        f: impl FnOnce(&mut BatchedCircomPlainVmWitnessExtension<F>, Vec<F>) -> eyre::Result<Vec<F>>,
+    ) -> eyre::Result<BatchedPackedShamirVmType<F>> {
+        match a {
+            BatchedPackedShamirVmType::Public(a) => Ok(f(&mut self.plain, a)?.into()),
+            BatchedPackedShamirVmType::Arithmetic(_) => {
+                eyre::bail!("{op} is not supported in packed Shamir mode")
+            }
+        }
+    }
+
+    // Evaluates an operation on public values with the plain VM, shared values are not supported
+    fn public_binary(
+        &mut self,
+        a: BatchedPackedShamirVmType<F>,
+        b: BatchedPackedShamirVmType<F>,
+        op: &str,
+        f: impl FnOnce(
+            &mut BatchedCircomPlainVmWitnessExtension<F>,
+            Vec<F>,
+            Vec<F>,
+        ) -> eyre::Result<Vec<F>>,
+    ) -> eyre::Result<BatchedPackedShamirVmType<F>> {
+        match (a, b) {
+            (BatchedPackedShamirVmType::Public(a), BatchedPackedShamirVmType::Public(b)) => {
+                Ok(f(&mut self.plain, a, b)?.into())
+            }
+            (_, _) => eyre::bail!("{op} is not supported in packed Shamir mode"),
+        }
+    }
+
+    fn open_vec(&mut self, shares: &[ArithmeticShare<F>]) -> io::Result<Vec<F>> {
+        let mut opened = arithmetic::open_vec(shares, &mut self.protocol0)?;
+        // Remove the padding of the last packed share
+        opened.truncate(self.batch_size);
+        Ok(opened)
+    }
+}
+
+/// This type represents a public or arithmetic share type used in the batched packed Shamir co-cricom MPC-VM.
+#[derive(Clone)]
+pub enum BatchedPackedShamirVmType<F: PrimeField> {
+    /// The public variant
+    Public(Vec<F>),
+    /// The arithemtic share variant, where `packing` elements of the batch share one packed share
+    Arithmetic(Vec<ArithmeticShare<F>>),
+}
+
+impl<F: PrimeField> std::fmt::Debug for BatchedPackedShamirVmType<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Public(field) => f.debug_tuple("Public").field(field).finish(),
+            Self::Arithmetic(share) => f.debug_tuple("Arithmetic").field(share).finish(),
+        }
+    }
+}
+
+impl<F: PrimeField> From<Vec<F>> for BatchedPackedShamirVmType<F> {
+    fn from(value: Vec<F>) -> Self {
+        Self::Public(value)
+    }
+}
+
+impl<F: PrimeField> From<Vec<ArithmeticShare<F>>> for BatchedPackedShamirVmType<F> {
+    fn from(value: Vec<ArithmeticShare<F>>) -> Self {
+        Self::Arithmetic(value)
+    }
+}
+
+impl<F: PrimeField, N: ShamirNetwork> VmCircomWitnessExtension<F>
+    for BatchedCircomPackedShamirVmWitnessExtension<F, N>
+{
+    type Public = Vec<F>;
+
+    type ArithmeticShare = Vec<ArithmeticShare<F>>;
+
+    type VmType = BatchedPackedShamirVmType<F>;
+
+    fn add(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        match (a, b) {
+            (BatchedPackedShamirVmType::Public(a), BatchedPackedShamirVmType::Public(b)) => {
+                Ok(self.plain.add(a, b)?.into())
+            }
+            (BatchedPackedShamirVmType::Public(b), BatchedPackedShamirVmType::Arithmetic(a))
+            | (BatchedPackedShamirVmType::Arithmetic(a), BatchedPackedShamirVmType::Public(b)) => {
+                Ok(arithmetic::add_public_vec(&a, &b, &self.protocol0).into())
+            }
+            (
+                BatchedPackedShamirVmType::Arithmetic(a),
+                BatchedPackedShamirVmType::Arithmetic(b),
+            ) => Ok(arithmetic::add_vec(&a, &b).into()),
+        }
+    }
+
+    fn sub(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        match (a, b) {
+            (BatchedPackedShamirVmType::Public(a), BatchedPackedShamirVmType::Public(b)) => {
+                Ok(self.plain.sub(a, b)?.into())
+            }
+            (BatchedPackedShamirVmType::Arithmetic(a), BatchedPackedShamirVmType::Public(b)) => {
+                Ok(arithmetic::sub_shared_by_public_vec(&a, &b, &self.protocol0).into())
+            }
+            (BatchedPackedShamirVmType::Public(a), BatchedPackedShamirVmType::Arithmetic(b)) => {
+                Ok(arithmetic::sub_public_by_shared_vec(&a, &b, &self.protocol0).into())
+            }
+            (
+                BatchedPackedShamirVmType::Arithmetic(a),
+                BatchedPackedShamirVmType::Arithmetic(b),
+            ) => Ok(arithmetic::sub_vec(&a, &b).into()),
+        }
+    }
+
+    fn mul(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        match (a, b) {
+            (BatchedPackedShamirVmType::Public(a), BatchedPackedShamirVmType::Public(b)) => {
+                Ok(self.plain.mul(a, b)?.into())
+            }
+            (BatchedPackedShamirVmType::Public(b), BatchedPackedShamirVmType::Arithmetic(a))
+            | (BatchedPackedShamirVmType::Arithmetic(a), BatchedPackedShamirVmType::Public(b)) => {
+                // If the public value is the same for the whole batch, we can multiply locally
+                let Some(&first) = b.first() else {
+                    eyre::bail!("Cannot multiply by an empty batch of public values");
+                };
+                if b.iter().all(|b| *b == first) {
+                    Ok(a.into_iter()
+                        .map(|a| arithmetic::mul_public(a, first))
+                        .collect::<Vec<_>>()
+                        .into())
+                } else {
+                    Ok(arithmetic::mul_public_vec(&a, &b, &mut self.protocol0)?.into())
+                }
+            }
+            (
+                BatchedPackedShamirVmType::Arithmetic(a),
+                BatchedPackedShamirVmType::Arithmetic(b),
+            ) => Ok(arithmetic::mul_vec(&a, &b, &mut self.protocol0)?.into()),
+        }
+    }
+
+    fn div(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "div", |plain, a, b| plain.div(a, b))
+    }
+
+    fn int_div(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "int_div", |plain, a, b| plain.int_div(a, b))
+    }
+
+    fn pow(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "pow", |plain, a, b| plain.pow(a, b))
+    }
+
+    fn modulo(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "mod", |plain, a, b| plain.modulo(a, b))
+    }
+
+    fn sqrt(&mut self, a: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_unary(a, "sqrt", |plain, a| plain.sqrt(a))
+    }
+
+    fn neg(&mut self, a: Self::VmType) -> eyre::Result<Self::VmType> {
+        match a {
+            BatchedPackedShamirVmType::Public(a) => {
+                Ok(a.into_iter().map(|a| -a).collect::<Vec<_>>().into())
+            }
+            BatchedPackedShamirVmType::Arithmetic(a) => Ok(arithmetic::neg_vec(&a).into()),
+        }
+    }
+
+    fn lt(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "lt", |plain, a, b| plain.lt(a, b))
+    }
+
+    fn le(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "le", |plain, a, b| plain.le(a, b))
+    }
+
+    fn gt(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "gt", |plain, a, b| plain.gt(a, b))
+    }
+
+    fn ge(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "ge", |plain, a, b| plain.ge(a, b))
+    }
+
+    fn eq(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "eq", |plain, a, b| plain.eq(a, b))
+    }
+
+    fn neq(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "neq", |plain, a, b| plain.neq(a, b))
+    }
+
+    fn shift_r(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "shift_r", |plain, a, b| plain.shift_r(a, b))
+    }
+
+    fn shift_l(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "shift_l", |plain, a, b| plain.shift_l(a, b))
+    }
+
+    fn bool_not(&mut self, a: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_unary(a, "bool_not", |plain, a| plain.bool_not(a))
+    }
+
+    fn bool_and(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "bool_and", |plain, a, b| plain.bool_and(a, b))
+    }
+
+    fn bool_or(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "bool_or", |plain, a, b| plain.bool_or(a, b))
+    }
+
+    fn cmux(
+        &mut self,
+        cond: Self::VmType,
+        truthy: Self::VmType,
+        falsy: Self::VmType,
+    ) -> eyre::Result<Self::VmType> {
+        let Some(mut args) = Self::all_public(vec![cond, truthy, falsy]) else {
+            eyre::bail!("cmux is not supported in packed Shamir mode");
+        };
+        let falsy = args.pop().expect("three arguments");
+        let truthy = args.pop().expect("three arguments");
+        let cond = args.pop().expect("three arguments");
+        Ok(self.plain.cmux(cond, truthy, falsy)?.into())
+    }
+
+    fn bit_xor(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "bit_xor", |plain, a, b| plain.bit_xor(a, b))
+    }
+
+    fn bit_or(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "bit_or", |plain, a, b| plain.bit_or(a, b))
+    }
+
+    fn bit_and(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
+        self.public_binary(a, b, "bit_and", |plain, a, b| plain.bit_and(a, b))
+    }
+
+    fn is_zero(&mut self, a: Self::VmType, allow_secret_inputs: bool) -> eyre::Result<bool> {
+        if !allow_secret_inputs && self.is_shared(&a)? {
+            eyre::bail!("allow_secret_inputs is false and input is shared");
+        }
+        match a {
+            BatchedPackedShamirVmType::Public(a) => {
+                Ok(self.plain.is_zero(a, allow_secret_inputs)?)
+            }
+            BatchedPackedShamirVmType::Arithmetic(a) => {
+                let a = self.open_vec(&a)?;
+                Ok(self.plain.is_zero(a, allow_secret_inputs)?)
+            }
+        }
+    }
+
+    fn is_shared(&mut self, a: &Self::VmType) -> eyre::Result<bool> {
+        match a {
+            BatchedPackedShamirVmType::Public(_) => Ok(false),
+            BatchedPackedShamirVmType::Arithmetic(_) => Ok(true),
+        }
+    }
+
+    fn to_index(&mut self, a: Self::VmType) -> eyre::Result<usize> {
+        if let BatchedPackedShamirVmType::Public(a) = a {
+            self.plain.to_index(a)
+        } else {
+            eyre::bail!("ToIndex called on shared value!")
+        }
+    }
+
+    fn open(&mut self, a: Self::VmType) -> eyre::Result<Self::Public> {
+        match a {
+            BatchedPackedShamirVmType::Public(public) => Ok(public),
+            BatchedPackedShamirVmType::Arithmetic(shares) => Ok(self.open_vec(&shares)?),
+        }
+    }
+
+    fn to_share(&mut self, a: Self::VmType) -> eyre::Result<Self::ArithmeticShare> {
+        match a {
+            BatchedPackedShamirVmType::Public(a) => {
+                Ok(self.protocol0.promote_to_trivial_shares(&a))
+            }
+            BatchedPackedShamirVmType::Arithmetic(a) => Ok(a),
+        }
+    }
+
+    fn public_one(&self) -> Self::VmType {
+        Self::VmType::Public(vec![F::one(); self.batch_size])
+    }
+
+    fn public_zero(&self) -> Self::VmType {
+        Self::VmType::Public(vec![F::zero(); self.batch_size])
+    }
+
+    fn compare_vm_config(&mut self, config: &crate::mpc_vm::VMConfig) -> eyre::Result<()> {
+        let ser = bincode::serialize(&config)?;
+        let network = &mut self.protocol0.network;
+        let num_parties = network.get_num_parties();
+        let id = network.get_id();
+        network.send_many((id + 1) % num_parties, &ser)?;
+        let rcv: Vec<u8> = network.recv_many((id + num_parties - 1) % num_parties)?;
+        let deser = bincode::deserialize(&rcv)?;
+        if config != &deser {
+            eyre::bail!("VM Config does not match: {:?} != {:?}", config, deser);
+        }
+
+        Ok(())
+    }
+
+    fn num2bits(&mut self, a: Self::VmType, bits: usize) -> eyre::Result<Vec<Self::VmType>> {
+        match a {
+            BatchedPackedShamirVmType::Public(a) => Ok(self
+                .plain
+                .num2bits(a, bits)?
+                .into_iter()
+                .map(Into::into)
+                .collect()),
+            BatchedPackedShamirVmType::Arithmetic(_) => {
+                eyre::bail!("num2bits is not supported in packed Shamir mode")
+            }
+        }
+    }
+
+    fn addbits(
+        &mut self,
+        a: Vec<Self::VmType>,
+        b: Vec<Self::VmType>,
+    ) -> eyre::Result<(Vec<Self::VmType>, Self::VmType)> {
+        let (Some(a), Some(b)) = (Self::all_public(a), Self::all_public(b)) else {
+            eyre::bail!("addbits is not supported in packed Shamir mode");
+        };
+        let (bits, carry) = self.plain.addbits(a, b)?;
+        Ok((bits.into_iter().map(Into::into).collect(), carry.into()))
+    }
+
+    fn bristol_fashion(
+        &mut self,
+        circuit: &BristolFashionCircuit,
+        args: Vec<Self::VmType>,
+    ) -> eyre::Result<Vec<Self::VmType>> {
+        let Some(args) = Self::all_public(args) else {
+            eyre::bail!("bristol_fashion is not supported in packed Shamir mode");
+        };
+        Ok(self
+            .plain
+            .bristol_fashion(circuit, args)?
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    fn log(&mut self, a: Self::VmType, allow_leaky_logs: bool) -> eyre::Result<String> {
+        match a {
+            BatchedPackedShamirVmType::Public(public) => self.plain.log(public, allow_leaky_logs),
+            BatchedPackedShamirVmType::Arithmetic(shares) => {
+                if allow_leaky_logs {
+                    let fields = self.open_vec(&shares)?;
+                    self.plain.log(fields, allow_leaky_logs)
+                } else {
+                    Ok("secret".to_string())
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use ark_ff::One;
 use ark_ff::PrimeField;
 use itertools::Itertools as _;
+use mpc_core::protocols::rep3::yao::bristol_fashion::BristolFashionCircuit;
 use num_bigint::BigUint;
 
 use crate::mpc::plain::to_usize;
@@ -88,11 +89,17 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for BatchedCircomPlainVmWitnessE
     }
 
     fn int_div(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.int_div(*a, *b))
+            .collect()
     }
 
     fn pow(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.pow(*a, *b))
+            .collect()
     }
 
     fn modulo(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
@@ -103,11 +110,11 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for BatchedCircomPlainVmWitnessE
     }
 
     fn sqrt(&mut self, a: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter().map(|a| self.plain_wts_ext.sqrt(*a)).collect()
     }
 
     fn neg(&mut self, a: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter().map(|a| self.plain_wts_ext.neg(*a)).collect()
     }
 
     fn lt(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
@@ -118,43 +125,70 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for BatchedCircomPlainVmWitnessE
     }
 
     fn le(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.le(*a, *b))
+            .collect()
     }
 
     fn gt(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.gt(*a, *b))
+            .collect()
     }
 
     fn ge(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.ge(*a, *b))
+            .collect()
     }
 
     fn eq(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.eq(*a, *b))
+            .collect()
     }
 
     fn neq(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.neq(*a, *b))
+            .collect()
     }
 
     fn shift_r(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.shift_r(*a, *b))
+            .collect()
     }
 
     fn shift_l(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.shift_l(*a, *b))
+            .collect()
     }
 
     fn bool_not(&mut self, a: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter().map(|a| self.plain_wts_ext.bool_not(*a)).collect()
     }
 
     fn bool_and(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.bool_and(*a, *b))
+            .collect()
     }
 
     fn bool_or(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.bool_or(*a, *b))
+            .collect()
     }
 
     fn cmux(
@@ -163,19 +197,30 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for BatchedCircomPlainVmWitnessE
         truthy: Self::VmType,
         falsy: Self::VmType,
     ) -> eyre::Result<Self::VmType> {
-        todo!()
+        itertools::izip!(cond, truthy, falsy)
+            .map(|(cond, truthy, falsy)| self.plain_wts_ext.cmux(cond, truthy, falsy))
+            .collect()
     }
 
     fn bit_xor(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.bit_xor(*a, *b))
+            .collect()
     }
 
     fn bit_or(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.bit_or(*a, *b))
+            .collect()
     }
 
     fn bit_and(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
-        todo!()
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.plain_wts_ext.bit_and(*a, *b))
+            .collect()
     }
 
     fn is_zero(&mut self, a: Self::VmType, _: bool) -> eyre::Result<bool> {
@@ -192,8 +237,8 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for BatchedCircomPlainVmWitnessE
         }
     }
 
-    fn is_shared(&mut self, a: &Self::VmType) -> eyre::Result<bool> {
-        todo!()
+    fn is_shared(&mut self, _: &Self::VmType) -> eyre::Result<bool> {
+        Ok(false)
     }
 
     fn to_index(&mut self, a: Self::VmType) -> eyre::Result<usize> {
@@ -221,7 +266,7 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for BatchedCircomPlainVmWitnessE
     }
 
     fn public_one(&self) -> Self::VmType {
-        todo!()
+        vec![F::one(); self.batch_size]
     }
 
     fn public_zero(&self) -> Self::VmType {
@@ -233,7 +278,14 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for BatchedCircomPlainVmWitnessE
     }
 
     fn num2bits(&mut self, a: Self::VmType, bits: usize) -> eyre::Result<Vec<Self::VmType>> {
-        todo!()
+        let mut res = vec![Vec::with_capacity(a.len()); bits];
+        for a in a {
+            let decomposed = self.plain_wts_ext.num2bits(a, bits)?;
+            for (res, bit) in res.iter_mut().zip(decomposed) {
+                res.push(bit);
+            }
+        }
+        Ok(res)
     }
 
     fn addbits(
@@ -277,6 +329,26 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for BatchedCircomPlainVmWitnessE
         Ok((res, carry))
     }
 
+    fn bristol_fashion(
+        &mut self,
+        circuit: &BristolFashionCircuit,
+        args: Vec<Self::VmType>,
+    ) -> eyre::Result<Vec<Self::VmType>> {
+        if args.iter().any(|arg| arg.len() != self.batch_size) {
+            eyre::bail!("All arguments must have the batch size {}", self.batch_size);
+        }
+        let mut outputs =
+            vec![Vec::with_capacity(self.batch_size); circuit.get_output_wires().len()];
+        for i in 0..self.batch_size {
+            let args = args.iter().map(|arg| arg[i]).collect();
+            let result = self.plain_wts_ext.bristol_fashion(circuit, args)?;
+            for (output, result) in outputs.iter_mut().zip(result) {
+                output.push(result);
+            }
+        }
+        Ok(outputs)
+    }
+
     fn log(&mut self, a: Self::VmType, _: bool) -> eyre::Result<String> {
         Ok(format!("[{}]", a.iter().map(|a| a.to_string()).join(", ")))
     }
@@ -5,8 +5,9 @@ use ark_ff::PrimeField;
 use itertools::Itertools;
 use mpc_core::protocols::rep3::{
     Rep3PrimeFieldShare, arithmetic,
-    conversion::{self, A2BType},
+    conversion::{self, A2BType, NetworkProfile},
     network::{IoContext, Rep3Network},
+    yao::{self, bristol_fashion::BristolFashionCircuit},
 };
 use num_bigint::BigUint;
 
@@ -26,9 +27,15 @@ pub struct BatchedCircomRep3VmWitnessExtension<F: PrimeField, N: Rep3Network> {
 }
 
 impl<F: PrimeField, N: Rep3Network> BatchedCircomRep3VmWitnessExtension<F, N> {
-    pub fn from_network(network: N, a2b_type: A2BType, batch_size: usize) -> io::Result<Self> {
+    pub fn from_network(
+        network: N,
+        a2b_type: A2BType,
+        network_profile: NetworkProfile,
+        batch_size: usize,
+    ) -> io::Result<Self> {
         let mut io_context = IoContext::init(network)?;
         io_context.set_a2b_type(a2b_type);
+        io_context.set_network_profile(network_profile);
         let io_context_fork = io_context.fork()?;
         Ok(Self {
             io_context0: io_context,
@@ -383,6 +390,58 @@ impl<F: PrimeField, N: Rep3Network> VmCircomWitnessExtension<F>
         Ok((res, carry))
     }
 
+    fn bristol_fashion(
+        &mut self,
+        circuit: &BristolFashionCircuit,
+        args: Vec<Self::VmType>,
+    ) -> eyre::Result<Vec<Self::VmType>> {
+        if args
+            .iter()
+            .all(|x| matches!(x, BatchedRep3VmType::Public(_)))
+        {
+            let args = args
+                .into_iter()
+                .map(|x| match x {
+                    BatchedRep3VmType::Public(x) => x,
+                    BatchedRep3VmType::Arithmetic(_) => unreachable!(),
+                })
+                .collect();
+            return Ok(self
+                .plain
+                .bristol_fashion(circuit, args)?
+                .into_iter()
+                .map(Into::into)
+                .collect());
+        }
+        let args = args
+            .into_iter()
+            .map(|x| match x {
+                BatchedRep3VmType::Public(x) => {
+                    arithmetic::promote_to_trivial_shares(x, self.io_context0.id)
+                }
+                BatchedRep3VmType::Arithmetic(x) => x,
+            })
+            .collect_vec();
+        if args.iter().any(|arg| arg.len() != self.batch_size) {
+            eyre::bail!("All arguments must have the batch size {}", self.batch_size);
+        }
+        // The circuit is executed for each element of the batch
+        let mut outputs =
+            vec![Vec::with_capacity(self.batch_size); circuit.get_output_wires().len()];
+        for i in 0..self.batch_size {
+            let args = args.iter().map(|arg| arg[i]).collect_vec();
+            let result = yao::bristol_fashion::execute_on_arithmetic_shares(
+                circuit,
+                &args,
+                &mut self.io_context0,
+            )?;
+            for (output, result) in outputs.iter_mut().zip(result) {
+                output.push(result);
+            }
+        }
+        Ok(outputs.into_iter().map(Into::into).collect())
+    }
+
     fn log(&mut self, a: Self::VmType, allow_leaky_logs: bool) -> eyre::Result<String> {
         match a {
             BatchedRep3VmType::Public(public) => self.plain.log(public, allow_leaky_logs),
@@ -3,6 +3,7 @@ use crate::mpc_vm::VMConfig;
 use ark_ff::{One, PrimeField};
 use eyre::Result;
 use eyre::eyre;
+use mpc_core::protocols::rep3::yao::bristol_fashion::{self, BristolFashionCircuit};
 use num_bigint::BigUint;
 
 /// Transforms a field element into an usize if possible.
@@ -112,7 +113,10 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for CircomPlainVmWitnessExtensio
     }
 
     fn sqrt(&mut self, a: Self::VmType) -> Result<Self::VmType> {
-        let sqrt = a.sqrt().ok_or(eyre!("cannot compute sqrt for {a}"))?;
+        // circom's sqrt function returns 0 for non-residues
+        let Some(sqrt) = a.sqrt() else {
+            return Ok(F::zero());
+        };
         if self.is_negative(sqrt) {
             Ok(-sqrt)
         } else {
@@ -292,6 +296,14 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for CircomPlainVmWitnessExtensio
         Ok((res, carry))
     }
 
+    fn bristol_fashion(
+        &mut self,
+        circuit: &BristolFashionCircuit,
+        args: Vec<Self::VmType>,
+    ) -> Result<Vec<Self::VmType>> {
+        Ok(bristol_fashion::execute_on_field_elements(circuit, &args)?)
+    }
+
     fn log(&mut self, a: Self::VmType, _: bool) -> eyre::Result<String> {
         Ok(a.to_string())
     }
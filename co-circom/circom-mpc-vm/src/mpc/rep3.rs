@@ -12,7 +12,7 @@ use mpc_core::protocols::rep3::{
     binary,
     conversion::{self, bit_inject_many},
     network::{IoContext, Rep3Network},
-    yao,
+    yao::{self, bristol_fashion::BristolFashionCircuit},
 };
 use num_bigint::BigUint;
 use std::io;
@@ -65,9 +65,14 @@ pub struct CircomRep3VmWitnessExtension<F: PrimeField, N: Rep3Network> {
 }
 
 impl<F: PrimeField, N: Rep3Network> CircomRep3VmWitnessExtension<F, N> {
-    pub fn from_network(network: N, a2b_type: conversion::A2BType) -> io::Result<Self> {
+    pub fn from_network(
+        network: N,
+        a2b_type: conversion::A2BType,
+        network_profile: conversion::NetworkProfile,
+    ) -> io::Result<Self> {
         let mut io_context = IoContext::init(network)?;
         io_context.set_a2b_type(a2b_type);
+        io_context.set_network_profile(network_profile);
         let io_context_fork = io_context.fork()?;
         Ok(Self {
             io_context0: io_context,
@@ -238,7 +243,8 @@ impl<F: PrimeField, N: Rep3Network> VmCircomWitnessExtension<F>
         match a {
             Rep3VmType::Public(a) => Ok(self.plain.sqrt(a)?.into()),
             Rep3VmType::Arithmetic(a) => {
-                let sqrt = arithmetic::sqrt(a, &mut self.io_context0)?;
+                // Obliviously returns 0 for non-residues, as circom's sqrt function does
+                let (sqrt, _) = arithmetic::sqrt_or_zero(a, &mut self.io_context0)?;
                 // Correction to give the result closest to 0
                 // I.e., 2 * is_pos * sqrt - sqrt
                 let sqrt_val = self.val(sqrt);
@@ -645,6 +651,43 @@ impl<F: PrimeField, N: Rep3Network> VmCircomWitnessExtension<F>
         Ok((result.into_iter().map(Into::into).collect(), carry.into()))
     }
 
+    fn bristol_fashion(
+        &mut self,
+        circuit: &BristolFashionCircuit,
+        args: Vec<Self::VmType>,
+    ) -> eyre::Result<Vec<Self::VmType>> {
+        if args.iter().all(|x| matches!(x, Rep3VmType::Public(_))) {
+            let args = args
+                .into_iter()
+                .map(|x| match x {
+                    Rep3VmType::Public(x) => x,
+                    Rep3VmType::Arithmetic(_) => unreachable!(),
+                })
+                .collect();
+            return Ok(self
+                .plain
+                .bristol_fashion(circuit, args)?
+                .into_iter()
+                .map(Into::into)
+                .collect());
+        }
+        let args = args
+            .into_iter()
+            .map(|x| match x {
+                Rep3VmType::Public(x) => promote_to_trivial_share(self.io_context0.id, x),
+                Rep3VmType::Arithmetic(x) => x,
+            })
+            .collect_vec();
+        Ok(yao::bristol_fashion::execute_on_arithmetic_shares(
+            circuit,
+            &args,
+            &mut self.io_context0,
+        )?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+    }
+
     fn log(&mut self, a: Self::VmType, allow_leaky_logs: bool) -> eyre::Result<String> {
         match a {
             Rep3VmType::Public(public) => self.plain.log(public, allow_leaky_logs),
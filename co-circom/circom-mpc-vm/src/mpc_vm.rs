@@ -1,4 +1,7 @@
 use crate::accelerator::MpcAcceleratorConfig;
+use crate::mpc::batched_packed_shamir::{
+    BatchedCircomPackedShamirVmWitnessExtension, BatchedPackedShamirVmType,
+};
 use crate::mpc::batched_plain::BatchedCircomPlainVmWitnessExtension;
 use crate::mpc::batched_rep3::{BatchedCircomRep3VmWitnessExtension, BatchedRep3VmType};
 use crate::mpc::plain::CircomPlainVmWitnessExtension;
@@ -17,11 +20,13 @@ use core::panic;
 use eyre::{Result, bail, eyre};
 use itertools::{Itertools, izip};
 use mpc_core::protocols::rep3::Rep3PrimeFieldShare;
-use mpc_core::protocols::rep3::conversion::A2BType;
+use mpc_core::protocols::rep3::conversion::{A2BType, NetworkProfile};
 use mpc_core::protocols::rep3::network::{Rep3MpcNet, Rep3Network};
+use mpc_core::protocols::shamir::network::ShamirNetwork;
 use mpc_net::config::NetworkConfig;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 /// The mpc-vm configuration
@@ -33,6 +38,12 @@ pub struct VMConfig {
     /// Define the implementation of the arithmetic/binary conversions.
     #[serde(default)]
     pub a2b_type: A2BType,
+    /// The network profile used to choose the arithmetic/binary conversions if `a2b_type` is [`A2BType::Auto`].
+    #[serde(default)]
+    pub network_profile: NetworkProfile,
+    /// User-supplied Bristol Fashion circuits, registered as function accelerators under the given circom function names (e.g., `sqrt_0`). They are registered in addition to the ones given by the `CIRCOM_MPC_ACCELERATOR_BRISTOL_FASHION` environment variable.
+    #[serde(default)]
+    pub bristol_fashion: BTreeMap<String, PathBuf>,
 }
 
 impl VMConfig {
@@ -79,6 +90,10 @@ pub type PlainWitnessExtension<F> = WitnessExtension<F, CircomPlainVmWitnessExte
 pub type BatchedRep3WitnessExtension<F, N> =
     WitnessExtension<F, BatchedCircomRep3VmWitnessExtension<F, N>>;
 
+/// Shorthand type for the MPC-VM instantiated with a packed `Shamir` protocol and batching multiple inputs into a single run, where `packing` inputs share the same packed shares.
+pub type BatchedPackedShamirWitnessExtension<F, N> =
+    WitnessExtension<F, BatchedCircomPackedShamirVmWitnessExtension<F, N>>;
+
 /// Shorthand type for the MPC-VM instantiated with a `Rep3` protocol.
 ///
 /// This is the only supported protocol at the moment.
@@ -898,6 +913,9 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> WitnessExtension<F, C> {
         &mut self,
         mut input_signals: SharedInput<C::Public, C::ArithmeticShare>,
     ) -> Result<usize> {
+        if !input_signals.ring_inputs.is_empty() {
+            bail!("integer inputs must be cast to field elements before the witness extension");
+        }
         let mut amount_public_inputs = 0;
         for (name, offset, size) in self.main_input_list.iter() {
             let input_signals =
@@ -1058,10 +1076,12 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> FinalizedWitnessExtension<F,
 }
 
 impl<F: PrimeField> PlainWitnessExtension<F> {
-    pub(crate) fn new(parser: &CoCircomCompilerParsed<F>, config: VMConfig) -> Self {
+    pub(crate) fn new(parser: &CoCircomCompilerParsed<F>, config: VMConfig) -> Result<Self> {
         let mut signals = vec![F::default(); parser.amount_signals];
         signals[0] = F::one();
-        Self {
+        let mpc_accelerator =
+            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env_and_vm_config(&config)?)?;
+        Ok(Self {
             driver: CircomPlainVmWitnessExtension::default(),
             signal_to_witness: parser.signal_to_witness.clone(),
             main: parser.main.clone(),
@@ -1071,14 +1091,14 @@ impl<F: PrimeField> PlainWitnessExtension<F> {
                 parser.fun_decls.clone(),
                 parser.templ_decls.clone(),
                 parser.string_table.clone(),
-                MpcAccelerator::from_config(MpcAcceleratorConfig::from_env()),
+                mpc_accelerator,
             ),
             main_inputs: parser.main_inputs,
             main_outputs: parser.main_outputs,
             main_input_list: parser.main_input_list.clone(),
             output_mapping: parser.output_mapping.clone(),
             config,
-        }
+        })
     }
 }
 
@@ -1087,9 +1107,11 @@ impl<F: PrimeField> BatchedPlainWitnessExtension<F> {
         parser: &CoCircomCompilerParsed<F>,
         config: VMConfig,
         batch_size: usize,
-    ) -> Self {
+    ) -> Result<Self> {
         let mut signals = vec![Vec::with_capacity(batch_size); parser.amount_signals];
         signals[0] = vec![F::one(); batch_size];
+        let mpc_accelerator =
+            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env_and_vm_config(&config)?)?;
         let batched_constant_table = parser
             .constant_table
             .iter()
@@ -1105,14 +1127,14 @@ impl<F: PrimeField> BatchedPlainWitnessExtension<F> {
                 parser.fun_decls.clone(),
                 parser.templ_decls.clone(),
                 parser.string_table.clone(),
-                MpcAccelerator::from_config(MpcAcceleratorConfig::from_env()),
+                mpc_accelerator,
             ),
             main_inputs: parser.main_inputs,
             main_outputs: parser.main_outputs,
             main_input_list: parser.main_input_list.clone(),
             output_mapping: parser.output_mapping.clone(),
             config,
-        }
+        })
     }
 }
 
@@ -1123,7 +1145,11 @@ impl<F: PrimeField, N: Rep3Network> Rep3WitnessExtension<F, N> {
         mpc_accelerator: MpcAccelerator<F, CircomRep3VmWitnessExtension<F, N>>,
         config: VMConfig,
     ) -> Result<Self> {
-        let driver = CircomRep3VmWitnessExtension::from_network(network, config.a2b_type)?;
+        let driver = CircomRep3VmWitnessExtension::from_network(
+            network,
+            config.a2b_type,
+            config.network_profile,
+        )?;
         let mut signals = vec![Rep3VmType::default(); parser.amount_signals];
         signals[0] = Rep3VmType::Public(F::one());
         let constant_table = parser
@@ -1198,6 +1224,7 @@ impl<F: PrimeField, N: Rep3Network> BatchedRep3WitnessExtension<F, N> {
         let driver = BatchedCircomRep3VmWitnessExtension::from_network(
             network,
             config.a2b_type,
+            config.network_profile,
             batch_size,
         )?;
 
@@ -1232,6 +1259,51 @@ impl<F: PrimeField, N: Rep3Network> BatchedRep3WitnessExtension<F, N> {
     }
 }
 
+impl<F: PrimeField, N: ShamirNetwork> BatchedPackedShamirWitnessExtension<F, N> {
+    pub(crate) fn from_network(
+        parser: &CoCircomCompilerParsed<F>,
+        network: N,
+        mpc_accelerator: MpcAccelerator<F, BatchedCircomPackedShamirVmWitnessExtension<F, N>>,
+        config: VMConfig,
+        threshold: usize,
+        packing: usize,
+        batch_size: usize,
+    ) -> Result<Self> {
+        let driver = BatchedCircomPackedShamirVmWitnessExtension::from_network(
+            network, threshold, packing, batch_size,
+        )?;
+
+        let mut signals = vec![
+            BatchedPackedShamirVmType::from(Vec::<F>::with_capacity(batch_size));
+            parser.amount_signals
+        ];
+        signals[0] = BatchedPackedShamirVmType::from(vec![F::one(); batch_size]);
+        let constant_table = parser
+            .constant_table
+            .iter()
+            .map(|constant| BatchedPackedShamirVmType::from(vec![*constant; batch_size]))
+            .collect_vec();
+        Ok(Self {
+            driver,
+            signal_to_witness: parser.signal_to_witness.clone(),
+            main: parser.main.clone(),
+            ctx: WitnessExtensionCtx::new(
+                signals,
+                constant_table,
+                parser.fun_decls.clone(),
+                parser.templ_decls.clone(),
+                parser.string_table.clone(),
+                mpc_accelerator,
+            ),
+            main_inputs: parser.main_inputs,
+            main_outputs: parser.main_outputs,
+            main_input_list: parser.main_input_list.clone(),
+            output_mapping: parser.output_mapping.clone(),
+            config,
+        })
+    }
+}
+
 impl<F: PrimeField> Rep3WitnessExtension<F, Rep3MpcNet> {
     pub(crate) fn new(
         parser: &CoCircomCompilerParsed<F>,
@@ -1,7 +1,10 @@
 use std::{collections::HashMap, sync::Arc};
 
 use ark_ff::PrimeField;
-use mpc_core::protocols::rep3::network::{Rep3MpcNet, Rep3Network};
+use mpc_core::protocols::{
+    rep3::network::{Rep3MpcNet, Rep3Network},
+    shamir::network::ShamirNetwork,
+};
 use mpc_net::config::NetworkConfig;
 use serde::{Deserialize, Serialize};
 
@@ -11,8 +14,9 @@ use crate::{
         batched_plain::BatchedCircomPlainVmWitnessExtension, plain::CircomPlainVmWitnessExtension,
     },
     mpc_vm::{
-        BatchedPlainWitnessExtension, BatchedRep3WitnessExtension, PlainWitnessExtension,
-        Rep3WitnessExtension, VMConfig, WitnessExtension,
+        BatchedPackedShamirWitnessExtension, BatchedPlainWitnessExtension,
+        BatchedRep3WitnessExtension, PlainWitnessExtension, Rep3WitnessExtension, VMConfig,
+        WitnessExtension,
     },
     op_codes::CodeBlock,
 };
@@ -169,7 +173,7 @@ impl<F: PrimeField> CoCircomCompilerParsed<F> {
     pub fn to_plain_vm(
         self,
         vm_config: VMConfig,
-    ) -> WitnessExtension<F, CircomPlainVmWitnessExtension<F>> {
+    ) -> Result<WitnessExtension<F, CircomPlainVmWitnessExtension<F>>> {
         PlainWitnessExtension::new(&self, vm_config)
     }
 
@@ -189,7 +193,7 @@ impl<F: PrimeField> CoCircomCompilerParsed<F> {
         self,
         vm_config: VMConfig,
         batch_size: usize,
-    ) -> WitnessExtension<F, BatchedCircomPlainVmWitnessExtension<F>> {
+    ) -> Result<WitnessExtension<F, BatchedCircomPlainVmWitnessExtension<F>>> {
         BatchedPlainWitnessExtension::new(&self, vm_config, batch_size)
     }
 
@@ -209,7 +213,7 @@ impl<F: PrimeField> CoCircomCompilerParsed<F> {
         Rep3WitnessExtension::new(
             &self,
             network_config,
-            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env()),
+            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env_and_vm_config(&vm_config)?)?,
             vm_config,
         )
     }
@@ -231,7 +235,7 @@ impl<F: PrimeField> CoCircomCompilerParsed<F> {
         Rep3WitnessExtension::from_network(
             &self,
             network,
-            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env()),
+            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env_and_vm_config(&vm_config)?)?,
             vm_config,
         )
     }
@@ -255,8 +259,39 @@ impl<F: PrimeField> CoCircomCompilerParsed<F> {
         BatchedRep3WitnessExtension::from_network(
             &self,
             network,
-            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env()),
+            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env_and_vm_config(&vm_config)?)?,
+            vm_config,
+            batch_size,
+        )
+    }
+
+    /// Consumes `self` and an already established [`ShamirNetwork`] and constructs an instance of [`BatchedPackedShamirWitnessExtension`].
+    ///
+    /// # Arguments
+    /// - `network`: An already established [`ShamirNetwork`].
+    /// - `vm_config`: The [`VMConfig`].
+    /// - `threshold`: The maximum number of tolerated colluding parties.
+    /// - `packing`: The number of inputs of the batch packed into one share. The number of parties n needs to satisfy 2 * (threshold + packing - 1) + 1 <= n.
+    /// - `batch_size`: The batched size the VM is operating on. The run will fail if the provided batch size doesn't match with the provided input.
+    ///
+    /// # Returns
+    /// - `Ok(BatchedPackedShamirWitnessExtension)`: The MPC-VM capable of performing the witness extension using the packed Shamir protocol.
+    /// - `Err(err)`: An error indicating a failure.
+    pub fn to_batched_packed_shamir_vm_with_network<N: ShamirNetwork>(
+        self,
+        network: N,
+        vm_config: VMConfig,
+        threshold: usize,
+        packing: usize,
+        batch_size: usize,
+    ) -> Result<BatchedPackedShamirWitnessExtension<F, N>> {
+        BatchedPackedShamirWitnessExtension::from_network(
+            &self,
+            network,
+            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env_and_vm_config(&vm_config)?)?,
             vm_config,
+            threshold,
+            packing,
             batch_size,
         )
     }
@@ -277,7 +312,7 @@ impl<F: PrimeField> CoCircomCompilerParsed<F> {
         Rep3WitnessExtension::new(
             self,
             network_config,
-            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env()),
+            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env_and_vm_config(&vm_config)?)?,
             vm_config,
         )
     }
@@ -299,7 +334,7 @@ impl<F: PrimeField> CoCircomCompilerParsed<F> {
         Rep3WitnessExtension::from_network(
             self,
             network,
-            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env()),
+            MpcAccelerator::from_config(MpcAcceleratorConfig::from_env_and_vm_config(&vm_config)?)?,
             vm_config,
         )
     }
@@ -1,6 +1,7 @@
 use std::{
     io::{Cursor, Read},
     marker::PhantomData,
+    ops::Range,
 };
 
 use ark_ec::pairing::Pairing;
@@ -106,3 +107,45 @@ where
         std::mem::take(&mut self.sections[id - 1])
     }
 }
+
+/// Computes the byte ranges of the sections of a bin file without copying them. The i-th entry holds the range of the section with id i + 1, or `None` if the section is not present.
+pub(crate) fn section_ranges(bytes: &[u8]) -> ZKeyParserResult<Vec<Option<Range<usize>>>> {
+    tracing::debug!("reading bin file section ranges");
+    let mut reader = bytes;
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    std::str::from_utf8(&magic[..])
+        .map_err(|_| ZKeyParserError::CorruptedBinFile("cannot parse magic number".to_owned()))?;
+    let _version = reader.read_u32::<LittleEndian>()?;
+
+    let num_sections: usize = reader
+        .read_u32::<LittleEndian>()?
+        .try_into()
+        .expect("u32 fits into usize");
+    let mut sections = vec![None; num_sections];
+    for _ in 0..num_sections {
+        let section_id: usize = reader
+            .read_u32::<LittleEndian>()?
+            .try_into()
+            .expect("u32 fits into usize");
+        let section_length: usize = reader
+            .read_u64::<LittleEndian>()?
+            .try_into()
+            .expect("u64 fits into usize");
+        let start = bytes.len() - reader.len();
+        if section_id == 0 || section_id > num_sections || reader.len() < section_length {
+            return Err(ZKeyParserError::CorruptedBinFile(format!(
+                "invalid section {section_id} with length {section_length}"
+            )));
+        }
+        let section = &mut sections[section_id - 1];
+        if section.is_some() {
+            return Err(ZKeyParserError::CorruptedBinFile(format!(
+                "section {section_id} occurs twice"
+            )));
+        }
+        *section = Some(start..start + section_length);
+        reader = &reader[section_length..];
+    }
+    Ok(sections)
+}
@@ -0,0 +1,284 @@
+//! This module defines the [`MmapZKey`] struct, which memory-maps a circom Groth16 zkey file and only deserializes the large point sections of the proving key on demand.
+//!
+//! In contrast to [`ZKey`](super::ZKey), which holds all proving key points in memory, the queries of an [`MmapZKey`] can be read in chunks via [`MmapZKeyQuery::points`], such that an MSM over a query can be computed while only holding one chunk of points in memory at a time.
+use std::{fs::File, ops::Range, path::Path};
+
+use ark_ec::pairing::Pairing;
+use ark_relations::r1cs::ConstraintMatrices;
+use ark_serialize::SerializationError;
+use memmap2::Mmap;
+use rayon::prelude::*;
+
+use crate::{
+    binfile::{self, ZKeyParserError, ZKeyParserResult},
+    traits::{CheckElement, CircomArkworksPairingBridge, CircomArkworksPrimeFieldBridge},
+};
+
+use super::zkey::{HeaderGroth, ZKey};
+
+/// The default number of points deserialized at once when reading an [`MmapZKeyQuery`] in chunks.
+pub const DEFAULT_MMAP_CHUNK_SIZE: usize = 1 << 16;
+
+/// The G1 queries of a Groth16 proving key stored in a zkey file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZKeyG1Query {
+    /// The a_query
+    A,
+    /// The b_query in G1
+    BG1,
+    /// The l_query
+    L,
+    /// The h_query
+    H,
+}
+
+/// Represents a memory-mapped zkey in the format defined by circom. The header, the constraint matrices, and the (small) ic section are deserialized when opening the file, while the a, b, l, and h queries stay on disk and are deserialized on demand via [`MmapZKey::g1_query`] and [`MmapZKey::b_g2_query`].
+pub struct MmapZKey<P: Pairing> {
+    mmap: Mmap,
+    check: CheckElement,
+    chunk_size: usize,
+    /// amount of public inputs
+    pub n_public: usize,
+    /// domain size
+    pub pow: usize,
+    /// beta
+    pub beta_g1: P::G1Affine,
+    /// delta
+    pub delta_g1: P::G1Affine,
+    /// alpha_g1
+    pub alpha_g1: P::G1Affine,
+    /// beta_g1
+    pub beta_g2: P::G2Affine,
+    /// delta_g1
+    pub delta_g2: P::G2Affine,
+    /// Used to bind public inputs to the proof
+    pub ic: Vec<P::G1Affine>,
+    /// The constraint matrices
+    pub matrices: ConstraintMatrices<P::ScalarField>,
+    a_query: Range<usize>,
+    b_g1_query: Range<usize>,
+    b_g2_query: Range<usize>,
+    l_query: Range<usize>,
+    h_query: Range<usize>,
+}
+
+/// A view on a single query of an [`MmapZKey`]. The points are only deserialized when calling [`MmapZKeyQuery::points`].
+pub struct MmapZKeyQuery<'a, A> {
+    bytes: &'a [u8],
+    len: usize,
+    point_size: usize,
+    chunk_size: usize,
+    check: CheckElement,
+    from_bytes: fn(&[u8], CheckElement) -> Result<A, SerializationError>,
+}
+
+impl<A: Send> MmapZKeyQuery<'_, A> {
+    /// Returns the number of points in this query.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if this query contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of points that should be deserialized at once when iterating over this query in chunks.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Deserializes the points in the given `range` of this query.
+    pub fn points(&self, range: Range<usize>) -> ZKeyParserResult<Vec<A>> {
+        if range.start > range.end || range.end > self.len {
+            return Err(ZKeyParserError::CorruptedBinFile(format!(
+                "point range {range:?} out of bounds for query of length {}",
+                self.len
+            )));
+        }
+        let point_size = self.point_size;
+        let check = self.check;
+        let from_bytes = self.from_bytes;
+        Ok(self.bytes[range.start * point_size..range.end * point_size]
+            .par_chunks_exact(point_size)
+            .map(|chunk| from_bytes(chunk, check))
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+}
+
+impl<P: Pairing + CircomArkworksPairingBridge> MmapZKey<P>
+where
+    P::BaseField: CircomArkworksPrimeFieldBridge,
+    P::ScalarField: CircomArkworksPrimeFieldBridge,
+{
+    /// Memory-maps the zkey file at the given path.
+    ///
+    /// The `check` parameter specifies whether the points should be checked when they are deserialized, see [`ZKey::from_reader`] and [`CheckElement`].
+    ///
+    /// The file must not be modified while the returned [`MmapZKey`] is alive.
+    pub fn open(path: impl AsRef<Path>, check: CheckElement) -> ZKeyParserResult<Self> {
+        let file = File::open(path)?;
+        // SAFETY: The zkey file is treated as read-only input and is not modified by us. Modifying it from another process while the map is alive is documented as a precondition of this function.
+        let mmap = unsafe { Mmap::map(&file)? };
+        Self::from_mmap(mmap, check)
+    }
+
+    fn from_mmap(mmap: Mmap, check: CheckElement) -> ZKeyParserResult<Self> {
+        tracing::debug!("start memory-mapping zkey...");
+        let sections = binfile::section_ranges(&mmap)?;
+        let section =
+            |id: usize| {
+                sections.get(id - 1).cloned().flatten().ok_or_else(|| {
+                    ZKeyParserError::CorruptedBinFile(format!("missing section {id}"))
+                })
+            };
+
+        let header = HeaderGroth::<P>::read(&mut &mmap[section(2)?], check)?;
+        let n_vars = header.n_vars;
+        let n_public = header.n_public;
+        let domain_size = usize::try_from(header.domain_size).expect("fits into usize");
+
+        let ic = P::g1_vec_from_reader(&mmap[section(3)?], n_public + 1, check)?;
+        let (num_constraints, a, b) =
+            ZKey::<P>::constraint_matrices(domain_size, n_public, &mmap[section(4)?])?;
+
+        let g1_size = P::G1_SERIALIZED_BYTE_SIZE_UNCOMPRESSED;
+        let g2_size = P::G2_SERIALIZED_BYTE_SIZE_UNCOMPRESSED;
+        let query = |id: usize, len: usize, point_size: usize| {
+            let range = section(id)?;
+            if range.len() < len * point_size {
+                return Err(ZKeyParserError::CorruptedBinFile(format!(
+                    "section {id} too short. Expected {} bytes but got {}",
+                    len * point_size,
+                    range.len()
+                )));
+            }
+            Ok(range.start..range.start + len * point_size)
+        };
+        let a_query = query(5, n_vars, g1_size)?;
+        let b_g1_query = query(6, n_vars, g1_size)?;
+        let b_g2_query = query(7, n_vars, g2_size)?;
+        let l_query = query(8, n_vars - n_public - 1, g1_size)?;
+        let h_query = query(9, domain_size, g1_size)?;
+
+        tracing::debug!("groth16 zkey memory-mapping done!");
+        Ok(Self {
+            mmap,
+            check,
+            chunk_size: DEFAULT_MMAP_CHUNK_SIZE,
+            n_public,
+            pow: header.pow,
+            beta_g1: header.beta_g1,
+            delta_g1: header.delta_g1,
+            alpha_g1: header.alpha_g1,
+            beta_g2: header.beta_g2,
+            delta_g2: header.delta_g2,
+            ic,
+            matrices: ConstraintMatrices {
+                num_instance_variables: n_public + 1,
+                num_witness_variables: n_vars,
+                num_constraints,
+                a_num_non_zero: a.len(),
+                b_num_non_zero: b.len(),
+                c_num_non_zero: 0,
+                a,
+                b,
+                c: vec![],
+            },
+            a_query,
+            b_g1_query,
+            b_g2_query,
+            l_query,
+            h_query,
+        })
+    }
+
+    /// Sets the number of points that are deserialized at once when iterating over a query in chunks. Defaults to [`DEFAULT_MMAP_CHUNK_SIZE`].
+    pub fn set_chunk_size(&mut self, chunk_size: usize) {
+        self.chunk_size = chunk_size.max(1);
+    }
+
+    /// Returns a view on the requested G1 query.
+    pub fn g1_query(&self, query: ZKeyG1Query) -> MmapZKeyQuery<'_, P::G1Affine> {
+        let range = match query {
+            ZKeyG1Query::A => &self.a_query,
+            ZKeyG1Query::BG1 => &self.b_g1_query,
+            ZKeyG1Query::L => &self.l_query,
+            ZKeyG1Query::H => &self.h_query,
+        };
+        MmapZKeyQuery {
+            bytes: &self.mmap[range.clone()],
+            len: range.len() / P::G1_SERIALIZED_BYTE_SIZE_UNCOMPRESSED,
+            point_size: P::G1_SERIALIZED_BYTE_SIZE_UNCOMPRESSED,
+            chunk_size: self.chunk_size,
+            check: self.check,
+            from_bytes: P::g1_from_bytes,
+        }
+    }
+
+    /// Returns a view on the b_query in G2.
+    pub fn b_g2_query(&self) -> MmapZKeyQuery<'_, P::G2Affine> {
+        MmapZKeyQuery {
+            bytes: &self.mmap[self.b_g2_query.clone()],
+            len: self.b_g2_query.len() / P::G2_SERIALIZED_BYTE_SIZE_UNCOMPRESSED,
+            point_size: P::G2_SERIALIZED_BYTE_SIZE_UNCOMPRESSED,
+            chunk_size: self.chunk_size,
+            check: self.check,
+            from_bytes: P::g2_from_bytes,
+        }
+    }
+
+    /// Returns the number of constraints.
+    pub fn num_constraints(&self) -> usize {
+        self.matrices.num_constraints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Bn254;
+
+    #[test]
+    fn mmap_zkey_matches_zkey() {
+        let path = "../../test_vectors/Groth16/bn254/multiplier2/circuit.zkey";
+        for check in [CheckElement::Yes, CheckElement::No] {
+            let zkey = ZKey::<Bn254>::from_reader(File::open(path).unwrap(), check).unwrap();
+            let mut mmap_zkey = MmapZKey::<Bn254>::open(path, check).unwrap();
+            // use a tiny chunk size to exercise the chunked reading
+            mmap_zkey.set_chunk_size(1);
+
+            assert_eq!(mmap_zkey.n_public, zkey.n_public);
+            assert_eq!(mmap_zkey.pow, zkey.pow);
+            assert_eq!(mmap_zkey.num_constraints(), zkey.num_constraints);
+            assert_eq!(mmap_zkey.alpha_g1, zkey.alpha_g1);
+            assert_eq!(mmap_zkey.beta_g1, zkey.beta_g1);
+            assert_eq!(mmap_zkey.beta_g2, zkey.beta_g2);
+            assert_eq!(mmap_zkey.delta_g1, zkey.delta_g1);
+            assert_eq!(mmap_zkey.delta_g2, zkey.delta_g2);
+            assert_eq!(mmap_zkey.ic, zkey.ic);
+            assert_eq!(mmap_zkey.matrices.a, zkey.a_matrix);
+            assert_eq!(mmap_zkey.matrices.b, zkey.b_matrix);
+
+            for (query, should) in [
+                (ZKeyG1Query::A, &zkey.a_query),
+                (ZKeyG1Query::BG1, &zkey.b_g1_query),
+                (ZKeyG1Query::L, &zkey.l_query),
+                (ZKeyG1Query::H, &zkey.h_query),
+            ] {
+                let query = mmap_zkey.g1_query(query);
+                assert_eq!(query.len(), should.len());
+                let chunked = (0..query.len())
+                    .flat_map(|i| query.points(i..i + 1).unwrap())
+                    .collect::<Vec<_>>();
+                assert_eq!(&chunked, should);
+                assert!(query.points(0..query.len() + 1).is_err());
+            }
+            let b_g2_query = mmap_zkey.b_g2_query();
+            assert_eq!(
+                b_g2_query.points(0..b_g2_query.len()).unwrap(),
+                zkey.b_g2_query
+            );
+        }
+    }
+}
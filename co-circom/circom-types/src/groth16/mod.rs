@@ -1,9 +1,11 @@
 //! This module defines types related to Groth16 used in circom and utilities to read these types from files.
+mod mmap_zkey;
 mod proof;
 mod public_input;
 mod verification_key;
 mod zkey;
 
+pub use mmap_zkey::{DEFAULT_MMAP_CHUNK_SIZE, MmapZKey, MmapZKeyQuery, ZKeyG1Query};
 pub use proof::CircomGroth16Proof;
 pub use public_input::JsonPublicInput;
 pub use verification_key::JsonVerificationKey;
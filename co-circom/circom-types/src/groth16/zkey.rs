@@ -82,7 +82,7 @@ pub struct ZKey<P: Pairing> {
 }
 
 /// A constraint matrix used in Groth16.
-type ConstraintMatrixAB<F> = (usize, Matrix<F>, Matrix<F>);
+pub(super) type ConstraintMatrixAB<F> = (usize, Matrix<F>, Matrix<F>);
 
 /// Note: The returned ProvingKey is only suitable for proving, the included VerificationKey is not complete due to elements missing in the zkey.
 impl<P: Pairing> From<ZKey<P>> for (ConstraintMatrices<P::ScalarField>, ProvingKey<P>) {
@@ -120,16 +120,16 @@ impl<P: Pairing> From<ZKey<P>> for (ConstraintMatrices<P::ScalarField>, ProvingK
 }
 
 #[derive(Clone, Debug)]
-struct HeaderGroth<P: Pairing> {
-    n_vars: usize,
-    n_public: usize,
-    domain_size: u32,
-    pow: usize,
-    alpha_g1: P::G1Affine,
-    beta_g1: P::G1Affine,
-    beta_g2: P::G2Affine,
-    delta_g1: P::G1Affine,
-    delta_g2: P::G2Affine,
+pub(super) struct HeaderGroth<P: Pairing> {
+    pub(super) n_vars: usize,
+    pub(super) n_public: usize,
+    pub(super) domain_size: u32,
+    pub(super) pow: usize,
+    pub(super) alpha_g1: P::G1Affine,
+    pub(super) beta_g1: P::G1Affine,
+    pub(super) beta_g2: P::G2Affine,
+    pub(super) delta_g1: P::G1Affine,
+    pub(super) delta_g2: P::G2Affine,
 }
 
 impl<P: Pairing + CircomArkworksPairingBridge> ZKey<P>
@@ -265,7 +265,7 @@ where
         Ok(P::g1_vec_from_reader(reader, n_vars, check)?)
     }
 
-    fn constraint_matrices<R: Read>(
+    pub(super) fn constraint_matrices<R: Read>(
         domain_size: usize,
         n_public: usize,
         mut matrices_section: R,
@@ -304,7 +304,7 @@ where
     P::BaseField: CircomArkworksPrimeFieldBridge,
     P::ScalarField: CircomArkworksPrimeFieldBridge,
 {
-    fn read<R: Read>(mut reader: &mut R, check: CheckElement) -> ZKeyParserResult<Self> {
+    pub(super) fn read<R: Read>(mut reader: &mut R, check: CheckElement) -> ZKeyParserResult<Self> {
         tracing::debug!("reading groth16 header..");
         let n8q: u32 = u32::deserialize_uncompressed(&mut reader)?;
         //modulus of BaseField
@@ -3,19 +3,25 @@
 //! this is [Groth16](https://eprint.iacr.org/2016/260.pdf) and [PLONK](https://eprint.iacr.org/2019/953.pdf).
 
 use ark_ff::PrimeField;
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use circom_types::Witness;
 use core::panic;
 use eyre::{Context, ContextCompat};
-use mpc_types::protocols::rep3::{self, Rep3PrimeFieldShare, Rep3ShareVecType};
-use mpc_types::protocols::shamir::{self, ShamirPrimeFieldShare};
+use mpc_types::input_provenance::{
+    ClientSigningKey, InputCoverage, InputNames, InputPolicy, InputProvenance,
+};
+use mpc_types::protocols::rep3::vss::{self, Rep3Components, Rep3InputCommitment};
+use mpc_types::protocols::rep3::{self, Rep3PrimeFieldShare, Rep3ShareVecType, id::PartyID};
+use mpc_types::protocols::rep3_ring::{Rep3TypedRingShare, RingType, typed};
+use mpc_types::protocols::shamir::{self, ShamirPrimeFieldShare, ShamirShareVecType};
 use mpc_types::serde_compat::{ark_de, ark_se};
 use num_bigint::BigUint;
-use num_traits::Num;
+use num_traits::{Num, ToPrimitive};
 use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::error::Error;
+use std::io::Write;
 
 /// A shorthand type for batched inputs. Should be used with the batched
 /// witness extension
@@ -58,6 +64,8 @@ pub struct CompressedRep3SharedWitness<F: PrimeField> {
     pub public_inputs: Vec<F>,
     /// The secret-shared witness elements.
     pub witness: Rep3ShareVecType<F>,
+    /// The integer inputs in their ring form, as they were provided before being cast to field elements for the witness extension.
+    pub ring_inputs: BTreeMap<String, Vec<Rep3TypedRingShare>>,
 }
 
 impl<F: PrimeField> From<Rep3SharedWitness<F>> for CompressedRep3SharedWitness<F> {
@@ -65,6 +73,37 @@ impl<F: PrimeField> From<Rep3SharedWitness<F>> for CompressedRep3SharedWitness<F
         Self {
             public_inputs: value.public_inputs,
             witness: Rep3ShareVecType::Replicated(value.witness),
+            ring_inputs: BTreeMap::new(),
+        }
+    }
+}
+
+/// This type represents the serialized version of a Shamir witness. Its share can be either fully expanded or compressed to a seed.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CompressedShamirSharedWitness<F: PrimeField> {
+    /// The public inputs (which are the outputs of the circom circuit).
+    /// This also includes the constant 1 at position 0.
+    #[serde(serialize_with = "ark_se", deserialize_with = "ark_de")]
+    pub public_inputs: Vec<F>,
+    /// The secret-shared witness elements.
+    pub witness: ShamirShareVecType<F>,
+}
+
+impl<F: PrimeField> From<ShamirSharedWitness<F>> for CompressedShamirSharedWitness<F> {
+    fn from(value: ShamirSharedWitness<F>) -> Self {
+        Self {
+            public_inputs: value.public_inputs,
+            witness: ShamirShareVecType::Shares(value.witness),
+        }
+    }
+}
+
+impl<F: PrimeField> From<CompressedShamirSharedWitness<F>> for ShamirSharedWitness<F> {
+    fn from(value: CompressedShamirSharedWitness<F>) -> Self {
+        SharedWitness {
+            public_inputs: value.public_inputs,
+            witness: value.witness.expand_vec(),
         }
     }
 }
@@ -131,6 +170,10 @@ where
             if !shared_input.maybe_shared_inputs.is_empty() {
                 eyre::bail!("Cannot build batched input if there are still maybe shares")
             }
+            if !shared_input.ring_inputs.is_empty() {
+                eyre::bail!("Cannot build batched input if there are still integer inputs")
+            }
+            // the commitments and signatures need to be verified before batching, as the shares are regrouped
         }
 
         // check that all values have same batch len
@@ -152,6 +195,9 @@ where
             public_inputs,
             shared_inputs,
             maybe_shared_inputs: BTreeMap::new(),
+            ring_inputs: BTreeMap::new(),
+            commitments: Vec::new(),
+            provenance: Vec::new(),
         })
     }
 }
@@ -246,6 +292,15 @@ where
     /// A map from variable names to vecs with maybe unknown elements that need to be merged.
     /// This is a BTreeMap because it implements Canonical(De)Serialize.
     pub maybe_shared_inputs: BTreeMap<String, Vec<Option<S>>>,
+    /// A map from variable names to the REP3 shares of integers, which are kept in their ring until they are cast to field elements before the witness extension.
+    #[serde(default)]
+    pub ring_inputs: BTreeMap<String, Vec<Rep3TypedRingShare>>,
+    /// The commitments of the dealers to their shares, which allow the parties to verify that the shares are consistent (see [`vss`]).
+    #[serde(default)]
+    pub commitments: Vec<Rep3InputCommitment>,
+    /// The signatures of the clients which provided the inputs (see [`mpc_types::input_provenance`]).
+    #[serde(default)]
+    pub provenance: Vec<InputProvenance>,
 }
 
 impl<F: PrimeField, S> SharedInput<F, S>
@@ -262,14 +317,38 @@ where
         self.shared_inputs.insert(key, elements);
     }
 
+    /// Adds a shared integer input with a given name to the [SharedInput].
+    pub fn add_ring_input(&mut self, key: String, elements: Vec<Rep3TypedRingShare>) {
+        self.ring_inputs.insert(key, elements);
+    }
+
     /// Merges two [SharedInput]s into one, performing basic sanity checks.
     pub fn merge(self, other: Self) -> eyre::Result<Self> {
         let mut shared_inputs = self.shared_inputs;
-        let public_inputs = self.public_inputs;
+        let mut public_inputs = self.public_inputs;
         let maybe_shared_inputs = self.maybe_shared_inputs;
+        let mut ring_inputs = self.ring_inputs;
+        let mut commitments = self.commitments;
+        commitments.extend(other.commitments);
+        let mut provenance = self.provenance;
+        provenance.extend(other.provenance);
 
+        for (key, value) in other.ring_inputs {
+            if ring_inputs.contains_key(&key)
+                || shared_inputs.contains_key(&key)
+                || other.shared_inputs.contains_key(&key)
+            {
+                eyre::bail!("Input with name {} present in multiple input shares", key);
+            }
+            if public_inputs.contains_key(&key) || other.public_inputs.contains_key(&key) {
+                eyre::bail!(
+                    "Input name is once in shared inputs and once in public inputs: \"{key}\""
+                );
+            }
+            ring_inputs.insert(key, value);
+        }
         for (key, value) in other.shared_inputs {
-            if shared_inputs.contains_key(&key) {
+            if shared_inputs.contains_key(&key) || ring_inputs.contains_key(&key) {
                 eyre::bail!("Input with name {} present in multiple input shares", key);
             }
             if public_inputs.contains_key(&key) || other.public_inputs.contains_key(&key) {
@@ -280,11 +359,19 @@ where
             shared_inputs.insert(key, value);
         }
         for (key, value) in other.public_inputs {
-            if !public_inputs.contains_key(&key) {
-                eyre::bail!("Public input \"{key}\" must be present in all files");
+            if shared_inputs.contains_key(&key) || ring_inputs.contains_key(&key) {
+                eyre::bail!(
+                    "Input name is once in shared inputs and once in public inputs: \"{key}\""
+                );
             }
-            if public_inputs.get(&key).expect("is there we checked") != &value {
-                eyre::bail!("Public input \"{key}\" must be same in all files");
+            match public_inputs.get(&key) {
+                Some(existing) if existing != &value => {
+                    eyre::bail!("Public input \"{key}\" must be same in all files");
+                }
+                Some(_) => {}
+                None => {
+                    public_inputs.insert(key, value);
+                }
             }
         }
 
@@ -320,6 +407,9 @@ where
             shared_inputs,
             public_inputs,
             maybe_shared_inputs: merged_maybe_shared_inputs,
+            ring_inputs,
+            commitments,
+            provenance,
         })
     }
 }
@@ -340,9 +430,156 @@ impl<F: PrimeField> SharedInput<F, Rep3PrimeFieldShare<F>> {
     ) -> [Vec<Option<Rep3PrimeFieldShare<F>>>; 3] {
         rep3::share_maybe_field_elements(input, rng)
     }
+
+    /// Returns the elements of the inputs covered by a commitment or signature, in the order in which they are committed.
+    fn committed_elements(&self, inputs: &InputNames) -> eyre::Result<Vec<InputElement<'_, F>>> {
+        fn select<'a, T, F>(
+            name: &str,
+            vals: &'a [T],
+            indices: &Option<Vec<usize>>,
+            element: impl Fn(&'a T) -> InputElement<'a, F>,
+            elements: &mut Vec<InputElement<'a, F>>,
+        ) -> eyre::Result<()> {
+            match indices {
+                Some(indices) => {
+                    for i in indices {
+                        let val = vals.get(*i).with_context(|| {
+                            format!("Committed element {i} of input \"{name}\" is missing")
+                        })?;
+                        elements.push(element(val));
+                    }
+                }
+                None => elements.extend(vals.iter().map(element)),
+            }
+            Ok(())
+        }
+
+        let mut elements = Vec::new();
+        for (name, indices) in inputs {
+            if let Some(vals) = self.shared_inputs.get(name) {
+                select(name, vals, indices, InputElement::shared, &mut elements)?;
+            } else if let Some(vals) = self.ring_inputs.get(name) {
+                select(name, vals, indices, InputElement::shared, &mut elements)?;
+            } else if let Some(vals) = self.public_inputs.get(name) {
+                select(name, vals, indices, InputElement::Public, &mut elements)?;
+            } else if let Some(vals) = self.maybe_shared_inputs.get(name) {
+                let indices = indices.as_ref().with_context(|| {
+                    format!("Input \"{name}\" with unknown elements is committed as a whole")
+                })?;
+                for i in indices {
+                    let val = vals.get(*i).and_then(Option::as_ref).with_context(|| {
+                        format!("Committed element {i} of input \"{name}\" is missing")
+                    })?;
+                    elements.push(InputElement::shared(val));
+                }
+            } else {
+                eyre::bail!("Committed input \"{name}\" is missing");
+            }
+        }
+        Ok(elements)
+    }
+
+    /// Returns the number of elements of an input.
+    fn input_len(&self, name: &str) -> Option<usize> {
+        self.shared_inputs
+            .get(name)
+            .map(Vec::len)
+            .or_else(|| self.ring_inputs.get(name).map(Vec::len))
+            .or_else(|| self.public_inputs.get(name).map(Vec::len))
+            .or_else(|| self.maybe_shared_inputs.get(name).map(Vec::len))
+    }
+
+    /// Returns the elements of the inputs covered by the commitments, failing if an element is committed by multiple dealers.
+    fn commitment_coverage(&self) -> eyre::Result<InputCoverage> {
+        let mut coverage = InputCoverage::default();
+        for commitment in self.commitments.iter() {
+            coverage.add_commitment(commitment, |name| self.input_len(name))?;
+        }
+        Ok(coverage)
+    }
+
+    /// Verifies the shares of party `id` against the commitments of the dealers, and checks that no element is committed by multiple dealers.
+    ///
+    /// Elements which are not committed at all are not checked, see [check_commitment_coverage](Self::check_commitment_coverage) for this. This only detects an inconsistent dealer if all parties received the same commitments, which needs to be checked by comparing [`vss::commitments_digest`] of the commitments with the other parties.
+    pub fn verify_commitments(&self, id: PartyID) -> eyre::Result<()> {
+        for (idx, commitment) in self.commitments.iter().enumerate() {
+            let shares = self.committed_elements(&commitment.inputs)?;
+            commitment
+                .verify(&shares, id)
+                .with_context(|| format!("while verifying input commitment {idx}"))?;
+        }
+        self.commitment_coverage()?;
+        Ok(())
+    }
+
+    /// Checks that every shared element, i.e., all elements of the shared and integer inputs and the known elements of the inputs with unknown elements, is committed by exactly one dealer.
+    pub fn check_commitment_coverage(&self) -> eyre::Result<()> {
+        let coverage = self.commitment_coverage()?;
+        for (name, vals) in self.shared_inputs.iter() {
+            coverage.check_committed(name, 0..vals.len())?;
+        }
+        for (name, vals) in self.ring_inputs.iter() {
+            coverage.check_committed(name, 0..vals.len())?;
+        }
+        for (name, vals) in self.maybe_shared_inputs.iter() {
+            let indices = vals
+                .iter()
+                .enumerate()
+                .filter_map(|(i, val)| val.as_ref().map(|_| i));
+            coverage.check_committed(name, indices)?;
+        }
+        Ok(())
+    }
+
+    /// Verifies that all inputs of party `id`, including the public ones, are signed by exactly one client for the circuit with the given hash, and that each client only provided the inputs it is allowed to by the `policy`.
+    pub fn verify_provenance(
+        &self,
+        policy: &InputPolicy,
+        id: PartyID,
+        circuit_hash: Option<[u8; 32]>,
+    ) -> eyre::Result<()> {
+        let mut coverage = InputCoverage::default();
+        for provenance in self.provenance.iter() {
+            let key = policy.check(provenance)?;
+            let elements = self.committed_elements(&provenance.inputs)?;
+            provenance
+                .verify(&elements, id, circuit_hash, key)
+                .with_context(|| {
+                    format!("while verifying inputs of client \"{}\"", provenance.client)
+                })?;
+            coverage.add(provenance, |name| self.input_len(name))?;
+        }
+
+        for (name, vals) in self.public_inputs.iter() {
+            coverage.check(name, 0..vals.len())?;
+        }
+        for (name, vals) in self.shared_inputs.iter() {
+            coverage.check(name, 0..vals.len())?;
+        }
+        for (name, vals) in self.ring_inputs.iter() {
+            coverage.check(name, 0..vals.len())?;
+        }
+        for (name, vals) in self.maybe_shared_inputs.iter() {
+            let indices = vals
+                .iter()
+                .enumerate()
+                .filter_map(|(i, val)| val.as_ref().map(|_| i));
+            coverage.check(name, indices)?;
+        }
+        Ok(())
+    }
 }
 
 impl<F: PrimeField> CompressedRep3SharedWitness<F> {
+    /// Attaches the ring shares of the integer inputs to the witness share.
+    pub fn with_ring_inputs(
+        mut self,
+        ring_inputs: BTreeMap<String, Vec<Rep3TypedRingShare>>,
+    ) -> Self {
+        self.ring_inputs = ring_inputs;
+        self
+    }
+
     /// Shares a given witness and public input vector using the Rep3 protocol.
     pub fn share_rep3<R: Rng + CryptoRng>(
         witness: Witness<F>,
@@ -388,14 +625,17 @@ impl<F: PrimeField> CompressedRep3SharedWitness<F> {
         let witness1 = Self {
             public_inputs: public_inputs.to_vec(),
             witness: share1,
+            ring_inputs: BTreeMap::new(),
         };
         let witness2 = Self {
             public_inputs: public_inputs.to_vec(),
             witness: share2,
+            ring_inputs: BTreeMap::new(),
         };
         let witness3 = Self {
             public_inputs: public_inputs.to_vec(),
             witness: share3,
+            ring_inputs: BTreeMap::new(),
         };
         [witness1, witness2, witness3]
     }
@@ -449,13 +689,47 @@ impl<F: PrimeField> SharedWitness<F, ShamirPrimeFieldShare<F>> {
     }
 }
 
+impl<F: PrimeField> CompressedShamirSharedWitness<F> {
+    /// Shares a given witness and public input vector using the Shamir protocol. If `seeded` is set, the shares of `degree` parties are compressed to seeds (see [`shamir::share_field_elements_seeded`]).
+    pub fn share_shamir<R: Rng + CryptoRng>(
+        witness: Witness<F>,
+        num_pub_inputs: usize,
+        degree: usize,
+        num_parties: usize,
+        rng: &mut R,
+        seeded: bool,
+    ) -> Vec<Self> {
+        let public_inputs = &witness.values[..num_pub_inputs];
+        let witness = &witness.values[num_pub_inputs..];
+        let shares = if seeded {
+            shamir::share_field_elements_seeded(witness, degree, num_parties, rng)
+                .into_iter()
+                .map(ShamirShareVecType::Seeded)
+                .collect::<Vec<_>>()
+        } else {
+            shamir::share_field_elements(witness, degree, num_parties, rng)
+                .into_iter()
+                .map(ShamirShareVecType::Shares)
+                .collect::<Vec<_>>()
+        };
+        shares
+            .into_iter()
+            .map(|share| Self {
+                public_inputs: public_inputs.to_vec(),
+                witness: share,
+            })
+            .collect()
+    }
+}
+
 /// A JSON map of input names and values
 pub type Input = serde_json::Map<String, serde_json::Value>;
 
-/// Splits the input into REP3 shares
+/// Splits the input into REP3 shares. The inputs in `ring_inputs` are shared as integers in the given ring instead of as field elements.
 pub fn split_input<F: PrimeField>(
     input: Input,
     public_inputs: &[String],
+    ring_inputs: &BTreeMap<String, RingType>,
 ) -> eyre::Result<[Rep3SharedInput<F>; 3]> {
     // create input shares
     let mut shares = [
@@ -485,6 +759,12 @@ pub fn split_input<F: PrimeField>(
                 .public_inputs
                 .insert(name.clone(), parsed_vals.clone());
             shares[2].public_inputs.insert(name.clone(), parsed_vals);
+        } else if let Some(ring_type) = ring_inputs.get(&name) {
+            let [share0, share1, share2] =
+                share_ring_input(&name, parsed_vals, *ring_type, &mut rng)?;
+            shares[0].ring_inputs.insert(name.clone(), share0);
+            shares[1].ring_inputs.insert(name.clone(), share1);
+            shares[2].ring_inputs.insert(name.clone(), share2);
         } else {
             // if all elements are Some, then we can share normally
             // else we can only share as Vec<Option<T>> and we have to merge unknown inputs later
@@ -509,6 +789,126 @@ pub fn split_input<F: PrimeField>(
     Ok(shares)
 }
 
+/// An element of an input covered by a commitment or signature.
+enum InputElement<'a, F> {
+    /// A public value, which is the same for all parties.
+    Public(&'a F),
+    /// A share of a secret value.
+    Shared(&'a dyn Rep3Components),
+}
+
+impl<'a, F> InputElement<'a, F> {
+    fn shared<S: Rep3Components>(share: &'a S) -> Self {
+        Self::Shared(share)
+    }
+}
+
+impl<F: PrimeField> Rep3Components for InputElement<'_, F> {
+    fn serialize_a(&self, writer: &mut dyn Write) -> Result<(), SerializationError> {
+        match self {
+            InputElement::Public(val) => val.serialize_compressed(writer),
+            InputElement::Shared(share) => share.serialize_a(writer),
+        }
+    }
+
+    fn serialize_b(&self, writer: &mut dyn Write) -> Result<(), SerializationError> {
+        match self {
+            InputElement::Public(val) => val.serialize_compressed(writer),
+            InputElement::Shared(share) => share.serialize_b(writer),
+        }
+    }
+}
+
+/// Commits to all shared inputs of the input shares produced by [split_input], so that the parties can verify that they received consistent shares (see [`vss`]). The commitment for each party is stored in its input share.
+pub fn commit_input_shares<F: PrimeField, R: Rng + CryptoRng>(
+    shares: &mut [Rep3SharedInput<F>; 3],
+    rng: &mut R,
+) -> eyre::Result<()> {
+    let inputs = shared_input_names(&shares[0]);
+    let [share0, share1, share2] = &*shares;
+    let committed0 = share0.committed_elements(&inputs)?;
+    let committed1 = share1.committed_elements(&inputs)?;
+    let committed2 = share2.committed_elements(&inputs)?;
+    let commitments = vss::commit(inputs, [&committed0, &committed1, &committed2], rng)?;
+    for (share, commitment) in shares.iter_mut().zip(commitments) {
+        share.commitments.push(commitment);
+    }
+    Ok(())
+}
+
+/// Returns the names of all shared inputs of an input share, where only the known elements of inputs with unknown elements are given.
+fn shared_input_names<F: PrimeField>(share: &Rep3SharedInput<F>) -> InputNames {
+    let mut inputs = BTreeMap::new();
+    for name in share.shared_inputs.keys().chain(share.ring_inputs.keys()) {
+        inputs.insert(name.to_owned(), None);
+    }
+    for (name, vals) in share.maybe_shared_inputs.iter() {
+        let indices = vals
+            .iter()
+            .enumerate()
+            .filter_map(|(i, val)| val.as_ref().map(|_| i))
+            .collect();
+        inputs.insert(name.to_owned(), Some(indices));
+    }
+    inputs
+}
+
+/// Signs all inputs of the input shares produced by [split_input], including the public ones, with the key of the `client` for the circuit with the given hash, so that the parties can check that the client is allowed to provide them (see [`mpc_types::input_provenance`]). The signature for each party is stored in its input share.
+pub fn sign_input_shares<F: PrimeField>(
+    shares: &mut [Rep3SharedInput<F>; 3],
+    client: &str,
+    circuit_hash: Option<[u8; 32]>,
+    key: &ClientSigningKey,
+) -> eyre::Result<()> {
+    let mut inputs = shared_input_names(&shares[0]);
+    for name in shares[0].public_inputs.keys() {
+        inputs.insert(name.to_owned(), None);
+    }
+    for (id, share) in [PartyID::ID0, PartyID::ID1, PartyID::ID2]
+        .into_iter()
+        .zip(shares.iter_mut())
+    {
+        let signed = share.committed_elements(&inputs)?;
+        let provenance = InputProvenance::sign(
+            client.to_owned(),
+            inputs.clone(),
+            &signed,
+            id,
+            circuit_hash,
+            key,
+        )?;
+        share.provenance.push(provenance);
+    }
+    Ok(())
+}
+
+/// Shares the values of an integer input in the given ring.
+fn share_ring_input<F: PrimeField, R: Rng + CryptoRng>(
+    name: &str,
+    vals: Vec<Option<F>>,
+    ring_type: RingType,
+    rng: &mut R,
+) -> eyre::Result<[Vec<Rep3TypedRingShare>; 3]> {
+    let mut shares = [
+        Vec::with_capacity(vals.len()),
+        Vec::with_capacity(vals.len()),
+        Vec::with_capacity(vals.len()),
+    ];
+    for val in vals {
+        let val = val.with_context(|| format!("Integer input \"{name}\" must not be unknown"))?;
+        let val: BigUint = val.into();
+        let val = val
+            .to_u128()
+            .with_context(|| format!("Integer input \"{name}\" does not fit into {ring_type}"))?;
+        let ring_shares = typed::share_typed_ring_element(val, ring_type, rng)
+            .with_context(|| format!("while sharing integer input \"{name}\""))?;
+        for (shares, share) in shares.iter_mut().zip(ring_shares) {
+            shares.push(share);
+        }
+    }
+    Ok(shares)
+}
+
 /// Merge multiple REP3 shared inputs into one
 pub fn merge_input_shares<F: PrimeField>(
     mut inputs: Vec<Rep3SharedInput<F>>,
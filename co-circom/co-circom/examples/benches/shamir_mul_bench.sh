@@ -0,0 +1,3 @@
+cargo run --release --bin shamir_mul_bench -- --config ../configs/party1.toml &
+cargo run --release --bin shamir_mul_bench -- --config ../configs/party2.toml &
+cargo run --release --bin shamir_mul_bench -- --config ../configs/party3.toml
@@ -6,7 +6,7 @@ use co_circom::{
 use co_groth16::CircomReduction;
 use color_eyre::Result;
 use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf};
 use tracing_subscriber::{
     EnvFilter,
     fmt::{self, format::FmtSpan},
@@ -67,7 +67,11 @@ fn main() -> Result<()> {
     let mut input = Input::new();
     input.insert("a".to_string(), Value::String("2".to_string()));
     input.insert("b".to_string(), Value::String("3".to_string()));
-    let [share0, share1, share2] = co_circom::split_input::<Bn254>(input, circuit.public_inputs())?;
+    let mut shares =
+        co_circom::split_input::<Bn254>(input, circuit.public_inputs(), &BTreeMap::new())?;
+    // commit to the shares, so that the parties can check that they received consistent shares
+    co_circom::commit_input_shares(&mut shares)?;
+    let [share0, share1, share2] = shares;
 
     // parse zkey, without performing extra checks (only advised for zkeys knwon to be valid)
     let zkey = Groth16ZKey::<Bn254>::from_reader(
@@ -1,23 +1,30 @@
 use circom_types::traits::CheckElement;
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use co_circom::{
-    Bls12_381, Bn254, CircomArkworksPairingBridge, CircomArkworksPrimeFieldBridge,
-    CircomGroth16Proof, CoCircomCompiler, CompilerConfig, Compression, Groth16,
-    Groth16JsonVerificationKey, Groth16ZKey, Pairing, Plonk, PlonkJsonVerificationKey, PlonkProof,
-    PlonkZKey, R1CS, Rep3CoGroth16, Rep3CoPlonk, Rep3MpcNet, Rep3SharedInput, ShamirCoGroth16,
+    Bls12_377, Bls12_381, Bn254, CircomArkworksPairingBridge, CircomArkworksPrimeFieldBridge,
+    CircomGroth16Proof, CoCircomCompiler, CompilerConfig, CompressedShamirSharedWitness,
+    Compression, Groth16, Groth16JsonVerificationKey, Groth16MmapZKey, Groth16ZKey, NetworkConfig,
+    Pairing, Plonk, PlonkJsonVerificationKey, PlonkProof, PlonkZKey, ProvingKeySource, R1CS,
+    Rep3CoGroth16, Rep3CoPlonk, Rep3MpcNet, Rep3SharedInput, RingType, ShamirCoGroth16,
     ShamirCoPlonk, ShamirMpcNet, ShamirSharedWitness, SimplificationLevel, VMConfig, Witness,
 };
 use co_circom_types::{CompressedRep3SharedWitness, VerificationError};
-use co_groth16::CircomReduction;
+use co_groth16::{CircomReduction, ConstraintMatrices, Proof};
 use color_eyre::eyre::{self, Context, ContextCompat, eyre};
 use figment::{
     Figment,
     providers::{Env, Format, Serialized, Toml},
 };
-use mpc_net::config::NetworkConfigFile;
+use mpc_net::config::{NetworkConfigFile, NetworkPartyConfig};
+use mpc_types::input_provenance::{self, InputPolicy, InputPolicyConfig};
+use mpc_types::share_file::{
+    self, ExpectedShareFile, ShareCompression, ShareCurve, ShareFileHeader, ShareKind,
+    ShareProtocol, SharePublicKey, ShareSecretKey,
+};
 use num_traits::Zero;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     fs::File,
     io::{BufReader, BufWriter},
     path::PathBuf,
@@ -53,17 +60,31 @@ pub enum Curve {
     BN254,
     /// The BLS12_381 curve.
     BLS12_381,
+    /// The BLS12_377 curve.
+    BLS12_377,
+}
+
+/// Calls the generic function `$f` with the [`Pairing`] of the selected [`Curve`], such that a new curve only needs to be added here and to [`Curve`].
+macro_rules! with_curve {
+    ($curve: expr, $f: ident($($arg: expr),*)) => {
+        match $curve {
+            Curve::BN254 => $f::<Bn254>($($arg),*),
+            Curve::BLS12_381 => $f::<Bls12_381>($($arg),*),
+            Curve::BLS12_377 => $f::<Bls12_377>($($arg),*),
+        }
+    };
 }
 
 impl ValueEnum for Curve {
     fn value_variants<'a>() -> &'a [Self] {
-        &[Curve::BN254, Curve::BLS12_381]
+        &[Curve::BN254, Curve::BLS12_381, Curve::BLS12_377]
     }
 
     fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
         match self {
             Curve::BN254 => Some(clap::builder::PossibleValue::new("BN254")),
             Curve::BLS12_381 => Some(clap::builder::PossibleValue::new("BLS12-381")),
+            Curve::BLS12_377 => Some(clap::builder::PossibleValue::new("BLS12-377")),
         }
     }
 }
@@ -73,6 +94,17 @@ impl std::fmt::Display for Curve {
         match self {
             Curve::BN254 => write!(f, "BN254"),
             Curve::BLS12_381 => write!(f, "BLS12-381"),
+            Curve::BLS12_377 => write!(f, "BLS12-377"),
+        }
+    }
+}
+
+impl From<Curve> for ShareCurve {
+    fn from(value: Curve) -> Self {
+        match value {
+            Curve::BN254 => ShareCurve::Bn254,
+            Curve::BLS12_381 => ShareCurve::Bls12_381,
+            Curve::BLS12_377 => ShareCurve::Bls12_377,
         }
     }
 }
@@ -98,6 +130,15 @@ impl std::fmt::Display for MPCProtocol {
     }
 }
 
+impl From<MPCProtocol> for ShareProtocol {
+    fn from(value: MPCProtocol) -> Self {
+        match value {
+            MPCProtocol::REP3 => ShareProtocol::Rep3,
+            MPCProtocol::SHAMIR => ShareProtocol::Shamir,
+        }
+    }
+}
+
 /// Cli arguments for `split_witness`
 #[derive(Debug, Default, Serialize, Args)]
 pub struct SplitWitnessCli {
@@ -131,6 +172,10 @@ pub struct SplitWitnessCli {
     /// The number of parties
     #[arg(short, long, default_value_t = 3)]
     pub num_parties: usize,
+    /// The path to a network config file declaring the share keys of the parties. If given, each share is encrypted to the key of its party
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub network_config: Option<PathBuf>,
 }
 
 /// Config for `split_witness`
@@ -150,6 +195,8 @@ pub struct SplitWitnessConfig {
     pub threshold: usize,
     /// The number of parties
     pub num_parties: usize,
+    /// The path to a network config file declaring the share keys of the parties. If given, each share is encrypted to the key of its party
+    pub network_config: Option<PathBuf>,
 }
 
 /// Cli arguments for `split_input`
@@ -179,6 +226,25 @@ pub struct SplitInputCli {
     #[arg(long)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
     pub out_dir: Option<PathBuf>,
+    /// The path to a network config file declaring the share keys of the parties. If given, each share is encrypted to the key of its party
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub network_config: Option<PathBuf>,
+    /// An input which is shared as integer in the given ring instead of as field element, in the format `<NAME>:<TYPE>` with type one of bool, u8, u16, u32, u64, u128. Can be given multiple times
+    #[arg(long = "integer-input")]
+    #[serde(skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub integer_inputs: Vec<String>,
+    /// Publish commitments to the shares alongside them, so that the parties can verify that they received consistent shares
+    #[arg(long)]
+    pub commit: bool,
+    /// The identifier of the client providing the input. If given, the shares are signed with the key of the client
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub client_id: Option<String>,
+    /// The path to the secret key the client signs its input shares with
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub client_key: Option<PathBuf>,
 }
 
 /// Config for `split_input`
@@ -194,6 +260,18 @@ pub struct SplitInputConfig {
     pub curve: Curve,
     /// The path to the (existing) output directory
     pub out_dir: PathBuf,
+    /// The path to a network config file declaring the share keys of the parties. If given, each share is encrypted to the key of its party
+    pub network_config: Option<PathBuf>,
+    /// The inputs which are shared as integers, in the format `<NAME>:<TYPE>`
+    #[serde(default)]
+    pub integer_inputs: Vec<String>,
+    /// Publish commitments to the shares alongside them, so that the parties can verify that they received consistent shares
+    #[serde(default)]
+    pub commit: bool,
+    /// The identifier of the client providing the input. If given, the shares are signed with the key of the client
+    pub client_id: Option<String>,
+    /// The path to the secret key the client signs its input shares with
+    pub client_key: Option<PathBuf>,
     /// MPC compiler config
     #[serde(default)]
     pub compiler: CompilerConfig,
@@ -221,6 +299,17 @@ pub struct MergeInputSharesCli {
     #[arg(long)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
     pub out: Option<PathBuf>,
+    /// The path to the secret key for decrypting encrypted input shares
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub share_secret_key: Option<PathBuf>,
+    /// The path to an input policy file declaring which client may provide which inputs. If given, every input share needs to be signed accordingly
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub input_policy: Option<PathBuf>,
+    /// Require every shared input element to be committed by exactly one dealer (see `--commit` of the SplitInput command), instead of only checking the committed ones
+    #[arg(long)]
+    pub require_commitments: bool,
 }
 
 /// Config for `merge_input_shares`
@@ -234,6 +323,13 @@ pub struct MergeInputSharesConfig {
     pub curve: Curve,
     /// The output file where the merged input share is written to
     pub out: PathBuf,
+    /// The path to the secret key for decrypting encrypted input shares
+    pub share_secret_key: Option<PathBuf>,
+    /// The path to an input policy file declaring which client may provide which inputs. If given, every input share needs to be signed accordingly
+    pub input_policy: Option<PathBuf>,
+    /// Require every shared input element to be committed by exactly one dealer (see `--commit` of the SplitInput command), instead of only checking the committed ones
+    #[serde(default)]
+    pub require_commitments: bool,
 }
 
 /// Cli arguments for `generate_witness`
@@ -251,6 +347,10 @@ pub struct GenerateWitnessCli {
     #[arg(long)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
     pub circuit: Option<String>,
+    /// The path to the r1cs file of the circuit. If given, the witness share is bound to it
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub r1cs: Option<PathBuf>,
     /// The MPC protocol to be used
     #[arg(long, value_enum)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
@@ -263,9 +363,20 @@ pub struct GenerateWitnessCli {
     #[arg(long)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
     pub out: Option<PathBuf>,
+    /// The path to an input policy file declaring which client may provide which inputs. If given, the input share needs to be signed accordingly
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub input_policy: Option<PathBuf>,
+    /// Require every shared input element to be committed by exactly one dealer (see `--commit` of the SplitInput command), instead of only checking the committed ones
+    #[arg(long)]
+    pub require_commitments: bool,
     /// The simplification level passed to the circom compiler (0-2)
     #[arg(short = 'O', default_value_t = 1, value_parser = clap::value_parser!(u8).range(0..3))]
     pub simplification_level: u8,
+    /// A Bristol Fashion circuit which is registered as accelerator for a circom function, in the format `<FUNCTION>=<PATH>` (e.g., `sqrt_0=sqrt.txt`). Can be given multiple times
+    #[arg(long = "bristol-fashion")]
+    #[serde(skip)]
+    pub bristol_fashion: Vec<String>,
 }
 
 /// Config for `generate_witness`
@@ -275,12 +386,19 @@ pub struct GenerateWitnessConfig {
     pub input: PathBuf,
     /// The path to the circuit file
     pub circuit: String,
+    /// The path to the r1cs file of the circuit. If given, the witness share is bound to it
+    pub r1cs: Option<PathBuf>,
     /// The MPC protocol to be used
     pub protocol: MPCProtocol,
     /// The pairing friendly curve to be used
     pub curve: Curve,
     /// The output file where the final witness share is written to
     pub out: PathBuf,
+    /// The path to an input policy file declaring which client may provide which inputs. If given, the input share needs to be signed accordingly
+    pub input_policy: Option<PathBuf>,
+    /// Require every shared input element to be committed by exactly one dealer (see `--commit` of the SplitInput command), instead of only checking the committed ones
+    #[serde(default)]
+    pub require_commitments: bool,
     /// MPC compiler config
     #[serde(default)]
     pub compiler: CompilerConfig,
@@ -355,9 +473,20 @@ pub struct GenerateProofCli {
     #[arg(long)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
     pub zkey: Option<PathBuf>,
+    /// The path to the r1cs file of the circuit. If given, the witness share needs to be bound to it
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub r1cs: Option<PathBuf>,
     /// Perform checks on the zkey elements (can take a long time)
     #[arg(long)]
     pub check_zkey: bool,
+    /// Memory-map the zkey and compute the MSMs in chunks instead of loading the proving key into memory (Groth16 only)
+    #[arg(long)]
+    pub mmap_zkey: bool,
+    /// The number of proving key points deserialized at once when the zkey is memory-mapped
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub msm_chunk_size: Option<usize>,
     /// The MPC protocol to be used
     #[arg(long, value_enum)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
@@ -388,8 +517,14 @@ pub struct GenerateProofConfig {
     pub witness: PathBuf,
     /// The path to the proving key (.zkey) file, generated by snarkjs setup phase
     pub zkey: PathBuf,
+    /// The path to the r1cs file of the circuit. If given, the witness share needs to be bound to it
+    pub r1cs: Option<PathBuf>,
     /// Perform checks on the zkey elements (can take a long time)
     pub check_zkey: bool,
+    /// Memory-map the zkey and compute the MSMs in chunks instead of loading the proving key into memory (Groth16 only)
+    pub mmap_zkey: bool,
+    /// The number of proving key points deserialized at once when the zkey is memory-mapped
+    pub msm_chunk_size: Option<usize>,
     /// The MPC protocol to be used
     pub protocol: MPCProtocol,
     /// The pairing friendly curve to be used
@@ -447,6 +582,58 @@ pub struct VerifyConfig {
     pub public_input: PathBuf,
 }
 
+/// Cli arguments for `generate_share_key`
+#[derive(Debug, Serialize, Args)]
+pub struct GenerateShareKeyCli {
+    /// The path to the config file
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub config: Option<PathBuf>,
+    /// The output file where the secret key is written to
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub secret_key: Option<PathBuf>,
+    /// The output file where the public key is written to
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub public_key: Option<PathBuf>,
+}
+
+/// Config for `generate_share_key`
+#[derive(Debug, Deserialize)]
+pub struct GenerateShareKeyConfig {
+    /// The output file where the secret key is written to
+    pub secret_key: PathBuf,
+    /// The output file where the public key is written to
+    pub public_key: PathBuf,
+}
+
+/// Cli arguments for `generate_client_key`
+#[derive(Debug, Serialize, Args)]
+pub struct GenerateClientKeyCli {
+    /// The path to the config file
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub config: Option<PathBuf>,
+    /// The output file where the secret key is written to
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub secret_key: Option<PathBuf>,
+    /// The output file where the public key is written to
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub public_key: Option<PathBuf>,
+}
+
+/// Config for `generate_client_key`
+#[derive(Debug, Deserialize)]
+pub struct GenerateClientKeyConfig {
+    /// The output file where the secret key is written to
+    pub secret_key: PathBuf,
+    /// The output file where the public key is written to
+    pub public_key: PathBuf,
+}
+
 /// Prefix for config env variables
 pub const CONFIG_ENV_PREFIX: &str = "COCIRCOM_";
 
@@ -478,12 +665,15 @@ impl_config!(MergeInputSharesCli, MergeInputSharesConfig);
 impl_config!(TranslateWitnessCli, TranslateWitnessConfig);
 impl_config!(GenerateProofCli, GenerateProofConfig);
 impl_config!(VerifyCli, VerifyConfig);
+impl_config!(GenerateShareKeyCli, GenerateShareKeyConfig);
+impl_config!(GenerateClientKeyCli, GenerateClientKeyConfig);
 
 // manual one since this is a bit more complex
 impl GenerateWitnessConfig {
     /// Parse config from file, env, cli
     pub fn parse(cli: GenerateWitnessCli) -> Result<Self, Box<figment::error::Error>> {
         let simplification_level = cli.simplification_level;
+        let bristol_fashion = cli.bristol_fashion.clone();
         let mut config: GenerateWitnessConfig = if let Some(path) = &cli.config {
             Figment::new()
                 .merge(Toml::file(path))
@@ -502,6 +692,17 @@ impl GenerateWitnessConfig {
             2 => config.compiler.simplification = SimplificationLevel::O2(usize::MAX),
             _ => {}
         }
+        for entry in bristol_fashion {
+            let (name, path) = entry.split_once('=').ok_or_else(|| {
+                figment::error::Error::from(format!(
+                    "invalid Bristol Fashion accelerator \"{entry}\", expected <FUNCTION>=<PATH>"
+                ))
+            })?;
+            config
+                .vm
+                .bristol_fashion
+                .insert(name.trim().to_owned(), PathBuf::from(path.trim()));
+        }
         Ok(config)
     }
 }
@@ -529,6 +730,10 @@ enum Commands {
     GenerateProof(GenerateProofCli),
     /// Verification of a circom proof.
     Verify(VerifyCli),
+    /// Generate a key pair for encrypting the share files of a party
+    GenerateShareKey(GenerateShareKeyCli),
+    /// Generate a key pair for signing the input shares of a client
+    GenerateClientKey(GenerateClientKeyCli),
 }
 
 fn install_tracing() {
@@ -562,56 +767,168 @@ fn main() -> color_eyre::Result<ExitCode> {
     match args.command {
         Commands::SplitWitness(cli) => {
             let config = SplitWitnessConfig::parse(cli).context("while parsing config")?;
-            match config.curve {
-                Curve::BN254 => run_split_witness::<Bn254>(config),
-                Curve::BLS12_381 => run_split_witness::<Bls12_381>(config),
-            }
+            with_curve!(config.curve, run_split_witness(config))
         }
         Commands::SplitInput(cli) => {
             let config = SplitInputConfig::parse(cli).context("while parsing config")?;
-            match config.curve {
-                Curve::BN254 => run_split_input::<Bn254>(config),
-                Curve::BLS12_381 => run_split_input::<Bls12_381>(config),
-            }
+            with_curve!(config.curve, run_split_input(config))
         }
         Commands::MergeInputShares(cli) => {
             let config = MergeInputSharesConfig::parse(cli).context("while parsing config")?;
-            match config.curve {
-                Curve::BN254 => run_merge_input_shares::<Bn254>(config),
-                Curve::BLS12_381 => run_merge_input_shares::<Bls12_381>(config),
-            }
+            with_curve!(config.curve, run_merge_input_shares(config))
         }
         Commands::GenerateWitness(cli) => {
             let config = GenerateWitnessConfig::parse(cli).context("while parsing config")?;
-            match config.curve {
-                Curve::BN254 => run_generate_witness::<Bn254>(config),
-                Curve::BLS12_381 => run_generate_witness::<Bls12_381>(config),
-            }
+            with_curve!(config.curve, run_generate_witness(config))
         }
         Commands::TranslateWitness(cli) => {
             let config = TranslateWitnessConfig::parse(cli).context("while parsing config")?;
-            match config.curve {
-                Curve::BN254 => run_translate_witness::<Bn254>(config),
-                Curve::BLS12_381 => run_translate_witness::<Bls12_381>(config),
-            }
+            with_curve!(config.curve, run_translate_witness(config))
         }
         Commands::GenerateProof(cli) => {
             let config = GenerateProofConfig::parse(cli).context("while parsing config")?;
-            match config.curve {
-                Curve::BN254 => run_generate_proof::<Bn254>(config),
-                Curve::BLS12_381 => run_generate_proof::<Bls12_381>(config),
-            }
+            with_curve!(config.curve, run_generate_proof(config))
         }
         Commands::Verify(cli) => {
             let config = VerifyConfig::parse(cli).context("while parsing config")?;
-            match config.curve {
-                Curve::BN254 => run_verify::<Bn254>(config),
-                Curve::BLS12_381 => run_verify::<Bls12_381>(config),
-            }
+            with_curve!(config.curve, run_verify(config))
+        }
+        Commands::GenerateShareKey(cli) => {
+            let config = GenerateShareKeyConfig::parse(cli).context("while parsing config")?;
+            run_generate_share_key(config)
+        }
+        Commands::GenerateClientKey(cli) => {
+            let config = GenerateClientKeyConfig::parse(cli).context("while parsing config")?;
+            run_generate_client_key(config)
         }
     }
 }
 
+/// Parses the integer inputs given in the format `<NAME>:<TYPE>`
+fn parse_integer_inputs(
+    integer_inputs: &[String],
+) -> color_eyre::Result<BTreeMap<String, RingType>> {
+    integer_inputs
+        .iter()
+        .map(|integer_input| {
+            let (name, ring_type) = integer_input.rsplit_once(':').ok_or_else(|| {
+                eyre!("invalid integer input \"{integer_input}\", expected <NAME>:<TYPE>")
+            })?;
+            Ok((name.to_owned(), ring_type.parse()?))
+        })
+        .collect()
+}
+
+/// Computes the circuit hash of witness shares, which is always the hash of the r1cs file.
+/// Input shares are bound to the .circom source instead, as the r1cs is not needed to share them.
+fn r1cs_hash(r1cs: Option<&std::path::Path>) -> color_eyre::Result<Option<[u8; 32]>> {
+    r1cs.map(|r1cs| {
+        let r1cs = std::fs::read(r1cs).context("while reading r1cs file")?;
+        Ok(share_file::circuit_hash(&r1cs))
+    })
+    .transpose()
+}
+
+/// Reads the share keys of all parties declared in the network config file, ordered by party id
+fn read_share_keys(
+    network_config: &std::path::Path,
+    num_parties: usize,
+) -> color_eyre::Result<Vec<SharePublicKey>> {
+    let parties: Vec<NetworkPartyConfig> = Figment::new()
+        .merge(Toml::file(network_config))
+        .extract_inner("network.parties")
+        .context("while parsing parties of network config")?;
+    (0..num_parties)
+        .map(|id| {
+            let party = parties
+                .iter()
+                .find(|party| party.id == id)
+                .ok_or_else(|| eyre!("party {id} is missing in the network config"))?;
+            let path = party
+                .share_key_path
+                .as_ref()
+                .ok_or_else(|| eyre!("party {id} does not declare a share key"))?;
+            share_file::read_share_public_key(path)
+        })
+        .collect()
+}
+
+/// Reads our secret key for decrypting share files, if one is declared in the network config
+fn read_share_secret_key(
+    network: &NetworkConfigFile,
+) -> color_eyre::Result<Option<ShareSecretKey>> {
+    network
+        .share_secret_key_path
+        .as_ref()
+        .map(share_file::read_share_secret_key)
+        .transpose()
+}
+
+/// Reads the input policy file declaring which client may provide which inputs
+fn read_input_policy(path: &std::path::Path) -> color_eyre::Result<InputPolicy> {
+    let config: InputPolicyConfig = Figment::new()
+        .merge(Toml::file(path))
+        .extract()
+        .context("while parsing input policy")?;
+    config.try_into()
+}
+
+/// Writes a share file, which is encrypted to the share key of the receiving party if given
+fn write_share<T: Serialize>(
+    path: &std::path::Path,
+    header: &ShareFileHeader,
+    share: &T,
+    share_key: Option<&SharePublicKey>,
+) -> color_eyre::Result<()> {
+    let out_file = BufWriter::new(File::create(path).context("while creating output file")?);
+    match share_key {
+        Some(share_key) => share_file::write_encrypted_share_file(
+            out_file,
+            header,
+            share,
+            share_key,
+            &mut rand::thread_rng(),
+        ),
+        None => share_file::write_share_file(out_file, header, share),
+    }
+}
+
+/// Returns the public key for the share files a party writes for itself, i.e., if the party has a share secret key, its own shares are encrypted as well, such that they can be read back with the key
+fn own_share_key(share_key: Option<&ShareSecretKey>) -> Option<SharePublicKey> {
+    share_key.map(SharePublicKey::from)
+}
+
+#[instrument(level = "debug", skip(config))]
+fn run_generate_share_key(config: GenerateShareKeyConfig) -> color_eyre::Result<ExitCode> {
+    let secret_key = share_file::generate_share_key(&mut rand::thread_rng());
+    let public_key = SharePublicKey::from(&secret_key);
+    share_file::write_secret_key(&config.secret_key, &secret_key.to_bytes())
+        .context("while writing secret key")?;
+    std::fs::write(&config.public_key, public_key.as_bytes())
+        .context("while writing public key")?;
+    tracing::info!(
+        "Wrote share key pair to {} and {}",
+        config.secret_key.display(),
+        config.public_key.display()
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+#[instrument(level = "debug", skip(config))]
+fn run_generate_client_key(config: GenerateClientKeyConfig) -> color_eyre::Result<ExitCode> {
+    let secret_key = input_provenance::generate_client_key(&mut rand::thread_rng());
+    share_file::write_secret_key(&config.secret_key, &secret_key.to_bytes())
+        .context("while writing secret key")?;
+    std::fs::write(&config.public_key, secret_key.verifying_key().as_bytes())
+        .context("while writing public key")?;
+    tracing::info!(
+        "Wrote client key pair to {} and {}",
+        config.secret_key.display(),
+        config.public_key.display()
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
 #[instrument(level = "debug", skip(config))]
 fn run_split_witness<P: Pairing + CircomArkworksPairingBridge>(
     config: SplitWitnessConfig,
@@ -626,6 +943,11 @@ where
     let out_dir = config.out_dir;
     let t = config.threshold;
     let n = config.num_parties;
+    let share_keys = config
+        .network_config
+        .as_deref()
+        .map(|path| read_share_keys(path, n))
+        .transpose()?;
 
     // read the circom witness file
     let witness_file =
@@ -634,8 +956,9 @@ where
         .context("while parsing witness file")?;
 
     // read the circom r1cs file
-    let r1cs_file = BufReader::new(File::open(&r1cs).context("while opening r1cs file")?);
-    let r1cs = R1CS::<P>::from_reader(r1cs_file).context("while parsing r1cs file")?;
+    let r1cs_bytes = std::fs::read(&r1cs).context("while reading r1cs file")?;
+    let circuit_hash = share_file::circuit_hash(&r1cs_bytes);
+    let r1cs = R1CS::<P>::from_reader(r1cs_bytes.as_slice()).context("while parsing r1cs file")?;
 
     tracing::info!("Starting split witness...");
     match protocol {
@@ -662,12 +985,25 @@ where
                 .context("we have a file name")?
                 .to_str()
                 .context("witness file name is not valid UTF-8")?;
+            let header = ShareFileHeader::new(
+                ShareKind::Witness,
+                ShareProtocol::Rep3,
+                config.curve.into(),
+                0,
+                3,
+                1,
+            )
+            .with_circuit_hash(circuit_hash)
+            .with_compression(ShareCompression::SeededHalfShares);
             for (i, share) in shares.iter().enumerate() {
                 let path = out_dir.join(format!("{base_name}.{i}.shared"));
-                let out_file =
-                    BufWriter::new(File::create(&path).context("while creating output file")?);
-                bincode::serialize_into(out_file, share)
-                    .context("while serializing witness share")?;
+                write_share(
+                    &path,
+                    &header.for_party(i),
+                    share,
+                    share_keys.as_ref().map(|keys| &keys[i]),
+                )
+                .context("while writing witness share")?;
                 tracing::info!("Wrote witness share {} to file {}", i, path.display());
             }
         }
@@ -684,12 +1020,25 @@ where
                 .context("we have a file name")?
                 .to_str()
                 .context("witness file name is not valid UTF-8")?;
+            let header = ShareFileHeader::new(
+                ShareKind::Witness,
+                ShareProtocol::Shamir,
+                config.curve.into(),
+                0,
+                n,
+                t,
+            )
+            .with_circuit_hash(circuit_hash)
+            .with_compression(ShareCompression::SeededShares);
             for (i, share) in shares.iter().enumerate() {
                 let path = out_dir.join(format!("{base_name}.{i}.shared"));
-                let out_file =
-                    BufWriter::new(File::create(&path).context("while creating output file")?);
-                bincode::serialize_into(out_file, share)
-                    .context("while serializing witness share")?;
+                write_share(
+                    &path,
+                    &header.for_party(i),
+                    share,
+                    share_keys.as_ref().map(|keys| &keys[i]),
+                )
+                .context("while writing witness share")?;
                 tracing::info!("Wrote witness share {} to file {}", i, path.display());
             }
         }
@@ -710,6 +1059,12 @@ where
     let circuit = config.circuit;
     let protocol = config.protocol;
     let out_dir = config.out_dir;
+    let share_keys = config
+        .network_config
+        .as_deref()
+        .map(|path| read_share_keys(path, 3))
+        .transpose()?;
+    let ring_inputs = parse_integer_inputs(&config.integer_inputs)?;
 
     if protocol != MPCProtocol::REP3 {
         return Err(eyre!(
@@ -717,6 +1072,9 @@ where
         ));
     }
     let circuit_path = PathBuf::from(&circuit);
+    let circuit_hash = share_file::circuit_hash(
+        &std::fs::read(&circuit_path).context("while reading circuit file")?,
+    );
 
     //get the public inputs if any from parser
     let public_inputs = CoCircomCompiler::<P>::get_public_inputs(circuit_path, config.compiler)
@@ -728,7 +1086,18 @@ where
 
     tracing::info!("Starting split input...");
     let start = Instant::now();
-    let shares = co_circom::split_input::<P>(input, &public_inputs)?;
+    let mut shares = co_circom::split_input::<P>(input, &public_inputs, &ring_inputs)?;
+    if config.commit {
+        co_circom::commit_input_shares(&mut shares)?;
+    }
+    if let Some(client_id) = &config.client_id {
+        let client_key = config
+            .client_key
+            .as_deref()
+            .context("a client key is required to sign the input shares")?;
+        let client_key = input_provenance::read_client_signing_key(client_key)?;
+        co_circom::sign_input_shares(&mut shares, client_id, Some(circuit_hash), &client_key)?;
+    }
     let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
     tracing::info!("Split input took {duration_ms} ms");
 
@@ -738,10 +1107,24 @@ where
         .context("we have a file name")?
         .to_str()
         .context("input file name is not valid UTF-8")?;
+    let header = ShareFileHeader::new(
+        ShareKind::Input,
+        ShareProtocol::Rep3,
+        config.curve.into(),
+        0,
+        3,
+        1,
+    )
+    .with_circuit_hash(circuit_hash);
     for (i, share) in shares.iter().enumerate() {
         let path = out_dir.join(format!("{base_name}.{i}.shared"));
-        let out_file = BufWriter::new(File::create(&path).context("while creating output file")?);
-        bincode::serialize_into(out_file, share).context("while serializing witness share")?;
+        write_share(
+            &path,
+            &header.for_party(i),
+            share,
+            share_keys.as_ref().map(|keys| &keys[i]),
+        )
+        .context("while writing input share")?;
         tracing::info!("Wrote input share {} to file {}", i, path.display());
     }
     tracing::info!("Split input into shares successfully");
@@ -769,18 +1152,69 @@ where
     if inputs.len() < 2 {
         return Err(eyre!("Need at least two input shares to merge"));
     }
-
+    let share_key = config
+        .share_secret_key
+        .as_deref()
+        .map(share_file::read_share_secret_key)
+        .transpose()?;
+    let input_policy = config
+        .input_policy
+        .as_deref()
+        .map(read_input_policy)
+        .transpose()?;
+
+    // all input shares need to belong to the same party and circuit as the first one
+    let mut expected = ExpectedShareFile {
+        kind: Some(ShareKind::Input),
+        protocol: Some(ShareProtocol::Rep3),
+        curve: Some(config.curve.into()),
+        num_parties: Some(3),
+        ..Default::default()
+    };
+    let mut header = None;
     let input_shares = inputs
         .iter()
         .map(|input| {
             let input_share_file =
                 BufReader::new(File::open(input).context("while opening input share file")?);
-            let input_share: Rep3SharedInput<P::ScalarField> =
-                bincode::deserialize_from(input_share_file)
-                    .context("trying to parse input share file")?;
+            let (input_header, input_share): (_, Rep3SharedInput<P::ScalarField>) =
+                share_file::read_share_file_with_key(
+                    input_share_file,
+                    &expected,
+                    share_key.as_ref(),
+                )
+                .with_context(|| format!("trying to load input share {}", input.display()))?;
+            expected.party_id = Some(input_header.party_id);
+            expected.circuit_hash = input_header.circuit_hash;
+            // reject inconsistent shares of a dealer before merging them with other inputs
+            let party_id = input_header.party_id.try_into()?;
+            input_share
+                .verify_commitments(party_id)
+                .with_context(|| format!("while verifying input share {}", input.display()))?;
+            if config.require_commitments {
+                input_share.check_commitment_coverage().with_context(|| {
+                    format!(
+                        "while checking commitments of input share {}",
+                        input.display()
+                    )
+                })?;
+            }
+            // reject inputs of clients which are not allowed to provide them
+            if let Some(input_policy) = &input_policy {
+                input_share
+                    .verify_provenance(input_policy, party_id, input_header.circuit_hash)
+                    .with_context(|| {
+                        format!(
+                            "while checking provenance of input share {}",
+                            input.display()
+                        )
+                    })?;
+            }
+            header.get_or_insert(input_header);
             color_eyre::Result::<_>::Ok(input_share)
         })
         .collect::<Result<Vec<_>, _>>()?;
+    let header = header.context("we have at least two input shares")?;
 
     tracing::info!("Starting input shares merging...");
     let start = Instant::now();
@@ -788,8 +1222,13 @@ where
     let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
     tracing::info!("Merge input shares took {duration_ms} ms");
 
-    let out_file = BufWriter::new(File::create(&out).context("while creating output file")?);
-    bincode::serialize_into(out_file, &merged).context("while serializing witness share")?;
+    write_share(
+        &out,
+        &header,
+        &merged,
+        own_share_key(share_key.as_ref()).as_ref(),
+    )
+    .context("while writing input share")?;
     tracing::info!("Wrote merged input share to file {}", out.display());
 
     Ok(ExitCode::SUCCESS)
@@ -807,6 +1246,7 @@ where
     let circuit = config.circuit;
     let protocol = config.protocol;
     let out = config.out;
+    let witness_circuit_hash = r1cs_hash(config.r1cs.as_deref())?;
 
     if protocol != MPCProtocol::REP3 {
         return Err(eyre!(
@@ -815,23 +1255,51 @@ where
     }
 
     // connect to network
-    let network_config = config
+    let network_config: NetworkConfig = config
         .network
         .to_owned()
         .try_into()
         .context("while converting network config")?;
+    let share_key = read_share_secret_key(&config.network)?;
+    let party_id = network_config.my_id;
     let mpc_net = Rep3MpcNet::new(network_config).context("while connecting to network")?;
 
     // parse input shares
+    let circuit_hash =
+        share_file::circuit_hash(&std::fs::read(&circuit).context("while reading circuit file")?);
     let input_share_file =
         BufReader::new(File::open(&input).context("while opening input share file")?);
-    let input_share: Rep3SharedInput<P::ScalarField> =
-        bincode::deserialize_from(input_share_file).context("trying to parse input share file")?;
+    let expected = ExpectedShareFile {
+        kind: Some(ShareKind::Input),
+        protocol: Some(ShareProtocol::Rep3),
+        curve: Some(config.curve.into()),
+        party_id: Some(party_id),
+        num_parties: Some(3),
+        circuit_hash: Some(circuit_hash),
+        ..Default::default()
+    };
+    let (_, input_share): (_, Rep3SharedInput<P::ScalarField>) =
+        share_file::read_share_file_with_key(input_share_file, &expected, share_key.as_ref())
+            .context("trying to load input share file")?;
+    if let Some(input_policy) = &config.input_policy {
+        let input_policy = read_input_policy(input_policy)?;
+        input_share
+            .verify_provenance(&input_policy, party_id.try_into()?, Some(circuit_hash))
+            .context("while checking provenance of input share")?;
+    }
+    if config.require_commitments {
+        input_share
+            .check_commitment_coverage()
+            .context("while checking commitments of input share")?;
+    }
 
     // parse circuit file & put through our compiler
     let circuit = CoCircomCompiler::<P>::parse(circuit, config.compiler)
         .context("while parsing circuit file")?;
 
+    // keep the integer inputs in ring form for the witness share file
+    let ring_inputs = input_share.ring_inputs.clone();
+
     // Extend the witness
     tracing::info!("Starting witness generation...");
     let start = Instant::now();
@@ -843,10 +1311,23 @@ where
     drop(mpc_net);
 
     // write result to output file
-    let out_file = BufWriter::new(std::fs::File::create(&out)?);
-    bincode::serialize_into(
-        out_file,
-        &CompressedRep3SharedWitness::from(result_witness_share),
+    let header = ShareFileHeader::new(
+        ShareKind::Witness,
+        ShareProtocol::Rep3,
+        config.curve.into(),
+        party_id,
+        3,
+        1,
+    );
+    let header = match witness_circuit_hash {
+        Some(circuit_hash) => header.with_circuit_hash(circuit_hash),
+        None => header,
+    };
+    write_share(
+        &out,
+        &header,
+        &CompressedRep3SharedWitness::from(result_witness_share).with_ring_inputs(ring_inputs),
+        own_share_key(share_key.as_ref()).as_ref(),
     )?;
     tracing::info!("Witness successfully written to {}", out.display());
     Ok(ExitCode::SUCCESS)
@@ -865,40 +1346,169 @@ where
     let target_protocol = config.target_protocol;
     let out = config.out;
 
-    if src_protocol != MPCProtocol::REP3 || target_protocol != MPCProtocol::SHAMIR {
-        return Err(eyre!("Only REP3 to SHAMIR translation is supported"));
-    }
-
-    // parse witness shares
-    let witness_file =
-        BufReader::new(File::open(witness).context("trying to open witness share file")?);
-    let witness_share: CompressedRep3SharedWitness<P::ScalarField> =
-        bincode::deserialize_from(witness_file)?;
-
-    // connect to network
-    let network_config = config
+    let network_config: NetworkConfig = config
         .network
         .to_owned()
         .try_into()
         .context("while converting network config")?;
-    let net = Rep3MpcNet::new(network_config).context("while connecting to network")?;
+    let share_key = read_share_secret_key(&config.network)?;
+    let witness_file =
+        BufReader::new(File::open(witness).context("trying to open witness share file")?);
+    // the translation is only supported for 3 parties and threshold 1
+    let expected = ExpectedShareFile {
+        kind: Some(ShareKind::Witness),
+        protocol: Some(src_protocol.into()),
+        curve: Some(config.curve.into()),
+        party_id: Some(network_config.my_id),
+        num_parties: Some(3),
+        threshold: Some(1),
+        ..Default::default()
+    };
 
-    // Translate witness to shamir shares
-    tracing::info!("Starting witness translation...");
-    let start = Instant::now();
-    let (shamir_witness_share, mpc_net) = co_circom::translate_witness::<P>(witness_share, net)?;
-    let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
-    tracing::info!("Translate witness took {duration_ms} ms");
-    // network is shutdown in drop, which can take seom time with quinn
-    drop(mpc_net);
+    match (src_protocol, target_protocol) {
+        (MPCProtocol::REP3, MPCProtocol::SHAMIR) => {
+            // parse witness shares
+            let (header, witness_share): (_, CompressedRep3SharedWitness<P::ScalarField>) =
+                share_file::read_share_file_with_key(witness_file, &expected, share_key.as_ref())
+                    .context("while loading witness share")?;
 
-    // write result to output file
-    let out_file = BufWriter::new(std::fs::File::create(&out)?);
-    bincode::serialize_into(out_file, &shamir_witness_share)?;
+            // connect to network
+            let net = Rep3MpcNet::new(network_config).context("while connecting to network")?;
+
+            // Translate witness to shamir shares
+            tracing::info!("Starting witness translation...");
+            let start = Instant::now();
+            let (shamir_witness_share, mpc_net) =
+                co_circom::translate_witness::<P>(witness_share, net)?;
+            let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
+            tracing::info!("Translate witness took {duration_ms} ms");
+            // network is shutdown in drop, which can take seom time with quinn
+            drop(mpc_net);
+
+            // write result to output file, in the same format as the split witness
+            let header = ShareFileHeader {
+                protocol: ShareProtocol::Shamir,
+                compression: ShareCompression::None,
+                ..header
+            };
+            let shamir_witness_share = CompressedShamirSharedWitness::from(shamir_witness_share);
+            write_share(
+                &out,
+                &header,
+                &shamir_witness_share,
+                own_share_key(share_key.as_ref()).as_ref(),
+            )?;
+        }
+        (MPCProtocol::SHAMIR, MPCProtocol::REP3) => {
+            // parse witness shares
+            let (header, witness_share): (_, CompressedShamirSharedWitness<P::ScalarField>) =
+                share_file::read_share_file_with_key(witness_file, &expected, share_key.as_ref())
+                    .context("while loading witness share")?;
+            let witness_share = ShamirSharedWitness::from(witness_share);
+
+            // connect to network
+            let net = ShamirMpcNet::new(network_config).context("while connecting to network")?;
+
+            // Translate witness to rep3 shares
+            tracing::info!("Starting witness translation...");
+            let start = Instant::now();
+            let (rep3_witness_share, mpc_net) =
+                co_circom::translate_witness_to_rep3::<P>(witness_share, net)?;
+            let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
+            tracing::info!("Translate witness took {duration_ms} ms");
+            // network is shutdown in drop, which can take seom time with quinn
+            drop(mpc_net);
+
+            // write result to output file, in the same format as the rep3 witness generation
+            let header = ShareFileHeader {
+                protocol: ShareProtocol::Rep3,
+                compression: ShareCompression::None,
+                ..header
+            };
+            let rep3_witness_share = CompressedRep3SharedWitness::from(rep3_witness_share);
+            write_share(
+                &out,
+                &header,
+                &rep3_witness_share,
+                own_share_key(share_key.as_ref()).as_ref(),
+            )?;
+        }
+        _ => {
+            return Err(eyre!(
+                "Only REP3 to SHAMIR and SHAMIR to REP3 translations are supported"
+            ));
+        }
+    }
     tracing::info!("Witness successfully written to {}", out.display());
     Ok(ExitCode::SUCCESS)
 }
 
+/// Creates a Groth16 proof with the given MPC protocol, where the proving key is either held in memory or memory-mapped
+fn groth16_prove<P: Pairing + CircomArkworksPairingBridge>(
+    protocol: MPCProtocol,
+    t: usize,
+    pkey: &impl ProvingKeySource<P>,
+    matrices: &ConstraintMatrices<P::ScalarField>,
+    witness_file: BufReader<File>,
+    expected_witness: &ExpectedShareFile,
+    share_key: Option<&ShareSecretKey>,
+    network_config: NetworkConfig,
+) -> color_eyre::Result<(Proof<P>, Vec<P::ScalarField>)>
+where
+    P::ScalarField: CircomArkworksPrimeFieldBridge,
+    P::BaseField: CircomArkworksPrimeFieldBridge,
+{
+    let (proof, public_input) = match protocol {
+        MPCProtocol::REP3 => {
+            if t != 1 {
+                return Err(eyre!("REP3 only allows the threshold to be 1"));
+            }
+
+            let mpc_net = Rep3MpcNet::new(network_config)?;
+            let (_, witness_share): (_, CompressedRep3SharedWitness<P::ScalarField>) =
+                share_file::read_share_file_with_key(witness_file, expected_witness, share_key)
+                    .context("while loading witness share")?;
+            let (witness_share, mpc_net) =
+                co_circom::uncompress_shared_witness(witness_share, mpc_net)?;
+            let public_input = witness_share.public_inputs.clone();
+
+            let start = Instant::now();
+            let (proof, mpc_net) =
+                Rep3CoGroth16::prove::<CircomReduction>(mpc_net, pkey, matrices, witness_share)?;
+            let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
+            tracing::info!("Generate proof took {duration_ms} ms");
+            // network is shutdown in drop, which can take seom time with quinn
+            drop(mpc_net);
+
+            (proof, public_input)
+        }
+        MPCProtocol::SHAMIR => {
+            let mpc_net = ShamirMpcNet::new(network_config)?;
+            let (_, witness_share): (_, CompressedShamirSharedWitness<P::ScalarField>) =
+                share_file::read_share_file_with_key(witness_file, expected_witness, share_key)
+                    .context("while loading witness share")?;
+            let witness_share = ShamirSharedWitness::from(witness_share);
+            let public_input = witness_share.public_inputs.clone();
+
+            let start = Instant::now();
+            let (proof, mpc_net) = ShamirCoGroth16::prove::<CircomReduction>(
+                mpc_net,
+                t,
+                pkey,
+                matrices,
+                witness_share,
+            )?;
+            let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
+            tracing::info!("Generate proof took {duration_ms} ms");
+            // network is shutdown in drop, which can take seom time with quinn
+            drop(mpc_net);
+
+            (proof, public_input)
+        }
+    };
+    Ok((proof, public_input))
+}
+
 #[instrument(level = "debug", skip(config))]
 fn run_generate_proof<P: Pairing + CircomArkworksPairingBridge>(
     config: GenerateProofConfig,
@@ -914,6 +1524,14 @@ where
     let out = config.out;
     let public_input_filename = config.public_input;
     let t = config.threshold;
+    let mmap_zkey = config.mmap_zkey;
+    let msm_chunk_size = config.msm_chunk_size;
+    let witness_circuit_hash = r1cs_hash(config.r1cs.as_deref())?;
+    if matches!(proof_system, ProofSystem::Plonk) && (mmap_zkey || msm_chunk_size.is_some()) {
+        return Err(eyre!(
+            "--mmap-zkey and --msm-chunk-size are only supported for Groth16"
+        ));
+    }
     let check = if config.check_zkey {
         CheckElement::Yes
     } else {
@@ -924,69 +1542,56 @@ where
     let witness_file =
         BufReader::new(File::open(witness).context("trying to open witness share file")?);
 
-    // parse Circom zkey file
-    let zkey_file = File::open(zkey)?;
-
-    let network_config = config
+    let network_config: NetworkConfig = config
         .network
         .to_owned()
         .try_into()
         .context("while converting network config")?;
+    let share_key = read_share_secret_key(&config.network)?;
+    let expected_witness = ExpectedShareFile {
+        kind: Some(ShareKind::Witness),
+        protocol: Some(protocol.into()),
+        curve: Some(config.curve.into()),
+        party_id: Some(network_config.my_id),
+        num_parties: Some(network_config.parties.len()),
+        threshold: Some(t),
+        circuit_hash: witness_circuit_hash,
+    };
 
     tracing::info!("Starting proof generation...");
     let public_input = match proof_system {
         ProofSystem::Groth16 => {
-            let zkey = Groth16ZKey::<P>::from_reader(zkey_file, check).context("reading zkey")?;
-            let (matrices, pkey) = zkey.into();
-
-            let (proof, public_input) = match protocol {
-                MPCProtocol::REP3 => {
-                    if t != 1 {
-                        return Err(eyre!("REP3 only allows the threshold to be 1"));
-                    }
-
-                    let mpc_net = Rep3MpcNet::new(network_config)?;
-                    let witness_share: CompressedRep3SharedWitness<P::ScalarField> =
-                        bincode::deserialize_from(witness_file)?;
-                    let (witness_share, mpc_net) =
-                        co_circom::uncompress_shared_witness(witness_share, mpc_net)?;
-                    let public_input = witness_share.public_inputs.clone();
-
-                    let start = Instant::now();
-                    let (proof, mpc_net) = Rep3CoGroth16::prove::<CircomReduction>(
-                        mpc_net,
-                        &pkey,
-                        &matrices,
-                        witness_share,
-                    )?;
-                    let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
-                    tracing::info!("Generate proof took {duration_ms} ms");
-                    // network is shutdown in drop, which can take seom time with quinn
-                    drop(mpc_net);
-
-                    (proof, public_input)
-                }
-                MPCProtocol::SHAMIR => {
-                    let mpc_net = ShamirMpcNet::new(network_config)?;
-                    let witness_share: ShamirSharedWitness<P::ScalarField> =
-                        bincode::deserialize_from(witness_file)?;
-                    let public_input = witness_share.public_inputs.clone();
-
-                    let start = Instant::now();
-                    let (proof, mpc_net) = ShamirCoGroth16::prove::<CircomReduction>(
-                        mpc_net,
-                        t,
-                        &pkey,
-                        &matrices,
-                        witness_share,
-                    )?;
-                    let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
-                    tracing::info!("Generate proof took {duration_ms} ms");
-                    // network is shutdown in drop, which can take seom time with quinn
-                    drop(mpc_net);
-
-                    (proof, public_input)
+            let (proof, public_input) = if mmap_zkey {
+                let mut zkey =
+                    Groth16MmapZKey::<P>::open(&zkey, check).context("memory-mapping zkey")?;
+                if let Some(msm_chunk_size) = msm_chunk_size {
+                    zkey.set_chunk_size(msm_chunk_size);
                 }
+                groth16_prove(
+                    protocol,
+                    t,
+                    &zkey,
+                    &zkey.matrices,
+                    witness_file,
+                    &expected_witness,
+                    share_key.as_ref(),
+                    network_config,
+                )?
+            } else {
+                let zkey_file = File::open(&zkey)?;
+                let zkey =
+                    Groth16ZKey::<P>::from_reader(zkey_file, check).context("reading zkey")?;
+                let (matrices, pkey) = zkey.into();
+                groth16_prove(
+                    protocol,
+                    t,
+                    &pkey,
+                    &matrices,
+                    witness_file,
+                    &expected_witness,
+                    share_key.as_ref(),
+                    network_config,
+                )?
             };
 
             // write result to output file
@@ -1006,7 +1611,8 @@ where
         }
         ProofSystem::Plonk => {
             let zkey = Arc::new(
-                PlonkZKey::<P>::from_reader(zkey_file, check).context("while parsing zkey")?,
+                PlonkZKey::<P>::from_reader(File::open(&zkey)?, check)
+                    .context("while parsing zkey")?,
             );
 
             let (proof, public_input) = match protocol {
@@ -1016,8 +1622,13 @@ where
                     }
 
                     let mpc_net = Rep3MpcNet::new(network_config)?;
-                    let witness_share: CompressedRep3SharedWitness<P::ScalarField> =
-                        bincode::deserialize_from(witness_file)?;
+                    let (_, witness_share): (_, CompressedRep3SharedWitness<P::ScalarField>) =
+                        share_file::read_share_file_with_key(
+                            witness_file,
+                            &expected_witness,
+                            share_key.as_ref(),
+                        )
+                        .context("while loading witness share")?;
                     let (witness_share, mpc_net) =
                         co_circom::uncompress_shared_witness(witness_share, mpc_net)?;
                     let public_input = witness_share.public_inputs.clone();
@@ -1033,8 +1644,14 @@ where
                 }
                 MPCProtocol::SHAMIR => {
                     let mpc_net = ShamirMpcNet::new(network_config)?;
-                    let witness_share: ShamirSharedWitness<P::ScalarField> =
-                        bincode::deserialize_from(witness_file)?;
+                    let (_, witness_share): (_, CompressedShamirSharedWitness<P::ScalarField>) =
+                        share_file::read_share_file_with_key(
+                            witness_file,
+                            &expected_witness,
+                            share_key.as_ref(),
+                        )
+                        .context("while loading witness share")?;
+                    let witness_share = ShamirSharedWitness::from(witness_share);
                     let public_input = witness_share.public_inputs.clone();
 
                     let start = Instant::now();
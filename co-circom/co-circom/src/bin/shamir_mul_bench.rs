@@ -0,0 +1,301 @@
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+use clap::{Parser, ValueEnum};
+use co_groth16::mpc::{CircomGroth16Prover, ShamirGroth16Driver};
+use co_plonk::mpc::{CircomPlonkProver, ShamirPlonkDriver};
+use color_eyre::eyre::{Context, eyre};
+use figment::{
+    Figment,
+    providers::{Env, Format, Serialized, Toml},
+};
+use mpc_core::protocols::shamir::{
+    self, ShamirMulMode, ShamirPreprocessing, ShamirPrimeFieldShare, ShamirProtocol,
+    network::{ShamirMpcNet, ShamirNetwork},
+};
+use mpc_net::config::NetworkConfigFile;
+use rand::{CryptoRng, Rng};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::PathBuf,
+    process::ExitCode,
+    thread::sleep,
+    time::{Duration, Instant},
+};
+use tracing_subscriber::fmt::format::FmtSpan;
+
+const SLEEP: Duration = Duration::from_millis(200);
+
+/// The [`ShamirMulMode`] to benchmark
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[clap(rename_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
+pub enum MulMode {
+    /// A single king reduces the degree of all multiplications
+    King,
+    /// The king is rotated over all parties
+    RotatingKing,
+    /// The king is rotated over all parties and the double sharings are expanded as in ATLAS
+    Atlas,
+}
+
+impl From<MulMode> for ShamirMulMode {
+    fn from(value: MulMode) -> Self {
+        match value {
+            MulMode::King => ShamirMulMode::King,
+            MulMode::RotatingKing => ShamirMulMode::RotatingKing,
+            MulMode::Atlas => ShamirMulMode::Atlas,
+        }
+    }
+}
+
+fn install_tracing() {
+    use tracing_subscriber::prelude::*;
+    use tracing_subscriber::{EnvFilter, fmt};
+
+    let fmt_layer = fmt::layer()
+        .with_target(false)
+        .with_line_number(false)
+        .with_span_events(FmtSpan::CLOSE | FmtSpan::ENTER);
+    let filter_layer = EnvFilter::try_from_default_env()
+        .or_else(|_| EnvFilter::try_new("info"))
+        .unwrap();
+
+    tracing_subscriber::registry()
+        .with(filter_layer)
+        .with(fmt_layer)
+        .init();
+}
+
+/// Cli arguments
+#[derive(Debug, Serialize, Parser)]
+pub struct Cli {
+    /// The path to the config file
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub config: Option<PathBuf>,
+
+    /// The number of testruns
+    #[arg(short, long, default_value_t = 10)]
+    pub runs: usize,
+
+    /// The threshold of tolerated colluding parties
+    #[arg(short, long, default_value_t = 1)]
+    pub threshold: usize,
+
+    /// The number of multiplications in one batch
+    #[arg(short, long, default_value_t = 1 << 16)]
+    pub batch_size: usize,
+
+    /// The multiplication mode to benchmark, all modes are benchmarked if not set
+    #[arg(long, value_enum)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub mul_mode: Option<MulMode>,
+}
+
+/// Config
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    /// The number of testruns
+    pub runs: usize,
+    /// The threshold of tolerated colluding parties
+    pub threshold: usize,
+    /// The number of multiplications in one batch
+    pub batch_size: usize,
+    /// The multiplication mode to benchmark, all modes are benchmarked if not set
+    #[serde(default)]
+    pub mul_mode: Option<MulMode>,
+    /// Network config
+    pub network: NetworkConfigFile,
+}
+
+/// Prefix for config env variables
+pub const CONFIG_ENV_PREFIX: &str = "COCIRCOM_";
+
+impl Config {
+    /// Parse config from file, env, cli
+    pub fn parse(cli: Cli) -> Result<Self, Box<figment::error::Error>> {
+        if let Some(path) = &cli.config {
+            Ok(Figment::new()
+                .merge(Toml::file(path))
+                .merge(Env::prefixed(CONFIG_ENV_PREFIX))
+                .merge(Serialized::defaults(cli))
+                .extract()?)
+        } else {
+            Ok(Figment::new()
+                .merge(Env::prefixed(CONFIG_ENV_PREFIX))
+                .merge(Serialized::defaults(cli))
+                .extract()?)
+        }
+    }
+}
+
+fn main() -> color_eyre::Result<ExitCode> {
+    install_tracing();
+    rustls::crypto::aws_lc_rs::default_provider()
+        .install_default()
+        .map_err(|_| eyre!("Could not install default rustls crypto provider"))?;
+
+    let cli = Cli::parse();
+    let config = Config::parse(cli).context("while parsing config")?;
+
+    // connect to network
+    let mut net = ShamirMpcNet::new(config.network.to_owned().try_into()?)?;
+    let mul_modes = match config.mul_mode {
+        Some(mul_mode) => vec![mul_mode],
+        None => vec![MulMode::King, MulMode::RotatingKing, MulMode::Atlas],
+    };
+    for mul_mode in mul_modes {
+        net = plonk_driver_mul_vec::<ark_bn254::Bn254>(&config, net, mul_mode.into())?;
+    }
+    // Groth16 multiplies half shares locally and only reduces the degree of a single point at the end of the proof, so it does not depend on the multiplication mode
+    groth16_driver_mul_vec::<ark_bn254::Bn254>(&config, net)?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn print_runtimes(times: Vec<f64>, id: usize, s: &str) {
+    let mut min = f64::INFINITY;
+    let mut max = 0f64;
+    let mut avg = 0f64;
+
+    let len = times.len();
+    for runtime in times {
+        avg += runtime;
+        min = min.min(runtime);
+        max = max.max(runtime);
+    }
+    avg /= len as f64;
+
+    tracing::info!("{}: Party {}, {} runs", s, id, len);
+    tracing::info!("\tavg: {:.2}µs", avg);
+    tracing::info!("\tmin: {:.2}µs", min);
+    tracing::info!("\tmax: {:.2}µs", max);
+}
+
+fn share_random_input_shamir<F: PrimeField, R: Rng + CryptoRng>(
+    net: &mut ShamirMpcNet,
+    threshold: usize,
+    num_elements: usize,
+    rng: &mut R,
+) -> color_eyre::Result<Vec<ShamirPrimeFieldShare<F>>> {
+    let share = if net.get_id() == 0 {
+        let input: Vec<F> = (0..num_elements).map(|_| F::rand(rng)).collect();
+        let shares = shamir::share_field_elements(&input, threshold, net.get_num_parties(), rng);
+        let myshare = shares[0].clone();
+        for (i, val) in shares.into_iter().enumerate().skip(1) {
+            net.send_many(i, &val)?;
+        }
+        myshare
+    } else {
+        net.recv_many(0)?
+    };
+
+    Ok(share)
+}
+
+// The UltraHonk Shamir driver is benchmarked in the ultrahonk_shamir_mul_bench binary of co-noir
+fn plonk_driver_mul_vec<P: Pairing>(
+    config: &Config,
+    mut net: ShamirMpcNet,
+    mul_mode: ShamirMulMode,
+) -> color_eyre::Result<ShamirMpcNet> {
+    let mut rng = rand::thread_rng();
+
+    let mut times = Vec::with_capacity(config.runs);
+
+    for _ in 0..config.runs {
+        let a = share_random_input_shamir::<P::ScalarField, _>(
+            &mut net,
+            config.threshold,
+            config.batch_size,
+            &mut rng,
+        )?;
+        let b = share_random_input_shamir::<P::ScalarField, _>(
+            &mut net,
+            config.threshold,
+            config.batch_size,
+            &mut rng,
+        )?;
+
+        // init MPC protocol
+        let preprocessing = ShamirPreprocessing::new_with_mul_mode(
+            config.threshold,
+            net,
+            config.batch_size,
+            mul_mode,
+        )?;
+        let mut protocol0 = ShamirProtocol::from(preprocessing);
+        let protocol1 = protocol0.fork_with_pairs(0)?;
+        let mut driver = ShamirPlonkDriver::new(protocol0, protocol1);
+
+        let start = Instant::now();
+        CircomPlonkProver::<P>::mul_vec(&mut driver, &a, &b)?;
+        let duration = start.elapsed().as_micros() as f64;
+        times.push(duration);
+
+        net = driver.get_network();
+    }
+
+    sleep(SLEEP);
+    print_runtimes(
+        times,
+        config.network.my_id,
+        format!(
+            "Shamir plonk driver mul_vec {mul_mode:?} n={}",
+            config.batch_size
+        )
+        .as_str(),
+    );
+
+    Ok(net)
+}
+
+fn groth16_driver_mul_vec<P: Pairing>(
+    config: &Config,
+    mut net: ShamirMpcNet,
+) -> color_eyre::Result<ShamirMpcNet> {
+    let mut rng = rand::thread_rng();
+
+    let mut times = Vec::with_capacity(config.runs);
+
+    for _ in 0..config.runs {
+        let a = share_random_input_shamir::<P::ScalarField, _>(
+            &mut net,
+            config.threshold,
+            config.batch_size,
+            &mut rng,
+        )?;
+        let b = share_random_input_shamir::<P::ScalarField, _>(
+            &mut net,
+            config.threshold,
+            config.batch_size,
+            &mut rng,
+        )?;
+
+        // init MPC protocol
+        let preprocessing = ShamirPreprocessing::new(config.threshold, net, 0)?;
+        let mut protocol0 = ShamirProtocol::from(preprocessing);
+        let protocol1 = protocol0.fork_with_pairs(0)?;
+        let mut driver = ShamirGroth16Driver::new(protocol0, protocol1);
+
+        let start = Instant::now();
+        std::hint::black_box(CircomGroth16Prover::<P>::local_mul_vec(&mut driver, a, b));
+        let duration = start.elapsed().as_micros() as f64;
+        times.push(duration);
+
+        net = driver.get_network();
+    }
+
+    sleep(SLEEP);
+    print_runtimes(
+        times,
+        config.network.my_id,
+        format!(
+            "Shamir groth16 driver local_mul_vec n={}",
+            config.batch_size
+        )
+        .as_str(),
+    );
+
+    Ok(net)
+}
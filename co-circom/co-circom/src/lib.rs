@@ -10,14 +10,20 @@ use std::{marker::PhantomData, sync::Arc};
 
 use ark_ff::PrimeField;
 use co_circom_types::{CompressedRep3SharedWitness, SharedWitness};
-use co_groth16::{CircomReduction, ConstraintMatrices, ProvingKey};
+use co_groth16::{CircomReduction, ConstraintMatrices};
 use color_eyre::eyre::{self, Context};
 use mpc_core::protocols::{
     bridges::network::RepToShamirNetwork,
-    rep3::{self, network::IoContext},
+    rep3::{
+        self,
+        network::{IoContext, Rep3Network},
+    },
+    rep3_ring,
     shamir::{ShamirPreprocessing, ShamirProtocol},
 };
+use std::collections::BTreeMap;
 
+pub use ark_bls12_377::Bls12_377;
 pub use ark_bls12_381::Bls12_381;
 pub use ark_bn254::Bn254;
 pub use ark_ec::pairing::Pairing;
@@ -26,22 +32,26 @@ pub use circom_mpc_vm::{mpc_vm::VMConfig, types::CoCircomCompilerParsed};
 pub use circom_types::{
     R1CS, Witness,
     groth16::{
-        CircomGroth16Proof, JsonVerificationKey as Groth16JsonVerificationKey, ZKey as Groth16ZKey,
+        CircomGroth16Proof, JsonVerificationKey as Groth16JsonVerificationKey,
+        MmapZKey as Groth16MmapZKey, ZKey as Groth16ZKey,
     },
     plonk::{JsonVerificationKey as PlonkJsonVerificationKey, PlonkProof, ZKey as PlonkZKey},
     traits::{CheckElement, CircomArkworksPairingBridge, CircomArkworksPrimeFieldBridge},
 };
 pub use co_circom_types::{
-    Compression, Input, Rep3SharedInput, Rep3SharedWitness, ShamirSharedWitness,
+    CompressedShamirSharedWitness, Compression, Input, Rep3SharedInput, Rep3SharedWitness,
+    ShamirSharedWitness,
 };
-pub use co_groth16::{Groth16, Rep3CoGroth16, ShamirCoGroth16};
+pub use co_groth16::{Groth16, ProvingKeySource, Rep3CoGroth16, ShamirCoGroth16};
 pub use co_plonk::{Plonk, Rep3CoPlonk, ShamirCoPlonk};
 pub use mpc_core::protocols::{
     rep3::{PartyID, network::Rep3MpcNet},
+    rep3_ring::RingType,
     shamir::network::ShamirMpcNet,
 };
 pub use mpc_net::config::{Address, NetworkConfig, NetworkParty, ParseAddressError};
-use mpc_types::protocols::rep3::Rep3ShareVecType;
+pub use mpc_types::input_provenance::{ClientSigningKey, InputPolicy};
+use mpc_types::protocols::rep3::{Rep3ShareVecType, vss};
 pub use serde_json::Number;
 pub use serde_json::Value;
 
@@ -80,7 +90,7 @@ where
     /// Create a Groth16 poof and return the public inputs
     pub fn prove_groth16(
         self,
-        pkey: &ProvingKey<P>,
+        pkey: &impl ProvingKeySource<P>,
         matrices: &ConstraintMatrices<P::ScalarField>,
     ) -> eyre::Result<(CircomGroth16Proof<P>, Vec<P::ScalarField>)> {
         let public_inputs = self.witness.public_inputs[1..].to_vec();
@@ -147,7 +157,7 @@ where
     /// Create a Groth16 poof and return the public inputs
     pub fn prove_groth16(
         self,
-        pkey: &ProvingKey<P>,
+        pkey: &impl ProvingKeySource<P>,
         matrices: &ConstraintMatrices<P::ScalarField>,
     ) -> eyre::Result<(CircomGroth16Proof<P>, Vec<P::ScalarField>)> {
         let (witness, net) = uncompress_shared_witness(self.witness, self.net)?;
@@ -203,12 +213,48 @@ where
     }
 }
 
-/// Split the input into REP3 shares
+/// Split the input into REP3 shares. The inputs in `ring_inputs` are shared as integers in the given ring.
 pub fn split_input<P: Pairing>(
     input: Input,
     public_inputs: &[String],
+    ring_inputs: &BTreeMap<String, RingType>,
 ) -> color_eyre::Result<[Rep3SharedInput<P::ScalarField>; 3]> {
-    co_circom_types::split_input(input, public_inputs)
+    co_circom_types::split_input(input, public_inputs, ring_inputs)
+}
+
+/// Commits to the input shares produced by [split_input], so that the parties can verify that they received consistent shares. See [`co_circom_types::commit_input_shares`].
+pub fn commit_input_shares<F: PrimeField>(
+    shares: &mut [Rep3SharedInput<F>; 3],
+) -> color_eyre::Result<()> {
+    co_circom_types::commit_input_shares(shares, &mut rand::thread_rng())
+}
+
+/// Signs the input shares produced by [split_input] with the key of the `client` for the circuit with the given hash, so that the parties can check that the client is allowed to provide the inputs. See [`co_circom_types::sign_input_shares`].
+pub fn sign_input_shares<F: PrimeField>(
+    shares: &mut [Rep3SharedInput<F>; 3],
+    client: &str,
+    circuit_hash: Option<[u8; 32]>,
+    key: &ClientSigningKey,
+) -> color_eyre::Result<()> {
+    co_circom_types::sign_input_shares(shares, client, circuit_hash, key)
+}
+
+/// Verifies the input share of this party against the commitments of the dealers, and checks with the other parties that all of them received the same commitments. If a dealer distributed inconsistent shares, at least one honest party returns an error.
+pub fn verify_input_commitments<F: PrimeField>(
+    input: &Rep3SharedInput<F>,
+    net: &mut Rep3MpcNet,
+) -> color_eyre::Result<()> {
+    let id = net.get_id();
+    input.verify_commitments(id)?;
+    let digest = vss::commitments_digest(&input.commitments);
+    let prev_digest = net.reshare_many(&digest)?;
+    if prev_digest != digest {
+        eyre::bail!(
+            "Party {} received different input commitments than party {id}",
+            id.prev_id()
+        );
+    }
+    Ok(())
 }
 
 /// Merge multiple REP3 shared inputs into one
@@ -218,6 +264,30 @@ pub fn merge_input_shares<P: Pairing>(
     co_circom_types::merge_input_shares(inputs)
 }
 
+/// Casts the integer inputs of a [`Rep3SharedInput`] from their rings to field elements. All integer inputs are cast together, so that only one conversion per ring is needed.
+pub fn cast_ring_inputs<F: PrimeField, N: Rep3Network>(
+    mut input: Rep3SharedInput<F>,
+    net: N,
+) -> eyre::Result<(Rep3SharedInput<F>, N)> {
+    let ring_inputs = std::mem::take(&mut input.ring_inputs);
+    if let Some(name) = ring_inputs.keys().find(|name| {
+        input.shared_inputs.contains_key(*name) || input.public_inputs.contains_key(*name)
+    }) {
+        eyre::bail!("Input with name {name} present as integer and field input");
+    }
+    let ring_shares = ring_inputs.values().flatten().copied().collect::<Vec<_>>();
+    let mut io_context = IoContext::init(net)?;
+    let mut shares = rep3_ring::casts::typed_ring_to_field_many(&ring_shares, &mut io_context)
+        .context("while casting integer inputs")?
+        .into_iter();
+    for (name, ring_shares) in ring_inputs {
+        input
+            .shared_inputs
+            .insert(name, shares.by_ref().take(ring_shares.len()).collect());
+    }
+    Ok((input, io_context.network))
+}
+
 /// Split the witness into REP3 shares
 pub fn split_witness_rep3<P: Pairing>(
     num_inputs: usize,
@@ -257,21 +327,22 @@ pub fn uncompress_shared_witness<F: PrimeField>(
     ))
 }
 
-/// Split the witness into shamir shares
+/// Split the witness into shamir shares, where the shares of `threshold` parties are compressed to seeds
 pub fn split_witness_shamir<P: Pairing>(
     num_inputs: usize,
     witness: Witness<P::ScalarField>,
     threshold: usize,
     num_parties: usize,
-) -> Vec<ShamirSharedWitness<P::ScalarField>> {
+) -> Vec<CompressedShamirSharedWitness<P::ScalarField>> {
     let mut rng = rand::thread_rng();
     // create witness shares
-    ShamirSharedWitness::<P::ScalarField>::share_shamir(
+    CompressedShamirSharedWitness::<P::ScalarField>::share_shamir(
         witness,
         num_inputs,
         threshold,
         num_parties,
         &mut rng,
+        true,
     )
 }
 
@@ -306,16 +377,45 @@ where
     Ok((shamir_witness_share, net))
 }
 
+/// Translate the 3-party shamir shared witness (with threshold 1) into a REP3 shared witness
+pub fn translate_witness_to_rep3<P>(
+    witness: ShamirSharedWitness<P::ScalarField>,
+    net: ShamirMpcNet,
+) -> color_eyre::Result<(Rep3SharedWitness<P::ScalarField>, ShamirMpcNet)>
+where
+    P: Pairing + CircomArkworksPairingBridge,
+    P::BaseField: CircomArkworksPrimeFieldBridge,
+    P::ScalarField: CircomArkworksPrimeFieldBridge,
+{
+    // init MPC protocol, the translation does not need any preprocessed randomness
+    let threshold = 1;
+    let preprocessing = ShamirPreprocessing::<P::ScalarField, _>::new(threshold, net, 0)
+        .context("while shamir preprocessing")?;
+    let mut protocol = ShamirProtocol::from(preprocessing);
+    // Translate witness to rep3 shares
+    let translated_witness = protocol
+        .translate_primefield_shamirshare_vec(witness.witness)
+        .context("while translating witness")?;
+    let rep3_witness_share: Rep3SharedWitness<P::ScalarField> = SharedWitness {
+        public_inputs: witness.public_inputs,
+        witness: translated_witness,
+    };
+
+    let net = protocol.get_network();
+    Ok((rep3_witness_share, net))
+}
+
 /// Invoke the MPC witness generation process. It will return a [SharedWitness] if successful.
 /// It executes several steps:
 /// 1. Parse the circuit file.
 /// 2. Compile the circuit to MPC VM bytecode.
 /// 3. Set up a network connection to the MPC network.
-/// 4. Execute the bytecode on the MPC VM to generate the witness.
+/// 4. Verify the input shares against the commitments of the dealers (see [verify_input_commitments]).
+/// 5. Execute the bytecode on the MPC VM to generate the witness.
 pub fn generate_witness_rep3<P>(
     circuit: CoCircomCompilerParsed<P::ScalarField>,
     input: Rep3SharedInput<P::ScalarField>,
-    net: Rep3MpcNet,
+    mut net: Rep3MpcNet,
     config: VMConfig,
 ) -> color_eyre::Result<(Rep3SharedWitness<P::ScalarField>, Rep3MpcNet)>
 where
@@ -327,6 +427,16 @@ where
         eyre::bail!("still unmerged elements left");
     }
 
+    // reject inconsistent input shares before doing any expensive work
+    verify_input_commitments(&input, &mut net).context("while verifying input commitments")?;
+
+    // cast integer inputs to field elements
+    let (input, net) = if input.ring_inputs.is_empty() {
+        (input, net)
+    } else {
+        cast_ring_inputs(input, net)?
+    };
+
     // init MPC protocol
     let rep3_vm = circuit
         .to_rep3_vm_with_network(net, config)
@@ -2,7 +2,7 @@
 use ark_ec::pairing::Pairing;
 use ark_ec::{AffineRepr, CurveGroup};
 use ark_ff::{FftField, LegendreSymbol, PrimeField};
-use ark_groth16::{Proof, ProvingKey};
+use ark_groth16::Proof;
 use ark_poly::{EvaluationDomain, GeneralEvaluationDomain};
 use ark_relations::r1cs::ConstraintMatrices;
 use co_circom_types::{Rep3SharedWitness, ShamirSharedWitness, SharedWitness};
@@ -19,7 +19,9 @@ use crate::mpc::plain::PlainGroth16Driver;
 use crate::mpc::rep3::Rep3Groth16Driver;
 use crate::mpc::shamir::ShamirGroth16Driver;
 
+pub use proving_key::{PointSource, ProvingKeySource};
 pub use reduction::{CircomReduction, LibSnarkReduction, R1CSToQAP};
+mod proving_key;
 mod reduction;
 
 macro_rules! rayon_join5 {
@@ -125,7 +127,7 @@ impl<P: Pairing, T: CircomGroth16Prover<P>> CoGroth16<P, T> {
     #[instrument(level = "debug", name = "Groth16 - Proof", skip_all)]
     fn prove_inner<R: R1CSToQAP>(
         mut self,
-        pkey: &ProvingKey<P>,
+        pkey: &impl ProvingKeySource<P>,
         matrices: &ConstraintMatrices<P::ScalarField>,
         private_witness: SharedWitness<P::ScalarField, T::ArithmeticShare>,
     ) -> Result<(Proof<P>, T)> {
@@ -162,49 +164,80 @@ impl<P: Pairing, T: CircomGroth16Prover<P>> CoGroth16<P, T> {
         )
     }
 
-    fn calculate_coeff<C>(
+    /// Computes the MSM between the points starting at `offset` and `scalars`, where the points are accessed in chunks of at most [`PointSource::chunk_size`] points.
+    fn msm_chunked<C, S>(
+        points: &S,
+        offset: usize,
+        scalars: &[T::ArithmeticHalfShare],
+    ) -> Result<T::PointHalfShare<C>>
+    where
+        C: CurveGroup<ScalarField = P::ScalarField>,
+        S: PointSource<C::Affine>,
+    {
+        let len = scalars.len().min(points.len().saturating_sub(offset));
+        let chunk_size = points.chunk_size().clamp(1, len.max(1));
+        let first = chunk_size.min(len);
+        let mut acc = points.with_points(offset..offset + first, |points| {
+            T::msm_public_points_hs(points, &scalars[..first])
+        })?;
+        for start in (chunk_size..len).step_by(chunk_size) {
+            let end = (start + chunk_size).min(len);
+            acc += points.with_points(offset + start..offset + end, |points| {
+                T::msm_public_points_hs(points, &scalars[start..end])
+            })?;
+        }
+        Ok(acc)
+    }
+
+    fn calculate_coeff<C, S>(
         id: T::PartyID,
         initial: T::PointHalfShare<C>,
-        query: &[C::Affine],
+        query: &S,
         vk_param: C::Affine,
         input_assignment: &[P::ScalarField],
         aux_assignment: &[T::ArithmeticHalfShare],
-    ) -> T::PointHalfShare<C>
+    ) -> Result<T::PointHalfShare<C>>
     where
         C: CurveGroup<ScalarField = P::ScalarField>,
+        S: PointSource<C::Affine>,
     {
         let pub_len = input_assignment.len();
 
         let (priv_acc, pub_acc) = rayon::join(
-            || T::msm_public_points_hs(&query[1 + pub_len..], aux_assignment),
-            || C::msm_unchecked(&query[1..=pub_len], input_assignment),
+            || Self::msm_chunked::<C, _>(query, 1 + pub_len, aux_assignment),
+            || {
+                query.with_points(1..pub_len + 1, |points| {
+                    C::msm_unchecked(points, input_assignment)
+                })
+            },
         );
+        let query_0 = query.with_points(0..1, |points| points[0].into_group())?;
 
         let mut res = initial;
-        T::add_assign_points_public_hs(id, &mut res, &query[0].into_group());
+        T::add_assign_points_public_hs(id, &mut res, &query_0);
         T::add_assign_points_public_hs(id, &mut res, &vk_param.into_group());
-        T::add_assign_points_public_hs(id, &mut res, &pub_acc);
-        res += priv_acc;
-        res
+        T::add_assign_points_public_hs(id, &mut res, &pub_acc?);
+        res += priv_acc?;
+        Ok(res)
     }
 
     #[instrument(level = "debug", name = "create proof with assignment", skip_all)]
     fn create_proof_with_assignment(
         mut self,
-        pkey: &ProvingKey<P>,
+        pkey: &impl ProvingKeySource<P>,
         r: T::ArithmeticShare,
         s: T::ArithmeticShare,
         h: Vec<T::ArithmeticHalfShare>,
         input_assignment: &[P::ScalarField],
         aux_assignment: &[T::ArithmeticHalfShare],
     ) -> Result<(Proof<P>, T)> {
-        let delta_g1 = pkey.delta_g1.into_group();
+        let delta_g1 = pkey.delta_g1().into_group();
 
         let party_id = self.driver.get_party_id();
-        let alpha_g1 = pkey.vk.alpha_g1;
-        let beta_g1 = pkey.beta_g1;
-        let beta_g2 = pkey.vk.beta_g2;
-        let delta_g2 = pkey.vk.delta_g2.into_group();
+        let alpha_g1 = pkey.alpha_g1();
+        let beta_g1 = pkey.beta_g1();
+        let beta_g2 = pkey.beta_g2();
+        let delta_g2 = pkey.delta_g2().into_group();
 
         let (r_g1, s_g1, s_g2, l_acc, h_acc) = rayon_join5!(
             || {
@@ -213,10 +246,10 @@ impl<P: Pairing, T: CircomGroth16Prover<P>> CoGroth16<P, T> {
                 // Compute A
                 let r = T::to_half_share(r);
                 let r_g1 = T::scalar_mul_public_point_hs(&delta_g1, r);
-                let r_g1 = Self::calculate_coeff(
+                let r_g1 = Self::calculate_coeff::<P::G1, _>(
                     party_id,
                     r_g1,
-                    &pkey.a_query,
+                    &pkey.a_query(),
                     alpha_g1,
                     &input_assignment[1..],
                     aux_assignment,
@@ -231,10 +264,10 @@ impl<P: Pairing, T: CircomGroth16Prover<P>> CoGroth16<P, T> {
                 // In original implementation this is skipped if r==0, however r is shared in our case
                 let s = T::to_half_share(s);
                 let s_g1 = T::scalar_mul_public_point_hs(&delta_g1, s);
-                let s_g1 = Self::calculate_coeff(
+                let s_g1 = Self::calculate_coeff::<P::G1, _>(
                     party_id,
                     s_g1,
-                    &pkey.b_g1_query,
+                    &pkey.b_g1_query(),
                     beta_g1,
                     &input_assignment[1..],
                     aux_assignment,
@@ -248,10 +281,10 @@ impl<P: Pairing, T: CircomGroth16Prover<P>> CoGroth16<P, T> {
                 // Compute B in G2
                 let s = T::to_half_share(s);
                 let s_g2 = T::scalar_mul_public_point_hs(&delta_g2, s);
-                let s_g2 = Self::calculate_coeff(
+                let s_g2 = Self::calculate_coeff::<P::G2, _>(
                     party_id,
                     s_g2,
-                    &pkey.b_g2_query,
+                    &pkey.b_g2_query(),
                     beta_g2,
                     &input_assignment[1..],
                     aux_assignment,
@@ -261,20 +294,21 @@ impl<P: Pairing, T: CircomGroth16Prover<P>> CoGroth16<P, T> {
             },
             || {
                 let msm_l_query = tracing::debug_span!("msm l_query").entered();
-                let result: <T as CircomGroth16Prover<P>>::PointHalfShare<P::G1> =
-                    T::msm_public_points_hs(&pkey.l_query, aux_assignment);
+                let result = Self::msm_chunked::<P::G1, _>(&pkey.l_query(), 0, aux_assignment);
                 msm_l_query.exit();
                 result
             },
             || {
                 let msm_h_query = tracing::debug_span!("msm h_query").entered();
                 //perform the msm for h
-                let result = T::msm_public_points_hs(&pkey.h_query, &h);
+                let result = Self::msm_chunked::<P::G1, _>(&pkey.h_query(), 0, &h);
                 msm_h_query.exit();
                 result
             }
         );
 
+        let (r_g1, s_g1, s_g2, l_acc, h_acc) = (r_g1?, s_g1?, s_g2?, l_acc?, h_acc?);
+
         let rs_span = tracing::debug_span!("r*s without networking").entered();
         let rs = self.driver.local_mul_vec(vec![r], vec![s]).pop().unwrap();
         let r_s_delta_g1 = T::scalar_mul_public_point_hs(&delta_g1, rs);
@@ -317,7 +351,7 @@ impl<P: Pairing, N: Rep3Network> Rep3CoGroth16<P, N> {
     /// Create a [`Proof`].
     pub fn prove<R: R1CSToQAP>(
         net: N,
-        pkey: &ProvingKey<P>,
+        pkey: &impl ProvingKeySource<P>,
         matrices: &ConstraintMatrices<P::ScalarField>,
         witness: Rep3SharedWitness<P::ScalarField>,
     ) -> Result<(Proof<P>, N)> {
@@ -339,7 +373,7 @@ impl<P: Pairing, N: ShamirNetwork> ShamirCoGroth16<P, N> {
     pub fn prove<R: R1CSToQAP>(
         net: N,
         threshold: usize,
-        pkey: &ProvingKey<P>,
+        pkey: &impl ProvingKeySource<P>,
         matrices: &ConstraintMatrices<P::ScalarField>,
         witness: ShamirSharedWitness<P::ScalarField>,
     ) -> Result<(Proof<P>, N)> {
@@ -368,7 +402,7 @@ impl<P: Pairing> Groth16<P> {
     ///
     /// DOES NOT PERFORM ANY MPC. For a plain prover checkout the [Groth16 implementation of arkworks](https://docs.rs/ark-groth16/latest/ark_groth16/).
     pub fn plain_prove<R: R1CSToQAP>(
-        pkey: &ProvingKey<P>,
+        pkey: &impl ProvingKeySource<P>,
         matrices: &ConstraintMatrices<P::ScalarField>,
         private_witness: SharedWitness<P::ScalarField, P::ScalarField>,
     ) -> Result<Proof<P>> {
@@ -0,0 +1,184 @@
+use std::ops::Range;
+
+use ark_ec::pairing::Pairing;
+use ark_groth16::ProvingKey;
+use circom_types::{
+    groth16::{MmapZKey, MmapZKeyQuery, ZKeyG1Query},
+    traits::{CircomArkworksPairingBridge, CircomArkworksPrimeFieldBridge},
+};
+use eyre::Result;
+
+/// A source of curve points for an MSM. The points may be stored in memory or may be deserialized on demand in chunks of [`PointSource::chunk_size`] points.
+pub trait PointSource<A>: Sync {
+    /// Returns the number of points.
+    fn len(&self) -> usize;
+
+    /// Returns true if there are no points.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of points that should be accessed at once.
+    fn chunk_size(&self) -> usize;
+
+    /// Calls `f` with the points in the given `range`.
+    fn with_points<R>(&self, range: Range<usize>, f: impl FnOnce(&[A]) -> R) -> Result<R>;
+}
+
+impl<A: Sync> PointSource<A> for &[A] {
+    fn len(&self) -> usize {
+        <[A]>::len(self)
+    }
+
+    fn chunk_size(&self) -> usize {
+        <[A]>::len(self)
+    }
+
+    fn with_points<R>(&self, range: Range<usize>, f: impl FnOnce(&[A]) -> R) -> Result<R> {
+        Ok(f(&self[range]))
+    }
+}
+
+impl<A: Send + Sync> PointSource<A> for MmapZKeyQuery<'_, A> {
+    fn len(&self) -> usize {
+        MmapZKeyQuery::len(self)
+    }
+
+    fn chunk_size(&self) -> usize {
+        MmapZKeyQuery::chunk_size(self)
+    }
+
+    fn with_points<R>(&self, range: Range<usize>, f: impl FnOnce(&[A]) -> R) -> Result<R> {
+        let points = self.points(range)?;
+        Ok(f(&points))
+    }
+}
+
+/// The parts of a Groth16 proving key that are required by the [`CoGroth16`](super::CoGroth16) prover.
+///
+/// This is implemented for the in-memory arkworks [`ProvingKey`] and for the memory-mapped [`MmapZKey`]. The latter only holds one chunk of each query in memory while computing the MSMs, which allows parties with modest RAM to prove large circuits.
+pub trait ProvingKeySource<P: Pairing>: Sync {
+    /// The type providing the G1 queries
+    type G1Points<'a>: PointSource<P::G1Affine>
+    where
+        Self: 'a;
+    /// The type providing the G2 query
+    type G2Points<'a>: PointSource<P::G2Affine>
+    where
+        Self: 'a;
+
+    /// Returns alpha in G1
+    fn alpha_g1(&self) -> P::G1Affine;
+    /// Returns beta in G1
+    fn beta_g1(&self) -> P::G1Affine;
+    /// Returns beta in G2
+    fn beta_g2(&self) -> P::G2Affine;
+    /// Returns delta in G1
+    fn delta_g1(&self) -> P::G1Affine;
+    /// Returns delta in G2
+    fn delta_g2(&self) -> P::G2Affine;
+    /// Returns the a_query
+    fn a_query(&self) -> Self::G1Points<'_>;
+    /// Returns the b_query in G1
+    fn b_g1_query(&self) -> Self::G1Points<'_>;
+    /// Returns the b_query in G2
+    fn b_g2_query(&self) -> Self::G2Points<'_>;
+    /// Returns the l_query
+    fn l_query(&self) -> Self::G1Points<'_>;
+    /// Returns the h_query
+    fn h_query(&self) -> Self::G1Points<'_>;
+}
+
+impl<P: Pairing> ProvingKeySource<P> for ProvingKey<P> {
+    type G1Points<'a> = &'a [P::G1Affine];
+    type G2Points<'a> = &'a [P::G2Affine];
+
+    fn alpha_g1(&self) -> P::G1Affine {
+        self.vk.alpha_g1
+    }
+
+    fn beta_g1(&self) -> P::G1Affine {
+        self.beta_g1
+    }
+
+    fn beta_g2(&self) -> P::G2Affine {
+        self.vk.beta_g2
+    }
+
+    fn delta_g1(&self) -> P::G1Affine {
+        self.delta_g1
+    }
+
+    fn delta_g2(&self) -> P::G2Affine {
+        self.vk.delta_g2
+    }
+
+    fn a_query(&self) -> Self::G1Points<'_> {
+        &self.a_query
+    }
+
+    fn b_g1_query(&self) -> Self::G1Points<'_> {
+        &self.b_g1_query
+    }
+
+    fn b_g2_query(&self) -> Self::G2Points<'_> {
+        &self.b_g2_query
+    }
+
+    fn l_query(&self) -> Self::G1Points<'_> {
+        &self.l_query
+    }
+
+    fn h_query(&self) -> Self::G1Points<'_> {
+        &self.h_query
+    }
+}
+
+impl<P: Pairing + CircomArkworksPairingBridge> ProvingKeySource<P> for MmapZKey<P>
+where
+    P::BaseField: CircomArkworksPrimeFieldBridge,
+    P::ScalarField: CircomArkworksPrimeFieldBridge,
+{
+    type G1Points<'a> = MmapZKeyQuery<'a, P::G1Affine>;
+    type G2Points<'a> = MmapZKeyQuery<'a, P::G2Affine>;
+
+    fn alpha_g1(&self) -> P::G1Affine {
+        self.alpha_g1
+    }
+
+    fn beta_g1(&self) -> P::G1Affine {
+        self.beta_g1
+    }
+
+    fn beta_g2(&self) -> P::G2Affine {
+        self.beta_g2
+    }
+
+    fn delta_g1(&self) -> P::G1Affine {
+        self.delta_g1
+    }
+
+    fn delta_g2(&self) -> P::G2Affine {
+        self.delta_g2
+    }
+
+    fn a_query(&self) -> Self::G1Points<'_> {
+        self.g1_query(ZKeyG1Query::A)
+    }
+
+    fn b_g1_query(&self) -> Self::G1Points<'_> {
+        self.g1_query(ZKeyG1Query::BG1)
+    }
+
+    fn b_g2_query(&self) -> Self::G2Points<'_> {
+        MmapZKey::b_g2_query(self)
+    }
+
+    fn l_query(&self) -> Self::G1Points<'_> {
+        self.g1_query(ZKeyG1Query::L)
+    }
+
+    fn h_query(&self) -> Self::G1Points<'_> {
+        self.g1_query(ZKeyG1Query::H)
+    }
+}
@@ -7,8 +7,8 @@ pub mod mpc;
 mod verifier;
 
 pub use groth16::{
-    CircomReduction, CoGroth16, Groth16, LibSnarkReduction, R1CSToQAP, Rep3CoGroth16,
-    ShamirCoGroth16,
+    CircomReduction, CoGroth16, Groth16, LibSnarkReduction, PointSource, ProvingKeySource,
+    R1CSToQAP, Rep3CoGroth16, ShamirCoGroth16,
 };
 
 pub use ark_groth16::{Proof, ProvingKey, VerifyingKey};
@@ -14,9 +14,9 @@ use mpc::rep3::Rep3PlonkDriver;
 use mpc::shamir::ShamirPlonkDriver;
 use mpc_core::protocols::rep3::network::IoContext;
 use mpc_core::protocols::rep3::network::Rep3Network;
-use mpc_core::protocols::shamir::ShamirPreprocessing;
 use mpc_core::protocols::shamir::ShamirProtocol;
 use mpc_core::protocols::shamir::network::ShamirNetwork;
+use mpc_core::protocols::shamir::{ShamirMulMode, ShamirPreprocessing};
 use round1::Round1;
 use std::io;
 use std::marker::PhantomData;
@@ -246,18 +246,40 @@ impl<P: Pairing, N: ShamirNetwork> ShamirCoPlonk<P, N> {
         zkey: Arc<ZKey<P>>,
         witness: ShamirSharedWitness<P::ScalarField>,
     ) -> eyre::Result<(PlonkProof<P>, N)>
+    where
+        P: Pairing + CircomArkworksPairingBridge,
+        P::BaseField: CircomArkworksPrimeFieldBridge,
+        P::ScalarField: CircomArkworksPrimeFieldBridge,
+    {
+        Self::prove_with_mul_mode(net, threshold, ShamirMulMode::default(), zkey, witness)
+    }
+
+    /// Create a [`PlonkProof`], where the multiplications use the given [`ShamirMulMode`]
+    pub fn prove_with_mul_mode(
+        net: N,
+        threshold: usize,
+        mul_mode: ShamirMulMode,
+        zkey: Arc<ZKey<P>>,
+        witness: ShamirSharedWitness<P::ScalarField>,
+    ) -> eyre::Result<(PlonkProof<P>, N)>
     where
         P: Pairing + CircomArkworksPairingBridge,
         P::BaseField: CircomArkworksPrimeFieldBridge,
         P::ScalarField: CircomArkworksPrimeFieldBridge,
     {
         let domain_size = zkey.domain_size;
+        let num_parties = net.get_num_parties();
         // TODO check and explain numbers
         let num_pairs = domain_size * 222 + 15;
-        let preprocessing = ShamirPreprocessing::new(threshold, net, num_pairs)?;
+        let preprocessing =
+            ShamirPreprocessing::new_with_mul_mode(threshold, net, num_pairs, mul_mode)?;
         let mut protocol0 = ShamirProtocol::from(preprocessing);
         // TODO check and explain numbers
-        let protocol1 = protocol0.fork_with_pairs(domain_size * 7 + 2)?;
+        let protocol1 = protocol0.fork_with_pairs(mul_mode.num_pairs(
+            domain_size * 7 + 2,
+            threshold,
+            num_parties,
+        ))?;
         let driver = ShamirPlonkDriver::new(protocol0, protocol1);
         let prover = CoPlonk {
             driver,
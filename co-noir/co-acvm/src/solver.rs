@@ -10,7 +10,7 @@ use intmap::IntMap;
 use mpc_core::{
     lut::LookupTableProvider,
     protocols::{
-        rep3::network::Rep3Network,
+        rep3::{network::Rep3Network, yao::bristol_fashion::BristolFashionCircuit},
         shamir::{ShamirPreprocessing, ShamirProtocol, network::ShamirNetwork},
     },
 };
@@ -329,6 +329,11 @@ where
         self.pedantic_solving
     }
 
+    /// Registers a Bristol Fashion circuit as accelerator for the Noir oracle function with the given name, see [`CoBrilligVM::register_bristol_fashion`].
+    pub fn register_bristol_fashion(&mut self, name: String, circuit: BristolFashionCircuit) {
+        self.brillig.register_bristol_fashion(name, circuit);
+    }
+
     #[inline(always)]
     fn witness(&mut self) -> &mut WitnessMap<T::AcvmType> {
         &mut self.witness_map[self.function_index]
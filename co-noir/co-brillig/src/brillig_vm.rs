@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use crate::memory::Memory;
 use crate::mpc::BrilligDriver;
@@ -10,6 +11,8 @@ use ark_ff::PrimeField;
 use brillig::{
     BitSize, HeapValueType, HeapVector, Label, MemoryAddress, Opcode as BrilligOpcode, ValueOrArray,
 };
+use itertools::izip;
+use mpc_core::protocols::rep3::yao::bristol_fashion::BristolFashionCircuit;
 
 /// The coBrillig-VM. It executes unconstrained functions for coNoir.
 ///
@@ -27,6 +30,7 @@ where
     pub(crate) persistent_shared_state: HashMap<String, Vec<T::BrilligType>>,
     calldata: Vec<T::BrilligType>,
     unconstrained_functions: Vec<BrilligBytecode<GenericFieldElement<F>>>,
+    bristol_fashion: HashMap<String, Arc<BristolFashionCircuit>>,
     call_stack: Vec<usize>,
     ip: usize,
 }
@@ -198,6 +202,7 @@ where
         Self {
             driver,
             unconstrained_functions,
+            bristol_fashion: HashMap::new(),
             calldata: vec![],
             call_stack: vec![],
             persistent_shared_state: HashMap::new(),
@@ -207,6 +212,16 @@ where
         }
     }
 
+    /// Registers a user-supplied Bristol Fashion circuit as accelerator
+    /// for the oracle function with the provided name. A Noir program
+    /// calls it by declaring an unconstrained oracle function, e.g.,
+    /// `#[oracle(my_circuit)] unconstrained fn my_circuit(a: u64, b: u64) -> u64`.
+    /// Every argument is mapped to one input bundle of the circuit and
+    /// every output bundle is cast to the declared return type.
+    pub fn register_bristol_fashion(&mut self, name: String, circuit: BristolFashionCircuit) {
+        self.bristol_fashion.insert(name, Arc::new(circuit));
+    }
+
     pub(crate) fn increment_program_counter(&mut self) {
         self.set_program_counter(self.ip + 1)
     }
@@ -390,6 +405,14 @@ where
                 inputs,
                 input_value_type,
             )?;
+        } else if let Some(circuit) = self.bristol_fashion.get(name).cloned() {
+            self.bristol_fashion_call(
+                name,
+                &circuit,
+                destinations,
+                destination_value_types,
+                inputs,
+            )?;
         } else {
             eyre::bail!(format!("unsupported oracle function: {name}"))
         }
@@ -397,6 +420,46 @@ where
         Ok(())
     }
 
+    fn bristol_fashion_call(
+        &mut self,
+        name: &str,
+        circuit: &BristolFashionCircuit,
+        destinations: &[ValueOrArray],
+        destination_value_types: &[HeapValueType],
+        inputs: &[ValueOrArray],
+    ) -> eyre::Result<()> {
+        let args = inputs
+            .iter()
+            .map(|input| match input {
+                ValueOrArray::MemoryAddress(address) => self.memory.read(*address),
+                _ => eyre::bail!(
+                    "Invalid signature for Bristol Fashion accelerator {name}. Only single values are supported as arguments"
+                ),
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let outputs = self.driver.bristol_fashion(circuit, args)?;
+        if destinations.len() != outputs.len() || destination_value_types.len() != outputs.len() {
+            eyre::bail!(
+                "Invalid signature for Bristol Fashion accelerator {name}. Expected {} return values",
+                outputs.len()
+            );
+        }
+        for (destination, value_type, output) in
+            izip!(destinations, destination_value_types, outputs)
+        {
+            match (destination, value_type) {
+                (ValueOrArray::MemoryAddress(address), HeapValueType::Simple(bit_size)) => {
+                    let output = self.driver.cast(output, *bit_size)?;
+                    self.memory.write(*address, output)?;
+                }
+                _ => eyre::bail!(
+                    "Invalid signature for Bristol Fashion accelerator {name}. Only single values are supported as return values"
+                ),
+            }
+        }
+        Ok(())
+    }
+
     fn handle_return(&mut self) -> eyre::Result<()> {
         if let Some(return_location) = self.call_stack.pop() {
             self.set_program_counter(return_location + 1);
@@ -484,6 +547,7 @@ where
             driver: driver1,
             calldata: self.calldata.clone(),
             unconstrained_functions: self.unconstrained_functions.clone(),
+            bristol_fashion: self.bristol_fashion.clone(),
             persistent_shared_state: self.persistent_shared_state.clone(),
             call_stack: self.call_stack.clone(),
             ip: self.ip,
@@ -495,6 +559,7 @@ where
             driver: driver2,
             calldata: self.calldata.clone(),
             unconstrained_functions: self.unconstrained_functions.clone(),
+            bristol_fashion: self.bristol_fashion.clone(),
             call_stack: self.call_stack.clone(),
             persistent_shared_state: self.persistent_shared_state.clone(),
             ip: self.ip,
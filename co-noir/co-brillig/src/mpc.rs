@@ -8,6 +8,7 @@
 use std::fmt;
 
 use ark_ff::PrimeField;
+use mpc_core::protocols::rep3::yao::bristol_fashion::BristolFashionCircuit;
 
 mod plain;
 mod rep3;
@@ -271,4 +272,13 @@ pub trait BrilligDriver<F: PrimeField>: Sized {
     /// Checks whether the provided value is a field type. Returns an
     /// error otherwise.
     fn expect_field(val: Self::BrilligType) -> eyre::Result<Self::BrilligType>;
+
+    /// Executes a user-supplied Bristol Fashion circuit. The i-th
+    /// argument is mapped to the i-th input bundle of the circuit.
+    /// Returns one field element for each output bundle.
+    fn bristol_fashion(
+        &mut self,
+        circuit: &BristolFashionCircuit,
+        args: Vec<Self::BrilligType>,
+    ) -> eyre::Result<Vec<Self::BrilligType>>;
 }
@@ -3,6 +3,7 @@ use std::marker::PhantomData;
 use acvm::brillig_vm::MEMORY_ADDRESSING_BIT_SIZE;
 use ark_ff::{One as _, PrimeField};
 use brillig::{BitSize, IntegerBitSize};
+use mpc_core::protocols::rep3::yao::bristol_fashion::{self, BristolFashionCircuit};
 use num_bigint::BigUint;
 use num_traits::Zero;
 use rand::Rng;
@@ -465,4 +466,19 @@ impl<F: PrimeField> BrilligDriver<F> for PlainBrilligDriver<F> {
             eyre::bail!("expected field, but got {val:?}")
         }
     }
+
+    fn bristol_fashion(
+        &mut self,
+        circuit: &BristolFashionCircuit,
+        args: Vec<Self::BrilligType>,
+    ) -> eyre::Result<Vec<Self::BrilligType>> {
+        let args = args
+            .into_iter()
+            .map(PlainBrilligType::into_field)
+            .collect::<Vec<_>>();
+        Ok(bristol_fashion::execute_on_field_elements(circuit, &args)?
+            .into_iter()
+            .map(PlainBrilligType::Field)
+            .collect())
+    }
 }
@@ -3,6 +3,7 @@ use ark_ff::{One as _, PrimeField};
 use brillig::{BitSize, IntegerBitSize};
 use core::panic;
 use mpc_core::protocols::rep3::network::{IoContext, Rep3Network};
+use mpc_core::protocols::rep3::yao::{self, bristol_fashion::BristolFashionCircuit};
 use mpc_core::protocols::rep3::{self, Rep3PrimeFieldShare};
 use mpc_core::protocols::rep3_ring::ring::bit::Bit;
 use mpc_core::protocols::rep3_ring::ring::int_ring::IntRing2k;
@@ -1665,4 +1666,46 @@ impl<F: PrimeField, N: Rep3Network> BrilligDriver<F> for Rep3BrilligDriver<F, N>
             _ => eyre::bail!("expected field but got int"),
         }
     }
+
+    fn bristol_fashion(
+        &mut self,
+        circuit: &BristolFashionCircuit,
+        args: Vec<Self::BrilligType>,
+    ) -> eyre::Result<Vec<Self::BrilligType>> {
+        if args.iter().all(|x| matches!(x, Rep3BrilligType::Public(_))) {
+            let args = args
+                .into_iter()
+                .map(|x| match x {
+                    Rep3BrilligType::Public(x) => x,
+                    Rep3BrilligType::Shared(_) => unreachable!(),
+                })
+                .collect();
+            return Ok(self
+                .plain_driver
+                .bristol_fashion(circuit, args)?
+                .into_iter()
+                .map(Rep3BrilligType::Public)
+                .collect());
+        }
+        let args = args
+            .into_iter()
+            .map(|x| match x {
+                Rep3BrilligType::Public(x) => Ok(rep3::arithmetic::promote_to_trivial_share(
+                    self.io_context.id,
+                    x.into_field(),
+                )),
+                Rep3BrilligType::Shared(x) => {
+                    Rep3BrilligType::into_arithmetic_share(&mut self.io_context, x)
+                }
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        Ok(yao::bristol_fashion::execute_on_arithmetic_shares(
+            circuit,
+            &args,
+            &mut self.io_context,
+        )?
+        .into_iter()
+        .map(Rep3BrilligType::shared_field)
+        .collect())
+    }
 }
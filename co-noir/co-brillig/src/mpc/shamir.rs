@@ -1,6 +1,7 @@
 use super::{BrilligDriver, PlainBrilligDriver};
 use ark_ff::{One, PrimeField};
 use brillig::{BitSize, IntegerBitSize};
+use mpc_core::protocols::rep3::yao::bristol_fashion::BristolFashionCircuit;
 use mpc_core::protocols::shamir::{
     self, ShamirPrimeFieldShare, ShamirProtocol, network::ShamirNetwork,
 };
@@ -370,4 +371,26 @@ impl<F: PrimeField, N: ShamirNetwork> BrilligDriver<F> for ShamirBrilligDriver<F
             _ => eyre::bail!("expected field but got int"),
         }
     }
+
+    fn bristol_fashion(
+        &mut self,
+        circuit: &BristolFashionCircuit,
+        args: Vec<Self::BrilligType>,
+    ) -> eyre::Result<Vec<Self::BrilligType>> {
+        let args = args
+            .into_iter()
+            .map(|x| match x {
+                ShamirBrilligType::Public(x) => Ok(x),
+                ShamirBrilligType::Shared(_) => eyre::bail!(
+                    "Bristol Fashion circuits on shared values are not supported for Shamir"
+                ),
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        Ok(self
+            .plain_driver
+            .bristol_fashion(circuit, args)?
+            .into_iter()
+            .map(ShamirBrilligType::Public)
+            .collect())
+    }
 }
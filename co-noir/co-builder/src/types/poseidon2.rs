@@ -9,7 +9,7 @@ use ark_ec::pairing::Pairing;
 use ark_ff::PrimeField;
 use co_acvm::mpc::NoirWitnessExtensionProtocol;
 use mpc_core::gadgets::poseidon2::{Poseidon2, Poseidon2Params};
-use std::{any::TypeId, array};
+use std::{any::TypeId, array, sync::Arc};
 
 // This workaround is required due to mutability issues
 macro_rules! create_dummy_gate {
@@ -28,7 +28,7 @@ pub(crate) struct Poseidon2CT<F: PrimeField, const T: usize, const D: u64> {
 
 impl<F: PrimeField, const T: usize, const D: u64> Poseidon2CT<F, T, D> {
     /// Constructs a new Poseidon2 circuit.
-    pub fn new(params: &'static Poseidon2Params<F, T, D>) -> Self {
+    pub fn new(params: Arc<Poseidon2Params<F, T, D>>) -> Self {
         let poseidon2 = Poseidon2::new(params);
         Self { poseidon2 }
     }
@@ -401,7 +401,7 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2CT<F, T, D> {
 impl<F: PrimeField> Default for Poseidon2CT<F, 4, 5> {
     fn default() -> Self {
         if TypeId::of::<F>() == TypeId::of::<ark_bn254::Fr>() {
-            let params = &mpc_core::gadgets::poseidon2::POSEIDON2_BN254_T4_PARAMS;
+            let params = Arc::clone(&mpc_core::gadgets::poseidon2::POSEIDON2_BN254_T4_PARAMS);
             let poseidon2 = Poseidon2CT::new(params);
             // Safety: We checked that the types match
             unsafe {
@@ -1,13 +1,15 @@
 use ark_bn254::Bn254;
 use ark_ff::Zero;
 use clap::{Args, Parser, Subcommand, ValueEnum};
-use co_acvm::{Rep3AcvmType, solver::Rep3CoSolver};
+use co_acvm::solver::Rep3CoSolver;
 use co_builder::prelude::Serialize as FieldSerialize;
-use co_noir::PubShared;
+use co_noir::{
+    CompressedShamirProvingKey, CompressedShamirWitnessShare, PubShared, Rep3InputShares,
+    Rep3WitnessShare,
+};
 use co_ultrahonk::prelude::{
     CrsParser, HonkProof, Poseidon2Sponge, ProvingKey, Rep3CoUltraHonk, Rep3UltraHonkDriver,
-    ShamirCoUltraHonk, ShamirUltraHonkDriver, UltraHonk, Utils, VerifyingKey,
-    VerifyingKeyBarretenberg, ZeroKnowledge,
+    ShamirCoUltraHonk, UltraHonk, Utils, VerifyingKey, VerifyingKeyBarretenberg, ZeroKnowledge,
 };
 use color_eyre::eyre::{Context, ContextCompat, eyre};
 use figment::{
@@ -15,7 +17,12 @@ use figment::{
     providers::{Env, Format, Serialized, Toml},
 };
 use mpc_core::protocols::{rep3::network::Rep3MpcNet, shamir::network::ShamirMpcNet};
-use mpc_net::config::NetworkConfigFile;
+use mpc_net::config::{NetworkConfig, NetworkConfigFile, NetworkPartyConfig};
+use mpc_types::input_provenance::{self, InputPolicy, InputPolicyConfig};
+use mpc_types::share_file::{
+    self, ExpectedShareFile, ShareCompression, ShareCurve, ShareFileHeader, ShareKind,
+    ShareProtocol, SharePublicKey, ShareSecretKey,
+};
 use serde::{Deserialize, Serialize};
 use sha3::Keccak256;
 use std::{
@@ -68,6 +75,15 @@ impl std::fmt::Display for TranscriptHash {
     }
 }
 
+impl From<MPCProtocol> for ShareProtocol {
+    fn from(value: MPCProtocol) -> Self {
+        match value {
+            MPCProtocol::REP3 => ShareProtocol::Rep3,
+            MPCProtocol::SHAMIR => ShareProtocol::Shamir,
+        }
+    }
+}
+
 /// Cli arguments for `split_witness`
 #[derive(Debug, Default, Serialize, Args)]
 pub struct SplitWitnessCli {
@@ -97,6 +113,10 @@ pub struct SplitWitnessCli {
     /// The number of parties
     #[arg(short, long, default_value_t = 3)]
     pub num_parties: usize,
+    /// The path to a network config file declaring the share keys of the parties. If given, each share is encrypted to the key of its party
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub network_config: Option<PathBuf>,
 }
 
 /// Config for `split_witness`
@@ -114,6 +134,8 @@ pub struct SplitWitnessConfig {
     pub threshold: usize,
     /// The number of parties
     pub num_parties: usize,
+    /// The path to a network config file declaring the share keys of the parties. If given, each share is encrypted to the key of its party
+    pub network_config: Option<PathBuf>,
 }
 
 /// Cli arguments for `split_input`
@@ -139,6 +161,24 @@ pub struct SplitInputCli {
     #[arg(long)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
     pub out_dir: Option<PathBuf>,
+    /// The path to a network config file declaring the share keys of the parties. If given, each share is encrypted to the key of its party
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub network_config: Option<PathBuf>,
+    /// Share the private integer and boolean inputs in their rings instead of as field elements
+    #[arg(long)]
+    pub ring_inputs: bool,
+    /// Publish commitments alongside the shares, which allow the parties to verify that the shares are consistent
+    #[arg(long)]
+    pub commit: bool,
+    /// The identifier of the client providing the input. If given, the shares are signed with the key of the client
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub client_id: Option<String>,
+    /// The path to the secret key the client signs its input shares with
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub client_key: Option<PathBuf>,
 }
 
 /// Config for `split_input`
@@ -152,6 +192,18 @@ pub struct SplitInputConfig {
     pub protocol: MPCProtocol,
     /// The path to the (existing) output directory
     pub out_dir: PathBuf,
+    /// The path to a network config file declaring the share keys of the parties. If given, each share is encrypted to the key of its party
+    pub network_config: Option<PathBuf>,
+    /// Share the private integer and boolean inputs in their rings instead of as field elements
+    #[serde(default)]
+    pub ring_inputs: bool,
+    /// Publish commitments alongside the shares, which allow the parties to verify that the shares are consistent
+    #[serde(default)]
+    pub commit: bool,
+    /// The identifier of the client providing the input. If given, the shares are signed with the key of the client
+    pub client_id: Option<String>,
+    /// The path to the secret key the client signs its input shares with
+    pub client_key: Option<PathBuf>,
 }
 
 /// Cli arguments for `split_proving_key`
@@ -193,6 +245,10 @@ pub struct SplitProvingKeyCli {
     /// Prove with or without the zero knowledge property
     #[arg(long)]
     pub zk: bool,
+    /// The path to a network config file declaring the share keys of the parties. If given, each share is encrypted to the key of its party
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub network_config: Option<PathBuf>,
 }
 
 /// Config for `split_proving_key`
@@ -216,6 +272,8 @@ pub struct SplitProvingKeyConfig {
     pub recursive: bool,
     /// Prove with or without the zero knowledge property
     pub zk: bool,
+    /// The path to a network config file declaring the share keys of the parties. If given, each share is encrypted to the key of its party
+    pub network_config: Option<PathBuf>,
 }
 
 /// Cli arguments for `merge_input_shares`
@@ -236,6 +294,17 @@ pub struct MergeInputSharesCli {
     #[arg(long)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
     pub out: Option<PathBuf>,
+    /// The path to the secret key for decrypting encrypted input shares
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub share_secret_key: Option<PathBuf>,
+    /// The path to an input policy file declaring which client may provide which inputs. If given, every input share needs to be signed accordingly
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub input_policy: Option<PathBuf>,
+    /// Require every shared input element to be committed by exactly one dealer (see `--commit` of the SplitInput command), instead of only checking the committed ones
+    #[arg(long)]
+    pub require_commitments: bool,
 }
 
 /// Config for `merge_input_shares`
@@ -247,6 +316,13 @@ pub struct MergeInputSharesConfig {
     pub protocol: MPCProtocol,
     /// The output file where the merged input share is written to
     pub out: PathBuf,
+    /// The path to the secret key for decrypting encrypted input shares
+    pub share_secret_key: Option<PathBuf>,
+    /// The path to an input policy file declaring which client may provide which inputs. If given, every input share needs to be signed accordingly
+    pub input_policy: Option<PathBuf>,
+    /// Require every shared input element to be committed by exactly one dealer (see `--commit` of the SplitInput command), instead of only checking the committed ones
+    #[serde(default)]
+    pub require_commitments: bool,
 }
 
 /// Cli arguments for `generate_witness`
@@ -272,6 +348,17 @@ pub struct GenerateWitnessCli {
     #[arg(long)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
     pub out: Option<PathBuf>,
+    /// The path to an input policy file declaring which client may provide which inputs. If given, the input share needs to be signed accordingly
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub input_policy: Option<PathBuf>,
+    /// Require every shared input element to be committed by exactly one dealer (see `--commit` of the SplitInput command), instead of only checking the committed ones
+    #[arg(long)]
+    pub require_commitments: bool,
+    /// A Bristol Fashion circuit which is registered as accelerator for a Noir oracle function, in the format `<FUNCTION>=<PATH>`. Can be given multiple times
+    #[arg(long = "bristol-fashion")]
+    #[serde(skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub bristol_fashion: Vec<String>,
 }
 
 /// Config for `generate_witness`
@@ -285,6 +372,14 @@ pub struct GenerateWitnessConfig {
     pub protocol: MPCProtocol,
     /// The output file where the final witness share is written to
     pub out: PathBuf,
+    /// The path to an input policy file declaring which client may provide which inputs. If given, the input share needs to be signed accordingly
+    pub input_policy: Option<PathBuf>,
+    /// Require every shared input element to be committed by exactly one dealer (see `--commit` of the SplitInput command), instead of only checking the committed ones
+    #[serde(default)]
+    pub require_commitments: bool,
+    /// The Bristol Fashion circuits which are registered as accelerators for Noir oracle functions, in the format `<FUNCTION>=<PATH>`
+    #[serde(default)]
+    pub bristol_fashion: Vec<String>,
     /// Network config
     pub network: NetworkConfigFile,
 }
@@ -691,6 +786,58 @@ pub struct DownloadCrsConfig {
     pub num_points: usize,
 }
 
+/// Cli arguments for `generate_share_key`
+#[derive(Debug, Serialize, Args)]
+pub struct GenerateShareKeyCli {
+    /// The path to the config file
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub config: Option<PathBuf>,
+    /// The output file where the secret key is written to
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub secret_key: Option<PathBuf>,
+    /// The output file where the public key is written to
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub public_key: Option<PathBuf>,
+}
+
+/// Config for `generate_share_key`
+#[derive(Debug, Deserialize)]
+pub struct GenerateShareKeyConfig {
+    /// The output file where the secret key is written to
+    pub secret_key: PathBuf,
+    /// The output file where the public key is written to
+    pub public_key: PathBuf,
+}
+
+/// Cli arguments for `generate_client_key`
+#[derive(Debug, Serialize, Args)]
+pub struct GenerateClientKeyCli {
+    /// The path to the config file
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub config: Option<PathBuf>,
+    /// The output file where the secret key is written to
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub secret_key: Option<PathBuf>,
+    /// The output file where the public key is written to
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub public_key: Option<PathBuf>,
+}
+
+/// Config for `generate_client_key`
+#[derive(Debug, Deserialize)]
+pub struct GenerateClientKeyConfig {
+    /// The output file where the secret key is written to
+    pub secret_key: PathBuf,
+    /// The output file where the public key is written to
+    pub public_key: PathBuf,
+}
+
 /// Prefix for config env variables
 pub const CONFIG_ENV_PREFIX: &str = "CONOIR_";
 
@@ -729,6 +876,8 @@ impl_config!(BuildAndGenerateProofCli, BuildAndGenerateProofConfig);
 impl_config!(CreateVKCli, CreateVKConfig);
 impl_config!(VerifyCli, VerifyConfig);
 impl_config!(DownloadCrsCli, DownloadCrsConfig);
+impl_config!(GenerateShareKeyCli, GenerateShareKeyConfig);
+impl_config!(GenerateClientKeyCli, GenerateClientKeyConfig);
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
@@ -765,6 +914,10 @@ enum Commands {
     Verify(VerifyCli),
     /// Download a CRS with a given number of points
     DownloadCrs(DownloadCrsCli),
+    /// Generate a key pair for encrypting the share files of a party
+    GenerateShareKey(GenerateShareKeyCli),
+    /// Generate a key pair for signing the input shares of a client
+    GenerateClientKey(GenerateClientKeyCli),
 }
 
 fn install_tracing() {
@@ -850,9 +1003,134 @@ fn main() -> color_eyre::Result<ExitCode> {
             tracing::info!("Downloaded CRS successfully");
             Ok(ExitCode::SUCCESS)
         }
+        Commands::GenerateShareKey(cli) => {
+            let config = GenerateShareKeyConfig::parse(cli).context("while parsing config")?;
+            run_generate_share_key(config)
+        }
+        Commands::GenerateClientKey(cli) => {
+            let config = GenerateClientKeyConfig::parse(cli).context("while parsing config")?;
+            run_generate_client_key(config)
+        }
+    }
+}
+
+/// Creates the header of a share file, which is always for the BN254 curve in co-noir
+fn share_header(
+    kind: ShareKind,
+    protocol: MPCProtocol,
+    party_id: usize,
+    num_parties: usize,
+    threshold: usize,
+    circuit_hash: Option<[u8; 32]>,
+) -> ShareFileHeader {
+    let header = ShareFileHeader::new(
+        kind,
+        protocol.into(),
+        ShareCurve::Bn254,
+        party_id,
+        num_parties,
+        threshold,
+    );
+    match circuit_hash {
+        Some(circuit_hash) => header.with_circuit_hash(circuit_hash),
+        None => header,
+    }
+}
+
+/// The expected properties of a share file of the given party
+fn expected_share(
+    kind: ShareKind,
+    protocol: MPCProtocol,
+    party_id: usize,
+    num_parties: usize,
+    circuit_hash: Option<[u8; 32]>,
+) -> ExpectedShareFile {
+    ExpectedShareFile {
+        kind: Some(kind),
+        protocol: Some(protocol.into()),
+        curve: Some(ShareCurve::Bn254),
+        party_id: Some(party_id),
+        num_parties: Some(num_parties),
+        threshold: None,
+        circuit_hash,
+    }
+}
+
+/// Reads the raw bytes of the circuit file and computes its hash for the share file header
+fn circuit_hash(circuit_path: impl AsRef<std::path::Path>) -> color_eyre::Result<[u8; 32]> {
+    let circuit = std::fs::read(circuit_path).context("while reading circuit file")?;
+    Ok(share_file::circuit_hash(&circuit))
+}
+
+/// Reads the share keys of all parties declared in the network config file, ordered by party id
+fn read_share_keys(
+    network_config: &std::path::Path,
+    num_parties: usize,
+) -> color_eyre::Result<Vec<SharePublicKey>> {
+    let parties: Vec<NetworkPartyConfig> = Figment::new()
+        .merge(Toml::file(network_config))
+        .extract_inner("network.parties")
+        .context("while parsing parties of network config")?;
+    (0..num_parties)
+        .map(|id| {
+            let party = parties
+                .iter()
+                .find(|party| party.id == id)
+                .ok_or_else(|| eyre!("party {id} is missing in the network config"))?;
+            let path = party
+                .share_key_path
+                .as_ref()
+                .ok_or_else(|| eyre!("party {id} does not declare a share key"))?;
+            share_file::read_share_public_key(path)
+        })
+        .collect()
+}
+
+/// Reads our secret key for decrypting share files, if one is declared in the network config
+fn read_share_secret_key(
+    network: &NetworkConfigFile,
+) -> color_eyre::Result<Option<ShareSecretKey>> {
+    network
+        .share_secret_key_path
+        .as_ref()
+        .map(share_file::read_share_secret_key)
+        .transpose()
+}
+
+/// Reads the input policy file declaring which client may provide which inputs
+fn read_input_policy(path: &std::path::Path) -> color_eyre::Result<InputPolicy> {
+    let config: InputPolicyConfig = Figment::new()
+        .merge(Toml::file(path))
+        .extract()
+        .context("while parsing input policy")?;
+    config.try_into()
+}
+
+/// Writes a share file, which is encrypted to the share key of the receiving party if given
+fn write_share<T: Serialize>(
+    path: &std::path::Path,
+    header: &ShareFileHeader,
+    share: &T,
+    share_key: Option<&SharePublicKey>,
+) -> color_eyre::Result<()> {
+    let out_file = BufWriter::new(File::create(path).context("while creating output file")?);
+    match share_key {
+        Some(share_key) => share_file::write_encrypted_share_file(
+            out_file,
+            header,
+            share,
+            share_key,
+            &mut rand::thread_rng(),
+        ),
+        None => share_file::write_share_file(out_file, header, share),
     }
 }
 
+/// Returns the public key for the share files a party writes for itself, i.e., if the party has a share secret key, its own shares are encrypted as well, such that they can be read back with the key
+fn own_share_key(share_key: Option<&ShareSecretKey>) -> Option<SharePublicKey> {
+    share_key.map(SharePublicKey::from)
+}
+
 #[instrument(level = "debug", skip(config))]
 fn run_split_witness(config: SplitWitnessConfig) -> color_eyre::Result<ExitCode> {
     let witness_path = config.witness;
@@ -861,11 +1139,17 @@ fn run_split_witness(config: SplitWitnessConfig) -> color_eyre::Result<ExitCode>
     let out_dir = config.out_dir;
     let t = config.threshold;
     let n = config.num_parties;
+    let share_keys = config
+        .network_config
+        .as_deref()
+        .map(|path| read_share_keys(path, n))
+        .transpose()?;
 
     // parse constraint system
     let program = Utils::get_program_artifact_from_file(&circuit_path)
         .context("while parsing program artifact")?;
     let circuit = &program.bytecode.functions[0];
+    let circuit_hash = circuit_hash(&circuit_path)?;
 
     // parse witness
     let witness = Utils::get_witness_from_file(&witness_path).context("while parsing witness")?;
@@ -901,7 +1185,7 @@ fn run_split_witness(config: SplitWitnessConfig) -> color_eyre::Result<ExitCode>
             }
             // create witness shares
             let start = Instant::now();
-            let shares = co_noir::split_witness_rep3(witness, &mut rng);
+            let shares = co_noir::split_witness_rep3(witness, &mut rng).map(Rep3WitnessShare::from);
             let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
             tracing::info!("Split witness took {duration_ms} ms");
 
@@ -913,10 +1197,15 @@ fn run_split_witness(config: SplitWitnessConfig) -> color_eyre::Result<ExitCode>
                 .context("witness file name is not valid UTF-8")?;
             for (i, share) in shares.iter().enumerate() {
                 let path = out_dir.join(format!("{base_name}.{i}.shared"));
-                let out_file =
-                    BufWriter::new(File::create(&path).context("while creating output file")?);
-                bincode::serialize_into(out_file, share)
-                    .context("while serializing witness share")?;
+                let header =
+                    share_header(ShareKind::Witness, protocol, i, n, t, Some(circuit_hash));
+                write_share(
+                    &path,
+                    &header,
+                    share,
+                    share_keys.as_ref().map(|keys| &keys[i]),
+                )
+                .context("while writing witness share")?;
                 tracing::info!("Wrote witness share {} to file {}", i, path.display());
             }
         }
@@ -935,10 +1224,16 @@ fn run_split_witness(config: SplitWitnessConfig) -> color_eyre::Result<ExitCode>
                 .context("witness file name is not valid UTF-8")?;
             for (i, share) in shares.iter().enumerate() {
                 let path = out_dir.join(format!("{base_name}.{i}.shared"));
-                let out_file =
-                    BufWriter::new(File::create(&path).context("while creating output file")?);
-                bincode::serialize_into(out_file, share)
-                    .context("while serializing witness share")?;
+                let header =
+                    share_header(ShareKind::Witness, protocol, i, n, t, Some(circuit_hash))
+                        .with_compression(ShareCompression::SeededShares);
+                write_share(
+                    &path,
+                    &header,
+                    share,
+                    share_keys.as_ref().map(|keys| &keys[i]),
+                )
+                .context("while writing witness share")?;
                 tracing::info!("Wrote witness share {} to file {}", i, path.display());
             }
         }
@@ -958,10 +1253,16 @@ fn run_split_proving_key(config: SplitProvingKeyConfig) -> color_eyre::Result<Ex
     let n = config.num_parties;
     let recursive = config.recursive;
     let has_zk = ZeroKnowledge::from(config.zk);
+    let share_keys = config
+        .network_config
+        .as_deref()
+        .map(|path| read_share_keys(path, n))
+        .transpose()?;
 
     // parse constraint system
     let constraint_system = Utils::get_constraint_system_from_file(&circuit_path, true)
         .context("while parsing program artifact")?;
+    let circuit_hash = circuit_hash(&circuit_path)?;
     // parse witness
     let witness = Utils::get_witness_from_file(&witness_path).context("while parsing witness")?;
     let circuit_size = co_noir::compute_circuit_size::<Bn254>(&constraint_system, recursive)?;
@@ -996,10 +1297,15 @@ fn run_split_proving_key(config: SplitProvingKeyConfig) -> color_eyre::Result<Ex
             let base_name = "proving_key";
             for (i, share) in shares.into_iter().enumerate() {
                 let path = out_dir.join(format!("{base_name}.{i}.shared"));
-                let out_file =
-                    BufWriter::new(File::create(&path).context("while creating output file")?);
-                bincode::serialize_into(out_file, &share)
-                    .context("while serializing proving_key share")?;
+                let header =
+                    share_header(ShareKind::ProvingKey, protocol, i, n, t, Some(circuit_hash));
+                write_share(
+                    &path,
+                    &header,
+                    &share,
+                    share_keys.as_ref().map(|keys| &keys[i]),
+                )
+                .context("while writing proving_key share")?;
                 tracing::info!("Wrote proving_key share {} to file {}", i, path.display());
             }
         }
@@ -1019,10 +1325,16 @@ fn run_split_proving_key(config: SplitProvingKeyConfig) -> color_eyre::Result<Ex
             let base_name = "proving_key";
             for (i, share) in shares.into_iter().enumerate() {
                 let path = out_dir.join(format!("{base_name}.{i}.shared"));
-                let out_file =
-                    BufWriter::new(File::create(&path).context("while creating output file")?);
-                bincode::serialize_into(out_file, &share)
-                    .context("while serializing proving_key share")?;
+                let header =
+                    share_header(ShareKind::ProvingKey, protocol, i, n, t, Some(circuit_hash))
+                        .with_compression(ShareCompression::SeededShares);
+                write_share(
+                    &path,
+                    &header,
+                    &share,
+                    share_keys.as_ref().map(|keys| &keys[i]),
+                )
+                .context("while writing proving_key share")?;
                 tracing::info!("Wrote proving_key share {} to file {}", i, path.display());
             }
         }
@@ -1037,6 +1349,11 @@ fn run_split_input(config: SplitInputConfig) -> color_eyre::Result<ExitCode> {
     let circuit = config.circuit;
     let protocol = config.protocol;
     let out_dir = config.out_dir;
+    let share_keys = config
+        .network_config
+        .as_deref()
+        .map(|path| read_share_keys(path, 3))
+        .transpose()?;
 
     if protocol != MPCProtocol::REP3 {
         return Err(eyre!(
@@ -1048,6 +1365,7 @@ fn run_split_input(config: SplitInputConfig) -> color_eyre::Result<ExitCode> {
     // parse constraint system
     let compiled_program = Utils::get_program_artifact_from_file(&circuit_path)
         .context("while parsing program artifact")?;
+    let circuit_hash = circuit_hash(&circuit_path)?;
 
     // read the input file
     let inputs = Rep3CoSolver::<_, Rep3MpcNet>::partially_read_abi_bn254_fieldelement(
@@ -1057,10 +1375,29 @@ fn run_split_input(config: SplitInputConfig) -> color_eyre::Result<ExitCode> {
     )?;
 
     // create input shares
+    let ring_types = if config.ring_inputs {
+        co_noir::input_ring_types(&compiled_program.abi)
+    } else {
+        BTreeMap::new()
+    };
     let mut rng = rand::thread_rng();
     tracing::info!("Starting split input...");
     let start = Instant::now();
-    let shares = co_noir::split_input_rep3::<Bn254, Rep3MpcNet, _>(inputs, &mut rng);
+    let shares =
+        co_noir::split_input_rep3_with_ring_types::<Bn254, _>(inputs, &ring_types, &mut rng)?;
+    let mut shares = if config.commit {
+        co_noir::commit_input_shares(shares, &mut rng)?
+    } else {
+        shares.map(Rep3InputShares::new)
+    };
+    if let Some(client_id) = &config.client_id {
+        let client_key = config
+            .client_key
+            .as_deref()
+            .context("a client key is required to sign the input shares")?;
+        let client_key = input_provenance::read_client_signing_key(client_key)?;
+        co_noir::sign_input_shares(&mut shares, client_id, Some(circuit_hash), &client_key)?;
+    }
     let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
     tracing::info!("Split input took {duration_ms} ms");
 
@@ -1072,8 +1409,14 @@ fn run_split_input(config: SplitInputConfig) -> color_eyre::Result<ExitCode> {
         .context("input file name is not valid UTF-8")?;
     for (i, share) in shares.iter().enumerate() {
         let path = out_dir.join(format!("{base_name}.{i}.shared"));
-        let out_file = BufWriter::new(File::create(&path).context("while creating output file")?);
-        bincode::serialize_into(out_file, share).context("while serializing input share")?;
+        let header = share_header(ShareKind::Input, protocol, i, 3, 1, Some(circuit_hash));
+        write_share(
+            &path,
+            &header,
+            share,
+            share_keys.as_ref().map(|keys| &keys[i]),
+        )
+        .context("while writing input share")?;
         tracing::info!("Wrote input share {} to file {}", i, path.display());
     }
 
@@ -1096,30 +1439,85 @@ fn run_merge_input_shares(config: MergeInputSharesConfig) -> color_eyre::Result<
     if inputs.len() < 2 {
         return Err(eyre!("Need at least two input shares to merge"));
     }
-
+    let share_key = config
+        .share_secret_key
+        .as_deref()
+        .map(share_file::read_share_secret_key)
+        .transpose()?;
+    let input_policy = config
+        .input_policy
+        .as_deref()
+        .map(read_input_policy)
+        .transpose()?;
+
+    // all input shares need to belong to the same party and circuit as the first one
+    let mut expected = ExpectedShareFile {
+        kind: Some(ShareKind::Input),
+        protocol: Some(ShareProtocol::Rep3),
+        curve: Some(ShareCurve::Bn254),
+        num_parties: Some(3),
+        ..Default::default()
+    };
+    let mut header = None;
     let input_shares = inputs
         .iter()
         .map(|input| {
             // parse input shares
             let input_share_file =
                 BufReader::new(File::open(input).context("while opening input share file")?);
-            let input_share: BTreeMap<String, Rep3AcvmType<ark_bn254::Fr>> =
-                bincode::deserialize_from(input_share_file)
-                    .context("while deserializing input share")?;
+            let (input_header, input_share): (_, Rep3InputShares<ark_bn254::Fr>) =
+                share_file::read_share_file_with_key(
+                    input_share_file,
+                    &expected,
+                    share_key.as_ref(),
+                )
+                .with_context(|| format!("trying to load input share {}", input.display()))?;
+            let party_id = input_header.party_id.try_into()?;
+            input_share
+                .verify_commitments(party_id)
+                .with_context(|| format!("while verifying input share {}", input.display()))?;
+            if config.require_commitments {
+                input_share.check_commitment_coverage().with_context(|| {
+                    format!(
+                        "while checking commitments of input share {}",
+                        input.display()
+                    )
+                })?;
+            }
+            // reject inputs of clients which are not allowed to provide them
+            if let Some(input_policy) = &input_policy {
+                input_share
+                    .verify_provenance(input_policy, party_id, input_header.circuit_hash)
+                    .with_context(|| {
+                        format!(
+                            "while checking provenance of input share {}",
+                            input.display()
+                        )
+                    })?;
+            }
+            expected.party_id = Some(input_header.party_id);
+            expected.circuit_hash = input_header.circuit_hash;
+            header.get_or_insert(input_header);
             color_eyre::Result::<_>::Ok(input_share)
         })
         .collect::<Result<Vec<_>, _>>()?;
+    let header = header.context("we have at least two input shares")?;
 
     tracing::info!("Starting input shares merging...");
     let start = Instant::now();
-    let result = co_noir::merge_input_shares::<Bn254>(input_shares)?;
+    let result = co_noir::merge_typed_input_shares::<Bn254>(input_shares)?;
     let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
     tracing::info!("Merge input shares took {duration_ms} ms");
 
     // write out the shares to the output file
-    let out_file = BufWriter::new(File::create(&out).context("while creating output file")?);
-    bincode::serialize_into(out_file, &result).context("while serializing witness share")?;
-    tracing::info!("Witness successfully written to {}", out.display());
+    write_share(
+        &out,
+        &header,
+        &result,
+        own_share_key(share_key.as_ref()).as_ref(),
+    )
+    .context("while writing input share")?;
+    tracing::info!("Input share successfully written to {}", out.display());
 
     tracing::info!("Merge input into shares successfully");
     Ok(ExitCode::SUCCESS)
@@ -1142,34 +1540,77 @@ fn run_generate_witness(config: GenerateWitnessConfig) -> color_eyre::Result<Exi
     // parse constraint system
     let compiled_program = Utils::get_program_artifact_from_file(&circuit_path)
         .context("while parsing program artifact")?;
+    let circuit_hash = circuit_hash(&circuit_path)?;
+    let bristol_fashion = co_noir::read_bristol_fashion(&config.bristol_fashion)?;
+
+    let network_config: NetworkConfig = config
+        .network
+        .to_owned()
+        .try_into()
+        .context("while converting network config")?;
+    let share_key = read_share_secret_key(&config.network)?;
+    let party_id = network_config.my_id;
 
     // parse input shares
     let input_share_file =
         BufReader::new(File::open(&input).context("while opening input share file")?);
-    let input_share: BTreeMap<String, Rep3AcvmType<ark_bn254::Fr>> =
-        bincode::deserialize_from(input_share_file).context("while deserializing input share")?;
+    let expected = expected_share(ShareKind::Input, protocol, party_id, 3, Some(circuit_hash));
+    let (_, input_share): (_, Rep3InputShares<ark_bn254::Fr>) =
+        share_file::read_share_file_with_key(input_share_file, &expected, share_key.as_ref())
+            .context("while reading input share")?;
+    if let Some(input_policy) = &config.input_policy {
+        let input_policy = read_input_policy(input_policy)?;
+        input_share
+            .verify_provenance(&input_policy, party_id.try_into()?, Some(circuit_hash))
+            .context("while checking provenance of input share")?;
+    }
+    if config.require_commitments {
+        input_share
+            .check_commitment_coverage()
+            .context("while checking commitments of input share")?;
+    }
 
     // connect to network
-    let network_config = config
-        .network
-        .to_owned()
-        .try_into()
-        .context("while converting network config")?;
-    let net = Rep3MpcNet::new(network_config)?;
+    let mut net = Rep3MpcNet::new(network_config)?;
+
+    // reject inconsistent input shares before starting the witness extension
+    co_noir::verify_input_commitments(&input_share, &mut net)?;
 
     tracing::info!("Starting witness generation...");
     let start = Instant::now();
-    let (result_witness_share, net) =
-        co_noir::generate_witness_rep3(input_share, compiled_program, net)?;
+    let ring_inputs = input_share.ring_inputs();
+    let (input_share, net) = co_noir::cast_input_shares(input_share.inputs, net)?;
+    let (result_witness_share, net) = co_noir::generate_witness_rep3_with_bristol_fashion(
+        input_share,
+        compiled_program,
+        net,
+        bristol_fashion,
+    )?;
     let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
     tracing::info!("Generate witness took {duration_ms} ms");
     // network is shutdown in drop, which can take seom time with quinn
     drop(net);
 
     // write result to output file
-    let out_file = BufWriter::new(std::fs::File::create(&out)?);
-    bincode::serialize_into(out_file, &result_witness_share)
-        .context("while serializing witness share")?;
+    let header = share_header(
+        ShareKind::Witness,
+        protocol,
+        party_id,
+        3,
+        1,
+        Some(circuit_hash),
+    );
+    let result_witness_share = Rep3WitnessShare {
+        witness: result_witness_share,
+        ring_inputs,
+    };
+    write_share(
+        &out,
+        &header,
+        &result_witness_share,
+        own_share_key(share_key.as_ref()).as_ref(),
+    )
+    .context("while writing witness share")?;
     tracing::info!("Witness successfully written to {}", out.display());
     Ok(ExitCode::SUCCESS)
 }
@@ -1181,37 +1622,99 @@ fn run_translate_witness(config: TranslateWitnessConfig) -> color_eyre::Result<E
     let target_protocol = config.target_protocol;
     let out = config.out;
 
-    if src_protocol != MPCProtocol::REP3 || target_protocol != MPCProtocol::SHAMIR {
-        return Err(eyre!("Only REP3 to SHAMIR translation is supported"));
-    }
-
-    // parse witness shares
     let witness_file =
         BufReader::new(File::open(witness).context("trying to open witness share file")?);
-    let witness_share: Vec<Rep3AcvmType<ark_bn254::Fr>> =
-        bincode::deserialize_from(witness_file).context("while deserializing witness share")?;
-
-    // connect to network
-    let network_config = config
+    let network_config: NetworkConfig = config
         .network
         .to_owned()
         .try_into()
         .context("while converting network config")?;
-    let net = Rep3MpcNet::new(network_config)?;
+    let share_key = read_share_secret_key(&config.network)?;
+    // translation is only possible for 3 parties with threshold 1
+    let expected = ExpectedShareFile {
+        threshold: Some(1),
+        ..expected_share(
+            ShareKind::Witness,
+            src_protocol,
+            network_config.my_id,
+            3,
+            None,
+        )
+    };
 
-    // Translate witness to shamir shares
-    tracing::info!("Starting witness translation...");
-    let start = Instant::now();
-    let (shamir_witness_shares, net) =
-        co_noir::translate_witness::<Bn254, _, _>(witness_share, net)?;
-    let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
-    tracing::info!("Translate witness took {duration_ms} ms");
-    // network is shutdown in drop, which can take seom time with quinn
-    drop(net);
+    match (src_protocol, target_protocol) {
+        (MPCProtocol::REP3, MPCProtocol::SHAMIR) => {
+            // parse witness shares
+            let (header, witness_share): (_, Rep3WitnessShare<ark_bn254::Fr>) =
+                share_file::read_share_file_with_key(witness_file, &expected, share_key.as_ref())
+                    .context("while reading witness share")?;
 
-    // write result to output file
-    let out_file = BufWriter::new(std::fs::File::create(&out)?);
-    bincode::serialize_into(out_file, &shamir_witness_shares)?;
+            // connect to network
+            let net = Rep3MpcNet::new(network_config)?;
+
+            // Translate witness to shamir shares
+            tracing::info!("Starting witness translation...");
+            let start = Instant::now();
+            let (shamir_witness_shares, net) =
+                co_noir::translate_witness::<Bn254, _, _>(witness_share.witness, net)?;
+            let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
+            tracing::info!("Translate witness took {duration_ms} ms");
+            // network is shutdown in drop, which can take seom time with quinn
+            drop(net);
+
+            // write result to output file, in the same format as the split witness
+            let header = ShareFileHeader {
+                protocol: ShareProtocol::Shamir,
+                compression: ShareCompression::None,
+                ..header
+            };
+            let shamir_witness_shares = CompressedShamirWitnessShare::from(shamir_witness_shares);
+            write_share(
+                &out,
+                &header,
+                &shamir_witness_shares,
+                own_share_key(share_key.as_ref()).as_ref(),
+            )?;
+        }
+        (MPCProtocol::SHAMIR, MPCProtocol::REP3) => {
+            // parse witness shares
+            let (header, witness_share): (_, CompressedShamirWitnessShare<ark_bn254::Fr>) =
+                share_file::read_share_file_with_key(witness_file, &expected, share_key.as_ref())
+                    .context("while reading witness share")?;
+            let witness_share = witness_share.expand()?;
+
+            // connect to network
+            let net = ShamirMpcNet::new(network_config)?;
+
+            // Translate witness to rep3 shares
+            tracing::info!("Starting witness translation...");
+            let start = Instant::now();
+            let (rep3_witness_shares, net) =
+                co_noir::translate_witness_to_rep3::<Bn254, _>(witness_share, net)?;
+            let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
+            tracing::info!("Translate witness took {duration_ms} ms");
+            // network is shutdown in drop, which can take seom time with quinn
+            drop(net);
+
+            // write result to output file
+            let header = ShareFileHeader {
+                protocol: ShareProtocol::Rep3,
+                compression: ShareCompression::None,
+                ..header
+            };
+            write_share(
+                &out,
+                &header,
+                &Rep3WitnessShare::from(rep3_witness_shares),
+                own_share_key(share_key.as_ref()).as_ref(),
+            )?;
+        }
+        _ => {
+            return Err(eyre!(
+                "Only REP3 to SHAMIR and SHAMIR to REP3 translations are supported"
+            ));
+        }
+    }
     tracing::info!("Witness successfully written to {}", out.display());
     Ok(ExitCode::SUCCESS)
 }
@@ -1223,36 +1726,106 @@ fn run_translate_proving_key(config: TranslateProvingKeyConfig) -> color_eyre::R
     let target_protocol = config.target_protocol;
     let out = config.out;
 
-    if src_protocol != MPCProtocol::REP3 || target_protocol != MPCProtocol::SHAMIR {
-        return Err(eyre!("Only REP3 to SHAMIR translation is supported"));
-    }
-
-    // parse proving_key shares
     let proving_key_file =
         BufReader::new(File::open(proving_key).context("trying to open witness share file")?);
-    let proving_key: ProvingKey<Rep3UltraHonkDriver<Rep3MpcNet>, Bn254> =
-        bincode::deserialize_from(proving_key_file).context("while deserializing witness share")?;
-
-    // connect to network
-    let network_config = config
+    let network_config: NetworkConfig = config
         .network
         .to_owned()
         .try_into()
         .context("while converting network config")?;
-    let net = Rep3MpcNet::new(network_config)?;
+    let share_key = read_share_secret_key(&config.network)?;
+    // translation is only possible for 3 parties with threshold 1
+    let expected = ExpectedShareFile {
+        threshold: Some(1),
+        ..expected_share(
+            ShareKind::ProvingKey,
+            src_protocol,
+            network_config.my_id,
+            3,
+            None,
+        )
+    };
 
-    // Translate proving key to shamir shares
-    tracing::info!("Starting proving key translation...");
-    let start = Instant::now();
-    let (shamir_proving_key, net) = co_noir::translate_proving_key(proving_key, net)?;
-    let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
-    tracing::info!("Translate proving key took {duration_ms} ms");
-    // network is shutdown in drop, which can take seom time with quinn
-    drop(net);
+    match (src_protocol, target_protocol) {
+        (MPCProtocol::REP3, MPCProtocol::SHAMIR) => {
+            // parse proving_key shares
+            let (header, proving_key): (_, ProvingKey<Rep3UltraHonkDriver<Rep3MpcNet>, Bn254>) =
+                share_file::read_share_file_with_key(
+                    proving_key_file,
+                    &expected,
+                    share_key.as_ref(),
+                )
+                .context("while reading proving key share")?;
 
-    // write result to output file
-    let out_file = BufWriter::new(std::fs::File::create(&out)?);
-    bincode::serialize_into(out_file, &shamir_proving_key)?;
+            // connect to network
+            let net = Rep3MpcNet::new(network_config)?;
+
+            // Translate proving key to shamir shares
+            tracing::info!("Starting proving key translation...");
+            let start = Instant::now();
+            let (shamir_proving_key, net) = co_noir::translate_proving_key(proving_key, net)?;
+            let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
+            tracing::info!("Translate proving key took {duration_ms} ms");
+            // network is shutdown in drop, which can take seom time with quinn
+            drop(net);
+
+            // write result to output file, in the same format as the split proving key
+            let header = ShareFileHeader {
+                protocol: ShareProtocol::Shamir,
+                compression: ShareCompression::None,
+                ..header
+            };
+            let shamir_proving_key = CompressedShamirProvingKey::from(shamir_proving_key);
+            write_share(
+                &out,
+                &header,
+                &shamir_proving_key,
+                own_share_key(share_key.as_ref()).as_ref(),
+            )?;
+        }
+        (MPCProtocol::SHAMIR, MPCProtocol::REP3) => {
+            // parse proving_key shares
+            let (header, proving_key): (_, CompressedShamirProvingKey<Bn254, ShamirMpcNet>) =
+                share_file::read_share_file_with_key(
+                    proving_key_file,
+                    &expected,
+                    share_key.as_ref(),
+                )
+                .context("while reading proving key share")?;
+            let proving_key = proving_key.expand()?;
+
+            // connect to network
+            let net = ShamirMpcNet::new(network_config)?;
+
+            // Translate proving key to rep3 shares
+            tracing::info!("Starting proving key translation...");
+            let start = Instant::now();
+            let (rep3_proving_key, net) =
+                co_noir::translate_proving_key_to_rep3::<_, Rep3MpcNet, _>(proving_key, net)?;
+            let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
+            tracing::info!("Translate proving key took {duration_ms} ms");
+            // network is shutdown in drop, which can take seom time with quinn
+            drop(net);
+
+            // write result to output file
+            let header = ShareFileHeader {
+                protocol: ShareProtocol::Rep3,
+                compression: ShareCompression::None,
+                ..header
+            };
+            write_share(
+                &out,
+                &header,
+                &rep3_proving_key,
+                own_share_key(share_key.as_ref()).as_ref(),
+            )?;
+        }
+        _ => {
+            return Err(eyre!(
+                "Only REP3 to SHAMIR and SHAMIR to REP3 translations are supported"
+            ));
+        }
+    }
     tracing::info!("Proving_key successfully written to {}", out.display());
     Ok(ExitCode::SUCCESS)
 }
@@ -1273,12 +1846,24 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
     // parse constraint system
     let constraint_system = Utils::get_constraint_system_from_file(&circuit_path, true)
         .context("while parsing program artifact")?;
+    let circuit_hash = circuit_hash(&circuit_path)?;
 
-    let network_config = config
+    let network_config: NetworkConfig = config
         .network
         .to_owned()
         .try_into()
         .context("while converting network config")?;
+    let share_key = read_share_secret_key(&config.network)?;
+    let expected = ExpectedShareFile {
+        threshold: Some(t),
+        ..expected_share(
+            ShareKind::Witness,
+            protocol,
+            network_config.my_id,
+            network_config.parties.len(),
+            Some(circuit_hash),
+        )
+    };
 
     tracing::info!("Starting proving key generation...");
     match protocol {
@@ -1286,8 +1871,9 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
             if t != 1 {
                 return Err(eyre!("REP3 only allows the threshold to be 1"));
             }
-            let witness_share = bincode::deserialize_from(witness_file)
-                .context("while deserializing witness share")?;
+            let (header, witness_share): (_, Rep3WitnessShare<ark_bn254::Fr>) =
+                share_file::read_share_file_with_key(witness_file, &expected, share_key.as_ref())
+                    .context("while reading witness share")?;
             // connect to network
             let net = Rep3MpcNet::new(network_config)?;
 
@@ -1295,7 +1881,7 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
             let (proving_key, net) = co_noir::generate_proving_key_rep3(
                 net,
                 &constraint_system,
-                witness_share,
+                witness_share.witness,
                 recursive,
             )?;
             let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
@@ -1304,13 +1890,24 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
             drop(net);
 
             // write result to output file
-            let out_file = BufWriter::new(std::fs::File::create(&out)?);
-            bincode::serialize_into(out_file, &proving_key)?;
+            let header = ShareFileHeader {
+                kind: ShareKind::ProvingKey,
+                compression: ShareCompression::None,
+                ..header
+            };
+            write_share(
+                &out,
+                &header,
+                &proving_key,
+                own_share_key(share_key.as_ref()).as_ref(),
+            )?;
             tracing::info!("Proving Key successfully written to {}", out.display());
         }
         MPCProtocol::SHAMIR => {
-            let witness_share = bincode::deserialize_from(witness_file)
-                .context("while deserializing witness share")?;
+            let (header, witness_share): (_, CompressedShamirWitnessShare<ark_bn254::Fr>) =
+                share_file::read_share_file_with_key(witness_file, &expected, share_key.as_ref())
+                    .context("while reading witness share")?;
+            let witness_share = witness_share.expand()?;
             // connect to network
             let net = ShamirMpcNet::new(network_config)?;
 
@@ -1327,9 +1924,19 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
             // network is shutdown in drop, which can take seom time with quinn
             drop(net);
 
-            // write result to output file
-            let out_file = BufWriter::new(std::fs::File::create(&out)?);
-            bincode::serialize_into(out_file, &proving_key)?;
+            // write result to output file, in the same format as the split proving key
+            let header = ShareFileHeader {
+                kind: ShareKind::ProvingKey,
+                compression: ShareCompression::None,
+                ..header
+            };
+            let proving_key = CompressedShamirProvingKey::from(proving_key);
+            write_share(
+                &out,
+                &header,
+                &proving_key,
+                own_share_key(share_key.as_ref()).as_ref(),
+            )?;
             tracing::info!("Proving Key successfully written to {}", out.display());
         }
     };
@@ -1350,11 +1957,22 @@ fn run_generate_proof(config: GenerateProofConfig) -> color_eyre::Result<ExitCod
     let fields_as_json = config.fields_as_json;
     let has_zk = ZeroKnowledge::from(config.zk);
 
-    let network_config = config
+    let network_config: NetworkConfig = config
         .network
         .to_owned()
         .try_into()
         .context("while converting network config")?;
+    let share_key = read_share_secret_key(&config.network)?;
+    let expected = ExpectedShareFile {
+        threshold: Some(t),
+        ..expected_share(
+            ShareKind::ProvingKey,
+            protocol,
+            network_config.my_id,
+            network_config.parties.len(),
+            None,
+        )
+    };
 
     // parse proving_key file
     let proving_key_file =
@@ -1369,9 +1987,13 @@ fn run_generate_proof(config: GenerateProofConfig) -> color_eyre::Result<ExitCod
             let net = Rep3MpcNet::new(network_config)?;
 
             // Get the proving key and prover
-            let proving_key: ProvingKey<Rep3UltraHonkDriver<Rep3MpcNet>, Bn254> =
-                bincode::deserialize_from(proving_key_file)
-                    .context("while deserializing input share")?;
+            let (_, proving_key): (_, ProvingKey<Rep3UltraHonkDriver<Rep3MpcNet>, Bn254>) =
+                share_file::read_share_file_with_key(
+                    proving_key_file,
+                    &expected,
+                    share_key.as_ref(),
+                )
+                .context("while reading proving key share")?;
             let prover_crs = CrsParser::<Bn254>::get_crs_g1(
                 crs_path,
                 proving_key.circuit_size as usize,
@@ -1417,9 +2039,14 @@ fn run_generate_proof(config: GenerateProofConfig) -> color_eyre::Result<ExitCod
             let net = ShamirMpcNet::new(network_config)?;
 
             // Get the proving key and prover
-            let proving_key: ProvingKey<ShamirUltraHonkDriver<ark_bn254::Fr, ShamirMpcNet>, Bn254> =
-                bincode::deserialize_from(proving_key_file)
-                    .context("while deserializing input share")?;
+            let (_, proving_key): (_, CompressedShamirProvingKey<Bn254, ShamirMpcNet>) =
+                share_file::read_share_file_with_key(
+                    proving_key_file,
+                    &expected,
+                    share_key.as_ref(),
+                )
+                .context("while reading proving key share")?;
+            let proving_key = proving_key.expand()?;
             let prover_crs = CrsParser::<Bn254>::get_crs_g1(
                 crs_path,
                 proving_key.circuit_size as usize,
@@ -1574,12 +2201,24 @@ fn run_build_and_generate_proof(
     // parse constraint system
     let constraint_system = Utils::get_constraint_system_from_file(&circuit_path, true)
         .context("while parsing program artifact")?;
+    let circuit_hash = circuit_hash(&circuit_path)?;
 
-    let network_config = config
+    let network_config: NetworkConfig = config
         .network
         .to_owned()
         .try_into()
         .context("while converting network config")?;
+    let share_key = read_share_secret_key(&config.network)?;
+    let expected = ExpectedShareFile {
+        threshold: Some(t),
+        ..expected_share(
+            ShareKind::Witness,
+            protocol,
+            network_config.my_id,
+            network_config.parties.len(),
+            Some(circuit_hash),
+        )
+    };
 
     let circuit_size = co_noir::compute_circuit_size::<Bn254>(&constraint_system, recursive)?;
     let prover_crs = CrsParser::get_crs_g1(crs_path, circuit_size, has_zk)?;
@@ -1590,8 +2229,9 @@ fn run_build_and_generate_proof(
             if t != 1 {
                 return Err(eyre!("REP3 only allows the threshold to be 1"));
             }
-            let witness_share = bincode::deserialize_from(witness_file)
-                .context("while deserializing witness share")?;
+            let (_, witness_share): (_, Rep3WitnessShare<ark_bn254::Fr>) =
+                share_file::read_share_file_with_key(witness_file, &expected, share_key.as_ref())
+                    .context("while reading witness share")?;
             // connect to network
             let net = Rep3MpcNet::new(network_config)?;
 
@@ -1599,7 +2239,7 @@ fn run_build_and_generate_proof(
             let (proving_key, net) = co_noir::generate_proving_key_rep3(
                 net,
                 &constraint_system,
-                witness_share,
+                witness_share.witness,
                 recursive,
             )?;
 
@@ -1642,8 +2282,10 @@ fn run_build_and_generate_proof(
             (proof, public_input)
         }
         MPCProtocol::SHAMIR => {
-            let witness_share = bincode::deserialize_from(witness_file)
-                .context("while deserializing witness share")?;
+            let (_, witness_share): (_, CompressedShamirWitnessShare<ark_bn254::Fr>) =
+                share_file::read_share_file_with_key(witness_file, &expected, share_key.as_ref())
+                    .context("while reading witness share")?;
+            let witness_share = witness_share.expand()?;
             // connect to network
             let net = ShamirMpcNet::new(network_config)?;
 
@@ -1779,6 +2421,37 @@ fn run_build_and_generate_proof(
     Ok(ExitCode::SUCCESS)
 }
 
+#[instrument(level = "debug", skip(config))]
+fn run_generate_share_key(config: GenerateShareKeyConfig) -> color_eyre::Result<ExitCode> {
+    let secret_key = share_file::generate_share_key(&mut rand::thread_rng());
+    let public_key = SharePublicKey::from(&secret_key);
+    share_file::write_secret_key(&config.secret_key, &secret_key.to_bytes())
+        .context("while writing secret key")?;
+    std::fs::write(&config.public_key, public_key.as_bytes())
+        .context("while writing public key")?;
+    tracing::info!(
+        "Wrote share key pair to {} and {}",
+        config.secret_key.display(),
+        config.public_key.display()
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+#[instrument(level = "debug", skip(config))]
+fn run_generate_client_key(config: GenerateClientKeyConfig) -> color_eyre::Result<ExitCode> {
+    let secret_key = input_provenance::generate_client_key(&mut rand::thread_rng());
+    share_file::write_secret_key(&config.secret_key, &secret_key.to_bytes())
+        .context("while writing secret key")?;
+    std::fs::write(&config.public_key, secret_key.verifying_key().as_bytes())
+        .context("while writing public key")?;
+    tracing::info!(
+        "Wrote client key pair to {} and {}",
+        config.secret_key.display(),
+        config.public_key.display()
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
 #[instrument(level = "debug", skip(config))]
 fn run_generate_vk(config: CreateVKConfig) -> color_eyre::Result<ExitCode> {
     let circuit_path = config.circuit;
@@ -9,7 +9,8 @@ use acir::{
     acir_field::GenericFieldElement,
     native_types::{WitnessMap, WitnessStack},
 };
-use ark_ff::PrimeField;
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{CanonicalSerialize, SerializationError};
 use co_acvm::pss_store::PssStore;
 use co_acvm::{
     PlainAcvmSolver, Rep3AcvmSolver, ShamirAcvmSolver,
@@ -22,13 +23,23 @@ use co_ultrahonk::prelude::{
 use color_eyre::eyre::{self, Context, Result, eyre};
 use mpc_core::protocols::{
     bridges::network::RepToShamirNetwork,
-    rep3::{self, network::Rep3Network},
-    shamir::{self, ShamirPreprocessing, ShamirProtocol, network::ShamirNetwork},
+    rep3::{
+        self,
+        network::{IoContext, Rep3Network},
+        vss::{self, Rep3Components, Rep3InputCommitment},
+    },
+    rep3_ring::{self, Rep3TypedRingShare, RingType},
+    shamir::{
+        self, SeedRng, ShamirPreprocessing, ShamirProtocol, ShamirShareVecType,
+        network::ShamirNetwork,
+    },
 };
 
-use noirc_abi::Abi;
+use mpc_types::input_provenance::{InputCoverage, InputNames, InputProvenance};
+use noirc_abi::{Abi, AbiType};
 use noirc_artifacts::program::ProgramArtifact;
 use rand::{CryptoRng, Rng};
+use serde::{Deserialize, Serialize};
 use std::{array, collections::BTreeMap, fs::File, io::Write, path::Path, sync::Arc};
 
 pub use ark_bn254::Bn254;
@@ -44,10 +55,11 @@ pub use co_ultrahonk::{
     },
 };
 pub use mpc_core::protocols::{
-    rep3::{PartyID, network::Rep3MpcNet},
+    rep3::{PartyID, network::Rep3MpcNet, yao::bristol_fashion::BristolFashionCircuit},
     shamir::network::ShamirMpcNet,
 };
 pub use mpc_net::config::{Address, NetworkConfig, NetworkParty, ParseAddressError};
+pub use mpc_types::input_provenance::{ClientPolicy, ClientSigningKey, InputPolicy};
 pub use sha3::Keccak256;
 
 /// State with a rep3 proving key
@@ -262,33 +274,137 @@ pub fn split_witness_rep3<F: PrimeField, R: Rng + CryptoRng>(
     res
 }
 
-/// Split a witness into shamir shares
+/// A shamir witness share as written by [split_witness_shamir]. The public values are stored in plain, while the shares of all shared values are stored together, such that they can be compressed to a seed.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CompressedShamirWitnessShare<F: PrimeField> {
+    /// The public values of the witness, where the shared values are `None`
+    #[serde(
+        serialize_with = "mpc_core::ark_se",
+        deserialize_with = "mpc_core::ark_de"
+    )]
+    pub public_values: Vec<Option<F>>,
+    /// The shares of the shared values of the witness
+    pub shares: ShamirShareVecType<F>,
+}
+
+impl<F: PrimeField> From<Vec<ShamirAcvmType<F>>> for CompressedShamirWitnessShare<F> {
+    fn from(value: Vec<ShamirAcvmType<F>>) -> Self {
+        let mut public_values = Vec::with_capacity(value.len());
+        let mut shares = Vec::new();
+        for el in value {
+            match el {
+                ShamirAcvmType::Public(f) => public_values.push(Some(f)),
+                ShamirAcvmType::Shared(share) => {
+                    public_values.push(None);
+                    shares.push(share);
+                }
+            }
+        }
+        Self {
+            public_values,
+            shares: ShamirShareVecType::Shares(shares),
+        }
+    }
+}
+
+impl<F: PrimeField> CompressedShamirWitnessShare<F> {
+    /// Expands the compressed witness share
+    pub fn expand(self) -> Result<Vec<ShamirAcvmType<F>>> {
+        let num_shared = self.public_values.iter().filter(|v| v.is_none()).count();
+        if num_shared != self.shares.length() {
+            return Err(eyre!(
+                "Number of shares ({}) does not match number of shared values ({num_shared})",
+                self.shares.length()
+            ));
+        }
+        let mut shares = self.shares.expand_vec().into_iter();
+        Ok(self
+            .public_values
+            .into_iter()
+            .map(|v| match v {
+                Some(f) => ShamirAcvmType::from(f),
+                None => ShamirAcvmType::from(shares.next().expect("checked length")),
+            })
+            .collect())
+    }
+}
+
+/// A shamir proving key share as written by [split_proving_key_shamir], where the shares of the witness polynomials are stored separately, such that they can be compressed to a seed.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CompressedShamirProvingKey<P: Pairing, N: ShamirNetwork> {
+    /// The proving key with empty witness polynomials
+    pub proving_key: ShamirProvingKey<P, N>,
+    /// The shares of the witness polynomials
+    pub witness: ShamirShareVecType<P::ScalarField>,
+}
+
+impl<P: Pairing, N: ShamirNetwork> From<ShamirProvingKey<P, N>>
+    for CompressedShamirProvingKey<P, N>
+{
+    fn from(mut proving_key: ShamirProvingKey<P, N>) -> Self {
+        let witness = proving_key
+            .polynomials
+            .witness
+            .iter_mut()
+            .flat_map(|el| std::mem::take(el).into_vec())
+            .collect::<Vec<_>>();
+        Self {
+            proving_key,
+            witness: ShamirShareVecType::Shares(witness),
+        }
+    }
+}
+
+impl<P: Pairing, N: ShamirNetwork> CompressedShamirProvingKey<P, N> {
+    /// Expands the compressed proving key share
+    pub fn expand(self) -> Result<ShamirProvingKey<P, N>> {
+        let mut proving_key = self.proving_key;
+        let circuit_size = proving_key.circuit_size as usize;
+        if self.witness.length() != circuit_size * PROVER_WITNESS_ENTITIES_SIZE {
+            return Err(eyre!(
+                "Share length is not {PROVER_WITNESS_ENTITIES_SIZE} times circuit size"
+            ));
+        }
+        let shares = self.witness.expand_vec();
+        for (src, des) in shares
+            .chunks_exact(circuit_size)
+            .zip(proving_key.polynomials.witness.iter_mut())
+        {
+            *des = Polynomial::new(src.to_owned());
+        }
+        Ok(proving_key)
+    }
+}
+
+/// Split a witness into shamir shares, where the shares of `degree` parties are compressed to seeds
 pub fn split_witness_shamir<F: PrimeField, R: Rng + CryptoRng>(
     witness: Vec<PubShared<F>>,
     degree: usize,
     num_parties: usize,
     rng: &mut R,
-) -> Vec<Vec<ShamirAcvmType<F>>> {
-    let mut res = (0..num_parties)
-        .map(|_| Vec::with_capacity(witness.len()))
-        .collect::<Vec<_>>();
+) -> Vec<CompressedShamirWitnessShare<F>> {
+    let mut public_values = Vec::with_capacity(witness.len());
+    let mut shared_values = Vec::new();
 
     for witness in witness {
         match witness {
-            PubShared::Public(f) => {
-                for r in res.iter_mut() {
-                    r.push(ShamirAcvmType::from(f));
-                }
-            }
+            PubShared::Public(f) => public_values.push(Some(f)),
             PubShared::Shared(f) => {
-                let shares = shamir::share_field_element(f, degree, num_parties, rng);
-                for (r, share) in res.iter_mut().zip(shares) {
-                    r.push(ShamirAcvmType::from(share));
-                }
+                public_values.push(None);
+                shared_values.push(f);
             }
         }
     }
-    res
+
+    shamir::share_field_elements_seeded::<_, _, SeedRng>(&shared_values, degree, num_parties, rng)
+        .into_iter()
+        .map(|shares| CompressedShamirWitnessShare {
+            public_values: public_values.clone(),
+            shares: ShamirShareVecType::Seeded(shares),
+        })
+        .collect()
 }
 
 #[allow(clippy::type_complexity)]
@@ -301,12 +417,30 @@ pub fn execute_circuit_rep3<N: Rep3Network>(
     Vec<Rep3AcvmType<ark_bn254::Fr>>,
     PssStore<Rep3AcvmSolver<ark_bn254::Fr, N>, ark_bn254::Fr>,
     N,
+)> {
+    execute_circuit_rep3_with_bristol_fashion(input_share, compiled_program, net, Vec::new())
+}
+
+#[allow(clippy::type_complexity)]
+/// Executes the noir circuit with REP3 protocol, where the given Bristol Fashion circuits are registered as accelerators for the Noir oracle functions with the given names (see [`CoSolver::register_bristol_fashion`](co_acvm::solver::CoSolver::register_bristol_fashion))
+pub fn execute_circuit_rep3_with_bristol_fashion<N: Rep3Network>(
+    input_share: BTreeMap<String, Rep3AcvmType<ark_bn254::Fr>>,
+    compiled_program: ProgramArtifact,
+    net: N,
+    bristol_fashion: Vec<(String, BristolFashionCircuit)>,
+) -> Result<(
+    Vec<Rep3AcvmType<ark_bn254::Fr>>,
+    PssStore<Rep3AcvmSolver<ark_bn254::Fr, N>, ark_bn254::Fr>,
+    N,
 )> {
     let input_share = witness_to_witness_map(input_share, &compiled_program.abi)?;
 
     // init MPC protocol
-    let rep3_vm = Rep3CoSolver::from_network_with_witness(net, compiled_program, input_share)
+    let mut rep3_vm = Rep3CoSolver::from_network_with_witness(net, compiled_program, input_share)
         .context("while creating VM")?;
+    for (name, circuit) in bristol_fashion {
+        rep3_vm.register_bristol_fashion(name, circuit);
+    }
 
     // execute witness generation in MPC
     let (result_witness_share, value_store, driver) = rep3_vm
@@ -330,6 +464,38 @@ pub fn generate_witness_rep3<N: Rep3Network>(
     Ok((witness_stack, network))
 }
 
+/// Generate a witness from REP3 input shares, where the given Bristol Fashion circuits are registered as accelerators for the Noir oracle functions with the given names
+pub fn generate_witness_rep3_with_bristol_fashion<N: Rep3Network>(
+    input_share: BTreeMap<String, Rep3AcvmType<ark_bn254::Fr>>,
+    compiled_program: ProgramArtifact,
+    net: N,
+    bristol_fashion: Vec<(String, BristolFashionCircuit)>,
+) -> Result<(Vec<Rep3AcvmType<ark_bn254::Fr>>, N)> {
+    let (witness_stack, _, network) = execute_circuit_rep3_with_bristol_fashion(
+        input_share,
+        compiled_program,
+        net,
+        bristol_fashion,
+    )?;
+    Ok((witness_stack, network))
+}
+
+/// Loads the Bristol Fashion circuits given in the format `<FUNCTION>=<PATH>`, e.g., for the `--bristol-fashion` option of the CLI
+pub fn read_bristol_fashion(entries: &[String]) -> Result<Vec<(String, BristolFashionCircuit)>> {
+    entries
+        .iter()
+        .map(|entry| {
+            let (name, path) = entry.split_once('=').ok_or_else(|| {
+                eyre!("invalid Bristol Fashion accelerator \"{entry}\", expected <FUNCTION>=<PATH>")
+            })?;
+            let circuit = BristolFashionCircuit::from_file(path.trim()).with_context(|| {
+                format!("while loading Bristol Fashion circuit {path} for accelerator {name}")
+            })?;
+            Ok((name.trim().to_owned(), circuit))
+        })
+        .collect()
+}
+
 /// Translate a REP3 shared witness to a shamir shared witness
 pub fn translate_witness<
     P: Pairing,
@@ -431,6 +597,106 @@ pub fn translate_proving_key<
     Ok((result, protocol.network))
 }
 
+/// Translate a 3-party shamir shared witness (with threshold 1) to a REP3 shared witness
+pub fn translate_witness_to_rep3<P: Pairing, N: ShamirNetwork>(
+    witness_share: Vec<ShamirAcvmType<P::ScalarField>>,
+    net: N,
+) -> Result<(Vec<Rep3AcvmType<P::ScalarField>>, N)> {
+    // extract shares only
+    let mut shares = vec![];
+    for share in witness_share.iter() {
+        if let ShamirAcvmType::Shared(value) = share {
+            shares.push(value.to_owned());
+        }
+    }
+
+    // The translation does not need any preprocessed randomness
+    let threshold = 1;
+    let preprocessing =
+        ShamirPreprocessing::new(threshold, net, 0).context("while shamir preprocessing")?;
+    let mut protocol = ShamirProtocol::from(preprocessing);
+
+    // Translate witness to rep3 shares
+    let translated_shares = protocol.translate_primefield_shamirshare_vec(shares)?;
+
+    let mut result = Vec::with_capacity(witness_share.len());
+    let mut iter = translated_shares.into_iter();
+    for val in witness_share.into_iter() {
+        match val {
+            ShamirAcvmType::Public(value) => result.push(Rep3AcvmType::Public(value)),
+            ShamirAcvmType::Shared(_) => {
+                let share = iter.next().expect("enough shares");
+                result.push(Rep3AcvmType::Shared(share))
+            }
+        }
+    }
+
+    Ok((result, protocol.network))
+}
+
+/// Translate a 3-party shamir shared proving key (with threshold 1) to a REP3 shared proving key
+#[allow(clippy::complexity)]
+pub fn translate_proving_key_to_rep3<P: Pairing, NA: Rep3Network, NB: ShamirNetwork>(
+    proving_key: ShamirProvingKey<P, NB>,
+    net: NB,
+) -> Result<(Rep3ProvingKey<P, NA>, NB)> {
+    // extract shares, the shared memory records are translated together with the witness
+    let (memory_keys, memory_shares): (Vec<_>, Vec<_>) =
+        proving_key.memory_records_shared.into_iter().unzip();
+    let shares = proving_key
+        .polynomials
+        .witness
+        .into_iter()
+        .flat_map(|el| el.into_vec().into_iter())
+        .chain(memory_shares)
+        .collect::<Vec<_>>();
+
+    let threshold = 1;
+    let preprocessing =
+        ShamirPreprocessing::new(threshold, net, 0).context("while shamir preprocessing")?;
+    let mut protocol = ShamirProtocol::from(preprocessing);
+
+    // Translate witness to rep3 shares
+    let mut translated_shares = protocol.translate_primefield_shamirshare_vec(shares)?;
+
+    let witness_size = PROVER_WITNESS_ENTITIES_SIZE * proving_key.circuit_size as usize;
+    if translated_shares.len() != witness_size + memory_keys.len() {
+        return Err(eyre!("Invalid number of shares translated"));
+    };
+    let memory_records_shared = memory_keys
+        .into_iter()
+        .zip(translated_shares.split_off(witness_size))
+        .collect();
+
+    let mut chunks = translated_shares.chunks_exact(proving_key.circuit_size as usize);
+    let translated_shares = array::from_fn(|_| {
+        Polynomial::new(chunks.next().expect("Length already checked").to_vec())
+    });
+
+    let polynomials = Polynomials {
+        witness: ProverWitnessEntities {
+            elements: translated_shares,
+        },
+        precomputed: proving_key.polynomials.precomputed,
+    };
+    let result = Rep3ProvingKey {
+        polynomials,
+        circuit_size: proving_key.circuit_size,
+        public_inputs: proving_key.public_inputs,
+        num_public_inputs: proving_key.num_public_inputs,
+        pub_inputs_offset: proving_key.pub_inputs_offset,
+        memory_read_records: proving_key.memory_read_records,
+        memory_write_records: proving_key.memory_write_records,
+        memory_records_shared,
+        final_active_wire_idx: proving_key.final_active_wire_idx,
+        phantom: std::marker::PhantomData,
+        active_region_data: proving_key.active_region_data,
+        pairing_inputs_public_input_key: proving_key.pairing_inputs_public_input_key,
+    };
+
+    Ok((result, protocol.network))
+}
+
 /// Compute the circuit size that is needed to load the prover crs
 pub fn compute_circuit_size<P: HonkCurve<TranscriptFieldType>>(
     constraint_system: &AcirFormat<P::ScalarField>,
@@ -593,26 +859,39 @@ pub fn split_proving_key_rep3<P: Pairing, R: Rng + CryptoRng, N: Rep3Network>(
     Ok([share0, share1, share2])
 }
 
-/// Split a proving key into shamir shares
+/// Split a proving key into shamir shares, where the shares of `degree` parties are compressed to seeds
 pub fn split_proving_key_shamir<P: Pairing, R: Rng + CryptoRng, N: ShamirNetwork>(
     proving_key: PlainProvingKey<P>,
     degree: usize,
     num_parties: usize,
     rng: &mut R,
-) -> Result<Vec<ShamirProvingKey<P, N>>> {
+) -> Result<Vec<CompressedShamirProvingKey<P, N>>> {
     let witness_entities = proving_key
         .polynomials
         .witness
         .iter()
         .flat_map(|el| el.iter().cloned())
         .collect::<Vec<_>>();
+    if witness_entities.len() != proving_key.circuit_size as usize * PROVER_WITNESS_ENTITIES_SIZE {
+        return Err(eyre!(
+            "Share length is not {PROVER_WITNESS_ENTITIES_SIZE} times circuit size"
+        ));
+    }
 
-    let shares = shamir::share_field_elements(&witness_entities, degree, num_parties, rng);
+    let shares = shamir::share_field_elements_seeded::<_, _, SeedRng>(
+        &witness_entities,
+        degree,
+        num_parties,
+        rng,
+    );
 
-    shares
+    Ok(shares
         .into_iter()
-        .map(|share| ShamirProvingKey::from_plain_key_and_shares(&proving_key, share))
-        .collect::<Result<Vec<_>>>()
+        .map(|share| CompressedShamirProvingKey {
+            proving_key: ShamirProvingKey::from_plain_key_without_witness(&proving_key),
+            witness: ShamirShareVecType::Seeded(share),
+        })
+        .collect())
 }
 
 /// Split input into REP3 shares
@@ -640,10 +919,414 @@ pub fn split_input_rep3<P: Pairing, N: Rep3Network, R: Rng + CryptoRng>(
     witnesses
 }
 
+/// A REP3 share of an input, which is either a field element or an integer that is kept in its ring until it is cast for the witness extension.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub enum Rep3InputShare<F: PrimeField> {
+    /// A public or shared field element
+    Field(Rep3AcvmType<F>),
+    /// A shared integer
+    Ring(Rep3TypedRingShare),
+}
+
+impl<F: PrimeField> From<Rep3AcvmType<F>> for Rep3InputShare<F> {
+    fn from(value: Rep3AcvmType<F>) -> Self {
+        Self::Field(value)
+    }
+}
+
+impl<F: PrimeField> Rep3Components for Rep3InputShare<F> {
+    fn serialize_a(&self, writer: &mut dyn Write) -> Result<(), SerializationError> {
+        match self {
+            Rep3InputShare::Field(Rep3AcvmType::Public(v)) => {
+                0u8.serialize_compressed(&mut *writer)?;
+                v.serialize_compressed(writer)
+            }
+            Rep3InputShare::Field(Rep3AcvmType::Shared(share)) => {
+                1u8.serialize_compressed(&mut *writer)?;
+                share.serialize_a(writer)
+            }
+            Rep3InputShare::Ring(share) => {
+                2u8.serialize_compressed(&mut *writer)?;
+                share.serialize_a(writer)
+            }
+        }
+    }
+
+    fn serialize_b(&self, writer: &mut dyn Write) -> Result<(), SerializationError> {
+        match self {
+            Rep3InputShare::Field(Rep3AcvmType::Public(v)) => {
+                0u8.serialize_compressed(&mut *writer)?;
+                v.serialize_compressed(writer)
+            }
+            Rep3InputShare::Field(Rep3AcvmType::Shared(share)) => {
+                1u8.serialize_compressed(&mut *writer)?;
+                share.serialize_b(writer)
+            }
+            Rep3InputShare::Ring(share) => {
+                2u8.serialize_compressed(&mut *writer)?;
+                share.serialize_b(writer)
+            }
+        }
+    }
+}
+
+/// The REP3 witness share of a party, as it is stored in witness share files.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Rep3WitnessShare<F: PrimeField> {
+    /// The shares of the witness
+    pub witness: Vec<Rep3AcvmType<F>>,
+    /// The integer inputs in their ring form, as they were provided before being cast to field elements for the witness extension.
+    pub ring_inputs: BTreeMap<String, Rep3TypedRingShare>,
+}
+
+impl<F: PrimeField> From<Vec<Rep3AcvmType<F>>> for Rep3WitnessShare<F> {
+    fn from(witness: Vec<Rep3AcvmType<F>>) -> Self {
+        Self {
+            witness,
+            ring_inputs: BTreeMap::new(),
+        }
+    }
+}
+
+/// The REP3 input shares of a party, together with the commitments of the dealers if the inputs were shared verifiably.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Rep3InputShares<F: PrimeField> {
+    /// The shares of the inputs
+    pub inputs: BTreeMap<String, Rep3InputShare<F>>,
+    /// The commitments of the dealers to the shares, see [`commit_input_shares`]
+    #[serde(default)]
+    pub commitments: Vec<Rep3InputCommitment>,
+    /// The signatures of the clients which provided the inputs, see [`sign_input_shares`]
+    #[serde(default)]
+    pub provenance: Vec<InputProvenance>,
+}
+
+impl<F: PrimeField> Rep3InputShares<F> {
+    /// Returns the integer inputs in their ring form.
+    pub fn ring_inputs(&self) -> BTreeMap<String, Rep3TypedRingShare> {
+        self.inputs
+            .iter()
+            .filter_map(|(name, share)| match share {
+                Rep3InputShare::Ring(share) => Some((name.clone(), *share)),
+                Rep3InputShare::Field(_) => None,
+            })
+            .collect()
+    }
+
+    /// Wraps input shares without commitments.
+    pub fn new(inputs: BTreeMap<String, Rep3InputShare<F>>) -> Self {
+        Self {
+            inputs,
+            commitments: Vec::new(),
+            provenance: Vec::new(),
+        }
+    }
+
+    /// Returns the shares of the given inputs. Each input must be contained in the shares and needs to be given as a whole.
+    fn named_shares(&self, inputs: &InputNames) -> Result<Vec<&Rep3InputShare<F>>> {
+        inputs
+            .iter()
+            .map(|(name, indices)| {
+                if indices.is_some() {
+                    return Err(eyre!("Elements of input {name} are given by indices"));
+                }
+                self.inputs
+                    .get(name)
+                    .ok_or_else(|| eyre!("Input {name} not found in input shares"))
+            })
+            .collect()
+    }
+
+    /// Returns the inputs covered by the commitments, failing if an input is committed by multiple dealers.
+    fn commitment_coverage(&self) -> Result<InputCoverage> {
+        let mut coverage = InputCoverage::default();
+        for commitment in self.commitments.iter() {
+            coverage.add_commitment(commitment, |name| self.inputs.get(name).map(|_| 1))?;
+        }
+        Ok(coverage)
+    }
+
+    /// Checks the shares of party `id` against the commitments of the dealers. Each committed input must be contained in the shares, each commitment must cover whole inputs, and no input may be committed by multiple dealers.
+    ///
+    /// Inputs which are not committed at all are not checked, see [`check_commitment_coverage`](Self::check_commitment_coverage) for this. This does not check that the other parties received the same commitments, see [`verify_input_commitments`] for this.
+    pub fn verify_commitments(&self, id: PartyID) -> Result<()> {
+        for commitment in self.commitments.iter() {
+            let shares = self.named_shares(&commitment.inputs)?;
+            commitment.verify(&shares, id)?;
+        }
+        self.commitment_coverage()?;
+        Ok(())
+    }
+
+    /// Checks that every shared input, i.e., every shared field element and every integer input, is committed by exactly one dealer.
+    pub fn check_commitment_coverage(&self) -> Result<()> {
+        let coverage = self.commitment_coverage()?;
+        for (name, input) in self.inputs.iter() {
+            if !matches!(input, Rep3InputShare::Field(Rep3AcvmType::Public(_))) {
+                coverage.check_committed(name, 0..1)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that all inputs of party `id`, including the public ones, are signed by exactly one client for the circuit with the given hash, and that each client only provided the inputs it is allowed to by the `policy`. The input names in the policy follow the naming of [parse_input].
+    pub fn verify_provenance(
+        &self,
+        policy: &InputPolicy,
+        id: PartyID,
+        circuit_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let mut coverage = InputCoverage::default();
+        for provenance in self.provenance.iter() {
+            let key = policy.check(provenance)?;
+            let shares = self.named_shares(&provenance.inputs)?;
+            provenance
+                .verify(&shares, id, circuit_hash, key)
+                .with_context(|| {
+                    format!("while verifying inputs of client \"{}\"", provenance.client)
+                })?;
+            coverage.add(provenance, |name| self.inputs.get(name).map(|_| 1))?;
+        }
+        for name in self.inputs.keys() {
+            coverage.check(name, 0..1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Commits to the REP3 input shares of the three parties, so that the parties can detect a dealer which hands out inconsistent shares before the witness extension starts. See [`mpc_core::protocols::rep3::vss`] for details.
+pub fn commit_input_shares<F: PrimeField, R: Rng + CryptoRng>(
+    shares: [BTreeMap<String, Rep3InputShare<F>>; 3],
+    rng: &mut R,
+) -> Result<[Rep3InputShares<F>; 3]> {
+    if shares.iter().any(|s| !s.keys().eq(shares[0].keys())) {
+        return Err(eyre!("All parties must have shares of the same inputs"));
+    }
+    let inputs = shares[0]
+        .keys()
+        .map(|name| (name.to_owned(), None))
+        .collect();
+    let [s0, s1, s2] = shares.each_ref().map(|s| s.values().collect::<Vec<_>>());
+    let commitments = vss::commit(inputs, [&s0, &s1, &s2], rng)?;
+    Ok(array::from_fn(|i| Rep3InputShares {
+        inputs: shares[i].clone(),
+        commitments: vec![commitments[i].clone()],
+        provenance: Vec::new(),
+    }))
+}
+
+/// Signs all inputs of the input shares, including the public ones, with the key of the `client` for the circuit with the given hash, so that the parties can check that the client is allowed to provide them (see [`mpc_types::input_provenance`]). The signature for each party is stored in its input share.
+pub fn sign_input_shares<F: PrimeField>(
+    shares: &mut [Rep3InputShares<F>; 3],
+    client: &str,
+    circuit_hash: Option<[u8; 32]>,
+    key: &ClientSigningKey,
+) -> Result<()> {
+    let inputs: InputNames = shares[0]
+        .inputs
+        .keys()
+        .map(|name| (name.to_owned(), None))
+        .collect();
+    for (id, share) in [PartyID::ID0, PartyID::ID1, PartyID::ID2]
+        .into_iter()
+        .zip(shares.iter_mut())
+    {
+        let signed = share.named_shares(&inputs)?;
+        let provenance = InputProvenance::sign(
+            client.to_owned(),
+            inputs.clone(),
+            &signed,
+            id,
+            circuit_hash,
+            key,
+        )?;
+        share.provenance.push(provenance);
+    }
+    Ok(())
+}
+
+/// Checks the input shares of this party against the commitments of the dealers, and checks with the other parties that all of them received the same commitments. Returns an error if the input was shared by a malicious dealer.
+///
+/// This is always run in the REP3 witness extension, as the parties need to agree on whether the inputs are committed.
+pub fn verify_input_commitments<F: PrimeField, N: Rep3Network>(
+    input: &Rep3InputShares<F>,
+    net: &mut N,
+) -> Result<()> {
+    let id = net.get_id();
+    input
+        .verify_commitments(id)
+        .context("while verifying the input commitments")?;
+    let digest = vss::commitments_digest(&input.commitments);
+    let prev_digest = net.reshare_many(&digest)?;
+    if prev_digest != digest {
+        return Err(eyre!(
+            "Party {} received different input commitments than party {id}",
+            id.prev_id()
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the rings of all inputs of the ABI which are unsigned or signed integers or booleans, or arrays thereof. The keys follow the naming of [parse_input]. Integers nested in structs or tuples are not included.
+pub fn input_ring_types(abi: &Abi) -> BTreeMap<String, RingType> {
+    let ring_type = |typ: &AbiType| match typ {
+        AbiType::Integer { width, .. } => RingType::from_bits(*width),
+        AbiType::Boolean => Some(RingType::Bit),
+        _ => None,
+    };
+    let mut result = BTreeMap::new();
+    for param in abi.parameters.iter() {
+        match &param.typ {
+            AbiType::Array { length, typ } if *length != 1 => {
+                if let Some(ring_type) = ring_type(typ) {
+                    for i in 0..*length {
+                        result.insert(format!("{}[{i}]", param.name), ring_type);
+                    }
+                }
+            }
+            AbiType::Array { typ, .. } => {
+                if let Some(ring_type) = ring_type(typ) {
+                    result.insert(param.name.to_owned(), ring_type);
+                }
+            }
+            typ => {
+                if let Some(ring_type) = ring_type(typ) {
+                    result.insert(param.name.to_owned(), ring_type);
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Split input into REP3 shares, where the private inputs in `ring_types` are shared as integers in the given ring instead of as field elements.
+pub fn split_input_rep3_with_ring_types<P: Pairing, R: Rng + CryptoRng>(
+    initial_witness: BTreeMap<String, PublicMarker<GenericFieldElement<P::ScalarField>>>,
+    ring_types: &BTreeMap<String, RingType>,
+    rng: &mut R,
+) -> Result<[BTreeMap<String, Rep3InputShare<P::ScalarField>>; 3]> {
+    let mut witnesses = array::from_fn(|_| BTreeMap::default());
+    for (witness, v) in initial_witness.into_iter() {
+        match (v, ring_types.get(&witness)) {
+            (PublicMarker::Private(v), Some(ring_type)) => {
+                let val = v.into_repr().into_bigint();
+                if val.num_bits() > 128 {
+                    return Err(eyre!("Input {witness} does not fit into {ring_type}"));
+                }
+                let limbs = val.as_ref();
+                let val = u128::from(limbs[0]) | (u128::from(limbs[1]) << 64);
+                let shares = rep3_ring::typed::share_typed_ring_element(val, *ring_type, rng)
+                    .with_context(|| format!("while sharing input {witness}"))?;
+                for (w, share) in witnesses.iter_mut().zip(shares) {
+                    w.insert(witness.clone(), Rep3InputShare::Ring(share));
+                }
+            }
+            (PublicMarker::Public(v), _) => {
+                for w in witnesses.iter_mut() {
+                    w.insert(
+                        witness.to_owned(),
+                        Rep3InputShare::Field(Rep3AcvmType::Public(v.into_repr())),
+                    );
+                }
+            }
+            (PublicMarker::Private(v), None) => {
+                let shares = rep3::share_field_element(v.into_repr(), rng);
+                for (w, share) in witnesses.iter_mut().zip(shares) {
+                    w.insert(
+                        witness.clone(),
+                        Rep3InputShare::Field(Rep3AcvmType::Shared(share)),
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(witnesses)
+}
+
+/// Casts the integer inputs of a REP3 input share to field elements, so that it can be used for the witness extension.
+pub fn cast_input_shares<F: PrimeField, N: Rep3Network>(
+    input_share: BTreeMap<String, Rep3InputShare<F>>,
+    net: N,
+) -> Result<(BTreeMap<String, Rep3AcvmType<F>>, N)> {
+    if input_share
+        .values()
+        .all(|share| matches!(share, Rep3InputShare::Field(_)))
+    {
+        let input_share = input_share
+            .into_iter()
+            .map(|(name, share)| match share {
+                Rep3InputShare::Field(share) => (name, share),
+                Rep3InputShare::Ring(_) => unreachable!("we checked that all are field shares"),
+            })
+            .collect();
+        return Ok((input_share, net));
+    }
+
+    // all integer inputs are cast together, so that we only need one conversion per ring
+    let (ring_names, ring_shares): (Vec<String>, Vec<Rep3TypedRingShare>) = input_share
+        .iter()
+        .filter_map(|(name, share)| match share {
+            Rep3InputShare::Ring(share) => Some((name.clone(), *share)),
+            Rep3InputShare::Field(_) => None,
+        })
+        .unzip();
+    let mut io_context = IoContext::init(net)?;
+    let casted = rep3_ring::casts::typed_ring_to_field_many(&ring_shares, &mut io_context)
+        .with_context(|| format!("while casting inputs {}", ring_names.join(", ")))?;
+    let mut casted = ring_names
+        .into_iter()
+        .zip(casted)
+        .collect::<BTreeMap<_, _>>();
+    let input_share = input_share
+        .into_iter()
+        .map(|(name, share)| {
+            let share = match share {
+                Rep3InputShare::Field(share) => share,
+                Rep3InputShare::Ring(_) => Rep3AcvmType::Shared(
+                    casted.remove(&name).expect("all integer inputs were cast"),
+                ),
+            };
+            (name, share)
+        })
+        .collect();
+    Ok((input_share, io_context.network))
+}
+
 /// Merge multiple REP3 input shares
 pub fn merge_input_shares<P: Pairing>(
     input_shares: Vec<BTreeMap<String, Rep3AcvmType<P::ScalarField>>>,
 ) -> Result<BTreeMap<String, Rep3AcvmType<P::ScalarField>>> {
+    merge_input_share_maps(input_shares)
+}
+
+/// Merge multiple REP3 input shares, which may contain integer inputs. The commitments and signatures of the input shares are kept.
+pub fn merge_typed_input_shares<P: Pairing>(
+    input_shares: Vec<Rep3InputShares<P::ScalarField>>,
+) -> Result<Rep3InputShares<P::ScalarField>> {
+    let mut commitments = Vec::new();
+    let mut provenance = Vec::new();
+    let inputs = input_shares
+        .into_iter()
+        .map(|input_share| {
+            commitments.extend(input_share.commitments);
+            provenance.extend(input_share.provenance);
+            input_share.inputs
+        })
+        .collect();
+    Ok(Rep3InputShares {
+        inputs: merge_input_share_maps(inputs)?,
+        commitments,
+        provenance,
+    })
+}
+
+fn merge_input_share_maps<T>(
+    input_shares: Vec<BTreeMap<String, T>>,
+) -> Result<BTreeMap<String, T>> {
     let mut result = BTreeMap::new();
     for input_share in input_shares.into_iter() {
         for (wit, share) in input_share.into_iter() {
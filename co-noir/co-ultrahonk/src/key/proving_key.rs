@@ -291,6 +291,23 @@ impl<T: NoirUltraHonkProver<P>, P: Pairing> ProvingKey<T, P> {
         plain_key: &PlainProvingKey<P>,
         shares: Vec<T::ArithmeticShare>,
     ) -> Result<Self> {
+        let circuit_size = plain_key.circuit_size;
+        if shares.len() != circuit_size as usize * 6 {
+            return Err(eyre::eyre!("Share length is not 6 times circuit size"));
+        }
+
+        let mut proving_key = Self::from_plain_key_without_witness(plain_key);
+        for (src, des) in shares
+            .chunks_exact(circuit_size as usize)
+            .zip(proving_key.polynomials.witness.iter_mut())
+        {
+            *des = Polynomial::new(src.to_owned());
+        }
+        Ok(proving_key)
+    }
+
+    /// Creates a proving key from the public parts of the plain proving key, where the shared witness polynomials are left empty.
+    pub fn from_plain_key_without_witness(plain_key: &PlainProvingKey<P>) -> Self {
         let circuit_size = plain_key.circuit_size;
         let public_inputs = plain_key.public_inputs.to_owned();
         let num_public_inputs = plain_key.num_public_inputs;
@@ -301,10 +318,6 @@ impl<T: NoirUltraHonkProver<P>, P: Pairing> ProvingKey<T, P> {
         let active_region_data = plain_key.active_region_data.to_owned();
         let pairing_inputs_public_input_key = plain_key.pairing_inputs_public_input_key.to_owned();
 
-        if shares.len() != circuit_size as usize * 6 {
-            return Err(eyre::eyre!("Share length is not 6 times circuit size"));
-        }
-
         let mut polynomials = Polynomials::default();
         for (src, des) in plain_key
             .polynomials
@@ -315,13 +328,7 @@ impl<T: NoirUltraHonkProver<P>, P: Pairing> ProvingKey<T, P> {
             *des = src.to_owned();
         }
 
-        for (src, des) in shares
-            .chunks_exact(circuit_size as usize)
-            .zip(polynomials.witness.iter_mut())
-        {
-            *des = Polynomial::new(src.to_owned());
-        }
-        Ok(Self {
+        Self {
             circuit_size,
             public_inputs,
             num_public_inputs,
@@ -334,7 +341,7 @@ impl<T: NoirUltraHonkProver<P>, P: Pairing> ProvingKey<T, P> {
             memory_records_shared: BTreeMap::new(),
             active_region_data,
             pairing_inputs_public_input_key,
-        })
+        }
     }
 
     pub fn ultrahonk_num_randomness(&self, has_zk: ZeroKnowledge) -> usize
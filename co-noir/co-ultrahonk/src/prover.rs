@@ -13,7 +13,7 @@ use co_builder::{
 };
 use mpc_core::protocols::{
     rep3::network::{IoContext, Rep3Network},
-    shamir::{ShamirPreprocessing, ShamirProtocol, network::ShamirNetwork},
+    shamir::{ShamirMulMode, ShamirPreprocessing, ShamirProtocol, network::ShamirNetwork},
 };
 use std::marker::PhantomData;
 use ultrahonk::prelude::{
@@ -121,6 +121,25 @@ impl<P: HonkCurve<TranscriptFieldType>, H: TranscriptHasher<TranscriptFieldType>
         proving_key: ProvingKey<ShamirUltraHonkDriver<<P as Pairing>::ScalarField, N>, P>,
         crs: &ProverCrs<P>,
         has_zk: ZeroKnowledge,
+    ) -> eyre::Result<(HonkProof<TranscriptFieldType>, Vec<TranscriptFieldType>, N)> {
+        Self::prove_with_mul_mode(
+            net,
+            threshold,
+            ShamirMulMode::default(),
+            proving_key,
+            crs,
+            has_zk,
+        )
+    }
+
+    /// Same as [`Self::prove`], but the multiplications use the given [`ShamirMulMode`]
+    pub fn prove_with_mul_mode(
+        net: N,
+        threshold: usize,
+        mul_mode: ShamirMulMode,
+        proving_key: ProvingKey<ShamirUltraHonkDriver<<P as Pairing>::ScalarField, N>, P>,
+        crs: &ProverCrs<P>,
+        has_zk: ZeroKnowledge,
     ) -> eyre::Result<(HonkProof<TranscriptFieldType>, Vec<TranscriptFieldType>, N)> {
         // init MPC protocol
         let num_pairs = if net.get_num_parties() == 3 {
@@ -128,7 +147,8 @@ impl<P: HonkCurve<TranscriptFieldType>, H: TranscriptHasher<TranscriptFieldType>
         } else {
             proving_key.ultrahonk_num_randomness(has_zk)
         };
-        let preprocessing = ShamirPreprocessing::new(threshold, net, num_pairs)?;
+        let preprocessing =
+            ShamirPreprocessing::new_with_mul_mode(threshold, net, num_pairs, mul_mode)?;
         let mut protocol0 = ShamirProtocol::from(preprocessing);
         let protocol1 = protocol0.fork_with_pairs(0)?;
         let driver = ShamirUltraHonkDriver::new(protocol0, protocol1);
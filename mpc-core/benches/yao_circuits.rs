@@ -0,0 +1,148 @@
+use ark_ff::PrimeField;
+use criterion::*;
+use fancy_garbling::{FancyBinary, Garbler, WireMod2};
+use mpc_core::protocols::rep3::yao::{
+    bristol_fashion::{BristolFashionCircuit, BristolFashionEvaluator, CircuitExecutionError},
+    stats::{self, GarbledCircuitStats},
+};
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+use scuttlebutt::Channel;
+use std::io::{Empty, Sink};
+
+type F = ark_bn254::Fr;
+type BenchGarbler = Garbler<Channel<Empty, Sink>, ChaCha12Rng, WireMod2>;
+
+// Garbles the gates of a Bristol Fashion circuit, the garbled tables are written to a sink
+struct GarblingEvaluator(BenchGarbler);
+
+impl GarblingEvaluator {
+    fn new() -> Self {
+        let channel = Channel::new(std::io::empty(), std::io::sink());
+        Self(Garbler::new(channel, ChaCha12Rng::from_entropy()))
+    }
+
+    fn garble(&mut self, circuit: &BristolFashionCircuit) -> Vec<Vec<WireMod2>> {
+        let inputs = circuit
+            .get_input_wires()
+            .iter()
+            .map(|wires| {
+                wires
+                    .iter()
+                    .map(|_| self.0.encode_wire(0, 2).0)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        circuit.evaluate(&inputs, self).unwrap()
+    }
+}
+
+fn garbler_error(e: impl std::fmt::Debug) -> CircuitExecutionError {
+    CircuitExecutionError::InvalidInput(format!("{e:?}"))
+}
+
+impl BristolFashionEvaluator for GarblingEvaluator {
+    type WireValue = WireMod2;
+
+    fn constant(&mut self, input: bool) -> Result<Self::WireValue, CircuitExecutionError> {
+        let zero = self.0.encode_wire(0, 2).0;
+        match input {
+            true => self.0.negate(&zero).map_err(garbler_error),
+            false => Ok(zero),
+        }
+    }
+
+    fn inv(&mut self, input: &Self::WireValue) -> Result<Self::WireValue, CircuitExecutionError> {
+        self.0.negate(input).map_err(garbler_error)
+    }
+
+    fn xor(
+        &mut self,
+        input1: &Self::WireValue,
+        input2: &Self::WireValue,
+    ) -> Result<Self::WireValue, CircuitExecutionError> {
+        self.0.xor(input1, input2).map_err(garbler_error)
+    }
+
+    fn and(
+        &mut self,
+        input1: &Self::WireValue,
+        input2: &Self::WireValue,
+    ) -> Result<Self::WireValue, CircuitExecutionError> {
+        self.0.and(input1, input2).map_err(garbler_error)
+    }
+}
+
+fn circuit_variants() -> Vec<(String, BristolFashionCircuit)> {
+    let mut variants = Vec::new();
+    for num_inputs in [1, 16] {
+        variants.push((
+            format!("decompose_arithmetic_many (n={num_inputs}, 64 bits, 16 bit chunks)"),
+            stats::decompose_arithmetic_many::<F>(num_inputs, 64, 16).unwrap(),
+        ));
+        variants.push((
+            format!("decompose_arithmetic_many (n={num_inputs}, 254 bits, 64 bit chunks)"),
+            stats::decompose_arithmetic_many::<F>(num_inputs, F::MODULUS_BIT_SIZE as usize, 64)
+                .unwrap(),
+        ));
+        variants.push((
+            format!("field_int_div_power_2_many (n={num_inputs}, 2^32)"),
+            stats::field_int_div_power_2_many::<F>(num_inputs, 32).unwrap(),
+        ));
+        variants.push((
+            format!("field_int_div_many (n={num_inputs})"),
+            stats::field_int_div_many::<F>(num_inputs).unwrap(),
+        ));
+    }
+    variants.push((
+        "sha256_from_bristol".to_string(),
+        stats::sha256_from_bristol::<F>().unwrap(),
+    ));
+    variants.push((
+        "aes_from_bristol (64 bytes)".to_string(),
+        stats::aes_from_bristol::<F>(64).unwrap(),
+    ));
+    variants.push((
+        "aes128_ctr_from_bristol (64 bytes)".to_string(),
+        stats::aes128_ctr_from_bristol::<F>(64).unwrap(),
+    ));
+    variants.push((
+        "sha256_hash_from_bristol (64 bytes)".to_string(),
+        stats::sha256_hash_from_bristol::<F>(64).unwrap(),
+    ));
+    variants.push((
+        "hmac_sha256_from_bristol (32 byte key, 64 bytes)".to_string(),
+        stats::hmac_sha256_from_bristol::<F>(32, 64).unwrap(),
+    ));
+    variants.push((
+        "sha512 (64 bytes)".to_string(),
+        stats::sha512::<F>(64).unwrap(),
+    ));
+    variants.push((
+        "blake2s (64 bytes)".to_string(),
+        stats::blake2s::<F>(&[8; 64]).unwrap(),
+    ));
+    variants.push((
+        "blake3 (64 bytes)".to_string(),
+        stats::blake3::<F>(&[8; 64]).unwrap(),
+    ));
+    variants
+}
+
+fn yao_circuits(c: &mut Criterion) {
+    let mut group = c.benchmark_group("garbling");
+    for (name, circuit) in circuit_variants() {
+        let stats = GarbledCircuitStats::from_circuit(&circuit);
+        println!("{name}: {stats}");
+
+        group.throughput(Throughput::Bytes(stats.ciphertext_bytes() as u64));
+        group.bench_function(name, |b| {
+            let mut garbler = GarblingEvaluator::new();
+            b.iter(|| black_box(garbler.garble(&circuit)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, yao_circuits);
+criterion_main!(benches);
@@ -0,0 +1,50 @@
+//! Exports the hand-written garbled circuits as Bristol Fashion files into the provided directory.
+//!
+//! Usage: `cargo run --example export_bristol -- <OUTPUT_DIR>`
+
+use std::{fs::File, io::BufWriter, path::PathBuf};
+
+use mpc_core::protocols::rep3::yao::bristol_fashion::{BristolFashionCircuit, export};
+
+fn write(dir: &PathBuf, name: &str, circuit: BristolFashionCircuit) -> eyre::Result<()> {
+    let path = dir.join(format!("{name}.txt"));
+    let mut writer = BufWriter::new(File::create(&path)?);
+    circuit.write_circuit_file(&mut writer)?;
+    println!(
+        "{}: {} AND gates, {} wires",
+        path.display(),
+        circuit.num_and_gates(),
+        circuit.num_wires()
+    );
+    Ok(())
+}
+
+fn main() -> eyre::Result<()> {
+    let dir = PathBuf::from(
+        std::env::args()
+            .nth(1)
+            .ok_or_else(|| eyre::eyre!("missing output directory"))?,
+    );
+    std::fs::create_dir_all(&dir)?;
+
+    for bitlen in [8, 16, 32, 64, 128] {
+        write(
+            &dir,
+            &format!("adder{bitlen}"),
+            export::adder_mod_2k(bitlen)?,
+        )?;
+        write(&dir, &format!("div{bitlen}"), export::ring_div(bitlen)?)?;
+    }
+    write(&dir, "adder_bn254", export::adder_mod_p::<ark_bn254::Fr>()?)?;
+    write(
+        &dir,
+        "blake2s_compress_final_block",
+        export::blake2s_compress([64, 0], [0xFFFFFFFF, 0])?,
+    )?;
+    write(
+        &dir,
+        "blake3_compress_single_block",
+        export::blake3_compress([0, 0], 64, 11)?,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,89 @@
+//! Grain LFSR
+//!
+//! This module contains the Grain LFSR which is used to derive the round constants (and for the original Poseidon also the MDS matrix) of the Poseidon and Poseidon2 permutations. It is compatible with the reference scripts found at:
+//! [https://extgit.isec.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.isec.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+
+const STATE_SIZE: usize = 80;
+
+/// The Grain LFSR in self-shrinking mode, initialized for a given Poseidon instance.
+pub(crate) struct GrainLfsr {
+    state: [bool; STATE_SIZE],
+    n_bits: usize,
+}
+
+impl GrainLfsr {
+    /// Initializes the LFSR for a prime field with `n_bits` bits, an x^5 S-box, a statesize of `t`, and the given amount of full and partial rounds.
+    pub(crate) fn new(n_bits: usize, t: usize, rounds_f: usize, rounds_p: usize) -> Self {
+        let mut state = [true; STATE_SIZE];
+        let mut idx = 0;
+        // field = 1 (prime field), sbox = 0 (x^alpha)
+        for (value, len) in [
+            (1, 2),
+            (0, 4),
+            (n_bits, 12),
+            (t, 12),
+            (rounds_f, 10),
+            (rounds_p, 10),
+        ] {
+            for i in (0..len).rev() {
+                state[idx] = (value >> i) & 1 == 1;
+                idx += 1;
+            }
+        }
+        // The remaining 30 bits stay set to 1
+
+        let mut lfsr = Self { state, n_bits };
+        for _ in 0..160 {
+            lfsr.update();
+        }
+        lfsr
+    }
+
+    fn update(&mut self) -> bool {
+        let s = &self.state;
+        let new_bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.rotate_left(1);
+        self.state[STATE_SIZE - 1] = new_bit;
+        new_bit
+    }
+
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let first = self.update();
+            let second = self.update();
+            if first {
+                return second;
+            }
+        }
+    }
+
+    fn next_biguint(&mut self) -> BigUint {
+        let mut res = BigUint::default();
+        for _ in 0..self.n_bits {
+            res <<= 1;
+            if self.next_bit() {
+                res += 1u64;
+            }
+        }
+        res
+    }
+
+    /// Samples the next field element, thereby rejecting all samples which are larger than the modulus. This is used for the round constants.
+    pub(crate) fn next_field_element_rejection<F: PrimeField>(&mut self) -> F {
+        let modulus: BigUint = F::MODULUS.into();
+        loop {
+            let res = self.next_biguint();
+            if res < modulus {
+                return F::from(res);
+            }
+        }
+    }
+
+    /// Samples the next field element and reduces it modulo the prime. This is used for the MDS matrix of the original Poseidon.
+    pub(crate) fn next_field_element_reduced<F: PrimeField>(&mut self) -> F {
+        F::from(self.next_biguint())
+    }
+}
@@ -0,0 +1,510 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    IoResult,
+    gadgets::poseidon2::Poseidon2,
+    protocols::{
+        rep3::{
+            self, PartyID, Rep3BigUintShare, Rep3PrimeFieldShare, arithmetic,
+            network::{IoContext, Rep3Network},
+        },
+        rep3_ring::{
+            self, Rep3RingShare,
+            gadgets::{lut, ohv},
+            ring::{bit::Bit, int_ring::IntRing2k},
+        },
+    },
+};
+use ark_ff::{One, PrimeField, Zero};
+
+/// A shared authentication path for a leaf at a (possibly secret) index of an [`IncrementalMerkleTree`]. All values are bottom-up, i.e., the first element belongs to the leaf layer.
+#[derive(Clone, Debug)]
+pub struct Rep3MerkleMembershipProof<F: PrimeField> {
+    /// The leaf at the requested index.
+    pub leaf: Rep3PrimeFieldShare<F>,
+    /// The siblings of the nodes on the path from the leaf to the root.
+    pub path_elements: Vec<Rep3PrimeFieldShare<F>>,
+    /// The position of the nodes on the path from the leaf to the root, i.e., 0 if the node is a left child and 1 if it is a right child. These are the bits of the index, least significant bit first.
+    pub path_indices: Vec<Rep3PrimeFieldShare<F>>,
+    /// The root of the tree.
+    pub root: Rep3PrimeFieldShare<F>,
+}
+
+impl<F: PrimeField> Rep3MerkleMembershipProof<F> {
+    /// Returns the proof as named inputs for a membership circuit, using the signal names of the common circom `MerkleTreeChecker` templates, i.e., `leaf`, `pathElements`, `pathIndices`, and `root`. Rename the entries if the circuit uses different names (e.g., a Noir circuit).
+    pub fn into_circuit_inputs(self) -> BTreeMap<String, Vec<Rep3PrimeFieldShare<F>>> {
+        BTreeMap::from([
+            ("leaf".to_owned(), vec![self.leaf]),
+            ("pathElements".to_owned(), self.path_elements),
+            ("pathIndices".to_owned(), self.path_indices),
+            ("root".to_owned(), vec![self.root]),
+        ])
+    }
+}
+
+/// A binary Merkle tree with secret-shared leaves for the Rep3 MPC protocol, which keeps all (shared) nodes in memory such that leaves can be inserted and updated without recomputing the whole tree.
+///
+/// Nodes are hashed with Poseidon2 in compression mode, i.e., the same way as [`Poseidon2::merkle_tree_compression`] with an arity of 2. Leaves which were not set yet are 0.
+///
+/// Leaves can be updated at public indices (in batches, where all hashes of one layer are computed in parallel) or at secret-shared indices. For the latter, the index is converted into a shared one-hot-encoded vector (OHV), which is then used to obliviously write the nodes on the path, such that the index stays hidden. Similarly, authentication paths can be produced for a shared index.
+#[derive(Clone, Debug)]
+pub struct IncrementalMerkleTree<F: PrimeField, const T: usize, const D: u64> {
+    poseidon2: Poseidon2<F, T, D>,
+    depth: usize,
+    next_index: usize,
+    // layers[0] are the leaves, layers[depth] only contains the root
+    layers: Vec<Vec<Rep3PrimeFieldShare<F>>>,
+}
+
+impl<F: PrimeField, const T: usize, const D: u64> IncrementalMerkleTree<F, T, D> {
+    /// Creates an empty tree with 2^depth leaves. No communication is required, since the empty tree is public.
+    pub fn new(poseidon2: Poseidon2<F, T, D>, depth: usize, id: PartyID) -> Self {
+        assert!(T >= 2);
+        assert!(
+            (1..=32).contains(&depth),
+            "only depths between 1 and 32 are supported"
+        );
+        let mut layers = Vec::with_capacity(depth + 1);
+        let mut node = F::zero();
+        for level in 0..=depth {
+            let share = arithmetic::promote_to_trivial_share(id, node);
+            layers.push(vec![share; 1 << (depth - level)]);
+            node = Self::hash_plain(&poseidon2, node, node);
+        }
+        Self {
+            poseidon2,
+            depth,
+            next_index: 0,
+            layers,
+        }
+    }
+
+    /// Creates a tree with 2^depth leaves, where the first leaves are set to the given shared leaves and the remaining ones are 0.
+    pub fn from_leaves<N: Rep3Network>(
+        poseidon2: Poseidon2<F, T, D>,
+        depth: usize,
+        leaves: Vec<Rep3PrimeFieldShare<F>>,
+        driver: &mut IoContext<N>,
+    ) -> IoResult<Self> {
+        let mut tree = Self::new(poseidon2, depth, driver.id);
+        assert!(leaves.len() <= tree.capacity());
+        tree.next_index = leaves.len();
+        tree.layers[0][..leaves.len()].copy_from_slice(&leaves);
+        for level in 0..depth {
+            let (left, right): (Vec<_>, Vec<_>) = tree.layers[level]
+                .chunks_exact(2)
+                .map(|c| (c[0], c[1]))
+                .unzip();
+            tree.layers[level + 1] = tree.hash_rep3(&left, &right, driver)?;
+        }
+        Ok(tree)
+    }
+
+    fn hash_plain(poseidon2: &Poseidon2<F, T, D>, left: F, right: F) -> F {
+        let mut state = [F::zero(); T];
+        state[0] = left;
+        state[1] = right;
+        poseidon2.permutation_in_place(&mut state);
+        state[0] + left
+    }
+
+    /// Hashes all pairs (left[i], right[i]) in parallel.
+    fn hash_rep3<N: Rep3Network>(
+        &self,
+        left: &[Rep3PrimeFieldShare<F>],
+        right: &[Rep3PrimeFieldShare<F>],
+        driver: &mut IoContext<N>,
+    ) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+        debug_assert_eq!(left.len(), right.len());
+        if left.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut precomp = self.poseidon2.precompute_rep3(left.len(), driver)?;
+        let mut state = Vec::with_capacity(left.len() * T);
+        for (l, r) in left.iter().zip(right.iter()) {
+            state.push(*l);
+            state.push(*r);
+            for _ in 2..T {
+                state.push(Rep3PrimeFieldShare::zero());
+            }
+        }
+        self.poseidon2
+            .rep3_permutation_in_place_with_precomputation_packed(
+                &mut state,
+                &mut precomp,
+                driver,
+            )?;
+        Ok(state
+            .chunks_exact(T)
+            .zip(left.iter())
+            .map(|(s, l)| s[0] + *l)
+            .collect())
+    }
+
+    /// Returns the depth of the tree.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the maximum number of leaves, i.e., 2^depth.
+    pub fn capacity(&self) -> usize {
+        self.layers[0].len()
+    }
+
+    /// Returns the index at which the next leaf is inserted by [`IncrementalMerkleTree::append`].
+    pub fn next_index(&self) -> usize {
+        self.next_index
+    }
+
+    /// Returns the shared root of the tree.
+    pub fn root(&self) -> Rep3PrimeFieldShare<F> {
+        self.layers[self.depth][0]
+    }
+
+    /// Returns the shared leaves of the tree.
+    pub fn leaves(&self) -> &[Rep3PrimeFieldShare<F>] {
+        &self.layers[0]
+    }
+
+    /// Inserts the given leaves at the next free (public) indices.
+    pub fn append<N: Rep3Network>(
+        &mut self,
+        leaves: &[Rep3PrimeFieldShare<F>],
+        driver: &mut IoContext<N>,
+    ) -> IoResult<()> {
+        assert!(
+            self.next_index + leaves.len() <= self.capacity(),
+            "Merkle tree is full"
+        );
+        let updates = leaves
+            .iter()
+            .enumerate()
+            .map(|(i, leaf)| (self.next_index + i, *leaf))
+            .collect::<Vec<_>>();
+        self.update(&updates, driver)?;
+        self.next_index += leaves.len();
+        Ok(())
+    }
+
+    /// Sets the leaves at the given public indices and recomputes all affected nodes. The hashes of each layer are computed in parallel. If an index appears multiple times, the last update wins.
+    pub fn update<N: Rep3Network>(
+        &mut self,
+        updates: &[(usize, Rep3PrimeFieldShare<F>)],
+        driver: &mut IoContext<N>,
+    ) -> IoResult<()> {
+        let mut dirty = BTreeSet::new();
+        for (index, leaf) in updates {
+            assert!(*index < self.capacity(), "index out of bounds");
+            self.layers[0][*index] = *leaf;
+            dirty.insert(*index / 2);
+        }
+
+        for level in 0..self.depth {
+            let layer = &self.layers[level];
+            let (left, right): (Vec<_>, Vec<_>) = dirty
+                .iter()
+                .map(|p| (layer[2 * p], layer[2 * p + 1]))
+                .unzip();
+            let hashes = self.hash_rep3(&left, &right, driver)?;
+            for (p, hash) in dirty.iter().zip(hashes) {
+                self.layers[level + 1][*p] = hash;
+            }
+            dirty = dirty.into_iter().map(|p| p / 2).collect();
+        }
+        Ok(())
+    }
+
+    fn ohv_internal<R: IntRing2k, N: Rep3Network>(
+        &self,
+        bits: &Rep3BigUintShare<F>,
+        driver: &mut IoContext<N>,
+    ) -> IoResult<Vec<Rep3RingShare<Bit>>> {
+        let a = R::cast_from_biguint(&bits.a);
+        let b = R::cast_from_biguint(&bits.b);
+        ohv::ohv(self.depth, Rep3RingShare::new(a, b), driver)
+    }
+
+    /// Converts a shared index into a shared one-hot-encoded vector of length 2^depth. Only the lowest depth bits of the index are considered.
+    fn ohv<N: Rep3Network>(
+        &self,
+        index: Rep3PrimeFieldShare<F>,
+        driver: &mut IoContext<N>,
+    ) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+        let bits = rep3::conversion::a2b_selector(index, driver)?;
+        let e = if self.depth <= 8 {
+            self.ohv_internal::<u8, _>(&bits, driver)?
+        } else if self.depth <= 16 {
+            self.ohv_internal::<u16, _>(&bits, driver)?
+        } else {
+            self.ohv_internal::<u32, _>(&bits, driver)?
+        };
+        rep3_ring::conversion::bit_inject_from_bits_to_field_many::<F, _>(&e, driver)
+    }
+
+    fn ohv_many_internal<R: IntRing2k, N: Rep3Network>(
+        &self,
+        bits: &[Rep3BigUintShare<F>],
+        driver: &mut IoContext<N>,
+    ) -> IoResult<Vec<Rep3RingShare<Bit>>> {
+        let bits = bits
+            .iter()
+            .map(|bits| {
+                let a = R::cast_from_biguint(&bits.a);
+                let b = R::cast_from_biguint(&bits.b);
+                Rep3RingShare::new(a, b)
+            })
+            .collect();
+        Ok(ohv::ohv_many(self.depth, bits, driver)?
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+
+    /// Converts multiple shared indices into shared OHVs at once, see [`IncrementalMerkleTree::ohv`].
+    fn ohv_many<N: Rep3Network>(
+        &self,
+        indices: &[Rep3PrimeFieldShare<F>],
+        driver: &mut IoContext<N>,
+    ) -> IoResult<Vec<Vec<Rep3PrimeFieldShare<F>>>> {
+        let bits = rep3::conversion::a2b_many_selector(indices, driver)?;
+        let e = if self.depth <= 8 {
+            self.ohv_many_internal::<u8, _>(&bits, driver)?
+        } else if self.depth <= 16 {
+            self.ohv_many_internal::<u16, _>(&bits, driver)?
+        } else {
+            self.ohv_many_internal::<u32, _>(&bits, driver)?
+        };
+        let e = rep3_ring::conversion::bit_inject_from_bits_to_field_many::<F, _>(&e, driver)?;
+        Ok(e.chunks_exact(self.capacity())
+            .map(|e| e.to_vec())
+            .collect())
+    }
+
+    /// Given the OHV of a node index, computes the OHV of the index of its parent. No communication is required.
+    fn parent_ohv(e: &[Rep3PrimeFieldShare<F>]) -> Vec<Rep3PrimeFieldShare<F>> {
+        e.chunks_exact(2).map(|c| c[0] + c[1]).collect()
+    }
+
+    /// Sets the leaf at a secret-shared index and recomputes the path to the root without revealing the index. Only the lowest depth bits of the index are considered.
+    pub fn update_shared<N: Rep3Network>(
+        &mut self,
+        index: Rep3PrimeFieldShare<F>,
+        leaf: Rep3PrimeFieldShare<F>,
+        driver: &mut IoContext<N>,
+    ) -> IoResult<()> {
+        let mut e = self.ohv(index, driver)?;
+        lut::write_lut_from_ohv(&leaf, &mut self.layers[0], &e, driver)?;
+
+        for level in 0..self.depth {
+            let parent_e = Self::parent_ohv(&e);
+
+            // Obliviously read both children of the parent on the path
+            let layer = &self.layers[level];
+            let mut left = driver.rngs.rand.masking_field_element::<F>();
+            let mut right = driver.rngs.rand.masking_field_element::<F>();
+            for (j, e) in parent_e.iter().enumerate() {
+                left += e * &layer[2 * j];
+                right += e * &layer[2 * j + 1];
+            }
+            let b = driver.network.reshare_many(&[left, right])?;
+            let left = Rep3PrimeFieldShare::new(left, b[0]);
+            let right = Rep3PrimeFieldShare::new(right, b[1]);
+
+            let parent = self.hash_rep3(&[left], &[right], driver)?[0];
+            lut::write_lut_from_ohv(&parent, &mut self.layers[level + 1], &parent_e, driver)?;
+            e = parent_e;
+        }
+        Ok(())
+    }
+
+    /// Sets the leaves at the given secret-shared indices, see [`IncrementalMerkleTree::update_shared`]. If two indices are equal, the last update wins, i.e., the result is the same as applying the updates in order.
+    ///
+    /// The OHVs of all indices are created at once and the oblivious reads and writes of each layer are batched over all updates. Since the indices are hidden, updates hitting the same node are detected obliviously by comparing their OHVs, and only the last of them writes the node. This costs O(m^2) local multiplications per layer for m updates, but the number of communication rounds only grows logarithmically with m.
+    pub fn update_shared_batch<N: Rep3Network>(
+        &mut self,
+        updates: &[(Rep3PrimeFieldShare<F>, Rep3PrimeFieldShare<F>)],
+        driver: &mut IoContext<N>,
+    ) -> IoResult<()> {
+        if updates.is_empty() {
+            return Ok(());
+        }
+        let (indices, mut values): (Vec<_>, Vec<_>) = updates.iter().copied().unzip();
+        let mut es = self.ohv_many(&indices, driver)?;
+
+        for level in 0..=self.depth {
+            let written = Self::dedup_ohvs(&es, driver)?;
+            lut::write_lut_from_ohvs(&values, &mut self.layers[level], &written, driver)?;
+            if level == self.depth {
+                break;
+            }
+
+            // Obliviously read both children of the parents on all paths
+            let layer = &self.layers[level];
+            es = es.iter().map(|e| Self::parent_ohv(e)).collect();
+            let mut additive = Vec::with_capacity(2 * es.len());
+            for e in es.iter() {
+                let mut left = driver.rngs.rand.masking_field_element::<F>();
+                let mut right = driver.rngs.rand.masking_field_element::<F>();
+                for (j, e) in e.iter().enumerate() {
+                    left += e * &layer[2 * j];
+                    right += e * &layer[2 * j + 1];
+                }
+                additive.push(left);
+                additive.push(right);
+            }
+            let b = driver.network.reshare_many(&additive)?;
+            let (left, right): (Vec<_>, Vec<_>) = additive
+                .chunks_exact(2)
+                .zip(b.chunks_exact(2))
+                .map(|(a, b)| {
+                    (
+                        Rep3PrimeFieldShare::new(a[0], b[0]),
+                        Rep3PrimeFieldShare::new(a[1], b[1]),
+                    )
+                })
+                .unzip();
+            values = self.hash_rep3(&left, &right, driver)?;
+        }
+        Ok(())
+    }
+
+    /// Zeroes the OHV of every update for which a later update has the same OHV, such that each index is written at most once (by the last update). The OHV of the last update is always kept.
+    fn dedup_ohvs<N: Rep3Network>(
+        es: &[Vec<Rep3PrimeFieldShare<F>>],
+        driver: &mut IoContext<N>,
+    ) -> IoResult<Vec<Vec<Rep3PrimeFieldShare<F>>>> {
+        let m = es.len();
+        if m == 1 {
+            return Ok(es.to_vec());
+        }
+
+        // The inner product of two OHVs is 1 if they are equal and 0 otherwise
+        let mut additive = Vec::with_capacity(m * (m - 1) / 2);
+        for (k, e) in es.iter().enumerate() {
+            for e_ in es[k + 1..].iter() {
+                let mut same = driver.rngs.rand.masking_field_element::<F>();
+                for (x, y) in e.iter().zip(e_.iter()) {
+                    same += x * y;
+                }
+                additive.push(same);
+            }
+        }
+        let b = driver.network.reshare_many(&additive)?;
+        let mut same = additive
+            .into_iter()
+            .zip(b)
+            .map(|(a, b)| Rep3PrimeFieldShare::new(a, b));
+        let factors = (0..m)
+            .map(|k| {
+                same.by_ref()
+                    .take(m - 1 - k)
+                    .map(|s| arithmetic::sub_public_by_shared(F::one(), s, driver.id))
+                    .collect()
+            })
+            .collect();
+        let keep = Self::product_many(factors, driver)?;
+
+        // The last update is always kept, so its keep flag is a trivial 1
+        let (lhs, rhs): (Vec<_>, Vec<_>) = es[..m - 1]
+            .iter()
+            .zip(keep.iter())
+            .flat_map(|(e, keep)| e.iter().map(move |e| (*e, *keep)))
+            .unzip();
+        let kept = arithmetic::mul_vec(&lhs, &rhs, driver)?;
+        let mut result = kept
+            .chunks_exact(es[0].len())
+            .map(|e| e.to_vec())
+            .collect::<Vec<_>>();
+        result.push(es[m - 1].clone());
+        Ok(result)
+    }
+
+    /// Computes the products of all given lists of factors in parallel with a multiplication tree. The product of an empty list is 1.
+    fn product_many<N: Rep3Network>(
+        mut factors: Vec<Vec<Rep3PrimeFieldShare<F>>>,
+        driver: &mut IoContext<N>,
+    ) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+        while factors.iter().any(|f| f.len() > 1) {
+            let (lhs, rhs): (Vec<_>, Vec<_>) = factors
+                .iter()
+                .flat_map(|f| f.chunks_exact(2).map(|c| (c[0], c[1])))
+                .unzip();
+            let mut products = arithmetic::mul_vec(&lhs, &rhs, driver)?.into_iter();
+            for f in factors.iter_mut() {
+                let mut next = products.by_ref().take(f.len() / 2).collect::<Vec<_>>();
+                if f.len() % 2 == 1 {
+                    next.push(f[f.len() - 1]);
+                }
+                *f = next;
+            }
+        }
+        Ok(factors
+            .into_iter()
+            .map(|f| {
+                f.first()
+                    .copied()
+                    .unwrap_or_else(|| arithmetic::promote_to_trivial_share(driver.id, F::one()))
+            })
+            .collect())
+    }
+
+    /// Produces the shared authentication path for a public index.
+    pub fn membership_proof(&self, mut index: usize, id: PartyID) -> Rep3MerkleMembershipProof<F> {
+        assert!(index < self.capacity(), "index out of bounds");
+        let leaf = self.layers[0][index];
+        let mut path_elements = Vec::with_capacity(self.depth);
+        let mut path_indices = Vec::with_capacity(self.depth);
+        for layer in self.layers.iter().take(self.depth) {
+            path_elements.push(layer[index ^ 1]);
+            let bit = F::from((index & 1) as u64);
+            path_indices.push(arithmetic::promote_to_trivial_share(id, bit));
+            index >>= 1;
+        }
+        Rep3MerkleMembershipProof {
+            leaf,
+            path_elements,
+            path_indices,
+            root: self.root(),
+        }
+    }
+
+    /// Produces the shared authentication path for a secret-shared index without revealing the index. Only the lowest depth bits of the index are considered. All oblivious reads are batched into one round of communication after creating the OHV.
+    pub fn membership_proof_shared<N: Rep3Network>(
+        &self,
+        index: Rep3PrimeFieldShare<F>,
+        driver: &mut IoContext<N>,
+    ) -> IoResult<Rep3MerkleMembershipProof<F>> {
+        let mut e = self.ohv(index, driver)?;
+
+        // The leaf and all siblings are read as additive shares and reshared at once
+        let mut additive = Vec::with_capacity(self.depth + 1);
+        let mut path_indices = Vec::with_capacity(self.depth);
+        additive.push(lut::read_shared_lut_from_ohv(&self.layers[0], &e, driver)?);
+        for layer in self.layers.iter().take(self.depth) {
+            let mut sibling = driver.rngs.rand.masking_field_element::<F>();
+            let mut bit = Rep3PrimeFieldShare::zero();
+            for (j, e) in e.iter().enumerate() {
+                sibling += e * &layer[j ^ 1];
+                if j & 1 == 1 {
+                    bit += e;
+                }
+            }
+            additive.push(sibling);
+            path_indices.push(bit);
+            e = Self::parent_ohv(&e);
+        }
+        let b = driver.network.reshare_many(&additive)?;
+        let mut shares = additive
+            .into_iter()
+            .zip(b)
+            .map(|(a, b)| Rep3PrimeFieldShare::new(a, b));
+        let leaf = shares.next().expect("leaf is present");
+
+        Ok(Rep3MerkleMembershipProof {
+            leaf,
+            path_elements: shares.collect(),
+            path_indices,
+            root: self.root(),
+        })
+    }
+}
@@ -2,6 +2,9 @@
 //!
 //! This module contains implementations to create Merkle trees with Poseidon2.
 
+pub(crate) mod incremental;
 pub(crate) mod plain;
 pub(crate) mod rep3;
 pub(crate) mod shamir;
+
+pub use incremental::{IncrementalMerkleTree, Rep3MerkleMembershipProof};
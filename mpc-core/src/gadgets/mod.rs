@@ -2,7 +2,9 @@
 //!
 //! This module contains some commonly used gadgets.
 
+pub(crate) mod grain_lfsr;
 pub mod merkle_tree;
+pub mod poseidon;
 pub mod poseidon2;
 
 use ark_ff::PrimeField;
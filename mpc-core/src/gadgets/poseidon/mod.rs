@@ -0,0 +1,17 @@
+//! Poseidon
+//!
+//! This module contains implementations of the original Poseidon permutation, as used for example by circomlib's `poseidon.circom`. The MPC implementations reuse the S-box preprocessing of the Poseidon2 permutation, see [`Poseidon2Precomputations`](super::poseidon2::Poseidon2Precomputations).
+//!
+//! Parameter sets are provided for circomlib's `Poseidon(n)` on BN254 with t=2 to t=17. Other parameter sets can be loaded with [`PoseidonParams::from_json`].
+//!
+//! The circom MPC-VM does not accelerate `Poseidon(n)` with these implementations yet. A component accelerator only writes the signals of the accelerated component itself, but `Poseidon(n)` instantiates `PoseidonEx` and its round subcomponents, whose signals are part of the witness as well.
+
+pub(crate) mod poseidon_circom_bn254;
+pub(crate) mod poseidon_params;
+pub(crate) mod poseidon_permutation;
+pub(crate) mod rep3;
+pub(crate) mod shamir;
+
+pub use poseidon_circom_bn254::*;
+pub use poseidon_params::PoseidonParams;
+pub use poseidon_permutation::Poseidon;
@@ -0,0 +1,39 @@
+/// Parameters are compatible with circomlib's `poseidon.circom` found at:
+/// [https://github.com/iden3/circomlib/blob/master/circuits/poseidon.circom](https://github.com/iden3/circomlib/blob/master/circuits/poseidon.circom)
+///
+/// The round constants and MDS matrices are derived with the Grain LFSR on first use, see [`PoseidonParams::circom`].
+use super::PoseidonParams;
+use std::sync::{Arc, LazyLock};
+
+type Scalar = ark_bn254::Fr;
+
+const D: u64 = 5;
+
+macro_rules! circom_params {
+    ($($name: ident, $t: literal, $n: literal;)*) => {
+        $(
+            #[doc = concat!("The parameters of circomlib's `Poseidon(", $n, ")` for the BN254 curve, i.e., with a internal state of size t=", $t, ".")]
+            pub static $name: LazyLock<Arc<PoseidonParams<Scalar, $t, D>>> =
+                LazyLock::new(|| Arc::new(PoseidonParams::circom()));
+        )*
+    };
+}
+
+circom_params! {
+    POSEIDON_CIRCOM_BN254_T2_PARAMS, 2, 1;
+    POSEIDON_CIRCOM_BN254_T3_PARAMS, 3, 2;
+    POSEIDON_CIRCOM_BN254_T4_PARAMS, 4, 3;
+    POSEIDON_CIRCOM_BN254_T5_PARAMS, 5, 4;
+    POSEIDON_CIRCOM_BN254_T6_PARAMS, 6, 5;
+    POSEIDON_CIRCOM_BN254_T7_PARAMS, 7, 6;
+    POSEIDON_CIRCOM_BN254_T8_PARAMS, 8, 7;
+    POSEIDON_CIRCOM_BN254_T9_PARAMS, 9, 8;
+    POSEIDON_CIRCOM_BN254_T10_PARAMS, 10, 9;
+    POSEIDON_CIRCOM_BN254_T11_PARAMS, 11, 10;
+    POSEIDON_CIRCOM_BN254_T12_PARAMS, 12, 11;
+    POSEIDON_CIRCOM_BN254_T13_PARAMS, 13, 12;
+    POSEIDON_CIRCOM_BN254_T14_PARAMS, 14, 13;
+    POSEIDON_CIRCOM_BN254_T15_PARAMS, 15, 14;
+    POSEIDON_CIRCOM_BN254_T16_PARAMS, 16, 15;
+    POSEIDON_CIRCOM_BN254_T17_PARAMS, 17, 16;
+}
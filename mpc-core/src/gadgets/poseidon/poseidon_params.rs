@@ -0,0 +1,165 @@
+use ark_ff::PrimeField;
+use eyre::{Context, bail};
+use serde::Deserialize;
+
+use crate::gadgets::grain_lfsr::GrainLfsr;
+
+/// A parameter set containing the parameters for the original Poseidon permutation.
+#[derive(Clone, Debug)]
+pub struct PoseidonParams<F: PrimeField, const T: usize, const D: u64> {
+    /// The amount of full rounds at the beginning of the permutation.
+    pub rounds_f_beginning: usize,
+    /// The amount of full rounds at the end of the permutation.
+    pub rounds_f_end: usize,
+    /// The amount of partial rounds.
+    pub rounds_p: usize,
+    /// The t x t MDS matrix of the linear layer. The new state is computed as `new[i] = sum_j mds[i][j] * state[j]`.
+    pub mds: [[F; T]; T],
+    /// The round constants, one array per round.
+    pub round_constants: Vec<[F; T]>,
+}
+
+/// The JSON representation of a [`PoseidonParams`]. All field elements are given as hexadecimal strings.
+#[derive(Deserialize)]
+struct PoseidonParamsJson {
+    rounds_f: usize,
+    rounds_p: usize,
+    mds: Vec<Vec<String>>,
+    round_constants: Vec<Vec<String>>,
+}
+
+fn parse_state<F: PrimeField, const T: usize>(elements: &[String]) -> eyre::Result<[F; T]> {
+    let state = elements
+        .iter()
+        .map(|el| {
+            crate::gadgets::field_from_hex_string(el)
+                .with_context(|| format!("invalid field element {el}"))
+        })
+        .collect::<eyre::Result<Vec<F>>>()?;
+    let len = state.len();
+    match state.try_into() {
+        Ok(state) => Ok(state),
+        Err(_) => bail!("expected {T} field elements, but got {len}"),
+    }
+}
+
+/// The amount of partial rounds used by circomlib's `poseidon.circom` for the statesizes t=2 to t=17.
+const CIRCOM_ROUNDS_P: [usize; 16] = [
+    56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68,
+];
+/// The amount of full rounds used by circomlib's `poseidon.circom`.
+const CIRCOM_ROUNDS_F: usize = 8;
+
+impl<F: PrimeField, const T: usize, const D: u64> PoseidonParams<F, T, D> {
+    /// Creates a new parameter set. Panics if the degree D of the S-box or the amount of round constants is not supported.
+    pub fn new(
+        rounds_f: usize,
+        rounds_p: usize,
+        mds: [[F; T]; T],
+        round_constants: Vec<[F; T]>,
+    ) -> Self {
+        assert!(T >= 2);
+        assert!(D % 2 == 1);
+        assert_eq!(rounds_f % 2, 0);
+        assert_eq!(round_constants.len(), rounds_f + rounds_p);
+        let rounds_f_beginning = rounds_f / 2;
+        let rounds_f_end = rounds_f / 2;
+
+        Self {
+            rounds_f_beginning,
+            rounds_f_end,
+            rounds_p,
+            mds,
+            round_constants,
+        }
+    }
+
+    /// Creates a new parameter set where the round constants and the Cauchy MDS matrix are derived with the Grain LFSR, as done by the reference parameter generation script found at:
+    /// [https://extgit.isec.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage](https://extgit.isec.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage)
+    ///
+    /// The reference script resamples the MDS matrix if it does not pass its security checks. These checks are not performed here, i.e., the parameters only match the reference if the first sampled matrix is accepted, which is the case for all parameter sets of circomlib.
+    pub fn from_grain_lfsr(rounds_f: usize, rounds_p: usize) -> Self {
+        assert_eq!(D, 5, "Grain LFSR parameters are only supported for x^5");
+        let mut lfsr = GrainLfsr::new(F::MODULUS_BIT_SIZE as usize, T, rounds_f, rounds_p);
+        let round_constants = (0..rounds_f + rounds_p)
+            .map(|_| std::array::from_fn(|_| lfsr.next_field_element_rejection()))
+            .collect();
+
+        let xs: [F; T] = std::array::from_fn(|_| lfsr.next_field_element_reduced());
+        let ys: [F; T] = std::array::from_fn(|_| lfsr.next_field_element_reduced());
+        let mds = std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                (xs[i] + ys[j])
+                    .inverse()
+                    .expect("Cauchy matrix entries are invertible")
+            })
+        });
+
+        Self::new(rounds_f, rounds_p, mds, round_constants)
+    }
+
+    /// Creates the parameter set used by circomlib's `poseidon.circom` for the statesize T, i.e., for `Poseidon(T - 1)`. The parameters are only compatible with circomlib for the BN254 scalar field. Panics if T is not in 2..=17.
+    pub fn circom() -> Self {
+        assert!(
+            (2..=17).contains(&T),
+            "circomlib only supports Poseidon with 2 <= t <= 17"
+        );
+        Self::from_grain_lfsr(CIRCOM_ROUNDS_F, CIRCOM_ROUNDS_P[T - 2])
+    }
+
+    /// Loads a parameter set from a JSON string of the following form, where all field elements are hexadecimal strings (with or without the 0x prefix):
+    ///
+    /// ```json
+    /// {
+    ///   "rounds_f": 8,
+    ///   "rounds_p": 57,
+    ///   "mds": [["0x..", "0x..", "0x.."], ...],
+    ///   "round_constants": [["0x..", "0x..", "0x.."], ...]
+    /// }
+    /// ```
+    ///
+    /// Thereby, `round_constants` contains `rounds_f + rounds_p` arrays with T elements each.
+    pub fn from_json(json: &str) -> eyre::Result<Self> {
+        let params: PoseidonParamsJson =
+            serde_json::from_str(json).context("while parsing Poseidon parameters")?;
+        if T < 2 {
+            bail!("unsupported statesize {T}");
+        }
+        if D % 2 == 0 {
+            bail!("unsupported S-box degree {D}");
+        }
+        if params.rounds_f % 2 != 0 {
+            bail!("the amount of full rounds must be even");
+        }
+        if params.round_constants.len() != params.rounds_f + params.rounds_p {
+            bail!(
+                "expected {} round constants, but got {}",
+                params.rounds_f + params.rounds_p,
+                params.round_constants.len()
+            );
+        }
+
+        let rows = params
+            .mds
+            .iter()
+            .map(|row| parse_state(row))
+            .collect::<eyre::Result<Vec<[F; T]>>>()?;
+        let num_rows = rows.len();
+        let mds = match rows.try_into() {
+            Ok(mds) => mds,
+            Err(_) => bail!("expected {T} rows in the MDS matrix, but got {num_rows}"),
+        };
+        let round_constants = params
+            .round_constants
+            .iter()
+            .map(|rc| parse_state(rc))
+            .collect::<eyre::Result<Vec<_>>>()?;
+
+        Ok(Self::new(
+            params.rounds_f,
+            params.rounds_p,
+            mds,
+            round_constants,
+        ))
+    }
+}
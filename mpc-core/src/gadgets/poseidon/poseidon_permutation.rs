@@ -0,0 +1,285 @@
+use super::poseidon_params::PoseidonParams;
+use ark_ff::PrimeField;
+use std::sync::Arc;
+
+/// A struct represnting the original Poseidon permutation.
+#[derive(Clone, Debug)]
+pub struct Poseidon<F: PrimeField, const T: usize, const D: u64> {
+    /// The parameter set containing the parameters for the Poseidon permutation.
+    pub params: Arc<PoseidonParams<F, T, D>>,
+}
+
+impl<F: PrimeField, const T: usize, const D: u64> Poseidon<F, T, D> {
+    /// Creates a new instance of the Poseidon permuation with given parameters
+    pub fn new(params: Arc<PoseidonParams<F, T, D>>) -> Self {
+        Self { params }
+    }
+
+    pub(crate) fn num_rounds(&self) -> usize {
+        self.params.rounds_f_beginning + self.params.rounds_p + self.params.rounds_f_end
+    }
+
+    pub(crate) fn is_full_round(&self, r: usize) -> bool {
+        r < self.params.rounds_f_beginning
+            || r >= self.params.rounds_f_beginning + self.params.rounds_p
+    }
+
+    pub(crate) fn num_sbox(&self) -> usize {
+        (self.params.rounds_f_beginning + self.params.rounds_f_end) * T + self.params.rounds_p
+    }
+
+    fn single_sbox(input: &mut F) {
+        match D {
+            3 => {
+                let input2 = input.square();
+                *input *= input2;
+            }
+            5 => {
+                let input2 = input.square();
+                let input4 = input2.square();
+                *input *= input4;
+            }
+            7 => {
+                let input2 = input.square();
+                let input4 = input2.square();
+                *input *= input4;
+                *input *= input2;
+            }
+            _ => {
+                *input = input.pow([D]);
+            }
+        }
+    }
+
+    /// The matrix multiplication with the MDS matrix in each round of the Poseidon permutation.
+    pub fn matmul(&self, input: &mut [F; T]) {
+        let res = std::array::from_fn(|i| {
+            self.params.mds[i]
+                .iter()
+                .zip(input.iter())
+                .map(|(m, s)| *m * s)
+                .sum()
+        });
+        *input = res;
+    }
+
+    pub(crate) fn add_rc(&self, input: &mut [F; T], rc_offset: usize) {
+        for (s, rc) in input
+            .iter_mut()
+            .zip(self.params.round_constants[rc_offset].iter())
+        {
+            *s += rc;
+        }
+    }
+
+    /// One round of the Poseidon permuation. Depending on `r`, this is either a full or a partial round.
+    pub fn round(&self, state: &mut [F; T], r: usize) {
+        self.add_rc(state, r);
+        if self.is_full_round(r) {
+            state.iter_mut().for_each(Self::single_sbox);
+        } else {
+            Self::single_sbox(&mut state[0]);
+        }
+        self.matmul(state);
+    }
+
+    /// Performs the Poseidon Permutation on the given state.
+    pub fn permutation_in_place(&self, state: &mut [F; T]) {
+        for r in 0..self.num_rounds() {
+            self.round(state, r);
+        }
+    }
+
+    /// Performs the Poseidon Permutation on the given state.
+    pub fn permutation(&self, input: &[F; T]) -> [F; T] {
+        let mut state = *input;
+        self.permutation_in_place(&mut state);
+        state
+    }
+
+    /// Computes the hash of T - 1 inputs as done by circomlib's `Poseidon(T - 1)` template, i.e., the state is initialized with `[0, inputs...]` and the first element of the permuted state is returned.
+    pub fn circom_hash(&self, inputs: &[F]) -> F {
+        assert_eq!(inputs.len(), T - 1);
+        let mut state = [F::zero(); T];
+        state[1..].copy_from_slice(inputs);
+        self.permutation_in_place(&mut state);
+        state[0]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gadgets::poseidon::*;
+    use std::str::FromStr;
+
+    const CIRCOMLIB_CONSTANTS: &str =
+        "../test_vectors/WitnessExtension/tests/libs/poseidon_constants_old.circom";
+
+    fn poseidon_circom_kat<const T: usize>(
+        params: &Arc<PoseidonParams<ark_bn254::Fr, T, 5>>,
+        expected: &str,
+    ) {
+        let poseidon = Poseidon::new(Arc::clone(params));
+        let inputs = (1..T as u64).map(ark_bn254::Fr::from).collect::<Vec<_>>();
+        let expected = ark_bn254::Fr::from_str(expected).unwrap();
+        assert_eq!(poseidon.circom_hash(&inputs), expected, "t={T}");
+    }
+
+    // Returns the flattened array returned by the circom function `name` for the given t
+    fn circomlib_constants(source: &str, name: &str, t: usize) -> Vec<ark_bn254::Fr> {
+        let function = &source[source.find(&format!("function {name}(t)")).unwrap()..];
+        let branch = &function[function.find(&format!("t == {t})")).unwrap()..];
+        let array =
+            &branch[branch.find("return").unwrap() + "return".len()..branch.find(';').unwrap()];
+        array
+            .split(',')
+            .map(|el| el.trim_matches(|c: char| c.is_whitespace() || c == '[' || c == ']'))
+            .map(|el| {
+                if el.starts_with("0x") {
+                    crate::gadgets::field_from_hex_string(el).unwrap()
+                } else {
+                    ark_bn254::Fr::from_str(el).unwrap()
+                }
+            })
+            .collect()
+    }
+
+    fn poseidon_circom_params_kat<const T: usize>(
+        source: &str,
+        params: &PoseidonParams<ark_bn254::Fr, T, 5>,
+    ) {
+        let round_constants = params.round_constants.iter().flatten().copied();
+        let mds = params.mds.iter().flatten().copied();
+        assert!(
+            round_constants.eq(circomlib_constants(source, "POSEIDON_C", T)),
+            "round constants of t={T}"
+        );
+        assert!(
+            mds.eq(circomlib_constants(source, "POSEIDON_M", T)),
+            "MDS matrix of t={T}"
+        );
+    }
+
+    // The expected values are the outputs of circomlib's Poseidon(n) for the inputs [1, 2, ..., n]
+    #[test]
+    fn poseidon_circom_bn254_kat() {
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T2_PARAMS,
+            "18586133768512220936620570745912940619677854269274689475585506675881198879027",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T3_PARAMS,
+            "7853200120776062878684798364095072458815029376092732009249414926327459813530",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T4_PARAMS,
+            "6542985608222806190361240322586112750744169038454362455181422643027100751666",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T5_PARAMS,
+            "18821383157269793795438455681495246036402687001665670618754263018637548127333",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T6_PARAMS,
+            "6183221330272524995739186171720101788151706631170188140075976616310159254464",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T7_PARAMS,
+            "20400040500897583745843009878988256314335038853985262692600694741116813247201",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T8_PARAMS,
+            "12748163991115452309045839028154629052133952896122405799815156419278439301912",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T9_PARAMS,
+            "18604317144381847857886385684060986177838410221561136253933256952257712543953",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T10_PARAMS,
+            "13589767895268936107593642967621470491511464502761040466226072462545218539640",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T11_PARAMS,
+            "3657500514307717306974218405144578736633140001277925127187636780142269815841",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T12_PARAMS,
+            "3572015662710076994097916907865950486270383304442561406230608893458731714472",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T13_PARAMS,
+            "2501997477381648492950318384533644783248002172679259592360114615426357826485",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T14_PARAMS,
+            "7041832639553862712666971417715061873827921493498355005117622707743491651590",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T15_PARAMS,
+            "8354478399926161176778659061636406690034081872658507739535256090879947077494",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T16_PARAMS,
+            "4203130618016961831408770638653325366880478848856764494148034853759773445968",
+        );
+        poseidon_circom_kat(
+            &POSEIDON_CIRCOM_BN254_T17_PARAMS,
+            "9989051620750914585850546081941653841776809718687451684622678807385399211877",
+        );
+    }
+
+    // Compares the derived parameters with the constants of circomlib's original `poseidon.circom`
+    #[test]
+    fn poseidon_circom_bn254_params_kat() {
+        let source = std::fs::read_to_string(CIRCOMLIB_CONSTANTS).unwrap();
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T2_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T3_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T4_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T5_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T6_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T7_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T8_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T9_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T10_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T11_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T12_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T13_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T14_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T15_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T16_PARAMS);
+        poseidon_circom_params_kat(&source, &POSEIDON_CIRCOM_BN254_T17_PARAMS);
+    }
+
+    #[test]
+    fn poseidon_from_json() {
+        let params = &POSEIDON_CIRCOM_BN254_T2_PARAMS;
+        let to_hex = |f: &ark_bn254::Fr| {
+            let f: num_bigint::BigUint = (*f).into();
+            format!("\"{f:#x}\"")
+        };
+        let join = |v: &[[ark_bn254::Fr; 2]]| {
+            v.iter()
+                .map(|row| format!("[{}]", row.iter().map(to_hex).collect::<Vec<_>>().join(",")))
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let json = format!(
+            r#"{{"rounds_f":{},"rounds_p":{},"mds":[{}],"round_constants":[{}]}}"#,
+            params.rounds_f_beginning + params.rounds_f_end,
+            params.rounds_p,
+            join(&params.mds),
+            join(&params.round_constants),
+        );
+        let loaded = PoseidonParams::<ark_bn254::Fr, 2, 5>::from_json(&json).unwrap();
+        assert_eq!(loaded.mds, params.mds);
+        assert_eq!(loaded.round_constants, params.round_constants);
+        let inputs = [ark_bn254::Fr::from(1u64)];
+        assert_eq!(
+            Poseidon::new(Arc::new(loaded)).circom_hash(&inputs),
+            Poseidon::new(Arc::clone(params)).circom_hash(&inputs)
+        );
+        assert!(PoseidonParams::<ark_bn254::Fr, 3, 5>::from_json(&json).is_err());
+    }
+}
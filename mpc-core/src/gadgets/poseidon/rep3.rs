@@ -0,0 +1,121 @@
+use super::Poseidon;
+use crate::gadgets::poseidon2::{
+    Poseidon2Precomputations,
+    rep3::{precompute_sbox_rep3, sbox_rep3_precomp},
+};
+use crate::protocols::rep3::{
+    Rep3PrimeFieldShare, arithmetic,
+    network::{IoContext, Rep3Network},
+};
+use ark_ff::PrimeField;
+
+impl<F: PrimeField, const T: usize, const D: u64> Poseidon<F, T, D> {
+    /// Create Poseidon2Precomputations for num_poseidon Poseidon permutations in the Rep3 MPC protocol.
+    pub fn precompute_rep3<N: Rep3Network>(
+        &self,
+        num_poseidon: usize,
+        driver: &mut IoContext<N>,
+    ) -> std::io::Result<Poseidon2Precomputations<Rep3PrimeFieldShare<F>>> {
+        assert_eq!(D, 5);
+        precompute_sbox_rep3(self.num_sbox() * num_poseidon, driver)
+    }
+
+    /// The matrix multiplication with the MDS matrix. Implemented for the Rep3 MPC protocol.
+    pub fn matmul_rep3(&self, input: &mut [Rep3PrimeFieldShare<F>; T]) {
+        let res = std::array::from_fn(|i| {
+            let mut acc = Rep3PrimeFieldShare::default();
+            for (m, s) in self.params.mds[i].iter().zip(input.iter()) {
+                acc += arithmetic::mul_public(*s, *m);
+            }
+            acc
+        });
+        *input = res;
+    }
+
+    /// One round of the Poseidon permuation for multiple states in parallel using Poseidon2Precomputations. Implemented for the Rep3 MPC protocol.
+    fn rep3_round_precomp_packed<N: Rep3Network>(
+        &self,
+        state: &mut [Rep3PrimeFieldShare<F>],
+        r: usize,
+        precomp: &mut Poseidon2Precomputations<Rep3PrimeFieldShare<F>>,
+        driver: &mut IoContext<N>,
+    ) -> std::io::Result<()> {
+        debug_assert_eq!(state.len() % T, 0);
+        let id = driver.network.get_id();
+        for s in state.chunks_exact_mut(T) {
+            for (s, rc) in s.iter_mut().zip(self.params.round_constants[r].iter()) {
+                arithmetic::add_assign_public(s, *rc, id);
+            }
+        }
+
+        if self.is_full_round(r) {
+            sbox_rep3_precomp(state, driver, precomp)?;
+        } else {
+            let mut vec = state.iter().cloned().step_by(T).collect::<Vec<_>>();
+            sbox_rep3_precomp(&mut vec, driver, precomp)?;
+            for (s, y) in state.iter_mut().step_by(T).zip(vec) {
+                *s = y;
+            }
+        }
+
+        for s in state.chunks_exact_mut(T) {
+            self.matmul_rep3(s.try_into().unwrap());
+        }
+        Ok(())
+    }
+
+    /// Computes multiple Poseidon permuations in parallel using the Rep3 MPC protocol while overwriting the input. Thereby, a preprocessing technique is used to reduce the depth of the computation.
+    pub fn rep3_permutation_in_place_with_precomputation_packed<N: Rep3Network>(
+        &self,
+        state: &mut [Rep3PrimeFieldShare<F>],
+        precomp: &mut Poseidon2Precomputations<Rep3PrimeFieldShare<F>>,
+        driver: &mut IoContext<N>,
+    ) -> std::io::Result<()> {
+        assert_eq!(D, 5);
+        assert_eq!(state.len() % T, 0);
+
+        let num_poseidon = state.len() / T;
+        let offset = precomp.offset;
+
+        for r in 0..self.num_rounds() {
+            self.rep3_round_precomp_packed(state, r, precomp, driver)?;
+        }
+
+        debug_assert_eq!(precomp.offset - offset, self.num_sbox() * num_poseidon);
+        Ok(())
+    }
+
+    /// Computes the Poseidon permuation using the Rep3 MPC protocol while overwriting the input. Thereby, a preprocessing technique is used to reduce the depth of the computation.
+    pub fn rep3_permutation_in_place_with_precomputation<N: Rep3Network>(
+        &self,
+        state: &mut [Rep3PrimeFieldShare<F>; T],
+        precomp: &mut Poseidon2Precomputations<Rep3PrimeFieldShare<F>>,
+        driver: &mut IoContext<N>,
+    ) -> std::io::Result<()> {
+        self.rep3_permutation_in_place_with_precomputation_packed(state, precomp, driver)
+    }
+
+    /// Computes the Poseidon permuation using the Rep3 MPC protocol. Thereby, a preprocessing technique is used to reduce the depth of the computation.
+    pub fn rep3_permutation_with_precomputation<N: Rep3Network>(
+        &self,
+        state: &[Rep3PrimeFieldShare<F>; T],
+        precomp: &mut Poseidon2Precomputations<Rep3PrimeFieldShare<F>>,
+        driver: &mut IoContext<N>,
+    ) -> std::io::Result<[Rep3PrimeFieldShare<F>; T]> {
+        let mut state = state.to_owned();
+        self.rep3_permutation_in_place_with_precomputation(&mut state, precomp, driver)?;
+        Ok(state)
+    }
+
+    /// Computes multiple Poseidon permuations in paralllel using the Rep3 MPC protocol. Thereby, a preprocessing technique is used to reduce the depth of the computation.
+    pub fn rep3_permutation_with_precomputation_packed<N: Rep3Network>(
+        &self,
+        state: &[Rep3PrimeFieldShare<F>],
+        precomp: &mut Poseidon2Precomputations<Rep3PrimeFieldShare<F>>,
+        driver: &mut IoContext<N>,
+    ) -> std::io::Result<Vec<Rep3PrimeFieldShare<F>>> {
+        let mut state = state.to_owned();
+        self.rep3_permutation_in_place_with_precomputation_packed(&mut state, precomp, driver)?;
+        Ok(state)
+    }
+}
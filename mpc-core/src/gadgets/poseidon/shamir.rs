@@ -0,0 +1,118 @@
+use super::Poseidon;
+use crate::gadgets::poseidon2::{
+    Poseidon2Precomputations,
+    shamir::{precompute_sbox_shamir, sbox_shamir_precomp},
+};
+use crate::protocols::shamir::{ShamirPrimeFieldShare, ShamirProtocol, network::ShamirNetwork};
+use ark_ff::PrimeField;
+
+impl<F: PrimeField, const T: usize, const D: u64> Poseidon<F, T, D> {
+    /// Returns how much preprocessed randomness is required for num_poseidon Poseidon permutations. Thereby, it distinguishes between whether the depth-reducing preprocessing step is used or not.
+    pub fn rand_required(&self, num_poseidon: usize, precomputation: bool) -> usize {
+        assert_eq!(D, 5);
+        let num_sbox = self.num_sbox();
+        let mut mult_per_sbox = 3;
+
+        if precomputation {
+            mult_per_sbox += 2;
+        }
+
+        num_sbox * mult_per_sbox * num_poseidon
+    }
+
+    /// Create Poseidon2Precomputations for num_poseidon Poseidon permutations in the Shamir MPC protocol.
+    pub fn precompute_shamir<N: ShamirNetwork>(
+        &self,
+        num_poseidon: usize,
+        driver: &mut ShamirProtocol<F, N>,
+    ) -> std::io::Result<Poseidon2Precomputations<ShamirPrimeFieldShare<F>>> {
+        assert_eq!(D, 5);
+        precompute_sbox_shamir(self.num_sbox() * num_poseidon, driver)
+    }
+
+    /// One round of the Poseidon permuation for multiple states in parallel using Poseidon2Precomputations. Implemented for the Shamir MPC protocol.
+    fn shamir_round_precomp_inner_packed<N: ShamirNetwork>(
+        &self,
+        state: &mut [F],
+        r: usize,
+        precomp: &mut Poseidon2Precomputations<ShamirPrimeFieldShare<F>>,
+        driver: &mut ShamirProtocol<F, N>,
+    ) -> std::io::Result<()> {
+        debug_assert_eq!(state.len() % T, 0);
+        for s in state.chunks_exact_mut(T) {
+            self.add_rc(s.try_into().unwrap(), r);
+        }
+
+        if self.is_full_round(r) {
+            sbox_shamir_precomp(state, driver, precomp)?;
+        } else {
+            let mut vec = state.iter().cloned().step_by(T).collect::<Vec<_>>();
+            sbox_shamir_precomp(&mut vec, driver, precomp)?;
+            for (s, y) in state.iter_mut().step_by(T).zip(vec) {
+                *s = y;
+            }
+        }
+
+        for s in state.chunks_exact_mut(T) {
+            self.matmul(s.try_into().unwrap());
+        }
+        Ok(())
+    }
+
+    /// Computes multiple Poseidon permuations in parallel using the Shamir MPC protocol while overwriting the input. Thereby, a preprocessing technique is used to reduce the depth of the computation.
+    pub fn shamir_permutation_in_place_with_precomputation_packed<N: ShamirNetwork>(
+        &self,
+        state: &mut [ShamirPrimeFieldShare<F>],
+        precomp: &mut Poseidon2Precomputations<ShamirPrimeFieldShare<F>>,
+        driver: &mut ShamirProtocol<F, N>,
+    ) -> std::io::Result<()> {
+        assert_eq!(D, 5);
+        assert_eq!(state.len() % T, 0);
+
+        let num_poseidon = state.len() / T;
+        let offset = precomp.offset;
+
+        // All linear operations can be computed on the raw shares
+        let state = ShamirPrimeFieldShare::convert_mut(state);
+        for r in 0..self.num_rounds() {
+            self.shamir_round_precomp_inner_packed(state, r, precomp, driver)?;
+        }
+
+        debug_assert_eq!(precomp.offset - offset, self.num_sbox() * num_poseidon);
+        Ok(())
+    }
+
+    /// Computes the Poseidon permuation using the Shamir MPC protocol while overwriting the input. Thereby, a preprocessing technique is used to reduce the depth of the computation.
+    pub fn shamir_permutation_in_place_with_precomputation<N: ShamirNetwork>(
+        &self,
+        state: &mut [ShamirPrimeFieldShare<F>; T],
+        precomp: &mut Poseidon2Precomputations<ShamirPrimeFieldShare<F>>,
+        driver: &mut ShamirProtocol<F, N>,
+    ) -> std::io::Result<()> {
+        self.shamir_permutation_in_place_with_precomputation_packed(state, precomp, driver)
+    }
+
+    /// Computes the Poseidon permuation using the Shamir MPC protocol. Thereby, a preprocessing technique is used to reduce the depth of the computation.
+    pub fn shamir_permutation_with_precomputation<N: ShamirNetwork>(
+        &self,
+        state: &[ShamirPrimeFieldShare<F>; T],
+        precomp: &mut Poseidon2Precomputations<ShamirPrimeFieldShare<F>>,
+        driver: &mut ShamirProtocol<F, N>,
+    ) -> std::io::Result<[ShamirPrimeFieldShare<F>; T]> {
+        let mut state = state.to_owned();
+        self.shamir_permutation_in_place_with_precomputation(&mut state, precomp, driver)?;
+        Ok(state)
+    }
+
+    /// Computes multiple Poseidon permuations in parallel using the Shamir MPC protocol. Thereby, a preprocessing technique is used to reduce the depth of the computation.
+    pub fn shamir_permutation_with_precomputation_packed<N: ShamirNetwork>(
+        &self,
+        state: &[ShamirPrimeFieldShare<F>],
+        precomp: &mut Poseidon2Precomputations<ShamirPrimeFieldShare<F>>,
+        driver: &mut ShamirProtocol<F, N>,
+    ) -> std::io::Result<Vec<ShamirPrimeFieldShare<F>>> {
+        let mut state = state.to_owned();
+        self.shamir_permutation_in_place_with_precomputation_packed(&mut state, precomp, driver)?;
+        Ok(state)
+    }
+}
@@ -1,7 +1,10 @@
 //! Poseidon2
 //!
 //! This module contains implementations of the Poseidon2 permutation.
+//!
+//! Parameter sets are provided for BN254 with t=2, t=3 and t=4, and for BLS12-381 with t=2 and t=3. The larger statesizes supported by the permutation (t=8, 12, 16, 20, 24) require parameters loaded with [`Poseidon2Params::from_json`] or derived with [`Poseidon2Params::from_grain_lfsr`], since the diagonal of their internal matrix is not derived from the Grain LFSR and has to be provided. No hardcoded instances are shipped for them, since the reference diagonals are the result of a randomized search in the original parameter generation script.
 
+pub(crate) mod poseidon2_bls12_381;
 pub(crate) mod poseidon2_bn254_t2;
 pub(crate) mod poseidon2_bn254_t3;
 pub(crate) mod poseidon2_bn254_t4;
@@ -10,11 +13,14 @@ pub(crate) mod poseidon2_permutation;
 pub(crate) mod rep3;
 pub(crate) mod shamir;
 
+pub use poseidon2_bls12_381::{POSEIDON2_BLS12_381_T2_PARAMS, POSEIDON2_BLS12_381_T3_PARAMS};
+pub use poseidon2_bn254_t2::POSEIDON2_BN254_T2_PARAMS;
+pub use poseidon2_bn254_t3::POSEIDON2_BN254_T3_PARAMS;
 pub use poseidon2_bn254_t4::POSEIDON2_BN254_T4_PARAMS;
 pub use poseidon2_params::Poseidon2Params;
 pub use poseidon2_permutation::Poseidon2;
 
-/// A struct holding data required for preprocessing the Sbox of the Poseidon2 permutation.
+/// A struct holding data required for preprocessing the Sbox of the Poseidon2 permutation. The same preprocessing is also used for the original [Poseidon](crate::gadgets::poseidon::Poseidon) permutation.
 #[derive(Clone, Debug, Default)]
 pub struct Poseidon2Precomputations<F> {
     pub(crate) r: Vec<F>,
@@ -0,0 +1,32 @@
+/// Parameters are compatible with the original Poseidon2 parameter generation script found at:
+/// [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+///
+/// In contrast to the BN254 parameters, the round constants are not hardcoded but derived with the Grain LFSR on first use.
+use super::Poseidon2Params;
+use std::sync::{Arc, LazyLock};
+
+type Scalar = ark_bls12_381::Fr;
+
+const D: u64 = 5;
+const ROUNDS_F: usize = 8;
+const ROUNDS_P: usize = 56;
+
+/// The Poseidon2 parameters for the BLS12-381 curve with a internal state of size t=2.
+pub static POSEIDON2_BLS12_381_T2_PARAMS: LazyLock<Arc<Poseidon2Params<Scalar, 2, D>>> =
+    LazyLock::new(|| {
+        Arc::new(Poseidon2Params::from_grain_lfsr(
+            ROUNDS_F,
+            ROUNDS_P,
+            [Scalar::from(1u64), Scalar::from(2u64)],
+        ))
+    });
+
+/// The Poseidon2 parameters for the BLS12-381 curve with a internal state of size t=3.
+pub static POSEIDON2_BLS12_381_T3_PARAMS: LazyLock<Arc<Poseidon2Params<Scalar, 3, D>>> =
+    LazyLock::new(|| {
+        Arc::new(Poseidon2Params::from_grain_lfsr(
+            ROUNDS_F,
+            ROUNDS_P,
+            [Scalar::from(1u64), Scalar::from(1u64), Scalar::from(2u64)],
+        ))
+    });
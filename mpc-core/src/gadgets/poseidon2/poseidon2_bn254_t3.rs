@@ -1,7 +1,7 @@
 /// Parameters are compatible with the original Poseidon2 parameter generation script found at:
 /// [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
 use super::Poseidon2Params;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 type Scalar = ark_bn254::Fr;
 
@@ -366,13 +366,13 @@ pub(crate) static INTERNAL_RC: LazyLock<Vec<Scalar>> = LazyLock::new(|| {
 });
 
 /// The Poseidon2 parameters for the BN254 curve with a internal state of size t=3.
-pub static POSEIDON2_BN254_T3_PARAMS: LazyLock<Poseidon2Params<Scalar, T, D>> =
+pub static POSEIDON2_BN254_T3_PARAMS: LazyLock<Arc<Poseidon2Params<Scalar, T, D>>> =
     LazyLock::new(|| {
-        Poseidon2Params::new(
+        Arc::new(Poseidon2Params::new(
             ROUNDS_F,
             ROUNDS_P,
-            &MAT_DIAG_M_1,
-            &EXTERNAL_RC,
-            &INTERNAL_RC,
-        )
+            *MAT_DIAG_M_1,
+            EXTERNAL_RC.clone(),
+            INTERNAL_RC.clone(),
+        ))
     });
@@ -1,4 +1,8 @@
 use ark_ff::PrimeField;
+use eyre::{Context, bail};
+use serde::Deserialize;
+
+use crate::gadgets::grain_lfsr::GrainLfsr;
 
 /// A parameter set containing the parameters for the Poseidon2 permutation.
 #[derive(Clone, Debug)]
@@ -10,20 +14,50 @@ pub struct Poseidon2Params<F: PrimeField, const T: usize, const D: u64> {
     /// The amount of internal rounds.
     pub rounds_p: usize,
     /// The diagonal of t x t matrix of the internal permutation. Each element is taken minus 1 for more efficient implementations.
-    pub mat_internal_diag_m_1: &'static [F; T],
+    pub mat_internal_diag_m_1: [F; T],
     /// The round constants of the external rounds.
-    pub round_constants_external: &'static Vec<[F; T]>,
+    pub round_constants_external: Vec<[F; T]>,
     /// The round constants of the internal rounds.
-    pub round_constants_internal: &'static Vec<F>,
+    pub round_constants_internal: Vec<F>,
+}
+
+/// The JSON representation of a [`Poseidon2Params`]. All field elements are given as hexadecimal strings.
+#[derive(Deserialize)]
+struct Poseidon2ParamsJson {
+    rounds_f: usize,
+    rounds_p: usize,
+    mat_internal_diag_m_1: Vec<String>,
+    round_constants_external: Vec<Vec<String>>,
+    round_constants_internal: Vec<String>,
+}
+
+fn parse_field_elements<F: PrimeField>(elements: &[String]) -> eyre::Result<Vec<F>> {
+    elements
+        .iter()
+        .map(|el| {
+            crate::gadgets::field_from_hex_string(el)
+                .with_context(|| format!("invalid field element {el}"))
+        })
+        .collect()
+}
+
+fn parse_state<F: PrimeField, const T: usize>(elements: &[String]) -> eyre::Result<[F; T]> {
+    let state = parse_field_elements(elements)?;
+    let len = state.len();
+    match state.try_into() {
+        Ok(state) => Ok(state),
+        Err(_) => bail!("expected {T} field elements, but got {len}"),
+    }
 }
 
 impl<F: PrimeField, const T: usize, const D: u64> Poseidon2Params<F, T, D> {
-    pub(crate) fn new(
+    /// Creates a new parameter set. Panics if the statesize T, the degree D of the S-box, or the amount of round constants is not supported.
+    pub fn new(
         rounds_f: usize,
         rounds_p: usize,
-        mat_internal_diag_m_1: &'static [F; T],
-        round_constants_external: &'static Vec<[F; T]>,
-        round_constants_internal: &'static Vec<F>,
+        mat_internal_diag_m_1: [F; T],
+        round_constants_external: Vec<[F; T]>,
+        round_constants_internal: Vec<F>,
     ) -> Self {
         assert!(T == 2 || T == 3 || ((T <= 24) && (T % 4 == 0)));
         assert!(D % 2 == 1);
@@ -42,4 +76,93 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2Params<F, T, D> {
             round_constants_internal,
         }
     }
+
+    /// Creates a new parameter set where the round constants are derived with the Grain LFSR, as done by the original Poseidon2 parameter generation script found at:
+    /// [https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage](https://github.com/HorizenLabs/poseidon2/blob/main/poseidon2_rust_params.sage)
+    ///
+    /// Only the diagonal of the internal matrix (minus 1) has to be provided, since it is not derived from the LFSR.
+    pub fn from_grain_lfsr(
+        rounds_f: usize,
+        rounds_p: usize,
+        mat_internal_diag_m_1: [F; T],
+    ) -> Self {
+        assert_eq!(D, 5, "Grain LFSR parameters are only supported for x^5");
+        assert_eq!(rounds_f % 2, 0);
+        let mut lfsr = GrainLfsr::new(F::MODULUS_BIT_SIZE as usize, T, rounds_f, rounds_p);
+        let mut round_constants_external = Vec::with_capacity(rounds_f);
+        for _ in 0..rounds_f / 2 {
+            round_constants_external
+                .push(std::array::from_fn(|_| lfsr.next_field_element_rejection()));
+        }
+        let round_constants_internal = (0..rounds_p)
+            .map(|_| lfsr.next_field_element_rejection())
+            .collect();
+        for _ in 0..rounds_f / 2 {
+            round_constants_external
+                .push(std::array::from_fn(|_| lfsr.next_field_element_rejection()));
+        }
+
+        Self::new(
+            rounds_f,
+            rounds_p,
+            mat_internal_diag_m_1,
+            round_constants_external,
+            round_constants_internal,
+        )
+    }
+
+    /// Loads a parameter set from a JSON string of the following form, where all field elements are hexadecimal strings (with or without the 0x prefix):
+    ///
+    /// ```json
+    /// {
+    ///   "rounds_f": 8,
+    ///   "rounds_p": 56,
+    ///   "mat_internal_diag_m_1": ["0x1", "0x1", "0x2"],
+    ///   "round_constants_external": [["0x..", "0x..", "0x.."], ...],
+    ///   "round_constants_internal": ["0x..", ...]
+    /// }
+    /// ```
+    pub fn from_json(json: &str) -> eyre::Result<Self> {
+        let params: Poseidon2ParamsJson =
+            serde_json::from_str(json).context("while parsing Poseidon2 parameters")?;
+        if !(T == 2 || T == 3 || ((T <= 24) && (T % 4 == 0))) {
+            bail!("unsupported statesize {T}");
+        }
+        if D % 2 == 0 {
+            bail!("unsupported S-box degree {D}");
+        }
+        if params.rounds_f % 2 != 0 {
+            bail!("the amount of external rounds must be even");
+        }
+        if params.round_constants_external.len() != params.rounds_f {
+            bail!(
+                "expected {} external round constants, but got {}",
+                params.rounds_f,
+                params.round_constants_external.len()
+            );
+        }
+        if params.round_constants_internal.len() != params.rounds_p {
+            bail!(
+                "expected {} internal round constants, but got {}",
+                params.rounds_p,
+                params.round_constants_internal.len()
+            );
+        }
+
+        let mat_internal_diag_m_1 = parse_state(&params.mat_internal_diag_m_1)?;
+        let round_constants_external = params
+            .round_constants_external
+            .iter()
+            .map(|rc| parse_state(rc))
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let round_constants_internal = parse_field_elements(&params.round_constants_internal)?;
+
+        Ok(Self::new(
+            params.rounds_f,
+            params.rounds_p,
+            mat_internal_diag_m_1,
+            round_constants_external,
+            round_constants_internal,
+        ))
+    }
 }
@@ -1,4 +1,4 @@
-use std::any::TypeId;
+use std::{any::TypeId, sync::Arc};
 
 use super::poseidon2_params::Poseidon2Params;
 use ark_ff::PrimeField;
@@ -7,12 +7,12 @@ use ark_ff::PrimeField;
 #[derive(Clone, Debug)]
 pub struct Poseidon2<F: PrimeField, const T: usize, const D: u64> {
     /// The parameter set containing the parameters for the Poseidon2 permutation.
-    pub params: &'static Poseidon2Params<F, T, D>,
+    pub params: Arc<Poseidon2Params<F, T, D>>,
 }
 
 impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
     /// Creates a new instance of the Poseidon2 permuation with given parameters
-    pub fn new(params: &'static Poseidon2Params<F, T, D>) -> Self {
+    pub fn new(params: Arc<Poseidon2Params<F, T, D>>) -> Self {
         Self { params }
     }
 
@@ -217,7 +217,7 @@ impl<F: PrimeField, const T: usize> Default for Poseidon2<F, T, 5> {
         if TypeId::of::<F>() == TypeId::of::<ark_bn254::Fr>() {
             match T {
                 2 => {
-                    let params = &super::poseidon2_bn254_t2::POSEIDON2_BN254_T2_PARAMS;
+                    let params = Arc::clone(&super::poseidon2_bn254_t2::POSEIDON2_BN254_T2_PARAMS);
                     let poseidon2 = Poseidon2::new(params);
                     // Safety: We checked that the types match
                     unsafe {
@@ -227,7 +227,7 @@ impl<F: PrimeField, const T: usize> Default for Poseidon2<F, T, 5> {
                     }
                 }
                 3 => {
-                    let params = &super::poseidon2_bn254_t3::POSEIDON2_BN254_T3_PARAMS;
+                    let params = Arc::clone(&super::poseidon2_bn254_t3::POSEIDON2_BN254_T3_PARAMS);
                     let poseidon2 = Poseidon2::new(params);
                     // Safety: We checked that the types match
                     unsafe {
@@ -237,7 +237,7 @@ impl<F: PrimeField, const T: usize> Default for Poseidon2<F, T, 5> {
                     }
                 }
                 4 => {
-                    let params = &super::poseidon2_bn254_t4::POSEIDON2_BN254_T4_PARAMS;
+                    let params = Arc::clone(&super::poseidon2_bn254_t4::POSEIDON2_BN254_T4_PARAMS);
                     let poseidon2 = Poseidon2::new(params);
                     // Safety: We checked that the types match
                     unsafe {
@@ -248,6 +248,32 @@ impl<F: PrimeField, const T: usize> Default for Poseidon2<F, T, 5> {
                 }
                 _ => panic!("No Poseidon2 implementation for T={T}"),
             }
+        } else if TypeId::of::<F>() == TypeId::of::<ark_bls12_381::Fr>() {
+            match T {
+                2 => {
+                    let params =
+                        Arc::clone(&super::poseidon2_bls12_381::POSEIDON2_BLS12_381_T2_PARAMS);
+                    let poseidon2 = Poseidon2::new(params);
+                    // Safety: We checked that the types match
+                    unsafe {
+                        std::mem::transmute::<Poseidon2<ark_bls12_381::Fr, 2, 5>, Poseidon2<F, T, 5>>(
+                            poseidon2,
+                        )
+                    }
+                }
+                3 => {
+                    let params =
+                        Arc::clone(&super::poseidon2_bls12_381::POSEIDON2_BLS12_381_T3_PARAMS);
+                    let poseidon2 = Poseidon2::new(params);
+                    // Safety: We checked that the types match
+                    unsafe {
+                        std::mem::transmute::<Poseidon2<ark_bls12_381::Fr, 3, 5>, Poseidon2<F, T, 5>>(
+                            poseidon2,
+                        )
+                    }
+                }
+                _ => panic!("No Poseidon2 implementation for T={T}"),
+            }
         } else {
             panic!("No Poseidon2 implementation for this field");
         }
@@ -258,6 +284,7 @@ impl<F: PrimeField, const T: usize> Default for Poseidon2<F, T, 5> {
 mod test {
     use super::*;
     use crate::gadgets::poseidon2::{
+        poseidon2_bls12_381::{POSEIDON2_BLS12_381_T2_PARAMS, POSEIDON2_BLS12_381_T3_PARAMS},
         poseidon2_bn254_t2::POSEIDON2_BN254_T2_PARAMS,
         poseidon2_bn254_t3::POSEIDON2_BN254_T3_PARAMS,
         poseidon2_bn254_t4::POSEIDON2_BN254_T4_PARAMS,
@@ -267,24 +294,24 @@ mod test {
     const TESTRUNS: usize = 10;
 
     fn poseidon2_kat<F: PrimeField, const T: usize, const D: u64>(
-        params: &'static Poseidon2Params<F, T, D>,
+        params: &Arc<Poseidon2Params<F, T, D>>,
         input: &[F; T],
         expected: &[F; T],
     ) {
-        let poseidon2 = Poseidon2::new(params);
+        let poseidon2 = Poseidon2::new(Arc::clone(params));
         let result = poseidon2.permutation(input);
         assert_eq!(&result, expected);
     }
 
     fn poseidon2_consistent_perm<F: PrimeField, const T: usize, const D: u64>(
-        params: &'static Poseidon2Params<F, T, D>,
+        params: &Arc<Poseidon2Params<F, T, D>>,
     ) {
         let mut rng = &mut thread_rng();
         let input1: Vec<F> = (0..T).map(|_| F::rand(&mut rng)).collect();
         let mut input2 = input1.clone();
         input2.rotate_right(T / 2);
 
-        let poseidon2 = Poseidon2::new(params);
+        let poseidon2 = Poseidon2::new(Arc::clone(params));
         let perm1 = poseidon2.permutation(input1.as_slice().try_into().unwrap());
         let perm2 = poseidon2.permutation(&input1.try_into().unwrap());
         let perm3 = poseidon2.permutation(&input2.try_into().unwrap());
@@ -300,6 +327,149 @@ mod test {
         }
     }
 
+    #[test]
+    fn posedon2_bls12_381_consistent_perm() {
+        for _ in 0..TESTRUNS {
+            poseidon2_consistent_perm(&POSEIDON2_BLS12_381_T2_PARAMS);
+            poseidon2_consistent_perm(&POSEIDON2_BLS12_381_T3_PARAMS);
+        }
+    }
+
+    fn poseidon2_kat_from_hex<F: PrimeField, const T: usize, const D: u64>(
+        params: &Arc<Poseidon2Params<F, T, D>>,
+        expected: [&str; T],
+    ) {
+        let input = std::array::from_fn(|i| F::from(i as u64));
+        let expected = expected.map(|el| crate::gadgets::field_from_hex_string(el).unwrap());
+        poseidon2_kat(params, &input, &expected);
+    }
+
+    // The expected values are the test vectors of the reference implementation found at:
+    // [https://github.com/HorizenLabs/poseidon2](https://github.com/HorizenLabs/poseidon2)
+    #[test]
+    fn posedon2_bls12_381_kat() {
+        poseidon2_kat_from_hex(
+            &POSEIDON2_BLS12_381_T2_PARAMS,
+            [
+                "0x73c46dd530e248a87b61d19e67fa1b4ed30fc3d09f16531fe189fb945a15ce4e",
+                "0x1f0e305ee21c9366d5793b80251405032a3fee32b9dd0b5f4578262891b043b4",
+            ],
+        );
+        poseidon2_kat_from_hex(
+            &POSEIDON2_BLS12_381_T3_PARAMS,
+            [
+                "0x1b152349b1950b6a8ca75ee4407b6e26ca5cca5650534e56ef3fd45761fbf5f0",
+                "0x4c5793c87d51bdc2c08a32108437dc0000bd0275868f09ebc5f36919af5b3891",
+                "0x1fc8ed171e67902ca49863159fe5ba6325318843d13976143b8125f08b50dc6b",
+            ],
+        );
+    }
+
+    // There are no reference parameters for larger statesizes, thus the internal matrix is chosen arbitrarily to test the generic linear layers. The expected values were computed with an independent implementation of the permutation.
+    #[test]
+    fn posedon2_bn254_large_statesize_kat() {
+        let params_t8 = Arc::new(Poseidon2Params::<ark_bn254::Fr, 8, 5>::from_grain_lfsr(
+            8,
+            56,
+            std::array::from_fn(|i| (i as u64 + 1).into()),
+        ));
+        let params_t16 = Arc::new(Poseidon2Params::<ark_bn254::Fr, 16, 5>::from_grain_lfsr(
+            8,
+            56,
+            std::array::from_fn(|i| (i as u64 + 1).into()),
+        ));
+        poseidon2_kat_from_hex(
+            &params_t8,
+            [
+                "0x2feb7cdaa24e6d50ffa77719083f5e7b9e78baafd595a9f04c75fe1511a910da",
+                "0x05f57777efb5b159d9489f575484071ccbbd5e07ae90b839ddb0a23a23e0b5bc",
+                "0x1834e5f971203ef03a3b67b246905aec5fa7f20d51e6339f15e119938d159cc5",
+                "0x030b783103c4f234dddb0fa2c42fb51e9bcb736331db97fefdf043f60701df62",
+                "0x213b521c717efe6b8e72afea0f2efa89f2fe2fab12f39b646c7cc1d611dad114",
+                "0x08da68ccc6173999967abb438c2f8f984d96673e3bb16e65e33ed273ccefbab6",
+                "0x23ec8df531fd5fab4dcd4d11abe25def9ed25a2d040fb0cede49ea101e7e500c",
+                "0x20a50cdabdbe57abced258a69824eae33bdd2a7d2bec18b7c585abaf441e3ef0",
+            ],
+        );
+        poseidon2_kat_from_hex(
+            &params_t16,
+            [
+                "0x00316c445dbb0a0c5503b567afc17c391f0da4b10e23e2869e2a55d3e7db1444",
+                "0x2d1d7874ddff3775bf0ce514ae981a392edc71fdebc40a0e64a85beedcc29e30",
+                "0x2192b2f79f1a8f1d32576254db640d52c33cd3e394dee911cbd553108310f169",
+                "0x23932f47b53e5f8dd042279a3bf3fb224e11621a43ede03a5196b683506764d5",
+                "0x1a4b039063bb1ef5aca0522b84beda95bb790664829aaff3d25ae4c017d37190",
+                "0x029df2535a1dd84858e2ced34e8463a04ac08d841291ebb91b50681be11a45c3",
+                "0x2372dd3a7b3dc7de67df4efad9abed6a6cbdc5252e903863963fdb86110ee023",
+                "0x2c24e075b3d49732f5d76d6bf9d9669b8e3e04094b864f947f383a86b369798c",
+                "0x1dadeff657e56c5c63bacd8255e74d1e52d778e680e996ea50661746ba37b455",
+                "0x2b0513bf5a42bfb2dcb70cd3a7da3d5f68da4f5f02d5e13151765e9ddbe2e7fa",
+                "0x2a36ad7b6f84670323579c883ed497c08e763b3d5815fa4368e576e5a4851053",
+                "0x2a66888428a3a2d92bdaeaac42e22997f1c55a6d8cc3e676ac22b9b1d88a2438",
+                "0x2157dcb385f11db270a6923a86bf6b042486a25fceceb00b47518f1b50737a9f",
+                "0x16c3cd9e562536df0616a339bc1f29fd72b72c629e023837941d2d6eaa810f49",
+                "0x0aed1c52e7882d7a20a27da10c7b9d2aaac39cd0bb5e745e2b777c331c02b45b",
+                "0x20f82bb7e4bc188a5facc295fd621d2287b2d8b010347dcf5f1b2776e9408969",
+            ],
+        );
+    }
+
+    #[test]
+    fn posedon2_bn254_t3_grain_lfsr() {
+        let params = Poseidon2Params::<ark_bn254::Fr, 3, 5>::from_grain_lfsr(
+            8,
+            56,
+            POSEIDON2_BN254_T3_PARAMS.mat_internal_diag_m_1,
+        );
+        assert_eq!(
+            params.round_constants_external,
+            POSEIDON2_BN254_T3_PARAMS.round_constants_external
+        );
+        assert_eq!(
+            params.round_constants_internal,
+            POSEIDON2_BN254_T3_PARAMS.round_constants_internal
+        );
+    }
+
+    #[test]
+    fn posedon2_bn254_t2_from_json() {
+        let to_hex = |f: &ark_bn254::Fr| {
+            let f: num_bigint::BigUint = (*f).into();
+            format!("\"{f:#x}\"")
+        };
+        let join = |v: &[ark_bn254::Fr]| v.iter().map(to_hex).collect::<Vec<_>>().join(",");
+        let params = &POSEIDON2_BN254_T2_PARAMS;
+        let json = format!(
+            r#"{{"rounds_f":{},"rounds_p":{},"mat_internal_diag_m_1":[{}],"round_constants_external":[{}],"round_constants_internal":[{}]}}"#,
+            params.rounds_f_beginning + params.rounds_f_end,
+            params.rounds_p,
+            join(&params.mat_internal_diag_m_1),
+            params
+                .round_constants_external
+                .iter()
+                .map(|rc| format!("[{}]", join(rc)))
+                .collect::<Vec<_>>()
+                .join(","),
+            join(&params.round_constants_internal),
+        );
+        let loaded = Poseidon2Params::<ark_bn254::Fr, 2, 5>::from_json(&json).unwrap();
+        assert_eq!(loaded.mat_internal_diag_m_1, params.mat_internal_diag_m_1);
+        assert_eq!(
+            loaded.round_constants_external,
+            params.round_constants_external
+        );
+        assert_eq!(
+            loaded.round_constants_internal,
+            params.round_constants_internal
+        );
+        let input = [ark_bn254::Fr::from(0u64), ark_bn254::Fr::from(1u64)];
+        assert_eq!(
+            Poseidon2::new(Arc::new(loaded)).permutation(&input),
+            Poseidon2::new(Arc::clone(params)).permutation(&input)
+        );
+        assert!(Poseidon2Params::<ark_bn254::Fr, 3, 5>::from_json(&json).is_err());
+    }
+
     #[test]
     fn posedon2_bn254_t2_kat1() {
         let input = [ark_bn254::Fr::from(0u64), ark_bn254::Fr::from(1u64)];
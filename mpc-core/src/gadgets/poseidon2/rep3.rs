@@ -16,37 +16,7 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
     ) -> std::io::Result<Poseidon2Precomputations<Rep3PrimeFieldShare<F>>> {
         assert_eq!(D, 5);
 
-        let num_sbox = self.num_sbox() * num_poseidon;
-
-        let mut r = Vec::with_capacity(num_sbox);
-        for _ in 0..num_sbox {
-            r.push(arithmetic::rand(driver));
-        }
-        let r2 = arithmetic::mul_vec(&r, &r, driver)?;
-        let r4 = arithmetic::mul_vec(&r2, &r2, driver)?;
-
-        let mut lhs = Vec::with_capacity(num_sbox * 2);
-        let mut rhs = Vec::with_capacity(num_sbox * 2);
-        for (r, r2) in r.iter().cloned().zip(r2.iter().cloned()) {
-            lhs.push(r);
-            rhs.push(r2);
-        }
-        for (r, r4) in r.iter().cloned().zip(r4.iter().cloned()) {
-            lhs.push(r);
-            rhs.push(r4);
-        }
-
-        let mut r3 = arithmetic::mul_vec(&lhs, &rhs, driver)?;
-        let r5 = r3.split_off(num_sbox);
-
-        Ok(Poseidon2Precomputations {
-            r,
-            r2,
-            r3,
-            r4,
-            r5,
-            offset: 0,
-        })
+        precompute_sbox_rep3(self.num_sbox() * num_poseidon, driver)
     }
 
     /// Create Poseidon2Precomputations for the Rep3 MPC protocol, but only save the additive shares.
@@ -213,31 +183,6 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         Ok(shares)
     }
 
-    fn sbox_rep3_precomp<N: Rep3Network>(
-        input: &mut [Rep3PrimeFieldShare<F>],
-        driver: &mut IoContext<N>,
-        precomp: &mut Poseidon2Precomputations<Rep3PrimeFieldShare<F>>,
-    ) -> std::io::Result<()> {
-        assert_eq!(D, 5);
-        for (i, inp) in input.iter_mut().enumerate() {
-            *inp -= precomp.r[precomp.offset + i];
-        }
-
-        // Open
-        let y = arithmetic::open_vec(input, driver)?;
-        let id = driver.network.get_id();
-
-        for (i, (inp, y)) in input.iter_mut().zip(y).enumerate() {
-            let (r, r2, r3, r4, r5) = precomp.get(precomp.offset + i);
-
-            *inp = Self::sbox_rep3_precomp_post(&y, r, r2, r3, r4, r5, &id);
-        }
-
-        precomp.offset += input.len();
-
-        Ok(())
-    }
-
     fn sbox_rep3_precomp_additive<N: Rep3Network>(
         input: &mut [F],
         driver: &mut IoContext<N>,
@@ -289,7 +234,7 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         // Open
         let y = arithmetic::open(*input, driver)?;
 
-        *input = Self::sbox_rep3_precomp_post(&y, r, r2, r3, r4, r5, &driver.network.get_id());
+        *input = sbox_rep3_precomp_post(&y, r, r2, r3, r4, r5, &driver.network.get_id());
         precomp.offset += 1;
 
         Ok(())
@@ -302,7 +247,7 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
     ) -> std::io::Result<()> {
         debug_assert_eq!(input.len() % T, 0);
         let mut vec = input.iter().cloned().step_by(T).collect::<Vec<_>>();
-        Self::sbox_rep3_precomp(&mut vec, driver, precomp)?;
+        sbox_rep3_precomp(&mut vec, driver, precomp)?;
 
         for (inp, r) in input.iter_mut().step_by(T).zip(vec) {
             *inp = r;
@@ -364,38 +309,6 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         Ok(())
     }
 
-    fn sbox_rep3_precomp_post(
-        y: &F,
-        r: &Rep3PrimeFieldShare<F>,
-        r2: &Rep3PrimeFieldShare<F>,
-        r3: &Rep3PrimeFieldShare<F>,
-        r4: &Rep3PrimeFieldShare<F>,
-        r5: &Rep3PrimeFieldShare<F>,
-        id: &PartyID,
-    ) -> Rep3PrimeFieldShare<F> {
-        assert_eq!(D, 5);
-        let y2 = y.square();
-        let y3 = y2 * y;
-        let y4 = y2.square();
-        let five = F::from(5u64);
-        let ten = F::from(10u64);
-
-        let mut res = *r5;
-        res += r4 * (five * y);
-        res += r3 * (ten * y2);
-        res += r2 * (ten * y3);
-        res += r * (five * y4);
-
-        if id == &PartyID::ID0 {
-            let y5 = y4 * y;
-            res.a += y5;
-        } else if id == &PartyID::ID1 {
-            let y5 = y4 * y;
-            res.b += y5;
-        }
-        res
-    }
-
     fn sbox_rep3_precomp_post_additive(
         y: &F,
         r: &F,
@@ -492,7 +405,7 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         driver: &mut IoContext<N>,
     ) -> std::io::Result<()> {
         self.add_rc_external_rep3(state, r, driver);
-        Self::sbox_rep3_precomp(state, driver, precomp)?;
+        sbox_rep3_precomp(state, driver, precomp)?;
         Self::matmul_external_rep3(state);
         Ok(())
     }
@@ -543,7 +456,7 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
                 }
             }
         }
-        Self::sbox_rep3_precomp(state, driver, precomp)?;
+        sbox_rep3_precomp(state, driver, precomp)?;
         for s in state.chunks_exact_mut(T) {
             Self::matmul_external_rep3(s.try_into().unwrap());
         }
@@ -803,3 +716,95 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         Ok(state)
     }
 }
+
+/// Creates the precomputations for `num_sbox` x^5 S-boxes for the Rep3 MPC protocol, i.e., random shares r and shares of r^2, r^3, r^4, and r^5.
+pub(crate) fn precompute_sbox_rep3<F: PrimeField, N: Rep3Network>(
+    num_sbox: usize,
+    driver: &mut IoContext<N>,
+) -> std::io::Result<Poseidon2Precomputations<Rep3PrimeFieldShare<F>>> {
+    let mut r = Vec::with_capacity(num_sbox);
+    for _ in 0..num_sbox {
+        r.push(arithmetic::rand(driver));
+    }
+    let r2 = arithmetic::mul_vec(&r, &r, driver)?;
+    let r4 = arithmetic::mul_vec(&r2, &r2, driver)?;
+
+    let mut lhs = Vec::with_capacity(num_sbox * 2);
+    let mut rhs = Vec::with_capacity(num_sbox * 2);
+    for (r, r2) in r.iter().cloned().zip(r2.iter().cloned()) {
+        lhs.push(r);
+        rhs.push(r2);
+    }
+    for (r, r4) in r.iter().cloned().zip(r4.iter().cloned()) {
+        lhs.push(r);
+        rhs.push(r4);
+    }
+
+    let mut r3 = arithmetic::mul_vec(&lhs, &rhs, driver)?;
+    let r5 = r3.split_off(num_sbox);
+
+    Ok(Poseidon2Precomputations {
+        r,
+        r2,
+        r3,
+        r4,
+        r5,
+        offset: 0,
+    })
+}
+
+/// Computes the x^5 S-box on all elements of `input` with one round of communication by consuming the next `input.len()` precomputations.
+pub(crate) fn sbox_rep3_precomp<F: PrimeField, N: Rep3Network>(
+    input: &mut [Rep3PrimeFieldShare<F>],
+    driver: &mut IoContext<N>,
+    precomp: &mut Poseidon2Precomputations<Rep3PrimeFieldShare<F>>,
+) -> std::io::Result<()> {
+    for (i, inp) in input.iter_mut().enumerate() {
+        *inp -= precomp.r[precomp.offset + i];
+    }
+
+    // Open
+    let y = arithmetic::open_vec(input, driver)?;
+    let id = driver.network.get_id();
+
+    for (i, (inp, y)) in input.iter_mut().zip(y).enumerate() {
+        let (r, r2, r3, r4, r5) = precomp.get(precomp.offset + i);
+
+        *inp = sbox_rep3_precomp_post(&y, r, r2, r3, r4, r5, &id);
+    }
+
+    precomp.offset += input.len();
+
+    Ok(())
+}
+
+fn sbox_rep3_precomp_post<F: PrimeField>(
+    y: &F,
+    r: &Rep3PrimeFieldShare<F>,
+    r2: &Rep3PrimeFieldShare<F>,
+    r3: &Rep3PrimeFieldShare<F>,
+    r4: &Rep3PrimeFieldShare<F>,
+    r5: &Rep3PrimeFieldShare<F>,
+    id: &PartyID,
+) -> Rep3PrimeFieldShare<F> {
+    let y2 = y.square();
+    let y3 = y2 * y;
+    let y4 = y2.square();
+    let five = F::from(5u64);
+    let ten = F::from(10u64);
+
+    let mut res = *r5;
+    res += r4 * (five * y);
+    res += r3 * (ten * y2);
+    res += r2 * (ten * y3);
+    res += r * (five * y4);
+
+    if id == &PartyID::ID0 {
+        let y5 = y4 * y;
+        res.a += y5;
+    } else if id == &PartyID::ID1 {
+        let y5 = y4 * y;
+        res.b += y5;
+    }
+    res
+}
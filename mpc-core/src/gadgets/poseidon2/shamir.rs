@@ -37,60 +37,8 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         driver: &mut ShamirProtocol<F, N>,
     ) -> std::io::Result<Poseidon2Precomputations<ShamirPrimeFieldShare<F>>> {
         assert_eq!(D, 5);
-        let num_sbox = self.num_sbox() * num_poseidon;
 
-        let mut r = Vec::with_capacity(num_sbox);
-        for _ in 0..num_sbox {
-            r.push(driver.rand()?);
-        }
-        let r2 = arithmetic::mul_vec(&r, &r, driver)?;
-        let r4 = arithmetic::mul_vec(&r2, &r2, driver)?;
-
-        let mut lhs = Vec::with_capacity(num_sbox * 2);
-        let mut rhs = Vec::with_capacity(num_sbox * 2);
-        for (r, r2) in r.iter().cloned().zip(r2.iter().cloned()) {
-            lhs.push(r);
-            rhs.push(r2);
-        }
-        for (r, r4) in r.iter().cloned().zip(r4.iter().cloned()) {
-            lhs.push(r);
-            rhs.push(r4);
-        }
-
-        let mut r3 = arithmetic::mul_vec(&lhs, &rhs, driver)?;
-        let r5 = r3.split_off(num_sbox);
-
-        Ok(Poseidon2Precomputations {
-            r,
-            r2,
-            r3,
-            r4,
-            r5,
-            offset: 0,
-        })
-    }
-
-    fn sbox_shamir_precomp<N: ShamirNetwork>(
-        input: &mut [F],
-        driver: &mut ShamirProtocol<F, N>,
-        precomp: &mut Poseidon2Precomputations<ShamirPrimeFieldShare<F>>,
-    ) -> std::io::Result<()> {
-        assert_eq!(D, 5);
-        for (i, inp) in input.iter_mut().enumerate() {
-            *inp -= &precomp.r[precomp.offset + i].a;
-        }
-
-        let y = arithmetic::open_vec(ShamirPrimeFieldShare::convert_slice_rev(&*input), driver)?;
-
-        for (i, (inp, y)) in input.iter_mut().zip(y).enumerate() {
-            let (r, r2, r3, r4, r5) = precomp.get(precomp.offset + i);
-
-            *inp = Self::sbox_shamir_precomp_post(&y, &r.a, &r2.a, &r3.a, &r4.a, &r5.a);
-        }
-
-        precomp.offset += input.len();
-
-        Ok(())
+        precompute_sbox_shamir(self.num_sbox() * num_poseidon, driver)
     }
 
     fn single_sbox_shamir_precomp<N: ShamirNetwork>(
@@ -103,7 +51,7 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
 
         *input -= &r.a;
         let y = arithmetic::open(ShamirPrimeFieldShare::new(*input), driver)?;
-        *input = Self::sbox_shamir_precomp_post(&y, &r.a, &r2.a, &r3.a, &r4.a, &r5.a);
+        *input = sbox_shamir_precomp_post(&y, &r.a, &r2.a, &r3.a, &r4.a, &r5.a);
         precomp.offset += 1;
 
         Ok(())
@@ -116,7 +64,7 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
     ) -> std::io::Result<()> {
         debug_assert_eq!(input.len() % T, 0);
         let mut vec = input.iter().cloned().step_by(T).collect::<Vec<_>>();
-        Self::sbox_shamir_precomp(&mut vec, driver, precomp)?;
+        sbox_shamir_precomp(&mut vec, driver, precomp)?;
 
         for (inp, r) in input.iter_mut().step_by(T).zip(vec) {
             *inp = r;
@@ -125,24 +73,6 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         Ok(())
     }
 
-    fn sbox_shamir_precomp_post(y: &F, r: &F, r2: &F, r3: &F, r4: &F, r5: &F) -> F {
-        assert_eq!(D, 5);
-        let y2 = y.square();
-        let y3 = y2 * y;
-        let y4 = y2.square();
-        let y5 = y4 * y;
-        let five = F::from(5u64);
-        let ten = F::from(10u64);
-
-        let mut res = y5;
-        res += y4 * r * five;
-        res += y3 * r2 * ten;
-        res += y2 * r3 * ten;
-        res += *y * r4 * five;
-        res += r5;
-        res
-    }
-
     fn sbox_shamir<N: ShamirNetwork>(
         input: &mut [F; T],
         driver: &mut ShamirProtocol<F, N>,
@@ -207,7 +137,7 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         driver: &mut ShamirProtocol<F, N>,
     ) -> std::io::Result<()> {
         self.add_rc_external(state, r);
-        Self::sbox_shamir_precomp(state, driver, precomp)?;
+        sbox_shamir_precomp(state, driver, precomp)?;
         Self::matmul_external(state);
         Ok(())
     }
@@ -251,7 +181,7 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         for s in state.chunks_exact_mut(T) {
             self.add_rc_external(s.try_into().unwrap(), r);
         }
-        Self::sbox_shamir_precomp(state, driver, precomp)?;
+        sbox_shamir_precomp(state, driver, precomp)?;
         for s in state.chunks_exact_mut(T) {
             Self::matmul_external(s.try_into().unwrap());
         }
@@ -441,3 +371,79 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         Ok(state)
     }
 }
+
+/// Creates the precomputations for `num_sbox` x^5 S-boxes for the Shamir MPC protocol, i.e., random shares r and shares of r^2, r^3, r^4, and r^5.
+pub(crate) fn precompute_sbox_shamir<F: PrimeField, N: ShamirNetwork>(
+    num_sbox: usize,
+    driver: &mut ShamirProtocol<F, N>,
+) -> std::io::Result<Poseidon2Precomputations<ShamirPrimeFieldShare<F>>> {
+    let mut r = Vec::with_capacity(num_sbox);
+    for _ in 0..num_sbox {
+        r.push(driver.rand()?);
+    }
+    let r2 = arithmetic::mul_vec(&r, &r, driver)?;
+    let r4 = arithmetic::mul_vec(&r2, &r2, driver)?;
+
+    let mut lhs = Vec::with_capacity(num_sbox * 2);
+    let mut rhs = Vec::with_capacity(num_sbox * 2);
+    for (r, r2) in r.iter().cloned().zip(r2.iter().cloned()) {
+        lhs.push(r);
+        rhs.push(r2);
+    }
+    for (r, r4) in r.iter().cloned().zip(r4.iter().cloned()) {
+        lhs.push(r);
+        rhs.push(r4);
+    }
+
+    let mut r3 = arithmetic::mul_vec(&lhs, &rhs, driver)?;
+    let r5 = r3.split_off(num_sbox);
+
+    Ok(Poseidon2Precomputations {
+        r,
+        r2,
+        r3,
+        r4,
+        r5,
+        offset: 0,
+    })
+}
+
+/// Computes the x^5 S-box on all elements of `input` with one round of communication by consuming the next `input.len()` precomputations.
+pub(crate) fn sbox_shamir_precomp<F: PrimeField, N: ShamirNetwork>(
+    input: &mut [F],
+    driver: &mut ShamirProtocol<F, N>,
+    precomp: &mut Poseidon2Precomputations<ShamirPrimeFieldShare<F>>,
+) -> std::io::Result<()> {
+    for (i, inp) in input.iter_mut().enumerate() {
+        *inp -= &precomp.r[precomp.offset + i].a;
+    }
+
+    let y = arithmetic::open_vec(ShamirPrimeFieldShare::convert_slice_rev(&*input), driver)?;
+
+    for (i, (inp, y)) in input.iter_mut().zip(y).enumerate() {
+        let (r, r2, r3, r4, r5) = precomp.get(precomp.offset + i);
+
+        *inp = sbox_shamir_precomp_post(&y, &r.a, &r2.a, &r3.a, &r4.a, &r5.a);
+    }
+
+    precomp.offset += input.len();
+
+    Ok(())
+}
+
+fn sbox_shamir_precomp_post<F: PrimeField>(y: &F, r: &F, r2: &F, r3: &F, r4: &F, r5: &F) -> F {
+    let y2 = y.square();
+    let y3 = y2 * y;
+    let y4 = y2.square();
+    let y5 = y4 * y;
+    let five = F::from(5u64);
+    let ten = F::from(10u64);
+
+    let mut res = y5;
+    res += y4 * r * five;
+    res += y3 * r2 * ten;
+    res += y2 * r3 * ten;
+    res += *y * r4 * five;
+    res += r5;
+    res
+}
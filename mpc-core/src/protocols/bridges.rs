@@ -1,6 +1,8 @@
 //! # MPC Bridges
 //!
-//! This module implements bridges between multiple MPC protocols. Currently, one can switch from Rep3 to a 3-party Shamir secret sharing protocol.
+//! This module implements bridges between multiple MPC protocols. Currently, one can switch from Rep3 to a 3-party Shamir secret sharing protocol (with threshold t = 1) and back. Furthermore, Shamir secret sharing with more than three parties can run Rep3 subprotocols on a 3-party committee.
 
 pub mod network;
 mod rep3_to_shamir;
+mod shamir_committee;
+mod shamir_to_rep3;
@@ -4,8 +4,14 @@
 
 use std::collections::HashMap;
 
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use async_trait::async_trait;
+
 use crate::protocols::{
-    rep3::network::{Rep3MpcNet, Rep3Network},
+    rep3::{
+        PartyID,
+        network::{Rep3MpcNet, Rep3Network},
+    },
     shamir::network::{ShamirMpcNet, ShamirNetwork},
 };
 
@@ -39,3 +45,154 @@ impl RepToShamirNetwork<ShamirMpcNet> for Rep3MpcNet {
         }
     }
 }
+
+// Returns the Rep3 id of the Shamir party `id` if it is a member of the committee
+pub(crate) fn committee_id(
+    committee: &[usize; 3],
+    num_parties: usize,
+    id: usize,
+) -> std::io::Result<Option<PartyID>> {
+    if committee.iter().any(|member| *member >= num_parties)
+        || committee[0] == committee[1]
+        || committee[0] == committee[2]
+        || committee[1] == committee[2]
+    {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "The committee must consist of 3 distinct parties of the Shamir network",
+        ));
+    }
+    Ok(committee
+        .iter()
+        .position(|member| *member == id)
+        .map(|pos| PartyID::try_from(pos).expect("position is smaller than 3")))
+}
+
+/// A 3-party committee of a [ShamirNetwork] with an arbitrary number of parties, which implements the [Rep3Network] trait. This allows the committee to run the subprotocols which only exist for 3-party replicated secret sharing (e.g., arithmetic/binary conversions and garbled circuits), see [ShamirProtocol::run_on_rep3_committee](crate::protocols::shamir::ShamirProtocol::run_on_rep3_committee).
+///
+/// The party `committee[i]` of the Shamir network takes the role of party i in the Rep3 protocol. Forking the Shamir network requires all of its parties to participate, thus the committee cannot fork it on its own. Instead, [Rep3Network::fork] hands out the networks which all parties forked in advance (see [ShamirCommitteeNetwork::with_forks]) and returns an error once they are used up.
+pub struct ShamirCommitteeNetwork<N: ShamirNetwork> {
+    id: PartyID,
+    committee: [usize; 3],
+    net: N,
+    forks: Vec<N>,
+}
+
+impl<N: ShamirNetwork> ShamirCommitteeNetwork<N> {
+    /// Creates a new [ShamirCommitteeNetwork] from the given network. Returns an error if the committee is invalid or if this party is not a member of it.
+    pub fn new(net: N, committee: [usize; 3]) -> std::io::Result<Self> {
+        let id =
+            committee_id(&committee, net.get_num_parties(), net.get_id())?.ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "This party is not a member of the committee",
+                )
+            })?;
+        Ok(Self {
+            id,
+            committee,
+            net,
+            forks: Vec::new(),
+        })
+    }
+
+    /// Adds networks which were forked from the underlying Shamir network by all parties. Each call to [Rep3Network::fork] uses one of them.
+    pub fn with_forks(mut self, forks: Vec<N>) -> Self {
+        self.forks = forks;
+        self
+    }
+
+    /// Returns the ids of the committee members in the Shamir network
+    pub fn committee(&self) -> [usize; 3] {
+        self.committee
+    }
+
+    /// Returns the underlying Shamir network
+    pub fn into_inner(self) -> N {
+        self.net
+    }
+
+    fn shamir_id(&self, id: PartyID) -> usize {
+        self.committee[usize::from(id)]
+    }
+}
+
+#[async_trait]
+impl<N: ShamirNetwork> Rep3Network for ShamirCommitteeNetwork<N> {
+    fn get_id(&self) -> PartyID {
+        self.id
+    }
+
+    fn reshare_many<F: CanonicalSerialize + CanonicalDeserialize>(
+        &mut self,
+        data: &[F],
+    ) -> std::io::Result<Vec<F>> {
+        self.send_many(self.id.next_id(), data)?;
+        self.recv_many(self.id.prev_id())
+    }
+
+    async fn reshare_many_async<F: CanonicalSerialize + CanonicalDeserialize + Send>(
+        &mut self,
+        data: Vec<F>,
+    ) -> std::io::Result<Vec<F>> {
+        self.reshare_many(&data)
+    }
+
+    fn broadcast_many<F: CanonicalSerialize + CanonicalDeserialize>(
+        &mut self,
+        data: &[F],
+    ) -> std::io::Result<(Vec<F>, Vec<F>)> {
+        self.send_many(self.id.next_id(), data)?;
+        self.send_many(self.id.prev_id(), data)?;
+        let recv_next = self.recv_many(self.id.next_id())?;
+        let recv_prev = self.recv_many(self.id.prev_id())?;
+        Ok((recv_prev, recv_next))
+    }
+
+    fn send_many<F: CanonicalSerialize>(
+        &mut self,
+        target: PartyID,
+        data: &[F],
+    ) -> std::io::Result<()> {
+        let target = self.shamir_id(target);
+        self.net.send_many(target, data)
+    }
+
+    async fn send_many_async<F: CanonicalSerialize + Send>(
+        &mut self,
+        target: PartyID,
+        data: Vec<F>,
+    ) -> std::io::Result<()> {
+        self.send_many(target, &data)
+    }
+
+    fn recv_many<F: CanonicalDeserialize>(&mut self, from: PartyID) -> std::io::Result<Vec<F>> {
+        let from = self.shamir_id(from);
+        self.net.recv_many(from)
+    }
+
+    async fn recv_many_async<F: CanonicalDeserialize>(
+        &mut self,
+        from: PartyID,
+    ) -> std::io::Result<Vec<F>> {
+        self.recv_many(from)
+    }
+
+    fn fork(&mut self) -> std::io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let net = self.forks.pop().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "No forked network left for the committee, all parties of the Shamir network need to fork it in advance",
+            )
+        })?;
+        Ok(Self {
+            id: self.id,
+            committee: self.committee,
+            net,
+            forks: Vec::new(),
+        })
+    }
+}
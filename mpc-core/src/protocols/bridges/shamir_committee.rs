@@ -0,0 +1,201 @@
+use super::network::{ShamirCommitteeNetwork, committee_id};
+use crate::{
+    RngType,
+    protocols::{
+        rep3::{Rep3PrimeFieldShare, network::IoContext},
+        shamir::{self, ShamirPrimeFieldShare, ShamirProtocol, network::ShamirNetwork},
+    },
+};
+use ark_ff::PrimeField;
+use rand::SeedableRng;
+
+impl<F: PrimeField, N: ShamirNetwork> ShamirProtocol<F, N> {
+    /// Translate a vector of Shamir prime field shares of all n parties into a vector of Rep3 prime field shares held by a 3-party committee. The party `committee[i]` gets the shares of party i of the Rep3 protocol. All parties need to call this function with the same committee, the committee members get `Some` result, all other parties get `None`.
+    ///
+    /// The security of the resulting sharing relies on an honest majority within the committee.
+    pub fn translate_primefield_shamirshare_vec_to_committee(
+        &mut self,
+        input: Vec<ShamirPrimeFieldShare<F>>,
+        committee: [usize; 3],
+    ) -> std::io::Result<Option<Vec<Rep3PrimeFieldShare<F>>>> {
+        let num_parties = self.network.get_num_parties();
+        let id = self.network.get_id();
+        let my_committee_id = committee_id(&committee, num_parties, id)?;
+
+        // The sharing polynomial has degree t < n, so we can use the Lagrange coefficients of all n parties to get an additive sharing
+        let my_lagrange_coeff =
+            shamir::lagrange_from_coeff::<F>(&(1..=num_parties).collect::<Vec<_>>())[id];
+
+        // Split the additive share into 3 random parts, one for each committee member
+        let mut rng = RngType::from_entropy();
+        let mut parts = [
+            Vec::with_capacity(input.len()),
+            Vec::with_capacity(input.len()),
+            Vec::with_capacity(input.len()),
+        ];
+        for share in input {
+            let r0 = F::rand(&mut rng);
+            let r1 = F::rand(&mut rng);
+            parts[0].push(r0);
+            parts[1].push(r1);
+            parts[2].push(share.a * my_lagrange_coeff - r0 - r1);
+        }
+        let [part0, part1, part2] = parts;
+        let mut my_part = None;
+        for (member, part) in committee.iter().zip([part0, part1, part2]) {
+            if *member == id {
+                my_part = Some(part);
+            } else {
+                self.network.send_many(*member, &part)?;
+            }
+        }
+
+        let Some(my_committee_id) = my_committee_id else {
+            return Ok(None);
+        };
+
+        // Sum up the parts of all parties to get a 3-party additive sharing within the committee
+        let mut a = my_part.expect("we are a committee member");
+        for other in (0..num_parties).filter(|other| *other != id) {
+            let part = self.network.recv_many::<F>(other)?;
+            if part.len() != a.len() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Received vector of wrong length while translating to the committee",
+                ));
+            }
+            for (a, part) in a.iter_mut().zip(part) {
+                *a += part;
+            }
+        }
+
+        // The additive shares are already rerandomized by all parties, so we just replicate them
+        let next = committee[usize::from(my_committee_id.next_id())];
+        let prev = committee[usize::from(my_committee_id.prev_id())];
+        self.network.send_many(next, &a)?;
+        let b = self.network.recv_many::<F>(prev)?;
+        if b.len() != a.len() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Received vector of wrong length while translating to the committee",
+            ));
+        }
+        Ok(Some(
+            a.into_iter()
+                .zip(b)
+                .map(|(a, b)| Rep3PrimeFieldShare::new(a, b))
+                .collect(),
+        ))
+    }
+
+    /// Translate a vector of Rep3 prime field shares held by a 3-party committee into a vector of Shamir prime field shares of all n parties, where the underlying sharing polynomial is of degree `self.threshold`. All parties need to call this function with the same committee, the committee members need to provide `Some` input, all other parties `None`.
+    pub fn translate_rep3share_vec_from_committee(
+        &mut self,
+        input: Option<Vec<Rep3PrimeFieldShare<F>>>,
+        committee: [usize; 3],
+    ) -> std::io::Result<Vec<ShamirPrimeFieldShare<F>>> {
+        let num_parties = self.network.get_num_parties();
+        let id = self.network.get_id();
+        let my_committee_id = committee_id(&committee, num_parties, id)?;
+
+        // Each committee member Shamir-shares its additive share
+        let mut my_shares = None;
+        match (my_committee_id, input) {
+            (Some(_), Some(input)) => {
+                let a = input.into_iter().map(|share| share.a).collect::<Vec<_>>();
+                let mut rng = RngType::from_entropy();
+                let shares =
+                    shamir::share_field_elements(&a, self.threshold, num_parties, &mut rng);
+                for (other, shares) in shares.into_iter().enumerate() {
+                    if other == id {
+                        my_shares = Some(shares);
+                    } else {
+                        self.network.send_many(other, &shares)?;
+                    }
+                }
+            }
+            (None, None) => {}
+            _ => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "Exactly the committee members need to provide input",
+                ));
+            }
+        }
+
+        // Sum up the shares of the three committee members
+        let mut result: Option<Vec<ShamirPrimeFieldShare<F>>> = None;
+        for member in committee {
+            let shares = if member == id {
+                my_shares.take().expect("we are a committee member")
+            } else {
+                self.network.recv_many(member)?
+            };
+            match result.as_mut() {
+                None => result = Some(shares),
+                Some(result) => {
+                    if result.len() != shares.len() {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            "Received vector of wrong length while translating from the committee",
+                        ));
+                    }
+                    for (r, s) in result.iter_mut().zip(shares) {
+                        r.a += s.a;
+                    }
+                }
+            }
+        }
+        Ok(result.expect("the committee is not empty"))
+    }
+
+    /// Runs a Rep3 subprotocol, which is not available for Shamir secret sharing, on a 3-party committee. The Shamir shares of all n parties are translated to Rep3 shares held by the committee, `func` is executed by the committee members on a [ShamirCommitteeNetwork], and the resulting Rep3 shares are translated back to Shamir shares of all n parties. All parties need to call this function with the same committee.
+    ///
+    /// The security of this hybrid mode relies on an honest majority within the committee. The committee cannot fork its network, use [ShamirProtocol::run_on_rep3_committee_with_forks] for subprotocols which fork the [IoContext].
+    pub fn run_on_rep3_committee<Func>(
+        &mut self,
+        input: Vec<ShamirPrimeFieldShare<F>>,
+        committee: [usize; 3],
+        func: Func,
+    ) -> std::io::Result<Vec<ShamirPrimeFieldShare<F>>>
+    where
+        Func: FnOnce(
+            Vec<Rep3PrimeFieldShare<F>>,
+            &mut IoContext<ShamirCommitteeNetwork<N>>,
+        ) -> std::io::Result<Vec<Rep3PrimeFieldShare<F>>>,
+    {
+        self.run_on_rep3_committee_with_forks(input, committee, 0, func)
+    }
+
+    /// Same as [ShamirProtocol::run_on_rep3_committee], but all parties fork the Shamir network `num_forks` additional times in advance. This allows `func` to fork the [IoContext] of the committee up to `num_forks` times (e.g., for [shift_l_public_by_shared](crate::protocols::rep3::binary::shift_l_public_by_shared)). All parties need to call this function with the same `num_forks`.
+    pub fn run_on_rep3_committee_with_forks<Func>(
+        &mut self,
+        input: Vec<ShamirPrimeFieldShare<F>>,
+        committee: [usize; 3],
+        num_forks: usize,
+        func: Func,
+    ) -> std::io::Result<Vec<ShamirPrimeFieldShare<F>>>
+    where
+        Func: FnOnce(
+            Vec<Rep3PrimeFieldShare<F>>,
+            &mut IoContext<ShamirCommitteeNetwork<N>>,
+        ) -> std::io::Result<Vec<Rep3PrimeFieldShare<F>>>,
+    {
+        let rep3_shares =
+            self.translate_primefield_shamirshare_vec_to_committee(input, committee)?;
+        // All parties fork the network, but only the committee uses it
+        let net = self.network.fork()?;
+        let forks = (0..num_forks)
+            .map(|_| self.network.fork())
+            .collect::<std::io::Result<Vec<_>>>()?;
+        let output = match rep3_shares {
+            Some(rep3_shares) => {
+                let net = ShamirCommitteeNetwork::new(net, committee)?.with_forks(forks);
+                let mut io_context = IoContext::init(net)?;
+                Some(func(rep3_shares, &mut io_context)?)
+            }
+            None => None,
+        };
+        self.translate_rep3share_vec_from_committee(output, committee)
+    }
+}
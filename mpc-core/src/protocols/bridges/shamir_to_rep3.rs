@@ -0,0 +1,111 @@
+use crate::{
+    RngType,
+    protocols::{
+        rep3::{Rep3PointShare, Rep3PrimeFieldShare, rngs::Rep3Rand},
+        shamir::{ShamirPointShare, ShamirPrimeFieldShare, ShamirProtocol, network::ShamirNetwork},
+    },
+};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use rand::{Rng, SeedableRng};
+
+impl<F: PrimeField, N: ShamirNetwork> ShamirProtocol<F, N> {
+    fn check_rep3_compatible(&self) -> std::io::Result<()> {
+        if self.network.get_num_parties() != 3 || self.threshold != 1 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Translating to Rep3 requires 3 parties and a threshold of 1",
+            ));
+        }
+        Ok(())
+    }
+
+    // Same as the Rep3 PRF setup: our seed is shared with the next party, the seed of the previous party is received
+    fn setup_rep3_rand(&mut self) -> std::io::Result<Rep3Rand> {
+        let id = self.network.get_id();
+        let seed1: [u8; crate::SEED_SIZE] = RngType::from_entropy().r#gen();
+        self.network.send((id + 1) % 3, seed1)?;
+        let seed2: [u8; crate::SEED_SIZE] = self.network.recv((id + 2) % 3)?;
+        Ok(Rep3Rand::new(seed1, seed2))
+    }
+
+    // Sends the additive shares to the next party and receives the ones of the previous party to replicate them
+    fn reshare_to_prev<T>(&mut self, a: Vec<T>) -> std::io::Result<Vec<T>>
+    where
+        T: ark_serialize::CanonicalSerialize + ark_serialize::CanonicalDeserialize,
+    {
+        let id = self.network.get_id();
+        self.network.send_many((id + 1) % 3, &a)?;
+        let b = self.network.recv_many::<T>((id + 2) % 3)?;
+        if b.len() != a.len() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Received vector of wrong length while translating to Rep3",
+            ));
+        }
+        Ok(b)
+    }
+
+    /// Translate a 3-party Shamir prime field share, where the underlying sharing polynomial is of degree 1 (i.e., the threshold t = 1), into a Rep3 prime field share.
+    pub fn translate_primefield_shamirshare(
+        &mut self,
+        input: ShamirPrimeFieldShare<F>,
+    ) -> std::io::Result<Rep3PrimeFieldShare<F>> {
+        let mut res = self.translate_primefield_shamirshare_vec(vec![input])?;
+        Ok(res.pop().expect("we translated one element"))
+    }
+
+    /// Translate a 3-party Shamir prime field share vector, where the underlying sharing polynomial is of degree 1 (i.e., the threshold t = 1), into a Rep3 prime field share vector.
+    pub fn translate_primefield_shamirshare_vec(
+        &mut self,
+        input: Vec<ShamirPrimeFieldShare<F>>,
+    ) -> std::io::Result<Vec<Rep3PrimeFieldShare<F>>> {
+        self.check_rep3_compatible()?;
+        let mut rand = self.setup_rep3_rand()?;
+        // Multiplying with the Lagrange coefficient gives a 3-party additive share, which is rerandomized by a zero share
+        let my_lagrange_coeff = self.open_lagrange_2t[0];
+        let a = input
+            .into_iter()
+            .map(|share| share.a * my_lagrange_coeff + rand.masking_field_element::<F>())
+            .collect::<Vec<_>>();
+        let b = self.reshare_to_prev(a.clone())?;
+        Ok(a.into_iter()
+            .zip(b)
+            .map(|(a, b)| Rep3PrimeFieldShare::new(a, b))
+            .collect())
+    }
+
+    /// Translate a 3-party Shamir point share, where the underlying sharing polynomial is of degree 1 (i.e., the threshold t = 1), into a Rep3 point share.
+    pub fn translate_point_shamirshare<C>(
+        &mut self,
+        input: ShamirPointShare<C>,
+    ) -> std::io::Result<Rep3PointShare<C>>
+    where
+        C: CurveGroup<ScalarField = F>,
+    {
+        let mut res = self.translate_point_shamirshare_vec(vec![input])?;
+        Ok(res.pop().expect("we translated one element"))
+    }
+
+    /// Translate a 3-party Shamir point share vector, where the underlying sharing polynomial is of degree 1 (i.e., the threshold t = 1), into a Rep3 point share vector.
+    pub fn translate_point_shamirshare_vec<C>(
+        &mut self,
+        input: Vec<ShamirPointShare<C>>,
+    ) -> std::io::Result<Vec<Rep3PointShare<C>>>
+    where
+        C: CurveGroup<ScalarField = F>,
+    {
+        self.check_rep3_compatible()?;
+        let mut rand = self.setup_rep3_rand()?;
+        let my_lagrange_coeff = self.open_lagrange_2t[0];
+        let a = input
+            .into_iter()
+            .map(|share| share.a * my_lagrange_coeff + rand.masking_ec_element::<C>())
+            .collect::<Vec<_>>();
+        let b = self.reshare_to_prev(a.clone())?;
+        Ok(a.into_iter()
+            .zip(b)
+            .map(|(a, b)| Rep3PointShare::new(a, b))
+            .collect())
+    }
+}
@@ -4,6 +4,7 @@
 
 pub mod arithmetic;
 pub mod binary;
+pub mod bitvec;
 pub mod conversion;
 mod detail;
 pub mod gadgets;
@@ -14,7 +15,8 @@ pub mod rngs;
 pub mod yao;
 
 pub use mpc_types::protocols::rep3::{
-    Rep3BigUintShare, Rep3PointShare, Rep3PrimeFieldShare, combine_binary_element,
-    combine_curve_point, combine_field_element, combine_field_elements, id::PartyID, share_biguint,
-    share_curve_point, share_field_element, share_field_elements,
+    Rep3BigUintShare, Rep3BitVecShare, Rep3PointShare, Rep3PrimeFieldShare, combine_binary_element,
+    combine_bitvec, combine_curve_point, combine_field_element, combine_field_elements,
+    id::PartyID, share_biguint, share_bitvec, share_curve_point, share_field_element,
+    share_field_elements, vss,
 };
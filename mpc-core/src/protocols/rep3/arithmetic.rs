@@ -461,6 +461,8 @@ pub fn rand<F: PrimeField, N: Rep3Network>(io_context: &mut IoContext<N>) -> Fie
 }
 
 /// Computes the square root of a shared value.
+///
+/// Returns an error if the shared value is zero or not a quadratic residue, which thereby gets revealed to all parties. Use [`sqrt_or_zero`] if the input is not guaranteed to be a non-zero quadratic residue.
 pub fn sqrt<F: PrimeField, N: Rep3Network>(
     share: FieldShare<F>,
     io_context: &mut IoContext<N>,
@@ -514,6 +516,77 @@ pub fn sqrt<F: PrimeField, N: Rep3Network>(
     Ok(a_sqrt)
 }
 
+// Returns the smallest quadratic non-residue of the field.
+fn quadratic_non_residue<F: PrimeField>() -> F {
+    let mut q = F::one();
+    while !q.legendre().is_qnr() {
+        q += F::one();
+    }
+    q
+}
+
+// Computes whether a shared non-zero value is a quadratic residue. The value is masked by a random square and a random factor which is either 1 or a quadratic non-residue. The masked value gets opened and its public quadratic residuosity is corrected by the shared random choice of the factor.
+fn is_quadratic_residue_non_zero<F: PrimeField, N: Rep3Network>(
+    share: FieldShare<F>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<FieldShare<F>> {
+    let qnr = quadratic_non_residue::<F>();
+    let r = rand(io_context);
+    let (bit_a, bit_b) = io_context.rngs.rand.random_biguint(1);
+    let bit = conversion::bit_inject(&Rep3BigUintShare::new(bit_a, bit_b), io_context)?;
+
+    // factor = 1 if bit = 0, qnr otherwise
+    let factor = add_public(mul_public(bit, qnr - F::one()), F::one(), io_context.id);
+    let lhs = vec![r, factor];
+    let rhs = vec![r, share];
+    let mul = mul_vec(&lhs, &rhs, io_context)?;
+    let masked = mul_open(mul[0], mul[1], io_context)?;
+
+    let legendre = masked.legendre();
+    if legendre.is_zero() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "During execution of quadratic residue test in MPC: masked value is zero",
+        ));
+    }
+    if legendre.is_qr() {
+        Ok(sub_public_by_shared(F::one(), bit, io_context.id))
+    } else {
+        Ok(bit)
+    }
+}
+
+/// Computes whether a shared value is a quadratic residue, without revealing the result. The result is a shared value that has value 1 if the shared value is a quadratic residue (including zero) and 0 otherwise.
+pub fn is_quadratic_residue<F: PrimeField, N: Rep3Network>(
+    share: FieldShare<F>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<FieldShare<F>> {
+    // Replace zero by one, which is a quadratic residue, to not reveal zero in the masked opening
+    let is_zero = eq_public(share, F::zero(), io_context)?;
+    is_quadratic_residue_non_zero(share + is_zero, io_context)
+}
+
+/// Computes the square root of a shared value, without revealing whether it exists. Returns the shared square root (or zero if none exists) and a shared flag which has value 1 if the shared value is a quadratic residue (including zero) and 0 otherwise. This matches the behavior of circom's `sqrt` function, which returns 0 for quadratic non-residues.
+pub fn sqrt_or_zero<F: PrimeField, N: Rep3Network>(
+    share: FieldShare<F>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<(FieldShare<F>, FieldShare<F>)> {
+    let qnr = quadratic_non_residue::<F>();
+    let is_zero = eq_public(share, F::zero(), io_context)?;
+    let non_zero = share + is_zero;
+    let is_qr = is_quadratic_residue_non_zero(non_zero, io_context)?;
+
+    // Multiplying a non-residue by a non-residue results in a residue, thus the square root of residue always exists. In parallel, compute whether the result needs to be set to zero, i.e., if the input is zero or a non-residue.
+    let factor = add_public(mul_public(is_qr, F::one() - qnr), qnr, io_context.id);
+    let non_zero_flag = sub_public_by_shared(F::one(), is_zero, io_context.id);
+    let lhs = vec![non_zero, is_qr];
+    let rhs = vec![factor, non_zero_flag];
+    let res = mul_vec(&lhs, &rhs, io_context)?;
+    let root = sqrt(res[0], io_context)?;
+    let root = mul(root, res[1], io_context)?;
+    Ok((root, is_qr))
+}
+
 /// Performs a pow operation using a shared value as base and a public value as exponent.
 pub fn pow_public<F: PrimeField, N: Rep3Network>(
     shared: FieldShare<F>,
@@ -0,0 +1,214 @@
+//! Bit Vectors
+//!
+//! This module contains operations with packed binary shares of a fixed width. In contrast to the operations in [`super::binary`], multiple shares are processed in a single communication round and only the words required for the width of the shares are sent.
+
+use itertools::{Itertools as _, izip};
+
+use crate::{
+    IoResult,
+    protocols::rep3::{PartyID, Rep3BitVecShare, network::Rep3Network},
+};
+
+use super::network::IoContext;
+
+type BitVecShare = Rep3BitVecShare;
+
+/// Returns a trivial share of the public all-ones vector of `len` bits, which is the same on all parties. Since each bit is contained in all three shares, XORing it negates a shared value.
+fn all_ones(len: usize) -> BitVecShare {
+    let words = vec![u64::MAX; len.div_ceil(u64::BITS as usize)];
+    BitVecShare::new(words.clone(), words, len)
+}
+
+/// Performs a bitwise XOR operation on two shared values.
+pub fn xor(a: &BitVecShare, b: &BitVecShare) -> BitVecShare {
+    a ^ b
+}
+
+/// Performs a bitwise XOR operation on a shared value and a public value.
+pub fn xor_public(shared: &BitVecShare, public: &[u64], id: PartyID) -> BitVecShare {
+    shared ^ &promote_to_trivial_share(id, public, shared.len())
+}
+
+/// Performs a bitwise AND operation on a shared value and a public value.
+pub fn and_with_public(shared: &BitVecShare, public: &[u64]) -> BitVecShare {
+    shared & public
+}
+
+/// Performs a bitwise NOT operation on a shared value.
+pub fn not(shared: &BitVecShare) -> BitVecShare {
+    shared ^ &all_ones(shared.len())
+}
+
+/// Transforms a public value of `len` bits into a shared value: \[a\] = a.
+pub fn promote_to_trivial_share(id: PartyID, public_value: &[u64], len: usize) -> BitVecShare {
+    let zero = vec![0; public_value.len()];
+    match id {
+        PartyID::ID0 => BitVecShare::new(public_value.to_owned(), zero, len),
+        PartyID::ID1 => BitVecShare::new(zero, public_value.to_owned(), len),
+        PartyID::ID2 => BitVecShare::zero_share(len),
+    }
+}
+
+/// Performs the opening of a shared value and returns the equivalent public value.
+pub fn open<N: Rep3Network>(a: &BitVecShare, io_context: &mut IoContext<N>) -> IoResult<Vec<u64>> {
+    let c = io_context.network.reshare_many(&a.b)?;
+    if c.len() != a.b.len() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "During execution of open in MPC: Invalid number of elements received",
+        ));
+    }
+    Ok(izip!(&a.a, &a.b, c).map(|(a, b, c)| a ^ b ^ c).collect())
+}
+
+/// Performs a bitwise AND operation on two shared values.
+pub fn and<N: Rep3Network>(
+    a: &BitVecShare,
+    b: &BitVecShare,
+    io_context: &mut IoContext<N>,
+) -> IoResult<BitVecShare> {
+    let mut res = and_vec(std::slice::from_ref(a), std::slice::from_ref(b), io_context)?;
+    Ok(res.pop().expect("one element"))
+}
+
+/// Performs element-wise bitwise AND operation on the provided shared values. All words of all elements are sent in a single communication round.
+pub fn and_vec<N: Rep3Network>(
+    a: &[BitVecShare],
+    b: &[BitVecShare],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<BitVecShare>> {
+    assert_eq!(a.len(), b.len());
+    let local_a = izip!(a, b)
+        .flat_map(|(a, b)| a & b)
+        .map(|and| {
+            let (mask_a, mask_b) = io_context.rngs.rand.random_elements::<u64>();
+            and ^ mask_a ^ mask_b
+        })
+        .collect_vec();
+    let local_b = io_context.network.reshare_many(&local_a)?;
+    if local_b.len() != local_a.len() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "During execution of and_vec in MPC: Invalid number of elements received",
+        ));
+    }
+    let mut local_a = local_a.into_iter();
+    let mut local_b = local_b.into_iter();
+    Ok(a.iter()
+        .map(|a| {
+            let words = a.a.len();
+            BitVecShare::new(
+                local_a.by_ref().take(words).collect(),
+                local_b.by_ref().take(words).collect(),
+                a.len(),
+            )
+        })
+        .collect())
+}
+
+/// Performs a bitwise OR operation on two shared values.
+pub fn or<N: Rep3Network>(
+    a: &BitVecShare,
+    b: &BitVecShare,
+    io_context: &mut IoContext<N>,
+) -> IoResult<BitVecShare> {
+    let xor = a ^ b;
+    let and = and(a, b, io_context)?;
+    Ok(xor ^ and)
+}
+
+/// Performs element-wise bitwise OR operation on the provided shared values.
+pub fn or_vec<N: Rep3Network>(
+    a: &[BitVecShare],
+    b: &[BitVecShare],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<BitVecShare>> {
+    let and = and_vec(a, b, io_context)?;
+    Ok(izip!(a, b, and).map(|(a, b, and)| a ^ b ^ and).collect())
+}
+
+/// Computes a CMUX: If `c` is `1`, returns `x_t`, otherwise returns `x_f`. If `c` consists of a single bit, it is applied to all bits of `x_t` and `x_f`, otherwise it is applied bitwise.
+pub fn cmux<N: Rep3Network>(
+    c: &BitVecShare,
+    x_t: &BitVecShare,
+    x_f: &BitVecShare,
+    io_context: &mut IoContext<N>,
+) -> IoResult<BitVecShare> {
+    let c = if c.len() == 1 {
+        c.repeat_bit(0, x_t.len())
+    } else {
+        c.to_owned()
+    };
+    let xor = x_f ^ x_t;
+    let mut and = and(&c, &xor, io_context)?;
+    and ^= x_f;
+    Ok(and)
+}
+
+/// Computes an OR tree of the input vec. Each level of the tree is computed in a single communication round.
+pub fn or_tree<N: Rep3Network>(
+    mut inputs: Vec<BitVecShare>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<BitVecShare> {
+    assert!(!inputs.is_empty(), "or tree requires at least one input");
+    while inputs.len() > 1 {
+        let leftover = if inputs.len() % 2 == 1 {
+            inputs.pop()
+        } else {
+            None
+        };
+        let b_vec = inputs.split_off(inputs.len() / 2);
+        inputs = or_vec(&inputs, &b_vec, io_context)?;
+        inputs.extend(leftover);
+    }
+    Ok(inputs.pop().expect("one element"))
+}
+
+/// Splits a value into the lower and upper half of its bits for the next level of the AND tree in [`is_zero`]. Values with an odd number of bits are padded with a publicly known 1.
+fn split_halves(x: &BitVecShare) -> (BitVecShare, BitVecShare) {
+    let x = if x.len() % 2 == 1 {
+        BitVecShare::concat(&[x.to_owned(), all_ones(1)])
+    } else {
+        x.to_owned()
+    };
+    let half = x.len() / 2;
+    (x.slice(0, half), x.slice(half, half))
+}
+
+/// Computes a binary circuit to check whether the replicated binary-shared input x is zero or not. The output is a binary sharing of one bit.
+pub fn is_zero<N: Rep3Network>(
+    x: &BitVecShare,
+    io_context: &mut IoContext<N>,
+) -> IoResult<BitVecShare> {
+    let mut res = is_zero_many(std::slice::from_ref(x), io_context)?;
+    Ok(res.pop().expect("one element"))
+}
+
+/// Computes a binary circuit to check whether each of the replicated binary-shared inputs in the vector x is zero or not. The output is a vector of binary sharings of one bit. Each level of the AND tree is computed for all inputs in a single communication round.
+pub fn is_zero_many<N: Rep3Network>(
+    x: &[BitVecShare],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<BitVecShare>> {
+    // an empty vector is zero
+    let mut x = x
+        .iter()
+        .map(|x| if x.is_empty() { all_ones(1) } else { not(x) })
+        .collect_vec();
+
+    // do ands in a tree, halving the width in each level
+    loop {
+        let (indices, (lo, hi)): (Vec<_>, (Vec<_>, Vec<_>)) = x
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| x.len() > 1)
+            .map(|(i, x)| (i, split_halves(x)))
+            .unzip();
+        if indices.is_empty() {
+            break;
+        }
+        for (i, and) in izip!(indices, and_vec(&lo, &hi, io_context)?) {
+            x[i] = and;
+        }
+    }
+    Ok(x)
+}
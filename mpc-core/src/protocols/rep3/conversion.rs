@@ -29,6 +29,119 @@ pub enum A2BType {
     /// The arithmetic-to-binary conversion is done by "Arithmetic to Yao" followed by "Yao to Binary", while the binary-to-arithmetic conversion is done using "Binary to Yao" followed by "Yao to Arithmetic". This process has a low number of communication rounds with more communicated bytes.
     #[default]
     Yao,
+    /// Chooses between [A2BType::Direct] and [A2BType::Yao] for every conversion, based on the number of converted elements and the [NetworkProfile] of the [IoContext]. See [A2BType::resolve] for the used cost model.
+    Auto,
+}
+
+/// Describes the network the parties are connected with. It is used by [A2BType::Auto] to estimate the runtime of the different conversion protocols. All parties need to use the same profile, otherwise they might choose different protocols.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct NetworkProfile {
+    /// The one-way latency between two parties in microseconds
+    pub latency_micros: u64,
+    /// The bandwidth between two parties in bytes per second
+    pub bandwidth_bytes_per_sec: u64,
+}
+
+impl Default for NetworkProfile {
+    fn default() -> Self {
+        Self::lan()
+    }
+}
+
+impl NetworkProfile {
+    /// A local network with 0.25ms latency and 1 Gbit/s bandwidth.
+    pub fn lan() -> Self {
+        Self {
+            latency_micros: 250,
+            bandwidth_bytes_per_sec: 125_000_000,
+        }
+    }
+
+    /// A wide area network with 50ms latency and 100 Mbit/s bandwidth.
+    pub fn wan() -> Self {
+        Self {
+            latency_micros: 50_000,
+            bandwidth_bytes_per_sec: 12_500_000,
+        }
+    }
+
+    // Estimated runtime in microseconds for the given number of communication rounds and bytes sent per party.
+    fn estimate_micros(&self, rounds: u64, bytes: u64) -> u128 {
+        let bandwidth = self.bandwidth_bytes_per_sec.max(1) as u128;
+        rounds as u128 * self.latency_micros as u128 + bytes as u128 * 1_000_000 / bandwidth
+    }
+}
+
+/// The conversion protocol an [A2BType] resolves to for a single conversion, see [A2BType::resolve].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum A2BProtocol {
+    /// See [A2BType::Direct]
+    Direct,
+    /// See [A2BType::Yao]
+    Yao,
+}
+
+impl A2BType {
+    // Fixed cost of a conversion using garbled circuits, i.e., the setup of the garbler and evaluator and the hashing of the global delta, in microseconds.
+    const YAO_SETUP_MICROS: u128 = 5_000;
+    // Local cost per AND gate of the garbled adder in nanoseconds
+    const YAO_NANOS_PER_AND: u128 = 20;
+    // Local cost per AND gate of the direct conversion in nanoseconds. Every layer of the binary adder works on the bit-decomposed values of all elements and reshares them, which dominates the runtime of large batches.
+    const DIRECT_NANOS_PER_AND: u128 = 60;
+
+    /// Resolves the [A2BType] to the [A2BProtocol] used for a conversion of `batch_size` elements with `bit_size` bits each. [A2BType::Direct] and [A2BType::Yao] are resolved to the respective protocol, [A2BType::Auto] chooses the protocol with the smaller estimated runtime for the given [NetworkProfile].
+    ///
+    /// The cost model estimates the rounds, the sent bytes and the local work of both protocols:
+    /// - The direct conversion needs a logarithmic number of rounds in `bit_size` for the binary adders modulo p, but only sends one bit per AND gate. Its large number of AND gates makes its local work grow quickly with the batch size.
+    /// - The conversion using Yao's garbled circuits has a constant number of rounds and fewer AND gates, but a fixed setup cost and 16-byte wire labels and garbled gates.
+    ///
+    /// Thus, [A2BProtocol::Direct] is chosen for small batches on low-latency networks, while [A2BProtocol::Yao] is chosen for large batches or high-latency networks. On low-bandwidth networks, very large batches favour [A2BProtocol::Direct] again, since it sends fewer bytes.
+    pub fn resolve(
+        self,
+        batch_size: usize,
+        bit_size: usize,
+        profile: &NetworkProfile,
+    ) -> A2BProtocol {
+        match self {
+            A2BType::Direct => A2BProtocol::Direct,
+            A2BType::Yao => A2BProtocol::Yao,
+            A2BType::Auto => {
+                if Self::estimate_direct_micros(batch_size, bit_size, profile)
+                    <= Self::estimate_yao_micros(batch_size, bit_size, profile)
+                {
+                    A2BProtocol::Direct
+                } else {
+                    A2BProtocol::Yao
+                }
+            }
+        }
+    }
+
+    // Two parallel prefix adders (adding the shares and subtracting p) with two AND gates per bit and layer, followed by a multiplexer. Every layer requires one round.
+    fn estimate_direct_micros(
+        batch_size: usize,
+        bit_size: usize,
+        profile: &NetworkProfile,
+    ) -> u128 {
+        let batch_size = batch_size.max(1) as u64;
+        let bit_size = bit_size.max(1) as u64;
+        let log = u64::from(bit_size.next_power_of_two().trailing_zeros()).max(1);
+        let rounds = 2 * log + 3;
+        let and_gates = batch_size * bit_size * (4 * log + 1);
+        profile.estimate_micros(rounds, and_gates.div_ceil(8))
+            + u128::from(and_gates) * Self::DIRECT_NANOS_PER_AND / 1_000
+    }
+
+    // Inputs (three labels per bit), the garbled ripple-carry adders modulo p (three AND gates per bit with two ciphertexts each) and the output.
+    fn estimate_yao_micros(batch_size: usize, bit_size: usize, profile: &NetworkProfile) -> u128 {
+        let batch_size = batch_size.max(1) as u64;
+        let bit_size = bit_size.max(1) as u64;
+        let and_gates = batch_size * bit_size * 3;
+        let bytes = batch_size * bit_size * 3 * 16 + and_gates * 2 * 16;
+        profile.estimate_micros(3, bytes)
+            + Self::YAO_SETUP_MICROS
+            + u128::from(and_gates) * Self::YAO_NANOS_PER_AND / 1_000
+    }
 }
 
 /// Depending on the `A2BType` of the io_context, this function selects the appropriate implementation for the arithmetic-to-binary conversion.
@@ -36,9 +149,20 @@ pub fn a2b_selector<F: PrimeField, N: Rep3Network>(
     x: Rep3PrimeFieldShare<F>,
     io_context: &mut IoContext<N>,
 ) -> std::io::Result<Rep3BigUintShare<F>> {
-    match io_context.a2b_type {
-        A2BType::Direct => a2b(x, io_context),
-        A2BType::Yao => a2y2b(x, io_context),
+    match io_context.resolve_a2b_type(1, F::MODULUS_BIT_SIZE as usize) {
+        A2BProtocol::Direct => a2b(x, io_context),
+        A2BProtocol::Yao => a2y2b(x, io_context),
+    }
+}
+
+/// Depending on the `A2BType` of the io_context, this function selects the appropriate implementation for the arithmetic-to-binary conversion of a vector of shared values.
+pub fn a2b_many_selector<F: PrimeField, N: Rep3Network>(
+    x: &[Rep3PrimeFieldShare<F>],
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Vec<Rep3BigUintShare<F>>> {
+    match io_context.resolve_a2b_type(x.len(), F::MODULUS_BIT_SIZE as usize) {
+        A2BProtocol::Direct => a2b_many(x, io_context),
+        A2BProtocol::Yao => a2y2b_many(x, io_context),
     }
 }
 
@@ -47,9 +171,20 @@ pub fn b2a_selector<F: PrimeField, N: Rep3Network>(
     x: &Rep3BigUintShare<F>,
     io_context: &mut IoContext<N>,
 ) -> std::io::Result<Rep3PrimeFieldShare<F>> {
-    match io_context.a2b_type {
-        A2BType::Direct => b2a(x, io_context),
-        A2BType::Yao => b2y2a(x, io_context),
+    match io_context.resolve_a2b_type(1, F::MODULUS_BIT_SIZE as usize) {
+        A2BProtocol::Direct => b2a(x, io_context),
+        A2BProtocol::Yao => b2y2a(x, io_context),
+    }
+}
+
+/// Depending on the `A2BType` of the io_context, this function selects the appropriate implementation for the binary-to-arithmetic conversion of a vector of shared values.
+pub fn b2a_many_selector<F: PrimeField, N: Rep3Network>(
+    x: &[Rep3BigUintShare<F>],
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Vec<Rep3PrimeFieldShare<F>>> {
+    match io_context.resolve_a2b_type(x.len(), F::MODULUS_BIT_SIZE as usize) {
+        A2BProtocol::Direct => b2a_many(x, io_context),
+        A2BProtocol::Yao => b2y2a_many(x, io_context),
     }
 }
 
@@ -217,6 +352,101 @@ pub fn b2a<F: PrimeField, N: Rep3Network>(
     Ok(res)
 }
 
+/// Transforms the provided replicated shared values from a binary sharing to an arithmetic sharing. I.e., x = x_1 xor x_2 xor x_3 gets transformed into x = x'_1 + x'_2 + x'_3. Reduces the mul-depth by batching all elements together. This implementation currently works only for binary sharings of valid field elements, i.e., x = x_1 xor x_2 xor x_3 < p.
+///
+/// Keep in mind: Only works if the inputs are actually binary sharings of valid field elements, see [b2a].
+pub fn b2a_many<F: PrimeField, N: Rep3Network>(
+    x: &[Rep3BigUintShare<F>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    if x.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut y_a = Vec::with_capacity(x.len());
+    let mut res = vec![Rep3PrimeFieldShare::zero_share(); x.len()];
+
+    for res in res.iter_mut() {
+        let (mut r, r2) = io_context
+            .rngs
+            .rand
+            .random_biguint(F::MODULUS_BIT_SIZE as usize);
+        r ^= r2;
+
+        match io_context.id {
+            PartyID::ID0 => {
+                let k3 = io_context.rngs.bitcomp2.random_fes_3keys::<F>();
+
+                res.b = (k3.0 + k3.1 + k3.2).neg();
+                y_a.push(r);
+            }
+            PartyID::ID1 => {
+                let k2 = io_context.rngs.bitcomp1.random_fes_3keys::<F>();
+
+                res.a = (k2.0 + k2.1 + k2.2).neg();
+                y_a.push(r);
+            }
+            PartyID::ID2 => {
+                let k2 = io_context.rngs.bitcomp1.random_fes_3keys::<F>();
+                let k3 = io_context.rngs.bitcomp2.random_fes_3keys::<F>();
+
+                let k2_comp = k2.0 + k2.1 + k2.2;
+                let k3_comp = k3.0 + k3.1 + k3.2;
+                let val: BigUint = (k2_comp + k3_comp).into();
+                y_a.push(val ^ r);
+                res.a = k3_comp.neg();
+                res.b = k2_comp.neg();
+            }
+        }
+    }
+
+    // reshare y
+    let y_b = io_context.network.reshare_many(&y_a)?;
+    let y = izip!(y_a, y_b)
+        .map(|(a, b)| BinaryShare::new(a, b))
+        .collect_vec();
+
+    let z = detail::low_depth_binary_add_mod_p_many::<F, N>(
+        x,
+        &y,
+        io_context,
+        F::MODULUS_BIT_SIZE as usize,
+    )?;
+
+    match io_context.id {
+        PartyID::ID0 => {
+            let z_b = z.iter().map(|z| z.b.to_owned()).collect_vec();
+            io_context.network.send_next_many(&z_b)?;
+            let rcv: Vec<BigUint> = io_context.network.recv_prev_many()?;
+            if rcv.len() != z.len() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Invalid number of elements received",
+                ));
+            }
+            for (res, z, rcv) in izip!(res.iter_mut(), z, rcv) {
+                res.a = (z.a ^ z.b ^ rcv).into();
+            }
+        }
+        PartyID::ID1 => {
+            let rcv: Vec<BigUint> = io_context.network.recv_prev_many()?;
+            if rcv.len() != z.len() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Invalid number of elements received",
+                ));
+            }
+            for (res, z, rcv) in izip!(res.iter_mut(), z, rcv) {
+                res.b = (z.a ^ z.b ^ rcv).into();
+            }
+        }
+        PartyID::ID2 => {
+            let z_b = z.into_iter().map(|z| z.b).collect_vec();
+            io_context.network.send_next_many(&z_b)?;
+        }
+    }
+    Ok(res)
+}
+
 /// Translates one shared bits into an arithmetic sharing of the same bit. I.e., the shared bit x = x_1 xor x_2 xor x_3 gets transformed into x = x'_1 + x'_2 + x'_3, with x being either 0 or 1.
 pub fn bit_inject<F: PrimeField, N: Rep3Network>(
     x: &Rep3BigUintShare<F>,
@@ -321,6 +551,52 @@ pub fn a2y<F: PrimeField, N: Rep3Network>(
     Ok(converted)
 }
 
+/// Transforms a vector of replicated shared values x from an arithmetic sharing to yao sharings. I.e., each x = x_1 + x_2 + x_3 gets transformed into wires, such that the garbler have keys (k_0, delta) for each bit of x, while the evaluator has k_x = k_0 xor delta * x. All conversions are done in one garbled circuit.
+pub fn a2y_many<F: PrimeField, N: Rep3Network>(
+    x: &[Rep3PrimeFieldShare<F>],
+    delta: Option<WireMod2>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<BinaryBundle<WireMod2>>> {
+    let [x01, x2] = yao::joint_input_arithmetic_added_many(x, delta, io_context)?;
+    let bitlen = F::MODULUS_BIT_SIZE as usize;
+
+    let converted = match io_context.id {
+        PartyID::ID0 => {
+            let mut evaluator = Rep3Evaluator::new(io_context);
+            evaluator.receive_circuit()?;
+            let mut res = Vec::with_capacity(x.len());
+            for (x01, x2) in izip!(x01.wires().chunks(bitlen), x2.wires().chunks(bitlen)) {
+                let x01 = BinaryBundle::new(x01.to_vec());
+                let x2 = BinaryBundle::new(x2.to_vec());
+                let added = GarbledCircuits::adder_mod_p::<_, F>(&mut evaluator, &x01, &x2);
+                res.push(GCUtils::garbled_circuits_error(added)?);
+            }
+            res
+        }
+        PartyID::ID1 | PartyID::ID2 => {
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+            let mut garbler = Rep3Garbler::new_with_delta(io_context, delta);
+            let mut res = Vec::with_capacity(x.len());
+            for (x01, x2) in izip!(x01.wires().chunks(bitlen), x2.wires().chunks(bitlen)) {
+                let x01 = BinaryBundle::new(x01.to_vec());
+                let x2 = BinaryBundle::new(x2.to_vec());
+                let added = GarbledCircuits::adder_mod_p::<_, F>(&mut garbler, &x01, &x2);
+                res.push(GCUtils::garbled_circuits_error(added)?);
+            }
+            garbler.send_circuit()?;
+            res
+        }
+    };
+
+    Ok(converted)
+}
+
 /// Transforms the replicated shared value x from an arithmetic sharing to a yao sharing. I.e., x = x_1 + x_2 + x_3 gets transformed into wires, such that the garbler have keys (k_0, delta) for each bit of x, while the evaluator has k_x = k_0 xor delta * x. Uses the Streaming Garbler/Evaluator.
 pub fn a2y_streaming<F: PrimeField, N: Rep3Network>(
     x: Rep3PrimeFieldShare<F>,
@@ -525,6 +801,152 @@ pub fn b2y<F: PrimeField, N: Rep3Network>(
     Ok(converted)
 }
 
+/// Transforms the shared values x from yao sharings to arithmetic sharings. I.e., the sharing such that the garbler have keys (k_0, delta) for each bit of x, while the evaluator has k_x = k_0 xor delta * x gets transformed into x = x_1 + x_2 + x_3. All conversions are done in one garbled circuit.
+///
+/// Keep in mind: Only works if the inputs are actually binary sharings of valid field elements, see [y2a].
+pub fn y2a_many<F: PrimeField, N: Rep3Network>(
+    x: Vec<BinaryBundle<WireMod2>>,
+    delta: Option<WireMod2>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    let n_inputs = x.len();
+    let bitlen = F::MODULUS_BIT_SIZE as usize;
+    let mut res = vec![Rep3PrimeFieldShare::zero_share(); n_inputs];
+
+    match io_context.id {
+        PartyID::ID0 => {
+            for res in res.iter_mut() {
+                let k3 = io_context.rngs.bitcomp2.random_fes_3keys::<F>();
+                res.b = (k3.0 + k3.1 + k3.2).neg();
+            }
+            let x23 = yao::input_field_id2_many::<F, _>(None, None, n_inputs, io_context)?;
+
+            let mut evaluator = Rep3Evaluator::new(io_context);
+            evaluator.receive_circuit()?;
+            let mut x1 = Vec::with_capacity(n_inputs * bitlen);
+            for (x, x23) in izip!(x.iter(), x23.wires().chunks(bitlen)) {
+                let x23 = BinaryBundle::new(x23.to_vec());
+                let added = GarbledCircuits::adder_mod_p::<_, F>(&mut evaluator, x, &x23);
+                x1.extend(GCUtils::garbled_circuits_error(added)?.wires().to_owned());
+            }
+            let x1 = evaluator.output_to_id0_and_id1(&x1)?;
+            for (res, x1) in izip!(res.iter_mut(), x1.chunks(bitlen)) {
+                res.a = GCUtils::bits_to_field(x1)?;
+            }
+        }
+        PartyID::ID1 => {
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+            for res in res.iter_mut() {
+                let k2 = io_context.rngs.bitcomp1.random_fes_3keys::<F>();
+                res.a = (k2.0 + k2.1 + k2.2).neg();
+            }
+            let x23 = yao::input_field_id2_many::<F, _>(None, None, n_inputs, io_context)?;
+
+            let mut garbler = Rep3Garbler::new_with_delta(io_context, delta);
+            let mut x1 = Vec::with_capacity(n_inputs * bitlen);
+            for (x, x23) in izip!(x.iter(), x23.wires().chunks(bitlen)) {
+                let x23 = BinaryBundle::new(x23.to_vec());
+                let added = GarbledCircuits::adder_mod_p::<_, F>(&mut garbler, x, &x23);
+                x1.extend(GCUtils::garbled_circuits_error(added)?.wires().to_owned());
+            }
+            let x1 = garbler.output_to_id0_and_id1(&x1)?;
+            let x1 = match x1 {
+                Some(x1) => x1,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "No output received",
+                ))?,
+            };
+            for (res, x1) in izip!(res.iter_mut(), x1.chunks(bitlen)) {
+                res.b = GCUtils::bits_to_field(x1)?;
+            }
+        }
+        PartyID::ID2 => {
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+            let mut x23 = Vec::with_capacity(n_inputs);
+            for res in res.iter_mut() {
+                let k2 = io_context.rngs.bitcomp1.random_fes_3keys::<F>();
+                let k3 = io_context.rngs.bitcomp2.random_fes_3keys::<F>();
+                let k2_comp = k2.0 + k2.1 + k2.2;
+                let k3_comp = k3.0 + k3.1 + k3.2;
+                x23.push(k2_comp + k3_comp);
+                res.a = k3_comp.neg();
+                res.b = k2_comp.neg();
+            }
+            let x23 = yao::input_field_id2_many(Some(x23), Some(delta), n_inputs, io_context)?;
+
+            let mut garbler = Rep3Garbler::new_with_delta(io_context, delta);
+            let mut x1 = Vec::with_capacity(n_inputs * bitlen);
+            for (x, x23) in izip!(x.iter(), x23.wires().chunks(bitlen)) {
+                let x23 = BinaryBundle::new(x23.to_vec());
+                let added = GarbledCircuits::adder_mod_p::<_, F>(&mut garbler, x, &x23);
+                x1.extend(GCUtils::garbled_circuits_error(added)?.wires().to_owned());
+            }
+            let x1 = garbler.output_to_id0_and_id1(&x1)?;
+            if x1.is_some() {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Unexpected output received",
+                ))?;
+            }
+        }
+    };
+
+    Ok(res)
+}
+
+/// Transforms the replicated shared values x from binary sharings to yao sharings. I.e., each x = x_1 xor x_2 xor x_3 gets transformed into wires, such that the garbler have keys (k_0, delta) for each bit of x, while the evaluator has k_x = k_0 xor delta * x. The inputs of all values are sent in one round.
+pub fn b2y_many<F: PrimeField, N: Rep3Network>(
+    x: &[Rep3BigUintShare<F>],
+    delta: Option<WireMod2>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<BinaryBundle<WireMod2>>> {
+    let bitlen = F::MODULUS_BIT_SIZE as usize;
+    let [x01, x2] = yao::joint_input_binary_xored_many(x, delta, io_context, bitlen)?;
+
+    let converted = match io_context.id {
+        PartyID::ID0 => {
+            // There is no code difference between Rep3Evaluator and StreamingRep3Evaluator
+            let mut evaluator = Rep3Evaluator::new(io_context);
+            // evaluator.receive_circuit()?; // No network used here
+            let res = GarbledCircuits::xor_many(&mut evaluator, &x01, &x2);
+            GCUtils::garbled_circuits_error(res)?
+        }
+        PartyID::ID1 | PartyID::ID2 => {
+            // There is no code difference between Rep3Garbler and StreamingRep3Garbler
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+            let mut garbler = Rep3Garbler::new_with_delta(io_context, delta);
+            let res = GarbledCircuits::xor_many(&mut garbler, &x01, &x2);
+            GCUtils::garbled_circuits_error(res)?
+            // garbler.send_circuit()?; // No network used here
+        }
+    };
+
+    Ok(converted
+        .wires()
+        .chunks(bitlen)
+        .map(|chunk| BinaryBundle::new(chunk.to_vec()))
+        .collect())
+}
+
 /// Transforms the shared value x from a yao sharing to a binary sharing. I.e., the sharing such that the garbler have keys (k_0, delta) for each bit of x, while the evaluator has k_x = k_0 xor delta * x gets transformed into x = x_1 xor x_2 xor x_3.
 pub fn y2b<F: PrimeField, N: Rep3Network>(
     x: BinaryBundle<WireMod2>,
@@ -621,6 +1043,19 @@ pub fn a2y2b<F: PrimeField, N: Rep3Network>(
     y2b(y, io_context)
 }
 
+/// Transforms the replicated shared values x from an arithmetic sharing to a binary sharing. I.e., x = x_1 + x_2 + x_3 gets transformed into x = x'_1 xor x'_2 xor x'_3. All conversions are done in one garbled circuit.
+pub fn a2y2b_many<F: PrimeField, N: Rep3Network>(
+    x: &[Rep3PrimeFieldShare<F>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3BigUintShare<F>>> {
+    if x.is_empty() {
+        return Ok(Vec::new());
+    }
+    let delta = io_context.rngs.generate_random_garbler_delta(io_context.id);
+    let y = a2y_many(x, delta, io_context)?;
+    y2b_many(y, io_context)
+}
+
 /// Transforms the replicated shared value x from an arithmetic sharing to a binary sharing. I.e., x = x_1 + x_2 + x_3 gets transformed into x = x'_1 xor x'_2 xor x'_3. Uses the Streaming Garbler/Evaluator.
 pub fn a2y2b_streaming<F: PrimeField, N: Rep3Network>(
     x: Rep3PrimeFieldShare<F>,
@@ -644,6 +1079,19 @@ pub fn b2y2a<F: PrimeField, N: Rep3Network>(
     y2a(y, delta, io_context)
 }
 
+/// Transforms the replicated shared values x from binary sharings to arithmetic sharings. I.e., x = x_1 xor x_2 xor x_3 gets transformed into x = x'_1 + x'_2 + x'_3. This implementations goes through the yao protocol, where all conversions are done in one garbled circuit, and currently works only for binary sharings of valid field elements, i.e., x = x_1 xor x_2 xor x_3 < p.
+pub fn b2y2a_many<F: PrimeField, N: Rep3Network>(
+    x: &[Rep3BigUintShare<F>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    if x.is_empty() {
+        return Ok(Vec::new());
+    }
+    let delta = io_context.rngs.generate_random_garbler_delta(io_context.id);
+    let y = b2y_many(x, delta, io_context)?;
+    y2a_many(y, delta, io_context)
+}
+
 /// Transforms the replicated shared value x from a binary sharing to an arithmetic sharing. I.e., x = x_1 xor x_2 xor x_3 gets transformed into x = x'_1 + x'_2 + x'_3. This implementations goes through the yao protocol and currently works only for a binary sharing of a valid field element, i.e., x = x_1 xor x_2 xor x_3 < p. Uses the Streaming Garbler/Evaluator.
 ///
 /// Keep in mind: Only works if the input is actually a binary sharing of a valid field element
@@ -441,18 +441,25 @@ pub(crate) fn point_from_xy<C: CurveGroup>(
         return Ok(C::zero());
     }
 
-    let point = if TypeId::of::<C>() == TypeId::of::<ark_bn254::G1Projective>() {
-        let x = *crate::downcast(&x).expect("We checked types");
-        let y = *crate::downcast(&y).expect("We checked types");
-        let result: ark_bn254::G1Projective = ark_bn254::G1Affine::new(x, y).into();
-        *crate::downcast(&result).expect("We checked types")
-    } else if TypeId::of::<C>() == TypeId::of::<ark_grumpkin::Projective>() {
-        let x = *crate::downcast(&x).expect("We checked types");
-        let y = *crate::downcast(&y).expect("We checked types");
-        let result: ark_grumpkin::Projective = ark_grumpkin::Affine::new(x, y).into();
-        *crate::downcast(&result).expect("We checked types")
-    } else {
-        panic!("Unsupported curve {}", std::any::type_name::<C>());
-    };
-    Ok(point)
+    // Creates the point for the first of the listed curves which matches C. A new curve only needs to be added to this list.
+    macro_rules! point_for_curves {
+        ($(($projective: ty, $affine: ty)),* $(,)?) => {
+            $(
+                if TypeId::of::<C>() == TypeId::of::<$projective>() {
+                    let x = *crate::downcast(&x).expect("We checked types");
+                    let y = *crate::downcast(&y).expect("We checked types");
+                    let result: $projective = <$affine>::new(x, y).into();
+                    return Ok(*crate::downcast(&result).expect("We checked types"));
+                }
+            )*
+        };
+    }
+    point_for_curves!(
+        (ark_bn254::G1Projective, ark_bn254::G1Affine),
+        (ark_grumpkin::Projective, ark_grumpkin::Affine),
+        (ark_bls12_377::G1Projective, ark_bls12_377::G1Affine),
+        (ark_bls12_381::G1Projective, ark_bls12_381::G1Affine),
+        (ark_secp256k1::Projective, ark_secp256k1::Affine),
+    );
+    panic!("Unsupported curve {}", std::any::type_name::<C>());
 }
@@ -16,7 +16,7 @@ use async_trait::async_trait;
 
 use super::{
     PartyID,
-    conversion::A2BType,
+    conversion::{A2BProtocol, A2BType, NetworkProfile},
     rngs::{Rep3CorrelatedRng, Rep3Rand, Rep3RandBitComp},
 };
 use rand::{CryptoRng, Rng, SeedableRng, distributions::Standard, prelude::Distribution};
@@ -34,6 +34,8 @@ pub struct IoContext<N: Rep3Network> {
     pub network: N,
     /// The used arithmetic/binary conversion protocol
     pub a2b_type: A2BType,
+    /// The network profile used to resolve [A2BType::Auto]
+    pub network_profile: NetworkProfile,
 }
 
 // impl<N: Rep3Network> Clone for IoContext<N> {
@@ -123,6 +125,7 @@ impl<N: Rep3Network> IoContext<N> {
             rngs,
             rng,
             a2b_type: A2BType::default(),
+            network_profile: NetworkProfile::default(),
         })
     }
 
@@ -131,6 +134,57 @@ impl<N: Rep3Network> IoContext<N> {
         self.a2b_type = a2b_type;
     }
 
+    /// Allows to change the network profile which is used to resolve [A2BType::Auto]. All parties need to set the same profile.
+    pub fn set_network_profile(&mut self, network_profile: NetworkProfile) {
+        self.network_profile = network_profile;
+    }
+
+    /// Returns the arithmetic/binary conversion protocol to use for converting `batch_size` elements with `bit_size` bits each, see [A2BType::resolve].
+    pub fn resolve_a2b_type(&self, batch_size: usize, bit_size: usize) -> A2BProtocol {
+        self.a2b_type
+            .resolve(batch_size, bit_size, &self.network_profile)
+    }
+
+    /// Measures the latency and bandwidth of the network and stores the result as the network profile of this [`IoContext`]. All parties need to call this function at the same time. The parties agree on the worst measured values, such that all of them resolve [A2BType::Auto] in the same way.
+    pub fn measure_network_profile(&mut self) -> IoResult<NetworkProfile> {
+        const ROUNDS: u32 = 10;
+        const BYTES: usize = 1 << 20;
+
+        let start = std::time::Instant::now();
+        for _ in 0..ROUNDS {
+            self.network.send_next(0u8)?;
+            let _: u8 = self.network.recv_prev()?;
+        }
+        let latency = start.elapsed() / ROUNDS;
+
+        let data = vec![0u8; BYTES];
+        let start = std::time::Instant::now();
+        self.network.send_next_many(&data)?;
+        let rcv: Vec<u8> = self.network.recv_prev_many()?;
+        let elapsed = start.elapsed().saturating_sub(latency);
+        if rcv.len() != BYTES {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Invalid number of elements received",
+            ));
+        }
+
+        let latency_micros = latency.as_micros() as u64;
+        let bandwidth_bytes_per_sec =
+            (BYTES as u128 * 1_000_000 / elapsed.as_micros().max(1)) as u64;
+
+        // Agree on the worst values
+        let (prev, next) = self
+            .network
+            .broadcast((latency_micros, bandwidth_bytes_per_sec))?;
+        let network_profile = NetworkProfile {
+            latency_micros: latency_micros.max(prev.0).max(next.0),
+            bandwidth_bytes_per_sec: bandwidth_bytes_per_sec.min(prev.1).min(next.1),
+        };
+        self.network_profile = network_profile;
+        Ok(network_profile)
+    }
+
     /// Cronstruct a fork of the [`IoContext`]. This fork can be used concurrently with its parent.
     pub fn fork(&mut self) -> IoResult<Self> {
         let network = self.network.fork()?;
@@ -138,6 +192,7 @@ impl<N: Rep3Network> IoContext<N> {
         let rng = RngType::from_seed(self.rng.r#gen());
         let id = self.id;
         let a2b_type = self.a2b_type;
+        let network_profile = self.network_profile;
 
         Ok(Self {
             id,
@@ -145,6 +200,7 @@ impl<N: Rep3Network> IoContext<N> {
             network,
             rng,
             a2b_type,
+            network_profile,
         })
     }
 
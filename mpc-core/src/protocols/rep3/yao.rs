@@ -6,6 +6,7 @@ pub mod bristol_fashion;
 pub mod circuits;
 pub mod evaluator;
 pub mod garbler;
+pub mod stats;
 pub mod streaming_evaluator;
 pub mod streaming_garbler;
 use super::network::{IoContext, Rep3Network};
@@ -593,6 +594,91 @@ pub fn joint_input_binary_xored<F: PrimeField, N: Rep3Network>(
     Ok([x01, x2])
 }
 
+/// Transforms a vector of binary shared inputs x = (x_1, x_2, x_3) into two yao shares x_1^Y, (x_2 xor x_3)^Y, where the wires of all inputs are concatenated. The used delta is an input to the function to allow for the same delta to be used for multiple conversions.
+pub fn joint_input_binary_xored_many<F: PrimeField, N: Rep3Network>(
+    x: &[Rep3BigUintShare<F>],
+    delta: Option<WireMod2>,
+    io_context: &mut IoContext<N>,
+    bitlen: usize,
+) -> IoResult<[BinaryBundle<WireMod2>; 2]> {
+    let id = io_context.id;
+    let bits = x.len() * bitlen;
+
+    let (x01, x2) = match id {
+        PartyID::ID0 => {
+            // Receive x01
+            let x01 = GCUtils::receive_bundle_from(bits, &mut io_context.network, PartyID::ID1)?;
+
+            // Receive x2
+            let x2 = GCUtils::receive_bundle_from(bits, &mut io_context.network, PartyID::ID2)?;
+            (x01, x2)
+        }
+        PartyID::ID1 => {
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+
+            let mut garbler_bundle = Vec::with_capacity(bits);
+            let mut evaluator_bundle = Vec::with_capacity(bits);
+
+            // Input x01
+            for x in x.iter() {
+                let xor = &x.a ^ &x.b;
+                let bits = GCUtils::biguint_to_bits_as_u16(&xor, bitlen);
+                let (garbler, evaluator) =
+                    GCUtils::encode_bits_as_wires(bits, &mut io_context.rng, delta);
+                garbler_bundle.extend(garbler);
+                evaluator_bundle.extend(evaluator);
+            }
+            let x01 = GCUtils::wires_to_gcinput(garbler_bundle, evaluator_bundle, delta);
+
+            // Send x01 to the other parties
+            GCUtils::send_inputs(&x01, &mut io_context.network, PartyID::ID2)?;
+            let x01 = x01.garbler_wires;
+
+            // Receive x2
+            let x2 = GCUtils::receive_bundle_from(bits, &mut io_context.network, PartyID::ID2)?;
+            (x01, x2)
+        }
+        PartyID::ID2 => {
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+
+            let mut garbler_bundle = Vec::with_capacity(bits);
+            let mut evaluator_bundle = Vec::with_capacity(bits);
+
+            // Input x2
+            for x in x.iter() {
+                let bits = GCUtils::biguint_to_bits_as_u16(&x.a, bitlen);
+                let (garbler, evaluator) =
+                    GCUtils::encode_bits_as_wires(bits, &mut io_context.rng, delta);
+                garbler_bundle.extend(garbler);
+                evaluator_bundle.extend(evaluator);
+            }
+            let x2 = GCUtils::wires_to_gcinput(garbler_bundle, evaluator_bundle, delta);
+
+            // Send x2 to the other parties
+            GCUtils::send_inputs(&x2, &mut io_context.network, PartyID::ID1)?;
+            let x2 = x2.garbler_wires;
+
+            // Receive x01
+            let x01 = GCUtils::receive_bundle_from(bits, &mut io_context.network, PartyID::ID1)?;
+            (x01, x2)
+        }
+    };
+
+    Ok([x01, x2])
+}
+
 /// Lets the party with id2 input a vector field elements, which gets shared as Yao wires to the other parties.
 pub fn input_field_id2_many<F: PrimeField, N: Rep3Network>(
     x: Option<Vec<F>>,
@@ -825,7 +911,7 @@ pub fn field_int_div_by_shared_many<F: PrimeField, N: Rep3Network>(
     )
 }
 
-/// Computes AES ciphertext from given plaintext, key and initialization vector using a bristol fashion circuit as a garbled circuit.
+/// Computes AES ciphertext in CBC mode with PKCS7 padding from given plaintext, key and initialization vector using a bristol fashion circuit as a garbled circuit.
 pub fn aes_from_bristol<F: PrimeField, N: Rep3Network>(
     plaintext: &[Rep3PrimeFieldShare<F>],
     key: &[Rep3PrimeFieldShare<F>],
@@ -853,6 +939,32 @@ pub fn aes_from_bristol<F: PrimeField, N: Rep3Network>(
     )
 }
 
+/// Computes AES ciphertext in CTR mode from given plaintext, key and initial counter block using a bristol fashion circuit as a garbled circuit. The counter is incremented as a 128-bit big-endian integer per block and no padding is applied, thus the ciphertext has the same length as the plaintext. Since the keystream is XORed to the input, the same function decrypts a ciphertext.
+pub fn aes128_ctr_from_bristol<F: PrimeField, N: Rep3Network>(
+    plaintext: &[Rep3PrimeFieldShare<F>],
+    key: &[Rep3PrimeFieldShare<F>],
+    iv: &[Rep3PrimeFieldShare<F>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    const AES_BLOCK_SIZE: usize = 16;
+    debug_assert_eq!(key.len(), AES_BLOCK_SIZE);
+    debug_assert_eq!(iv.len(), AES_BLOCK_SIZE);
+
+    let mut combined_inputs = Vec::with_capacity(key.len() + plaintext.len() + iv.len());
+    combined_inputs.extend_from_slice(plaintext);
+    combined_inputs.extend_from_slice(key);
+    combined_inputs.extend_from_slice(iv);
+
+    let total_output_elements = plaintext.len();
+    decompose_circuit_compose_blueprint!(
+        &combined_inputs,
+        io_context,
+        total_output_elements,
+        GarbledCircuits::aes128_ctr::<_, F>,
+        (plaintext.len())
+    )
+}
+
 /// Divides a field element by another, rounding down.
 pub fn field_int_div_by_shared<F: PrimeField, N: Rep3Network>(
     input: F,
@@ -1217,6 +1329,58 @@ pub fn sha256_from_bristol<F: PrimeField, N: Rep3Network>(
     )
 }
 
+/// Computes the SHA256 hash of the shared bytes, i.e., the message is padded and compressed block by block using the Bristol fashion compression circuit. Each input is interpreted as one byte, the output consists of the 32 bytes of the digest.
+pub fn sha256_hash_from_bristol<F: PrimeField, N: Rep3Network>(
+    message: &[Rep3PrimeFieldShare<F>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    let total_output_elements = 32;
+
+    decompose_circuit_compose_blueprint!(
+        message,
+        io_context,
+        total_output_elements,
+        GarbledCircuits::sha256::<_, F>,
+        ()
+    )
+}
+
+/// Computes HMAC-SHA256 of the shared message bytes with the shared key bytes using the Bristol fashion SHA256 compression circuit. Keys longer than 64 bytes are hashed first. Each input is interpreted as one byte, the output consists of the 32 bytes of the MAC.
+pub fn hmac_sha256_from_bristol<F: PrimeField, N: Rep3Network>(
+    key: &[Rep3PrimeFieldShare<F>],
+    message: &[Rep3PrimeFieldShare<F>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    let mut combined_inputs = Vec::with_capacity(key.len() + message.len());
+    combined_inputs.extend_from_slice(key);
+    combined_inputs.extend_from_slice(message);
+    let total_output_elements = 32;
+
+    decompose_circuit_compose_blueprint!(
+        &combined_inputs,
+        io_context,
+        total_output_elements,
+        GarbledCircuits::hmac_sha256::<_, F>,
+        (key.len())
+    )
+}
+
+/// Computes the SHA512 hash of the shared bytes. Each input is interpreted as one byte, the output consists of the 64 bytes of the digest.
+pub fn sha512<F: PrimeField, N: Rep3Network>(
+    message: &[Rep3PrimeFieldShare<F>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    let total_output_elements = 64;
+
+    decompose_circuit_compose_blueprint!(
+        message,
+        io_context,
+        total_output_elements,
+        GarbledCircuits::sha512::<_, F>,
+        ()
+    )
+}
+
 /// Slices two slices of field elements, does XOR on the slices and then rotates them. The rotation is done on 32-bit values. Base_bit is the size of the slices, rotation the the length of the rotation and total_output_bitlen_per_field is the amount of bits per input. It also prepares the (rotated) slices into 32 bits such that these can be multiplied with the base powers and then summed up. See get_sparse_table_with_rotation_values in co-noir/co-builder/src/types/plookup.rs for the intended functionality.
 pub fn get_sparse_table_with_rotation_values_many<F: PrimeField, N: Rep3Network>(
     input1: &[Rep3PrimeFieldShare<F>],
@@ -3,6 +3,8 @@ mod builder;
 /// Parsing code for Bristol Fashion circuits
 mod parser;
 
+pub use builder::{BristolFashionCircuitBuilder, BristolFashionWire};
+
 use core::panic;
 use std::{
     cmp::max,
@@ -73,22 +75,21 @@ pub struct BristolFashionCircuit {
     gates: Vec<BristolFashionGate>,
     num_and_gates: usize,
 }
-#[expect(dead_code)]
 impl BristolFashionCircuit {
     /// Parse a Bristol-Fashion style circuit from a file
-    pub(crate) fn from_file(path: impl AsRef<Path>) -> Result<Self, CircuitBuilderError> {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, CircuitBuilderError> {
         let circuit = std::fs::read_to_string(path)?;
         parser::parse(&circuit)?.verify()
     }
     /// Parse a Bristol-Fashion style circuit from a reader
-    pub(crate) fn from_reader(mut reader: impl Read) -> Result<Self, CircuitBuilderError> {
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, CircuitBuilderError> {
         let mut circuit = String::new();
         reader.read_to_string(&mut circuit)?;
         parser::parse(&circuit)?.verify()
     }
 
     /// Transform circuit into a leveled circuit, for more efficient execution
-    pub(crate) fn level(self) -> LeveledBristolFashionCircuit {
+    pub fn level(self) -> LeveledBristolFashionCircuit {
         let BristolFashionCircuit {
             input_wires,
             output_wires,
@@ -244,29 +245,70 @@ impl BristolFashionCircuit {
         }
     }
     /// Returns the total number of AND gates
-    pub(crate) fn num_and_gates(&self) -> usize {
+    pub fn num_and_gates(&self) -> usize {
         self.num_and_gates
     }
+    /// Returns the total number of XOR gates
+    pub fn num_xor_gates(&self) -> usize {
+        self.gates
+            .iter()
+            .filter(|gate| matches!(gate, BristolFashionGate::Xor { .. }))
+            .count()
+    }
+    /// Returns the total number of INV gates
+    pub fn num_inv_gates(&self) -> usize {
+        self.gates
+            .iter()
+            .filter(|gate| matches!(gate, BristolFashionGate::Inv { .. }))
+            .count()
+    }
+    /// Returns the multiplicative depth of the circuit, i.e., the maximum number of AND gates on a path from an input to an output wire
+    pub fn and_depth(&self) -> usize {
+        let mut depth = vec![0; self.num_wires()];
+        for gate in self.gates.iter() {
+            match gate {
+                BristolFashionGate::EqConst { .. } => {}
+                BristolFashionGate::EqWire { inwire, outwire }
+                | BristolFashionGate::Inv { inwire, outwire } => depth[*outwire] = depth[*inwire],
+                BristolFashionGate::Xor {
+                    inwire1,
+                    inwire2,
+                    outwire,
+                } => depth[*outwire] = max(depth[*inwire1], depth[*inwire2]),
+                BristolFashionGate::And {
+                    inwire1,
+                    inwire2,
+                    outwire,
+                } => depth[*outwire] = max(depth[*inwire1], depth[*inwire2]) + 1,
+            }
+        }
+        self.output_wires
+            .iter()
+            .flatten()
+            .map(|wire| depth[*wire])
+            .max()
+            .unwrap_or_default()
+    }
     /// Returns the total number of input wires
-    pub(crate) fn num_input_wires(&self) -> usize {
+    pub fn num_input_wires(&self) -> usize {
         self.input_wires.iter().flatten().count()
     }
     /// Returns the input wires
-    pub(crate) fn get_input_wires(&self) -> Vec<Vec<usize>> {
+    pub fn get_input_wires(&self) -> Vec<Vec<usize>> {
         self.input_wires.to_owned()
     }
     /// Returns the output wires
-    pub(crate) fn get_output_wires(&self) -> Vec<Vec<usize>> {
+    pub fn get_output_wires(&self) -> Vec<Vec<usize>> {
         self.output_wires.to_owned()
     }
 
     /// Returns the total number of wires in the circuit, including input +  output wires
-    pub(crate) fn num_wires(&self) -> usize {
+    pub fn num_wires(&self) -> usize {
         self.num_input_wires() + self.gates.len()
     }
 
     /// Evaluate the Bristol-Fashion circuit
-    pub(crate) fn evaluate<T>(
+    pub fn evaluate<T>(
         &self,
         inputs: &[impl AsRef<[T]>],
         evaluator: &mut impl BristolFashionEvaluator<WireValue = T>,
@@ -278,7 +320,7 @@ impl BristolFashionCircuit {
     }
 
     /// Evaluate the Bristol-Fashion circuit from a given default value, such that the Default trait not being required
-    pub(crate) fn evaluate_with_default<T>(
+    pub fn evaluate_with_default<T>(
         &self,
         inputs: &[impl AsRef<[T]>],
         evaluator: &mut impl BristolFashionEvaluator<WireValue = T>,
@@ -366,7 +408,8 @@ impl BristolFashionCircuit {
             .collect::<Vec<Vec<T>>>())
     }
 
-    pub(crate) fn write_circuit_file(&self, writer: &mut impl Write) -> Result<(), std::io::Error> {
+    /// Writes the circuit in Bristol Fashion format
+    pub fn write_circuit_file(&self, writer: &mut impl Write) -> Result<(), std::io::Error> {
         writeln!(writer, "{} {}", self.gates.len(), self.num_wires())?;
         write!(writer, "{} ", self.input_wires.len())?;
         for inputs in self.input_wires.iter() {
@@ -633,6 +676,7 @@ pub trait BristolFashionEvaluator {
     }
 }
 
+/// Evaluates a Bristol Fashion circuit on plain booleans
 pub struct BoolBristolFashionEvaluator;
 
 impl BristolFashionEvaluator for BoolBristolFashionEvaluator {
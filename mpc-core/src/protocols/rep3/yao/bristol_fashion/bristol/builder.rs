@@ -1,8 +1,13 @@
 use std::collections::HashSet;
 
-use crate::protocols::rep3::yao::bristol_fashion::CircuitBuilderError;
+use fancy_garbling::{BinaryBundle, Fancy, FancyBinary, HasModulus};
 
-use super::{BristolFashionCircuit, BristolFashionGate};
+use crate::protocols::rep3::yao::{
+    bristol_fashion::{CircuitBuilderError, CircuitExecutionError},
+    circuits::FancyBinaryConstant,
+};
+
+use super::{BristolFashionCircuit, BristolFashionEvaluator, BristolFashionGate};
 
 /// A Bristol Fashion Circuit
 pub struct UnverifiedBristolFashionCircuit {
@@ -139,3 +144,222 @@ impl UnverifiedBristolFashionCircuit {
         })
     }
 }
+
+/// A wire of a circuit recorded by the [BristolFashionCircuitBuilder]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BristolFashionWire(usize);
+
+impl BristolFashionWire {
+    /// Returns the id of the wire in the recorded circuit
+    pub fn id(&self) -> usize {
+        self.0
+    }
+}
+
+impl HasModulus for BristolFashionWire {
+    fn modulus(&self) -> u16 {
+        2
+    }
+}
+
+/// Records the gates of a binary circuit (e.g., one of the [GarbledCircuits](crate::protocols::rep3::yao::circuits::GarbledCircuits)) to produce a [BristolFashionCircuit].
+///
+/// Since the input wires of a Bristol Fashion circuit are the first wires, all input bundles have to be added before the first gate is recorded. The output wires are copied to the end of the circuit when building it.
+#[derive(Debug, Default)]
+pub struct BristolFashionCircuitBuilder {
+    num_wires: usize,
+    input_wires: Vec<Vec<usize>>,
+    gates: Vec<BristolFashionGate>,
+    const_zero: Option<BristolFashionWire>,
+    const_one: Option<BristolFashionWire>,
+}
+
+impl BristolFashionCircuitBuilder {
+    /// Creates a new builder for an empty circuit
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a new input bundle of the given size to the circuit and returns its wires
+    pub fn add_input_bundle(
+        &mut self,
+        size: usize,
+    ) -> Result<BinaryBundle<BristolFashionWire>, CircuitBuilderError> {
+        if !self.gates.is_empty() {
+            return Err(CircuitBuilderError::InvalidCircuit(
+                "input bundles have to be added before the first gate".to_string(),
+            ));
+        }
+        let wires = (self.num_wires..self.num_wires + size).collect::<Vec<_>>();
+        self.num_wires += size;
+        let bundle = BinaryBundle::new(wires.iter().copied().map(BristolFashionWire).collect());
+        self.input_wires.push(wires);
+        Ok(bundle)
+    }
+
+    /// Returns the number of gates recorded so far
+    pub fn num_gates(&self) -> usize {
+        self.gates.len()
+    }
+
+    fn next_wire(&mut self) -> usize {
+        let wire = self.num_wires;
+        self.num_wires += 1;
+        wire
+    }
+
+    fn eq_const(&mut self, input: bool) -> BristolFashionWire {
+        let outwire = self.next_wire();
+        self.gates
+            .push(BristolFashionGate::EqConst { input, outwire });
+        BristolFashionWire(outwire)
+    }
+
+    fn record_inv(&mut self, a: &BristolFashionWire) -> BristolFashionWire {
+        let outwire = self.next_wire();
+        self.gates.push(BristolFashionGate::Inv {
+            inwire: a.0,
+            outwire,
+        });
+        BristolFashionWire(outwire)
+    }
+
+    fn record_xor(&mut self, a: &BristolFashionWire, b: &BristolFashionWire) -> BristolFashionWire {
+        let outwire = self.next_wire();
+        self.gates.push(BristolFashionGate::Xor {
+            inwire1: a.0,
+            inwire2: b.0,
+            outwire,
+        });
+        BristolFashionWire(outwire)
+    }
+
+    fn record_and(&mut self, a: &BristolFashionWire, b: &BristolFashionWire) -> BristolFashionWire {
+        let outwire = self.next_wire();
+        self.gates.push(BristolFashionGate::And {
+            inwire1: a.0,
+            inwire2: b.0,
+            outwire,
+        });
+        BristolFashionWire(outwire)
+    }
+
+    /// Builds and verifies the recorded circuit with the provided output bundles
+    pub fn build(
+        mut self,
+        outputs: &[BinaryBundle<BristolFashionWire>],
+    ) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+        // Bristol Fashion requires the output wires to be the last wires of the circuit
+        let mut output_wires = Vec::with_capacity(outputs.len());
+        for bundle in outputs {
+            let mut wires = Vec::with_capacity(bundle.size());
+            for wire in bundle.wires() {
+                let outwire = self.next_wire();
+                self.gates.push(BristolFashionGate::EqWire {
+                    inwire: wire.0,
+                    outwire,
+                });
+                wires.push(outwire);
+            }
+            output_wires.push(wires);
+        }
+
+        UnverifiedBristolFashionCircuit {
+            num_wires: self.num_wires,
+            input_wires: self.input_wires,
+            output_wires,
+            gates: self.gates,
+        }
+        .verify()
+    }
+}
+
+impl Fancy for BristolFashionCircuitBuilder {
+    type Item = BristolFashionWire;
+    type Error = CircuitBuilderError;
+
+    fn constant(&mut self, x: u16, q: u16) -> Result<Self::Item, Self::Error> {
+        if q != 2 {
+            return Err(CircuitBuilderError::InvalidCircuit(format!(
+                "only binary wires are supported, got modulus {q}"
+            )));
+        }
+        Ok(self.eq_const(x != 0))
+    }
+
+    fn output(&mut self, _: &Self::Item) -> Result<Option<u16>, Self::Error> {
+        Ok(None)
+    }
+}
+
+impl FancyBinary for BristolFashionCircuitBuilder {
+    fn and(&mut self, a: &Self::Item, b: &Self::Item) -> Result<Self::Item, Self::Error> {
+        Ok(self.record_and(a, b))
+    }
+
+    fn xor(&mut self, a: &Self::Item, b: &Self::Item) -> Result<Self::Item, Self::Error> {
+        Ok(self.record_xor(a, b))
+    }
+
+    fn negate(&mut self, a: &Self::Item) -> Result<Self::Item, Self::Error> {
+        Ok(self.record_inv(a))
+    }
+}
+
+impl FancyBinaryConstant for BristolFashionCircuitBuilder {
+    fn const_zero(&mut self) -> Result<Self::Item, Self::Error> {
+        let zero = match self.const_zero {
+            Some(zero) => zero,
+            None => {
+                let zero = self.eq_const(false);
+                self.const_zero = Some(zero);
+                zero
+            }
+        };
+        Ok(zero)
+    }
+
+    fn const_one(&mut self) -> Result<Self::Item, Self::Error> {
+        let one = match self.const_one {
+            Some(one) => one,
+            None => {
+                let one = self.eq_const(true);
+                self.const_one = Some(one);
+                one
+            }
+        };
+        Ok(one)
+    }
+}
+
+impl BristolFashionEvaluator for BristolFashionCircuitBuilder {
+    type WireValue = BristolFashionWire;
+
+    fn constant(&mut self, input: bool) -> Result<Self::WireValue, CircuitExecutionError> {
+        Ok(match input {
+            true => self.const_one(),
+            false => self.const_zero(),
+        }
+        .map_err(|e| std::io::Error::other(e.to_string()))?)
+    }
+
+    fn inv(&mut self, input: &Self::WireValue) -> Result<Self::WireValue, CircuitExecutionError> {
+        Ok(self.record_inv(input))
+    }
+
+    fn xor(
+        &mut self,
+        input1: &Self::WireValue,
+        input2: &Self::WireValue,
+    ) -> Result<Self::WireValue, CircuitExecutionError> {
+        Ok(self.record_xor(input1, input2))
+    }
+
+    fn and(
+        &mut self,
+        input1: &Self::WireValue,
+        input2: &Self::WireValue,
+    ) -> Result<Self::WireValue, CircuitExecutionError> {
+        Ok(self.record_and(input1, input2))
+    }
+}
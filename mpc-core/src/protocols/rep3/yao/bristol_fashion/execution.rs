@@ -0,0 +1,163 @@
+//! Execution of user-supplied Bristol Fashion circuits on Rep3 shares using the streaming garbler/evaluator, and on public values
+
+use ark_ff::PrimeField;
+use fancy_garbling::{BinaryBundle, FancyBinary, WireMod2};
+use itertools::{Itertools, izip};
+use num_bigint::BigUint;
+
+use super::{BoolBristolFashionEvaluator, BristolFashionCircuit, BristolFashionEvaluator};
+use crate::{
+    IoResult,
+    protocols::rep3::{
+        PartyID, Rep3BigUintShare, Rep3PrimeFieldShare, conversion,
+        network::{IoContext, Rep3Network},
+        yao::{
+            self, GCUtils, circuits::GarbledCircuits, streaming_evaluator::StreamingRep3Evaluator,
+            streaming_garbler::StreamingRep3Garbler,
+        },
+    },
+};
+
+fn evaluate_circuit<G>(
+    g: &mut G,
+    circuit: &BristolFashionCircuit,
+    inputs: Vec<[BinaryBundle<WireMod2>; 2]>,
+) -> IoResult<Vec<BinaryBundle<WireMod2>>>
+where
+    G: FancyBinary<Item = WireMod2> + BristolFashionEvaluator<WireValue = WireMod2>,
+{
+    let mut wires = Vec::with_capacity(inputs.len());
+    for [x01, x2] in inputs {
+        let xored = GarbledCircuits::xor_many(g, &x01, &x2);
+        wires.push(GCUtils::garbled_circuits_error(xored)?.wires().to_vec());
+    }
+    let outputs = circuit
+        .evaluate(&wires, g)
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+    Ok(outputs.into_iter().map(BinaryBundle::new).collect())
+}
+
+/// Executes a Bristol Fashion circuit on binary shared inputs. The i-th input share is mapped to the i-th input bundle of the circuit, whereas only the lowest bits of the share (according to the size of the bundle) are used. The circuit is garbled and evaluated with the streaming garbler/evaluator and binary shares of the output bundles are returned, in the order of the output bundles of the circuit.
+pub fn execute_on_binary_shares<F: PrimeField, N: Rep3Network>(
+    circuit: &BristolFashionCircuit,
+    inputs: &[Rep3BigUintShare<F>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3BigUintShare<F>>> {
+    let input_sizes = circuit.get_input_wires().iter().map(Vec::len).collect_vec();
+    if inputs.len() != input_sizes.len() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "Provided {} inputs, circuit requires {} input bundles",
+                inputs.len(),
+                input_sizes.len()
+            ),
+        ));
+    }
+
+    let delta = io_context.rngs.generate_random_garbler_delta(io_context.id);
+    let mut bundles = Vec::with_capacity(inputs.len());
+    for (x, size) in izip!(inputs, input_sizes) {
+        bundles.push(yao::joint_input_binary_xored(x, delta, io_context, size)?);
+    }
+
+    let outputs = match io_context.id {
+        PartyID::ID0 => {
+            let mut evaluator = StreamingRep3Evaluator::new(io_context);
+            let res = evaluate_circuit(&mut evaluator, circuit, bundles)?;
+            evaluator.receive_hash()?;
+            res
+        }
+        PartyID::ID1 | PartyID::ID2 => {
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+            let mut garbler = StreamingRep3Garbler::new_with_delta(io_context, delta);
+            let res = evaluate_circuit(&mut garbler, circuit, bundles)?;
+            garbler.send_hash()?;
+            res
+        }
+    };
+
+    conversion::y2b_many(outputs, io_context)
+}
+
+/// Executes a Bristol Fashion circuit on arithmetic shared inputs. The i-th input share is mapped to the i-th input bundle of the circuit, i.e., the shared values must be smaller than 2^(bundle size). The output bundles are interpreted as little-endian integers and are returned as arithmetic shares, thus they need to be smaller than the field modulus.
+///
+/// The arithmetic/binary conversions are selected according to the `A2BType` of the io_context, while the circuit itself is executed using [execute_on_binary_shares].
+pub fn execute_on_arithmetic_shares<F: PrimeField, N: Rep3Network>(
+    circuit: &BristolFashionCircuit,
+    inputs: &[Rep3PrimeFieldShare<F>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    let bitlen = F::MODULUS_BIT_SIZE as usize;
+    if circuit
+        .get_input_wires()
+        .iter()
+        .any(|wires| wires.len() > bitlen)
+    {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Input bundles of the circuit must not exceed {bitlen} bits"),
+        ));
+    }
+    if circuit
+        .get_output_wires()
+        .iter()
+        .any(|wires| wires.len() >= bitlen)
+    {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Output bundles of the circuit must have less than {bitlen} bits"),
+        ));
+    }
+
+    let binary = conversion::a2b_many_selector(inputs, io_context)?;
+    let outputs = execute_on_binary_shares(circuit, &binary, io_context)?;
+    conversion::b2a_many_selector(&outputs, io_context)
+}
+
+/// Executes a Bristol Fashion circuit on public field elements, which is the plain counterpart of [`execute_on_arithmetic_shares`]. The i-th input is mapped to the i-th input bundle of the circuit and must fit into it. The output bundles are returned as field elements, in the order of the output bundles of the circuit.
+pub fn execute_on_field_elements<F: PrimeField>(
+    circuit: &BristolFashionCircuit,
+    inputs: &[F],
+) -> IoResult<Vec<F>> {
+    let input_wires = circuit.get_input_wires();
+    if inputs.len() != input_wires.len() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "Provided {} inputs, circuit requires {} input bundles",
+                inputs.len(),
+                input_wires.len()
+            ),
+        ));
+    }
+    let inputs = izip!(inputs, input_wires.iter())
+        .map(|(x, wires)| {
+            let x: BigUint = (*x).into();
+            if x.bits() > wires.len() as u64 {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("Input does not fit into {} bits", wires.len()),
+                ));
+            }
+            Ok((0..wires.len()).map(|i| x.bit(i as u64)).collect_vec())
+        })
+        .collect::<IoResult<Vec<_>>>()?;
+    let outputs = circuit
+        .evaluate(&inputs, &mut BoolBristolFashionEvaluator)
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+    Ok(outputs
+        .into_iter()
+        .map(|bits| {
+            bits.into_iter()
+                .rev()
+                .fold(F::ZERO, |acc, bit| acc.double() + F::from(bit))
+        })
+        .collect())
+}
@@ -0,0 +1,155 @@
+//! Export of the hand-written [GarbledCircuits] as Bristol Fashion circuits, such that they can be audited, their gates can be counted, and they can be reused by other MPC frameworks.
+//!
+//! All bundles are encoded little-endian, i.e., the first wire of a bundle corresponds to the least significant bit. The SHA-256 compression and AES-128 circuits are not exported, since they are already evaluated from Bristol Fashion files.
+
+use ark_ff::PrimeField;
+use fancy_garbling::BinaryBundle;
+
+use super::{
+    BristolFashionCircuit, BristolFashionCircuitBuilder, BristolFashionWire, CircuitBuilderError,
+};
+use crate::protocols::rep3::yao::circuits::GarbledCircuits;
+
+/// Returns the circuit adding two `bitlen`-bit values mod 2^`bitlen`.
+pub fn adder_mod_2k(bitlen: usize) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    let mut builder = BristolFashionCircuitBuilder::new();
+    let a = builder.add_input_bundle(bitlen)?;
+    let b = builder.add_input_bundle(bitlen)?;
+    let sum = GarbledCircuits::adder_mod_2k(&mut builder, &a, &b)?;
+    builder.build(&[sum])
+}
+
+/// Returns the circuit adding two field elements mod p, each represented with `F::MODULUS_BIT_SIZE` bits.
+pub fn adder_mod_p<F: PrimeField>() -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    let bitlen = F::MODULUS_BIT_SIZE as usize;
+    let mut builder = BristolFashionCircuitBuilder::new();
+    let a = builder.add_input_bundle(bitlen)?;
+    let b = builder.add_input_bundle(bitlen)?;
+    let sum = GarbledCircuits::adder_mod_p::<_, F>(&mut builder, &a, &b)?;
+    builder.build(&[sum])
+}
+
+/// Returns the circuit computing the integer division of two `bitlen`-bit values. The inputs are the dividend and the divisor, the output is the quotient.
+pub fn ring_div(bitlen: usize) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    let mut builder = BristolFashionCircuitBuilder::new();
+    let dividend = builder.add_input_bundle(bitlen)?;
+    let divisor = builder.add_input_bundle(bitlen)?;
+    let quotient = GarbledCircuits::bin_div(&mut builder, dividend.wires(), divisor.wires())?;
+    builder.build(&[BinaryBundle::new(quotient)])
+}
+
+/// Returns the circuit of the BLAKE2s compression function with the given public counter `t` and finalization flags `f`. The inputs are the 16 32-bit words of the message block, followed by the 8 32-bit words of the chaining value. The outputs are the 8 32-bit words of the new chaining value.
+pub fn blake2s_compress(
+    t: [u32; 2],
+    f: [u32; 2],
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    let mut builder = BristolFashionCircuitBuilder::new();
+    let input = add_words(&mut builder, 16)?;
+    let h = add_words(&mut builder, 8)?;
+    let output = GarbledCircuits::blake2s_compress(&mut builder, &input, &h, t, f)?;
+    build_words(builder, output)
+}
+
+/// Returns the circuit of the BLAKE3 compression function with the given public counter `t`, block length and flags. The inputs are the 16 32-bit words of the message block, followed by the 8 32-bit words of the chaining value. The outputs are the 8 32-bit words of the new chaining value.
+pub fn blake3_compress(
+    t: [u32; 2],
+    blocklen: u32,
+    flags: u32,
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    let mut builder = BristolFashionCircuitBuilder::new();
+    let input = add_words(&mut builder, 16)?;
+    let h = add_words(&mut builder, 8)?;
+    let output = GarbledCircuits::blake3_compress(&mut builder, &input, &h, t, blocklen, flags)?;
+    build_words(builder, output)
+}
+
+fn add_words(
+    builder: &mut BristolFashionCircuitBuilder,
+    num_words: usize,
+) -> Result<Vec<Vec<BristolFashionWire>>, CircuitBuilderError> {
+    (0..num_words)
+        .map(|_| Ok(builder.add_input_bundle(32)?.wires().to_vec()))
+        .collect()
+}
+
+fn build_words(
+    builder: BristolFashionCircuitBuilder,
+    words: Vec<Vec<BristolFashionWire>>,
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    let outputs = words.into_iter().map(BinaryBundle::new).collect::<Vec<_>>();
+    builder.build(&outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::rep3::yao::bristol_fashion::BoolBristolFashionEvaluator;
+    use rand::{Rng, thread_rng};
+
+    fn to_bits(x: u64, bitlen: usize) -> Vec<bool> {
+        (0..bitlen).map(|i| (x >> i) & 1 == 1).collect()
+    }
+
+    fn from_bits(bits: &[bool]) -> u64 {
+        bits.iter()
+            .rev()
+            .fold(0, |acc, bit| (acc << 1) | u64::from(*bit))
+    }
+
+    fn reparse(circuit: &BristolFashionCircuit) -> BristolFashionCircuit {
+        let mut file = Vec::new();
+        circuit.write_circuit_file(&mut file).unwrap();
+        BristolFashionCircuit::from_reader(file.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn adder_mod_2k_gate_count() {
+        let circuit = adder_mod_2k(64).unwrap();
+        assert_eq!(circuit.num_and_gates(), 63);
+        assert_eq!(reparse(&circuit).num_and_gates(), 63);
+    }
+
+    #[test]
+    fn adder_mod_2k_evaluate() {
+        let mut rng = thread_rng();
+        let circuit = reparse(&adder_mod_2k(32).unwrap());
+        for _ in 0..10 {
+            let a = rng.r#gen::<u32>();
+            let b = rng.r#gen::<u32>();
+            let output = circuit
+                .evaluate(
+                    &[to_bits(a.into(), 32), to_bits(b.into(), 32)],
+                    &mut BoolBristolFashionEvaluator,
+                )
+                .unwrap();
+            assert_eq!(from_bits(&output[0]), u64::from(a.wrapping_add(b)));
+        }
+    }
+
+    #[test]
+    fn ring_div_evaluate() {
+        let mut rng = thread_rng();
+        let circuit = reparse(&ring_div(16).unwrap());
+        for _ in 0..10 {
+            let a = rng.r#gen::<u16>();
+            let b = rng.gen_range(1..=u16::MAX);
+            let output = circuit
+                .evaluate(
+                    &[to_bits(a.into(), 16), to_bits(b.into(), 16)],
+                    &mut BoolBristolFashionEvaluator,
+                )
+                .unwrap();
+            assert_eq!(from_bits(&output[0]), u64::from(a / b));
+        }
+    }
+
+    #[test]
+    fn blake_compress_gate_count() {
+        // 6 32-bit additions per mix, 8 mixes per round
+        let blake2s = blake2s_compress([64, 0], [0xFFFFFFFF, 0]).unwrap();
+        assert_eq!(blake2s.num_and_gates(), 10 * 8 * 6 * 31);
+        let blake3 = blake3_compress([0, 0], 64, 11).unwrap();
+        assert_eq!(blake3.num_and_gates(), 7 * 8 * 6 * 31);
+        assert_eq!(reparse(&blake3).num_and_gates(), 7 * 8 * 6 * 31);
+    }
+}
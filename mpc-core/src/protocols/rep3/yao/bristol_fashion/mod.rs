@@ -2,7 +2,15 @@
 
 use thiserror::Error;
 mod bristol;
-pub use bristol::{BristolFashionCircuit, BristolFashionEvaluator, LeveledBristolFashionCircuit};
+mod execution;
+pub mod export;
+pub use bristol::{
+    BoolBristolFashionEvaluator, BristolFashionCircuit, BristolFashionCircuitBuilder,
+    BristolFashionEvaluator, BristolFashionWire, LeveledBristolFashionCircuit,
+};
+pub use execution::{
+    execute_on_arithmetic_shares, execute_on_binary_shares, execute_on_field_elements,
+};
 
 /// Errors that happen during parsing of circuits
 #[derive(Error, Debug)]
@@ -16,6 +24,9 @@ pub enum CircuitBuilderError {
     /// The built/parsed circuit is invalid
     #[error("{0}")]
     InvalidCircuit(String),
+    /// An Error has occured while recording a circuit
+    #[error("{0}")]
+    FancyError(#[from] fancy_garbling::errors::FancyError),
 }
 
 /// Errors that happen during execution of circuits
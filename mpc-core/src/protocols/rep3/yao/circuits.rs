@@ -407,7 +407,7 @@ impl GarbledCircuits {
 
     // From swanky:
     /// Binary division
-    fn bin_div<G: FancyBinary + FancyBinaryConstant>(
+    pub(crate) fn bin_div<G: FancyBinary + FancyBinaryConstant>(
         g: &mut G,
         dividend: &[G::Item],
         divisor: &[G::Item],
@@ -991,6 +991,22 @@ impl GarbledCircuits {
         g.negate(&ge)
     }
 
+    /// Computes a < b, where b is a public constant given as little-endian bits of the same length as a.
+    fn unsigned_lt_const<G: FancyBinary + FancyBinaryConstant>(
+        g: &mut G,
+        a: &[G::Item],
+        b: &[bool],
+    ) -> Result<G::Item, G::Error> {
+        debug_assert_eq!(a.len(), b.len());
+        // Twos complement is negation + 1, we implement by having cin in adder = 1, so only negation is required
+        let (_, mut c) = Self::full_adder_const_cin_set(g, &a[0], b[0].not())?;
+        for (x, y) in a.iter().zip(b.iter()).skip(1) {
+            let (_, c_) = Self::full_adder_const(g, x, y.not(), &c)?;
+            c = c_;
+        }
+        g.negate(&c)
+    }
+
     #[expect(dead_code)]
     fn unsigned_le<G: FancyBinary>(
         g: &mut G,
@@ -1591,6 +1607,31 @@ impl GarbledCircuits {
         Ok(BinaryBundle::new(results))
     }
 
+    /// Compares a vector of ring elements to a public bound. Each ring element is represented as two bitdecompositions wires_a, wires_b of length bound.len(), which need to be added first. The addition is done modulo 2^bound.len(), i.e., only the lowest bound.len() bits of the ring elements are considered. The output is one wire per ring element, which is set iff the element is smaller than bound.
+    pub(crate) fn ring_lt_public_many<G: FancyBinary + FancyBinaryConstant>(
+        g: &mut G,
+        wires_a: &BinaryBundle<G::Item>,
+        wires_b: &BinaryBundle<G::Item>,
+        bound: &[bool],
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        debug_assert_eq!(wires_a.size(), wires_b.size());
+        let input_size = wires_a.size();
+        let input_bitlen = bound.len();
+        debug_assert!(input_bitlen > 0);
+        debug_assert_eq!(input_size % input_bitlen, 0);
+
+        let mut results = Vec::with_capacity(input_size / input_bitlen);
+
+        for (chunk_a, chunk_b) in izip!(
+            wires_a.wires().chunks(input_bitlen),
+            wires_b.wires().chunks(input_bitlen),
+        ) {
+            let sum = Self::bin_addition_no_carry(g, chunk_a, chunk_b)?;
+            results.push(Self::unsigned_lt_const(g, &sum, bound)?);
+        }
+        Ok(BinaryBundle::new(results))
+    }
+
     /// Divides a field element by a power of 2. The field element is represented as two bitdecompositions wires_a, wires_b which need to be added first. The output is composed using wires_c, whereas wires_c are the same size as wires_a and wires_b.
     pub(crate) fn field_int_div_power_2_many<G: FancyBinary, F: PrimeField>(
         g: &mut G,
@@ -3051,62 +3092,86 @@ impl GarbledCircuits {
         state_length: usize,
     ) -> Result<BinaryBundle<G::Item>, G::Error> {
         let input_bitlen = F::MODULUS_BIT_SIZE as usize;
+        let circuit = Self::sha256_bristol_circuit();
 
-        // Reading the circuit from txt file
-        let circuit = include_str!(concat!(
-            env!("CARGO_MANIFEST_DIR"),
-            "/src/protocols/rep3/yao/bristol_fashion/circuit_files/sha256.txt"
-        ));
-        let circuit_read =
-            BristolFashionCircuit::from_reader(circuit.as_bytes()).expect("sha256 circuit works");
-
-        let mut state = Vec::new();
-        let mut message = Vec::new();
+        let mut state = Vec::with_capacity(state_length);
+        let mut message = Vec::with_capacity(16);
 
         for (chunk_x1, chunk_x2) in izip!(
             wires_x1.wires()[..state_length * input_bitlen].chunks(input_bitlen),
             wires_x2.wires()[..state_length * input_bitlen].chunks(input_bitlen)
         ) {
-            let mut state_bits =
-                Self::adder_mod_p_with_output_size::<_, F>(g, chunk_x1, chunk_x2, 32)?;
-            state_bits.reverse();
-            state.extend(state_bits);
+            state.push(Self::adder_mod_p_with_output_size::<_, F>(
+                g, chunk_x1, chunk_x2, 32,
+            )?);
         }
         for (chunk_y1, chunk_y2) in izip!(
             wires_x1.wires()[state_length * input_bitlen..].chunks(input_bitlen),
             wires_x2.wires()[state_length * input_bitlen..].chunks(input_bitlen),
         ) {
-            let mut message_bits =
-                Self::adder_mod_p_with_output_size::<_, F>(g, chunk_y1, chunk_y2, 32)?;
-            message_bits.reverse();
-            message.extend(message_bits);
+            message.push(Self::adder_mod_p_with_output_size::<_, F>(
+                g, chunk_y1, chunk_y2, 32,
+            )?);
+        }
+
+        let result = Self::sha256_compress_words(g, &state, &message, &circuit)?;
+
+        let mut results = Vec::with_capacity(result.len() * input_bitlen);
+        for (xs, ys) in izip!(result.iter(), wires_c.wires().chunks(input_bitlen)) {
+            let result = Self::compose_field_element::<_, F>(g, xs, ys)?;
+            results.extend(result);
         }
 
+        Ok(BinaryBundle::new(results))
+    }
+
+    fn sha256_bristol_circuit() -> BristolFashionCircuit {
+        // Reading the circuit from txt file
+        let circuit = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/protocols/rep3/yao/bristol_fashion/circuit_files/sha256.txt"
+        ));
+        BristolFashionCircuit::from_reader(circuit.as_bytes()).expect("sha256 circuit works")
+    }
+
+    /// Computes the SHA256 compression of the 8 state words and the 16 message words using the parsed Bristol circuit. All words are given as 32 little-endian bits and the 8 output words are returned in the same format.
+    fn sha256_compress_words<
+        G: FancyBinary + FancyBinaryConstant + BristolFashionEvaluator<WireValue = G::Item>,
+    >(
+        g: &mut G,
+        state: &[Vec<G::Item>],
+        message: &[Vec<G::Item>],
+        circuit: &BristolFashionCircuit,
+    ) -> Result<Vec<Vec<G::Item>>, G::Error> {
+        debug_assert_eq!(state.len(), 8);
+        debug_assert_eq!(message.len(), 16);
+
+        // the input (and the output) for the bristol circuit is in reversed order
+        let mut state = state
+            .iter()
+            .flat_map(|word| word.iter().rev().cloned())
+            .collect::<Vec<_>>();
+        let mut message = message
+            .iter()
+            .flat_map(|word| word.iter().rev().cloned())
+            .collect::<Vec<_>>();
         message.reverse();
         state.reverse();
 
         let input = [message, state];
         let zero = g.const_zero()?;
-        let mut output = circuit_read
+        let mut output = circuit
             .evaluate_with_default::<G::Item>(&input, g, zero)
-            .map_err(|e| e.into())?;
+            .map_err(|e| G::Error::from(FancyError::from(e)))?;
         let mut result = output.pop().ok_or(FancyError::InvalidArg(
             "No output found in circuit evaluation".to_string(),
         ))?;
-        let mut results = Vec::with_capacity(result.len());
 
-        // the input (and the output) for the bristol circuit is in reversed order
         result.reverse();
-        for chunk in result.chunks_mut(32) {
-            chunk.reverse();
-        }
-
-        for (xs, ys) in izip!(result.chunks(32), wires_c.wires().chunks(input_bitlen)) {
-            let result = Self::compose_field_element::<_, F>(g, xs, ys)?;
-            results.extend(result);
-        }
-
-        Ok(BinaryBundle::new(results))
+        Ok(result
+            .chunks(32)
+            .map(|word| word.iter().rev().cloned().collect())
+            .collect())
     }
 
     /// Computes the BLAKE2s hash of 'num_inputs' inputs, each of 'num_bits' bits (rounded to next multiple of 8). The inputs are given as two bitdecompositions wires_a and wires_b, and the output is composed using wires_c. The output is then compose into size 32 Vec of field elements.
@@ -3727,7 +3792,7 @@ impl GarbledCircuits {
         [11, 15, 5, 0, 1, 9, 8, 6, 14, 10, 2, 12, 3, 4, 7, 13],
     ];
 
-    /// Computes AES ct with given pt, iv and key which are represented as wires_x1 and wires_x2 which need to be added first get the inputs. The output is composed using wires_c. If the plaintext is not of size 0 mod 16 it is padded using PKCS7 padding.
+    /// Computes AES ct in CBC mode with given pt, iv and key which are represented as wires_x1 and wires_x2 which need to be added first get the inputs. The output is composed using wires_c. If the plaintext is not of size 0 mod 16 it is padded using PKCS7 padding.
     pub(crate) fn aes128<
         G: FancyBinary + FancyBinaryConstant + BristolFashionEvaluator<WireValue = G::Item>,
         F: PrimeField,
@@ -3743,13 +3808,7 @@ impl GarbledCircuits {
         const AES_BLOCK_SIZE: usize = 16;
         let input_bitlen = F::MODULUS_BIT_SIZE as usize;
 
-        // Reading the circuit from txt file
-        let circuit = include_str!(concat!(
-            env!("CARGO_MANIFEST_DIR"),
-            "/src/protocols/rep3/yao/bristol_fashion/circuit_files/aes_128.txt"
-        ));
-        let circuit_read =
-            BristolFashionCircuit::from_reader(circuit.as_bytes()).expect("aes128 circuit works");
+        let circuit_read = Self::aes128_bristol_circuit();
 
         let mut plaintext = Vec::new();
         let mut key = Vec::new();
@@ -3843,6 +3902,15 @@ impl GarbledCircuits {
         Ok(BinaryBundle::new(results))
     }
 
+    fn aes128_bristol_circuit() -> BristolFashionCircuit {
+        // Reading the circuit from txt file
+        let circuit = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/protocols/rep3/yao/bristol_fashion/circuit_files/aes_128.txt"
+        ));
+        BristolFashionCircuit::from_reader(circuit.as_bytes()).expect("aes128 circuit works")
+    }
+
     /// Computes one single AES block using the parsed Bristol circuit.
     pub(crate) fn aes128_block<
         G: FancyBinary + FancyBinaryConstant + BristolFashionEvaluator<WireValue = G::Item>,
@@ -3870,6 +3938,449 @@ impl GarbledCircuits {
         };
         Ok(result)
     }
+
+    /// Computes AES ct in CTR mode with given pt, iv and key which are represented as wires_x1 and wires_x2 which need to be added first get the inputs. The iv is the initial counter block, which is incremented as a 128-bit big-endian integer for each block. No padding is applied, i.e., the ct has the same length as the pt. Since the keystream is only XORed to the input, the same circuit also decrypts. The output is composed using wires_c.
+    pub(crate) fn aes128_ctr<
+        G: FancyBinary + FancyBinaryConstant + BristolFashionEvaluator<WireValue = G::Item>,
+        F: PrimeField,
+    >(
+        g: &mut G,
+        wires_x1: &BinaryBundle<G::Item>,
+        wires_x2: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+        pt_length: usize,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        const AES_BLOCK_SIZE: usize = 16;
+        let circuit = Self::aes128_bristol_circuit();
+
+        let input = Self::bytes_from_added_shares::<_, F>(g, wires_x1.wires(), wires_x2.wires())?;
+        let (plaintext, rest) = input.split_at(pt_length);
+        let (key, iv) = rest.split_at(AES_BLOCK_SIZE);
+        debug_assert_eq!(iv.len(), AES_BLOCK_SIZE);
+
+        // The Bristol circuit expects the blocks as 128-bit big-endian integers
+        let key = Self::be_words_from_bytes(key, AES_BLOCK_SIZE).remove(0);
+        let mut counter = Self::be_words_from_bytes(iv, AES_BLOCK_SIZE).remove(0);
+        let mut one = vec![g.const_zero()?; AES_BLOCK_SIZE * 8];
+        one[0] = g.const_one()?;
+
+        let mut ciphertext = Vec::with_capacity(pt_length);
+        for (i, block) in plaintext.chunks(AES_BLOCK_SIZE).enumerate() {
+            if i > 0 {
+                counter = Self::bin_addition_no_carry(g, &counter, &one)?;
+            }
+            let keystream = Self::aes128_block(g, &key, &counter, &circuit)?;
+            let keystream = Self::be_words_to_bytes(&[keystream], AES_BLOCK_SIZE);
+            for (pt, ks) in izip!(block, keystream) {
+                ciphertext.push(Self::xor_many_as_wires(g, pt, &ks)?);
+            }
+        }
+
+        Self::compose_bytes::<_, F>(g, &ciphertext, wires_c.wires())
+    }
+
+    /// Computes the SHA256 hash of the bytes given as wires_x1 and wires_x2, which need to be added first to get the inputs. The message is padded and then compressed using the Bristol fashion circuit. The 32 bytes of the digest are composed using wires_c.
+    pub(crate) fn sha256<
+        G: FancyBinary + FancyBinaryConstant + BristolFashionEvaluator<WireValue = G::Item>,
+        F: PrimeField,
+    >(
+        g: &mut G,
+        wires_x1: &BinaryBundle<G::Item>,
+        wires_x2: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        let circuit = Self::sha256_bristol_circuit();
+        let input = Self::bytes_from_added_shares::<_, F>(g, wires_x1.wires(), wires_x2.wires())?;
+        let digest = Self::sha256_bytes(g, &input, &circuit)?;
+        Self::compose_bytes::<_, F>(g, &digest, wires_c.wires())
+    }
+
+    /// Computes HMAC-SHA256 (RFC 2104) of a key of key_length bytes followed by the message bytes, which are given as wires_x1 and wires_x2 which need to be added first to get the inputs. The 32 bytes of the MAC are composed using wires_c.
+    pub(crate) fn hmac_sha256<
+        G: FancyBinary + FancyBinaryConstant + BristolFashionEvaluator<WireValue = G::Item>,
+        F: PrimeField,
+    >(
+        g: &mut G,
+        wires_x1: &BinaryBundle<G::Item>,
+        wires_x2: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+        key_length: usize,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        const BLOCK_SIZE: usize = 64;
+        let circuit = Self::sha256_bristol_circuit();
+        let input = Self::bytes_from_added_shares::<_, F>(g, wires_x1.wires(), wires_x2.wires())?;
+        let (key, message) = input.split_at(key_length);
+
+        // Keys longer than the block size are hashed first
+        let mut key = if key.len() > BLOCK_SIZE {
+            Self::sha256_bytes(g, key, &circuit)?
+        } else {
+            key.to_vec()
+        };
+        key.resize(BLOCK_SIZE, Self::constant_bundle_from_usize(g, 0, 8)?);
+
+        let mut inner = Self::xor_bytes_with_constant(g, &key, 0x36)?;
+        inner.extend_from_slice(message);
+        let inner = Self::sha256_bytes(g, &inner, &circuit)?;
+
+        let mut outer = Self::xor_bytes_with_constant(g, &key, 0x5c)?;
+        outer.extend(inner);
+        let mac = Self::sha256_bytes(g, &outer, &circuit)?;
+
+        Self::compose_bytes::<_, F>(g, &mac, wires_c.wires())
+    }
+
+    /// Computes the SHA512 hash of the bytes given as wires_x1 and wires_x2, which need to be added first to get the inputs. The 64 bytes of the digest are composed using wires_c.
+    pub(crate) fn sha512<G: FancyBinary + FancyBinaryConstant, F: PrimeField>(
+        g: &mut G,
+        wires_x1: &BinaryBundle<G::Item>,
+        wires_x2: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        let input = Self::bytes_from_added_shares::<_, F>(g, wires_x1.wires(), wires_x2.wires())?;
+        let digest = Self::sha512_bytes(g, &input)?;
+        Self::compose_bytes::<_, F>(g, &digest, wires_c.wires())
+    }
+
+    /// Computes the SHA256 hash of the given bytes, each represented as 8 little-endian bits.
+    fn sha256_bytes<
+        G: FancyBinary + FancyBinaryConstant + BristolFashionEvaluator<WireValue = G::Item>,
+    >(
+        g: &mut G,
+        bytes: &[Vec<G::Item>],
+        circuit: &BristolFashionCircuit,
+    ) -> Result<Vec<Vec<G::Item>>, G::Error> {
+        let mut padded = bytes.to_vec();
+        Self::sha2_padding(g, &mut padded, 64, 8)?;
+
+        // SHA256 uses the same IV as BLAKE2s
+        let mut state = Vec::with_capacity(Self::IV.len());
+        for iv in Self::IV {
+            state.push(Self::constant_bundle_from_u32(g, iv, 32)?);
+        }
+        for block in padded.chunks(64) {
+            let message = Self::be_words_from_bytes(block, 4);
+            state = Self::sha256_compress_words(g, &state, &message, circuit)?;
+        }
+        Ok(Self::be_words_to_bytes(&state, 4))
+    }
+
+    /// Computes the SHA512 hash of the given bytes, each represented as 8 little-endian bits.
+    fn sha512_bytes<G: FancyBinary + FancyBinaryConstant>(
+        g: &mut G,
+        bytes: &[Vec<G::Item>],
+    ) -> Result<Vec<Vec<G::Item>>, G::Error> {
+        let mut padded = bytes.to_vec();
+        Self::sha2_padding(g, &mut padded, 128, 16)?;
+
+        let mut state = Vec::with_capacity(Self::SHA512_IV.len());
+        for iv in Self::SHA512_IV {
+            state.push(Self::constant_bundle_from_u64(g, iv, 64)?);
+        }
+        for block in padded.chunks(128) {
+            let message = Self::be_words_from_bytes(block, 8);
+            state = Self::sha512_compress(g, &state, &message)?;
+        }
+        Ok(Self::be_words_to_bytes(&state, 8))
+    }
+
+    /// Computes the SHA512 compression of the 8 state words and the 16 message words. All words are given as 64 little-endian bits and the 8 output words are returned in the same format.
+    pub(crate) fn sha512_compress<G: FancyBinary + FancyBinaryConstant>(
+        g: &mut G,
+        state: &[Vec<G::Item>],
+        message: &[Vec<G::Item>],
+    ) -> Result<Vec<Vec<G::Item>>, G::Error> {
+        debug_assert_eq!(state.len(), 8);
+        debug_assert_eq!(message.len(), 16);
+
+        // Message schedule
+        let mut w = Vec::with_capacity(80);
+        w.extend_from_slice(message);
+        for t in 16..80 {
+            let s0 = Self::sha512_small_sigma(g, &w[t - 15], 1, 8, 7)?;
+            let s1 = Self::sha512_small_sigma(g, &w[t - 2], 19, 61, 6)?;
+            let tmp = Self::bin_addition_no_carry(g, &s1, &w[t - 7])?;
+            let tmp = Self::bin_addition_no_carry(g, &tmp, &s0)?;
+            w.push(Self::bin_addition_no_carry(g, &tmp, &w[t - 16])?);
+        }
+
+        // The working variables a, b, c, d, e, f, g, h
+        let mut vars = state.to_vec();
+        for (k, w) in izip!(Self::SHA512_K, w) {
+            // ch(e, f, g) = g ^ (e & (f ^ g))
+            let mut ch = Vec::with_capacity(64);
+            for (e, f, g_) in izip!(&vars[4], &vars[5], &vars[6]) {
+                let tmp = g.xor(f, g_)?;
+                let tmp = g.and(e, &tmp)?;
+                ch.push(g.xor(g_, &tmp)?);
+            }
+            // maj(a, b, c) = b ^ ((a ^ b) & (b ^ c))
+            let mut maj = Vec::with_capacity(64);
+            for (a, b, c) in izip!(&vars[0], &vars[1], &vars[2]) {
+                let tmp1 = g.xor(a, b)?;
+                let tmp2 = g.xor(b, c)?;
+                let tmp = g.and(&tmp1, &tmp2)?;
+                maj.push(g.xor(b, &tmp)?);
+            }
+
+            let sum1 = Self::sha512_big_sigma(g, &vars[4], 14, 18, 41)?;
+            let k = Self::constant_bundle_from_u64(g, k, 64)?;
+            let t1 = Self::bin_addition_no_carry(g, &vars[7], &sum1)?;
+            let t1 = Self::bin_addition_no_carry(g, &t1, &ch)?;
+            let t1 = Self::bin_addition_no_carry(g, &t1, &k)?;
+            let t1 = Self::bin_addition_no_carry(g, &t1, &w)?;
+
+            let sum0 = Self::sha512_big_sigma(g, &vars[0], 28, 34, 39)?;
+            let t2 = Self::bin_addition_no_carry(g, &sum0, &maj)?;
+
+            // h = g, g = f, f = e, e = d + t1, d = c, c = b, b = a, a = t1 + t2
+            vars.rotate_right(1);
+            vars[4] = Self::bin_addition_no_carry(g, &vars[4], &t1)?;
+            vars[0] = Self::bin_addition_no_carry(g, &t1, &t2)?;
+        }
+
+        let mut result = Vec::with_capacity(8);
+        for (h, v) in izip!(state, vars) {
+            result.push(Self::bin_addition_no_carry(g, h, &v)?);
+        }
+        Ok(result)
+    }
+
+    // Computes rotr(x, r1) ^ rotr(x, r2) ^ shr(x, s)
+    fn sha512_small_sigma<G: FancyBinary + FancyBinaryConstant>(
+        g: &mut G,
+        x: &[G::Item],
+        r1: usize,
+        r2: usize,
+        s: usize,
+    ) -> Result<Vec<G::Item>, G::Error> {
+        let mut x1 = x.to_vec();
+        x1.rotate_left(r1);
+        let mut x2 = x.to_vec();
+        x2.rotate_left(r2);
+        let mut x3 = x[s..].to_vec();
+        x3.resize(x.len(), g.const_zero()?);
+        let tmp = Self::xor_many_as_wires(g, &x1, &x2)?;
+        Self::xor_many_as_wires(g, &tmp, &x3)
+    }
+
+    // Computes rotr(x, r1) ^ rotr(x, r2) ^ rotr(x, r3)
+    fn sha512_big_sigma<G: FancyBinary>(
+        g: &mut G,
+        x: &[G::Item],
+        r1: usize,
+        r2: usize,
+        r3: usize,
+    ) -> Result<Vec<G::Item>, G::Error> {
+        let mut x1 = x.to_vec();
+        x1.rotate_left(r1);
+        let mut x2 = x.to_vec();
+        x2.rotate_left(r2);
+        let mut x3 = x.to_vec();
+        x3.rotate_left(r3);
+        let tmp = Self::xor_many_as_wires(g, &x1, &x2)?;
+        Self::xor_many_as_wires(g, &tmp, &x3)
+    }
+
+    /// Appends the SHA2 padding to the bytes, i.e., a single 1 bit, zeros and the big-endian bit length of the message in length_size bytes, such that the result is a multiple of block_size bytes.
+    fn sha2_padding<G: FancyBinary + FancyBinaryConstant>(
+        g: &mut G,
+        bytes: &mut Vec<Vec<G::Item>>,
+        block_size: usize,
+        length_size: usize,
+    ) -> Result<(), G::Error> {
+        let bit_length = bytes.len() as u128 * 8;
+        bytes.push(Self::constant_bundle_from_usize(g, 0x80, 8)?);
+        let zero = Self::constant_bundle_from_usize(g, 0, 8)?;
+        while (bytes.len() + length_size) % block_size != 0 {
+            bytes.push(zero.clone());
+        }
+        for i in (0..length_size).rev() {
+            let byte = (bit_length >> (8 * i)) as u8;
+            bytes.push(Self::constant_bundle_from_usize(g, byte as usize, 8)?);
+        }
+        Ok(())
+    }
+
+    fn constant_bundle_from_u64<G: FancyBinary + FancyBinaryConstant>(
+        g: &mut G,
+        c: u64,
+        size: usize,
+    ) -> Result<Vec<G::Item>, G::Error> {
+        let mut result = Vec::with_capacity(size);
+        for i in 0..size {
+            if (c >> i) & 1 != 0 {
+                result.push(g.const_one()?);
+            } else {
+                result.push(g.const_zero()?);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Packs bytes into big-endian words of word_size bytes. The bytes and the resulting words are represented as little-endian bits.
+    fn be_words_from_bytes<T: Clone>(bytes: &[Vec<T>], word_size: usize) -> Vec<Vec<T>> {
+        bytes
+            .chunks(word_size)
+            .map(|word| word.iter().rev().flatten().cloned().collect())
+            .collect()
+    }
+
+    /// Splits big-endian words of word_size bytes into bytes. The words and the resulting bytes are represented as little-endian bits.
+    fn be_words_to_bytes<T: Clone>(words: &[Vec<T>], word_size: usize) -> Vec<Vec<T>> {
+        debug_assert!(words.iter().all(|word| word.len() == word_size * 8));
+        words
+            .iter()
+            .flat_map(|word| word.chunks(8).rev().map(|byte| byte.to_vec()))
+            .collect()
+    }
+
+    /// XORs all bytes with a public constant, which only requires negations.
+    fn xor_bytes_with_constant<G: FancyBinary>(
+        g: &mut G,
+        bytes: &[Vec<G::Item>],
+        c: u8,
+    ) -> Result<Vec<Vec<G::Item>>, G::Error> {
+        let mut result = Vec::with_capacity(bytes.len());
+        for byte in bytes {
+            let mut res = Vec::with_capacity(8);
+            for (i, bit) in byte.iter().enumerate() {
+                if (c >> i) & 1 != 0 {
+                    res.push(g.negate(bit)?);
+                } else {
+                    res.push(bit.clone());
+                }
+            }
+            result.push(res);
+        }
+        Ok(result)
+    }
+
+    /// Adds the bitdecompositions wires_a and wires_b and returns the lowest byte of each sum as 8 little-endian bits.
+    fn bytes_from_added_shares<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &[G::Item],
+        wires_b: &[G::Item],
+    ) -> Result<Vec<Vec<G::Item>>, G::Error> {
+        let input_bitlen = F::MODULUS_BIT_SIZE as usize;
+        let mut result = Vec::with_capacity(wires_a.len() / input_bitlen);
+        for (chunk_a, chunk_b) in izip!(wires_a.chunks(input_bitlen), wires_b.chunks(input_bitlen))
+        {
+            result.push(Self::adder_mod_p_with_output_size::<_, F>(
+                g, chunk_a, chunk_b, 8,
+            )?);
+        }
+        Ok(result)
+    }
+
+    /// Composes each byte into a field element using the randomness wires_c.
+    fn compose_bytes<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        bytes: &[Vec<G::Item>],
+        wires_c: &[G::Item],
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        let input_bitlen = F::MODULUS_BIT_SIZE as usize;
+        let mut results = Vec::with_capacity(bytes.len() * input_bitlen);
+        for (xs, ys) in izip!(bytes, wires_c.chunks(input_bitlen)) {
+            let result = Self::compose_field_element::<_, F>(g, xs, ys)?;
+            results.extend(result);
+        }
+        Ok(BinaryBundle::new(results))
+    }
+
+    const SHA512_IV: [u64; 8] = [
+        0x6A09E667F3BCC908,
+        0xBB67AE8584CAA73B,
+        0x3C6EF372FE94F82B,
+        0xA54FF53A5F1D36F1,
+        0x510E527FADE682D1,
+        0x9B05688C2B3E6C1F,
+        0x1F83D9ABFB41BD6B,
+        0x5BE0CD19137E2179,
+    ];
+
+    const SHA512_K: [u64; 80] = [
+        0x428A2F98D728AE22,
+        0x7137449123EF65CD,
+        0xB5C0FBCFEC4D3B2F,
+        0xE9B5DBA58189DBBC,
+        0x3956C25BF348B538,
+        0x59F111F1B605D019,
+        0x923F82A4AF194F9B,
+        0xAB1C5ED5DA6D8118,
+        0xD807AA98A3030242,
+        0x12835B0145706FBE,
+        0x243185BE4EE4B28C,
+        0x550C7DC3D5FFB4E2,
+        0x72BE5D74F27B896F,
+        0x80DEB1FE3B1696B1,
+        0x9BDC06A725C71235,
+        0xC19BF174CF692694,
+        0xE49B69C19EF14AD2,
+        0xEFBE4786384F25E3,
+        0x0FC19DC68B8CD5B5,
+        0x240CA1CC77AC9C65,
+        0x2DE92C6F592B0275,
+        0x4A7484AA6EA6E483,
+        0x5CB0A9DCBD41FBD4,
+        0x76F988DA831153B5,
+        0x983E5152EE66DFAB,
+        0xA831C66D2DB43210,
+        0xB00327C898FB213F,
+        0xBF597FC7BEEF0EE4,
+        0xC6E00BF33DA88FC2,
+        0xD5A79147930AA725,
+        0x06CA6351E003826F,
+        0x142929670A0E6E70,
+        0x27B70A8546D22FFC,
+        0x2E1B21385C26C926,
+        0x4D2C6DFC5AC42AED,
+        0x53380D139D95B3DF,
+        0x650A73548BAF63DE,
+        0x766A0ABB3C77B2A8,
+        0x81C2C92E47EDAEE6,
+        0x92722C851482353B,
+        0xA2BFE8A14CF10364,
+        0xA81A664BBC423001,
+        0xC24B8B70D0F89791,
+        0xC76C51A30654BE30,
+        0xD192E819D6EF5218,
+        0xD69906245565A910,
+        0xF40E35855771202A,
+        0x106AA07032BBD1B8,
+        0x19A4C116B8D2D0C8,
+        0x1E376C085141AB53,
+        0x2748774CDF8EEB99,
+        0x34B0BCB5E19B48A8,
+        0x391C0CB3C5C95A63,
+        0x4ED8AA4AE3418ACB,
+        0x5B9CCA4F7763E373,
+        0x682E6FF3D6B2B8A3,
+        0x748F82EE5DEFB2FC,
+        0x78A5636F43172F60,
+        0x84C87814A1F0AB72,
+        0x8CC702081A6439EC,
+        0x90BEFFFA23631E28,
+        0xA4506CEBDE82BDE9,
+        0xBEF9A3F7B2C67915,
+        0xC67178F2E372532B,
+        0xCA273ECEEA26619C,
+        0xD186B8C721C0C207,
+        0xEADA7DD6CDE0EB1E,
+        0xF57D4F7FEE6ED178,
+        0x06F067AA72176FBA,
+        0x0A637DC5A2C898A6,
+        0x113F9804BEF90DAE,
+        0x1B710B35131C471B,
+        0x28DB77F523047D84,
+        0x32CAAB7B40C72493,
+        0x3C9EBE0A15C9BEBC,
+        0x431D67C49C100D4C,
+        0x4CC5D4BECB3E42B6,
+        0x597F299CFC657E2A,
+        0x5FCB6FAB3AD6FAEC,
+        0x6C44198C4A475817,
+    ];
 }
 
 #[cfg(test)]
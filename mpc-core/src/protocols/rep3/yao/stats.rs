@@ -0,0 +1,273 @@
+//! Gate statistics of the garbled circuits
+//!
+//! The [GarbledCircuits] are data-independent, thus the gates of a call only depend on its public parameters. This module records the circuit garbled by a call (e.g., [decompose_arithmetic_many](super::decompose_arithmetic_many)) with a [BristolFashionCircuitBuilder] and reports its gate counts, depth and communication. The recorded circuits have the same shape as the ones garbled in the MPC protocol: two input bundles containing the shares which are added inside the circuit, followed by one input bundle of the randomness used to re-share the outputs.
+//!
+//! The garbler uses half-gates with free-XOR: every AND gate produces two ciphertexts of 16 bytes, while XOR and INV gates are free. Wire labels are hashed using fixed-key AES (see [fancy_garbling::hash_wires]) with the gate number as tweak.
+
+use std::fmt;
+
+use ark_ff::PrimeField;
+use fancy_garbling::BinaryBundle;
+
+use super::{
+    bristol_fashion::{
+        BristolFashionCircuit, BristolFashionCircuitBuilder, BristolFashionWire,
+        CircuitBuilderError,
+    },
+    circuits::GarbledCircuits,
+};
+
+/// Gate counts, depth and communication of a garbled circuit
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GarbledCircuitStats {
+    /// The number of AND gates
+    pub and_gates: usize,
+    /// The number of XOR gates
+    pub xor_gates: usize,
+    /// The number of INV gates
+    pub inv_gates: usize,
+    /// The multiplicative depth, i.e., the maximum number of AND gates on a path from an input to an output
+    pub and_depth: usize,
+    /// The number of input wires
+    pub input_wires: usize,
+    /// The number of output wires
+    pub output_wires: usize,
+}
+
+impl GarbledCircuitStats {
+    /// The number of bytes the garbler sends per AND gate (two half-gate ciphertexts)
+    pub const CIPHERTEXT_BYTES_PER_AND_GATE: usize = 32;
+
+    /// Computes the statistics of a Bristol Fashion circuit
+    pub fn from_circuit(circuit: &BristolFashionCircuit) -> Self {
+        Self {
+            and_gates: circuit.num_and_gates(),
+            xor_gates: circuit.num_xor_gates(),
+            inv_gates: circuit.num_inv_gates(),
+            and_depth: circuit.and_depth(),
+            input_wires: circuit.num_input_wires(),
+            output_wires: circuit.get_output_wires().iter().map(Vec::len).sum(),
+        }
+    }
+
+    /// Returns the number of bytes of the garbled tables, i.e., the ciphertexts the garbler sends to the evaluator
+    pub fn ciphertext_bytes(&self) -> usize {
+        self.and_gates * Self::CIPHERTEXT_BYTES_PER_AND_GATE
+    }
+}
+
+impl fmt::Display for GarbledCircuitStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "AND: {}, XOR: {}, INV: {}, AND depth: {}, ciphertext bytes: {}, inputs: {}, outputs: {}",
+            self.and_gates,
+            self.xor_gates,
+            self.inv_gates,
+            self.and_depth,
+            self.ciphertext_bytes(),
+            self.input_wires,
+            self.output_wires
+        )
+    }
+}
+
+type Bundle = BinaryBundle<BristolFashionWire>;
+
+// Records a circuit in the shape of decompose_circuit_compose_blueprint
+fn record_blueprint<F: PrimeField>(
+    num_inputs: usize,
+    output_size: usize,
+    circuit: impl FnOnce(
+        &mut BristolFashionCircuitBuilder,
+        &Bundle,
+        &Bundle,
+        &Bundle,
+    ) -> Result<Bundle, CircuitBuilderError>,
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    let bitlen = F::MODULUS_BIT_SIZE as usize;
+    let mut builder = BristolFashionCircuitBuilder::new();
+    let x01 = builder.add_input_bundle(num_inputs * bitlen)?;
+    let x2 = builder.add_input_bundle(num_inputs * bitlen)?;
+    let x23 = builder.add_input_bundle(output_size * bitlen)?;
+    let output = circuit(&mut builder, &x01, &x2, &x23)?;
+    builder.build(&[output])
+}
+
+/// Records the circuit garbled by [decompose_arithmetic_many](super::decompose_arithmetic_many) for `num_inputs` inputs.
+pub fn decompose_arithmetic_many<F: PrimeField>(
+    num_inputs: usize,
+    total_bit_size_per_field: usize,
+    decompose_bit_size: usize,
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    let output_size = total_bit_size_per_field.div_ceil(decompose_bit_size) * num_inputs;
+    record_blueprint::<F>(num_inputs, output_size, |g, x01, x2, x23| {
+        GarbledCircuits::decompose_field_element_many::<_, F>(
+            g,
+            x01,
+            x2,
+            x23,
+            decompose_bit_size,
+            total_bit_size_per_field,
+        )
+    })
+}
+
+/// Records the circuit garbled by [field_int_div_power_2_many](super::field_int_div_power_2_many) for `num_inputs` inputs.
+pub fn field_int_div_power_2_many<F: PrimeField>(
+    num_inputs: usize,
+    divisor_bit: usize,
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    record_blueprint::<F>(num_inputs, num_inputs, |g, x01, x2, x23| {
+        GarbledCircuits::field_int_div_power_2_many::<_, F>(g, x01, x2, x23, divisor_bit)
+    })
+}
+
+/// Records the circuit garbled by [field_int_div_many](super::field_int_div_many) for `num_inputs` dividends and divisors.
+pub fn field_int_div_many<F: PrimeField>(
+    num_inputs: usize,
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    record_blueprint::<F>(2 * num_inputs, num_inputs, |g, x01, x2, x23| {
+        GarbledCircuits::field_int_div_many::<_, F>(g, x01, x2, x23)
+    })
+}
+
+/// Records the circuit garbled by [sha256_from_bristol](super::sha256_from_bristol).
+pub fn sha256_from_bristol<F: PrimeField>() -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    const STATE_SIZE: usize = 8;
+    const MESSAGE_SIZE: usize = 16;
+    record_blueprint::<F>(STATE_SIZE + MESSAGE_SIZE, STATE_SIZE, |g, x01, x2, x23| {
+        GarbledCircuits::sha256_compression::<_, F>(g, x01, x2, x23, STATE_SIZE)
+    })
+}
+
+/// Records the circuit garbled by [aes_from_bristol](super::aes_from_bristol) for a plaintext of `plaintext_len` bytes.
+pub fn aes_from_bristol<F: PrimeField>(
+    plaintext_len: usize,
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    const AES_BLOCK_SIZE: usize = 16;
+    const BIT_SIZE: usize = 8;
+    let output_size = plaintext_len + AES_BLOCK_SIZE - (plaintext_len % AES_BLOCK_SIZE);
+    record_blueprint::<F>(
+        plaintext_len + 2 * AES_BLOCK_SIZE,
+        output_size,
+        |g, x01, x2, x23| {
+            GarbledCircuits::aes128::<_, F>(
+                g,
+                x01,
+                x2,
+                x23,
+                plaintext_len,
+                AES_BLOCK_SIZE,
+                BIT_SIZE,
+            )
+        },
+    )
+}
+
+/// Records the circuit garbled by [aes128_ctr_from_bristol](super::aes128_ctr_from_bristol) for a plaintext of `plaintext_len` bytes.
+pub fn aes128_ctr_from_bristol<F: PrimeField>(
+    plaintext_len: usize,
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    const AES_BLOCK_SIZE: usize = 16;
+    record_blueprint::<F>(
+        plaintext_len + 2 * AES_BLOCK_SIZE,
+        plaintext_len,
+        |g, x01, x2, x23| GarbledCircuits::aes128_ctr::<_, F>(g, x01, x2, x23, plaintext_len),
+    )
+}
+
+/// Records the circuit garbled by [sha256_hash_from_bristol](super::sha256_hash_from_bristol) for a message of `message_len` bytes.
+pub fn sha256_hash_from_bristol<F: PrimeField>(
+    message_len: usize,
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    record_blueprint::<F>(message_len, 32, |g, x01, x2, x23| {
+        GarbledCircuits::sha256::<_, F>(g, x01, x2, x23)
+    })
+}
+
+/// Records the circuit garbled by [hmac_sha256_from_bristol](super::hmac_sha256_from_bristol) for a key of `key_len` bytes and a message of `message_len` bytes.
+pub fn hmac_sha256_from_bristol<F: PrimeField>(
+    key_len: usize,
+    message_len: usize,
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    record_blueprint::<F>(key_len + message_len, 32, |g, x01, x2, x23| {
+        GarbledCircuits::hmac_sha256::<_, F>(g, x01, x2, x23, key_len)
+    })
+}
+
+/// Records the circuit garbled by [sha512](super::sha512) for a message of `message_len` bytes.
+pub fn sha512<F: PrimeField>(
+    message_len: usize,
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    record_blueprint::<F>(message_len, 64, |g, x01, x2, x23| {
+        GarbledCircuits::sha512::<_, F>(g, x01, x2, x23)
+    })
+}
+
+/// Records the circuit garbled by [blake2s](super::blake2s) for inputs of the given bit sizes.
+pub fn blake2s<F: PrimeField>(
+    num_bits: &[usize],
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    record_blueprint::<F>(num_bits.len(), 32, |g, x01, x2, x23| {
+        GarbledCircuits::blake2s::<_, F>(g, x01, x2, x23, num_bits.len(), num_bits)
+    })
+}
+
+/// Records the circuit garbled by [blake3](super::blake3) for inputs of the given bit sizes.
+pub fn blake3<F: PrimeField>(
+    num_bits: &[usize],
+) -> Result<BristolFashionCircuit, CircuitBuilderError> {
+    record_blueprint::<F>(num_bits.len(), 32, |g, x01, x2, x23| {
+        GarbledCircuits::blake3::<_, F>(g, x01, x2, x23, num_bits.len(), num_bits)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::rep3::yao::bristol_fashion::BoolBristolFashionEvaluator;
+    use ark_ff::{BigInteger, Zero};
+    use rand::{Rng, thread_rng};
+
+    type F = ark_bn254::Fr;
+
+    fn to_bits(x: F) -> Vec<bool> {
+        x.into_bigint().to_bits_le()[..F::MODULUS_BIT_SIZE as usize].to_vec()
+    }
+
+    #[test]
+    fn stats_scale_linearly() {
+        let one = GarbledCircuitStats::from_circuit(&field_int_div_many::<F>(1).unwrap());
+        let four = GarbledCircuitStats::from_circuit(&field_int_div_many::<F>(4).unwrap());
+        assert!(one.and_gates > 0);
+        assert_eq!(four.and_gates, 4 * one.and_gates);
+        assert_eq!(four.xor_gates, 4 * one.xor_gates);
+        assert_eq!(four.and_depth, one.and_depth);
+        assert_eq!(one.ciphertext_bytes(), 32 * one.and_gates);
+        assert_eq!(one.input_wires, 5 * F::MODULUS_BIT_SIZE as usize);
+    }
+
+    #[test]
+    fn recorded_decompose_evaluates() {
+        let mut rng = thread_rng();
+        let circuit = decompose_arithmetic_many::<F>(1, 64, 16).unwrap();
+        let x = F::from(rng.r#gen::<u64>());
+        let share = F::from(rng.r#gen::<u64>());
+        let output = circuit
+            .evaluate(
+                &[
+                    to_bits(x - share),
+                    to_bits(share),
+                    to_bits(F::zero()).repeat(4),
+                ],
+                &mut BoolBristolFashionEvaluator,
+            )
+            .unwrap();
+        let x = to_bits(x);
+        for (i, chunk) in output[0].chunks(F::MODULUS_BIT_SIZE as usize).enumerate() {
+            assert_eq!(chunk[..16], x[i * 16..(i + 1) * 16]);
+            assert!(chunk[16..].iter().all(|bit| !bit));
+        }
+    }
+}
@@ -4,7 +4,7 @@
 //!
 //! This file is heavily inspired by [fancy-garbling](https://github.com/GaloisInc/swanky/blob/dev/fancy-garbling/src/garble/evaluator.rs)
 
-use super::{GCUtils, circuits::FancyBinaryConstant};
+use super::{GCUtils, bristol_fashion::BristolFashionEvaluator, circuits::FancyBinaryConstant};
 use crate::{
     IoResult,
     protocols::rep3::{
@@ -285,3 +285,47 @@ impl<N: Rep3Network> FancyBinaryConstant for StreamingRep3Evaluator<'_, N> {
         Ok(one)
     }
 }
+
+impl<N: Rep3Network> BristolFashionEvaluator for StreamingRep3Evaluator<'_, N> {
+    type WireValue = WireMod2;
+
+    fn constant(
+        &mut self,
+        input: bool,
+    ) -> Result<Self::WireValue, super::bristol_fashion::CircuitExecutionError> {
+        match input {
+            true => Ok(self
+                .const_one()
+                .map_err(|e| std::io::Error::other(format!("{e:?}")))?),
+            false => Ok(self
+                .const_zero()
+                .map_err(|e| std::io::Error::other(format!("{e:?}")))?),
+        }
+    }
+
+    fn inv(
+        &mut self,
+        input: &Self::WireValue,
+    ) -> Result<Self::WireValue, super::bristol_fashion::CircuitExecutionError> {
+        Ok(<Self as FancyBinary>::negate(self, input)
+            .map_err(|e| std::io::Error::other(format!("{e:?}")))?)
+    }
+
+    fn xor(
+        &mut self,
+        input1: &Self::WireValue,
+        input2: &Self::WireValue,
+    ) -> Result<Self::WireValue, super::bristol_fashion::CircuitExecutionError> {
+        Ok(<Self as FancyBinary>::xor(self, input1, input2)
+            .map_err(|e| std::io::Error::other(format!("{e:?}")))?)
+    }
+
+    fn and(
+        &mut self,
+        input1: &Self::WireValue,
+        input2: &Self::WireValue,
+    ) -> Result<Self::WireValue, super::bristol_fashion::CircuitExecutionError> {
+        Ok(<Self as FancyBinary>::and(self, input1, input2)
+            .map_err(|e| std::io::Error::other(format!("{e:?}")))?)
+    }
+}
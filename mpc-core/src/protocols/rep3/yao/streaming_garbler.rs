@@ -4,7 +4,9 @@
 //!
 //! This implementation is heavily inspired by [fancy-garbling](https://github.com/GaloisInc/swanky/blob/dev/fancy-garbling/src/garble/garbler.rs)
 
-use super::{GCInputs, GCUtils, circuits::FancyBinaryConstant};
+use super::{
+    GCInputs, GCUtils, bristol_fashion::BristolFashionEvaluator, circuits::FancyBinaryConstant,
+};
 use crate::{
     IoResult, RngType,
     protocols::rep3::{
@@ -282,3 +284,47 @@ impl<N: Rep3Network> FancyBinaryConstant for StreamingRep3Garbler<'_, N> {
         Ok(zero)
     }
 }
+
+impl<N: Rep3Network> BristolFashionEvaluator for StreamingRep3Garbler<'_, N> {
+    type WireValue = WireMod2;
+
+    fn constant(
+        &mut self,
+        input: bool,
+    ) -> Result<Self::WireValue, super::bristol_fashion::CircuitExecutionError> {
+        match input {
+            true => Ok(self
+                .const_one()
+                .map_err(|e| std::io::Error::other(format!("{e:?}")))?),
+            false => Ok(self
+                .const_zero()
+                .map_err(|e| std::io::Error::other(format!("{e:?}")))?),
+        }
+    }
+
+    fn inv(
+        &mut self,
+        input: &Self::WireValue,
+    ) -> Result<Self::WireValue, super::bristol_fashion::CircuitExecutionError> {
+        Ok(<Self as FancyBinary>::negate(self, input)
+            .map_err(|e| std::io::Error::other(format!("{e:?}")))?)
+    }
+
+    fn xor(
+        &mut self,
+        input1: &Self::WireValue,
+        input2: &Self::WireValue,
+    ) -> Result<Self::WireValue, super::bristol_fashion::CircuitExecutionError> {
+        Ok(<Self as FancyBinary>::xor(self, input1, input2)
+            .map_err(|e| std::io::Error::other(format!("{e:?}")))?)
+    }
+
+    fn and(
+        &mut self,
+        input1: &Self::WireValue,
+        input2: &Self::WireValue,
+    ) -> Result<Self::WireValue, super::bristol_fashion::CircuitExecutionError> {
+        Ok(<Self as FancyBinary>::and(self, input1, input2)
+            .map_err(|e| std::io::Error::other(format!("{e:?}")))?)
+    }
+}
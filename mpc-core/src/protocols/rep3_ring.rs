@@ -9,10 +9,12 @@ pub mod conversion;
 mod detail;
 pub mod gadgets;
 pub mod lut;
+pub mod signed;
 pub mod yao;
 
 pub use mpc_types::protocols::rep3_ring::{
-    Rep3BitShare, Rep3RingShare, combine_ring_element, combine_ring_element_binary,
-    combine_ring_elements, ring, share_ring_element, share_ring_element_binary,
-    share_ring_elements,
+    Rep3BitShare, Rep3RingShare, Rep3SignedRingShare, Rep3TypedRingShare, RingType,
+    combine_ring_element, combine_ring_element_binary, combine_ring_elements,
+    combine_signed_ring_element, ring, share_ring_element, share_ring_element_binary,
+    share_ring_elements, share_signed_ring_element, typed,
 };
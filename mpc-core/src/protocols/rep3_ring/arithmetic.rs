@@ -21,7 +21,7 @@ use num_traits::{One, Zero};
 use rand::{distributions::Standard, prelude::Distribution};
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 
-use super::{binary, conversion, detail};
+use super::{binary, conversion, detail, yao};
 
 /// Type alias for a [`Rep3RingShare`] which is used for both arithmetic and binary shares.
 pub type RingShare<F> = Rep3RingShare<F>;
@@ -454,6 +454,33 @@ where
     detail::unsigned_ge_const_rhs(lhs, rhs, io_context)
 }
 
+/// Returns 1 if lhs < rhs and 0 otherwise for each element of lhs. Compared to [lt_public], the shared values are only converted to Yao wires and compared on their lowest `bitlen` bits, i.e., they are required to be smaller than 2^bitlen. The comparisons are batched into a single garbled circuit.
+pub fn lt_public_bitlen_many<T: IntRing2k, N: Rep3Network>(
+    lhs: &[RingShare<T>],
+    rhs: RingElement<T>,
+    bitlen: usize,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<RingShare<Bit>>>
+where
+    Standard: Distribution<T>,
+{
+    yao::lt_public_truncated_many(lhs, rhs, bitlen, io_context)
+}
+
+/// Returns 1 if lower <= x <= upper and 0 otherwise for each element x of shared. The shared values and upper are required to be smaller than 2^bitlen, which allows to only evaluate the range check on the lowest `bitlen` bits. Use `bitlen = T::K` if no such bound is known. The range checks are batched into a single garbled circuit.
+pub fn is_in_range_many<T: IntRing2k, N: Rep3Network>(
+    shared: &[RingShare<T>],
+    lower: RingElement<T>,
+    upper: RingElement<T>,
+    bitlen: usize,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<RingShare<Bit>>>
+where
+    Standard: Distribution<T>,
+{
+    yao::is_in_range_truncated_many(shared, lower, upper, bitlen, io_context)
+}
+
 /// Checks if a shared value is equal to a public value. The result is a shared value that has value 1 if the two values are equal and 0 otherwise.
 pub fn eq_public<T: IntRing2k, N: Rep3Network>(
     shared: RingShare<T>,
@@ -76,6 +76,29 @@ where
     Ok(RingShare::new_ring(local_a, local_b))
 }
 
+/// Performs element-wise bitwise AND operation on the provided shared values.
+pub fn and_vec<T: IntRing2k, N: Rep3Network>(
+    a: &[RingShare<T>],
+    b: &[RingShare<T>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    debug_assert_eq!(a.len(), b.len());
+    let local_a = izip!(a, b)
+        .map(|(a, b)| {
+            let (mut mask, mask_b) = io_context.rngs.rand.random_elements::<RingElement<T>>();
+            mask ^= mask_b;
+            (a & b) ^ mask
+        })
+        .collect::<Vec<_>>();
+    let local_b = io_context.network.reshare_many(&local_a)?;
+    Ok(izip!(local_a, local_b)
+        .map(|(a, b)| RingShare::new_ring(a, b))
+        .collect())
+}
+
 /// Performs a bitwise AND operation on a shared value and a public value.
 pub fn and_with_public<T: IntRing2k>(
     shared: &RingShare<T>,
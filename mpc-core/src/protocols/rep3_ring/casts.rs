@@ -2,17 +2,17 @@
 //!
 //! Implements casts for sharings of different datatypes
 
-use super::{conversion, yao};
+use super::{arithmetic, conversion, signed::sign_offset, yao};
 use crate::protocols::rep3::{
     self,
-    conversion::A2BType,
+    conversion::A2BProtocol,
     network::{IoContext, Rep3Network},
 };
 use ark_ff::PrimeField;
 use mpc_types::protocols::{
     rep3::{Rep3BigUintShare, Rep3PrimeFieldShare},
     rep3_ring::{
-        Rep3RingShare,
+        Rep3RingShare, Rep3SignedRingShare, Rep3TypedRingShare,
         ring::{bit::Bit, int_ring::IntRing2k, ring_impl::RingElement},
     },
 };
@@ -32,9 +32,26 @@ where
     N: Rep3Network,
     Standard: Distribution<T> + Distribution<U>,
 {
-    match io_context.a2b_type {
-        A2BType::Direct => cast_a2b(x, io_context),
-        A2BType::Yao => cast_gc(x, io_context),
+    match io_context.resolve_a2b_type(1, T::K.max(U::K)) {
+        A2BProtocol::Direct => cast_a2b(x, io_context),
+        A2BProtocol::Yao => cast_gc(x, io_context),
+    }
+}
+
+/// Depending on the `A2BType` of the io_context, this function selects the appropriate implementation for the signed ring cast. In case of an upcast, the sign bit is extended, in case of a downcast, the excess bits are just truncated.
+pub fn signed_ring_cast_selector<T, U, N>(
+    x: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Rep3SignedRingShare<U>>
+where
+    T: IntRing2k + AsPrimitive<U>,
+    U: IntRing2k,
+    N: Rep3Network,
+    Standard: Distribution<T> + Distribution<U>,
+{
+    match io_context.resolve_a2b_type(1, T::K.max(U::K)) {
+        A2BProtocol::Direct => signed_cast_a2b(x, io_context),
+        A2BProtocol::Yao => signed_cast_gc(x, io_context),
     }
 }
 
@@ -46,10 +63,117 @@ pub fn ring_to_field_selector<T: IntRing2k, F: PrimeField, N: Rep3Network>(
 where
     Standard: Distribution<T>,
 {
-    match io_context.a2b_type {
-        A2BType::Direct => ring_to_field_a2b(x, io_context),
-        A2BType::Yao => Ok(yao::ring_to_field_many(&[x], io_context)?[0]),
+    match io_context.resolve_a2b_type(1, T::K) {
+        A2BProtocol::Direct => ring_to_field_a2b(x, io_context),
+        A2BProtocol::Yao => Ok(yao::ring_to_field_many(&[x], io_context)?[0]),
+    }
+}
+
+/// Depending on the `A2BType` of the io_context, this function selects the appropriate implementation for the ring_to_field cast of a vector of shared values.
+pub fn ring_to_field_many_selector<T: IntRing2k, F: PrimeField, N: Rep3Network>(
+    x: &[Rep3RingShare<T>],
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Vec<Rep3PrimeFieldShare<F>>>
+where
+    Standard: Distribution<T>,
+{
+    match io_context.resolve_a2b_type(x.len(), T::K) {
+        A2BProtocol::Direct => ring_to_field_a2b_many(x, io_context),
+        A2BProtocol::Yao => yao::ring_to_field_many(x, io_context),
+    }
+}
+
+/// Casts a [`Rep3TypedRingShare`] to a field share, using [`ring_to_field_selector`] for the ring of the share.
+pub fn typed_ring_to_field_selector<F: PrimeField, N: Rep3Network>(
+    x: Rep3TypedRingShare,
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Rep3PrimeFieldShare<F>> {
+    match x {
+        Rep3TypedRingShare::Bit(x) => ring_to_field_selector(x, io_context),
+        Rep3TypedRingShare::U8(x) => ring_to_field_selector(x, io_context),
+        Rep3TypedRingShare::U16(x) => ring_to_field_selector(x, io_context),
+        Rep3TypedRingShare::U32(x) => ring_to_field_selector(x, io_context),
+        Rep3TypedRingShare::U64(x) => ring_to_field_selector(x, io_context),
+        Rep3TypedRingShare::U128(x) => ring_to_field_selector(x, io_context),
+    }
+}
+
+// Calls ring_to_field_group for all given variants of Rep3TypedRingShare. The match without a wildcard fails to compile if a variant is missing.
+macro_rules! ring_to_field_groups {
+    ($x:expr, $res:expr, $io_context:expr, $($variant:ident),*) => {{
+        fn _all_variants(x: &Rep3TypedRingShare) {
+            match x {
+                $(Rep3TypedRingShare::$variant(_))|* => {}
+            }
+        }
+        $(
+            ring_to_field_group(
+                $x,
+                |x| match x {
+                    Rep3TypedRingShare::$variant(x) => Some(*x),
+                    _ => None,
+                },
+                $res,
+                $io_context,
+            )?;
+        )*
+    }};
+}
+
+/// Casts a slice of [`Rep3TypedRingShare`]s to field shares. The shares are grouped by their ring and each group is cast with a single call to [`ring_to_field_many_selector`], the output keeps the order of the input.
+pub fn typed_ring_to_field_many<F: PrimeField, N: Rep3Network>(
+    x: &[Rep3TypedRingShare],
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Vec<Rep3PrimeFieldShare<F>>> {
+    let mut res = vec![None; x.len()];
+    ring_to_field_groups!(x, &mut res, io_context, Bit, U8, U16, U32, U64, U128);
+    Ok(res
+        .into_iter()
+        .map(|x| x.expect("every share belongs to exactly one ring"))
+        .collect())
+}
+
+/// Casts all shares of `x` selected by `extract` with one call to [`ring_to_field_many_selector`] and writes the results to the corresponding positions of `res`.
+fn ring_to_field_group<T: IntRing2k, F: PrimeField, N: Rep3Network>(
+    x: &[Rep3TypedRingShare],
+    extract: impl Fn(&Rep3TypedRingShare) -> Option<Rep3RingShare<T>>,
+    res: &mut [Option<Rep3PrimeFieldShare<F>>],
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<()>
+where
+    Standard: Distribution<T>,
+{
+    let (indices, shares): (Vec<usize>, Vec<Rep3RingShare<T>>) = x
+        .iter()
+        .enumerate()
+        .filter_map(|(i, x)| extract(x).map(|x| (i, x)))
+        .unzip();
+    if shares.is_empty() {
+        return Ok(());
+    }
+    let casted = ring_to_field_many_selector::<T, F, N>(&shares, io_context)?;
+    for (i, c) in indices.into_iter().zip(casted) {
+        res[i] = Some(c);
     }
+    Ok(())
+}
+
+/// Depending on the `A2BType` of the io_context, this function selects the appropriate implementation for the signed ring_to_field cast. Negative values are mapped to their additive inverse in the field.
+pub fn signed_ring_to_field_selector<T: IntRing2k, F: PrimeField, N: Rep3Network>(
+    x: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Rep3PrimeFieldShare<F>>
+where
+    Standard: Distribution<T>,
+{
+    let id = io_context.id;
+    let biased = arithmetic::add_public(x.into_unsigned(), sign_offset(), id);
+    let res = ring_to_field_selector(biased, io_context)?;
+    Ok(rep3::arithmetic::sub_shared_by_public(
+        res,
+        signed_field_offset::<T, F>(),
+        id,
+    ))
 }
 
 /// Depending on the `A2BType` of the io_context, this function selects the appropriate implementation for the field_to_ring cast.
@@ -60,9 +184,23 @@ pub fn field_to_ring_selector<F: PrimeField, T: IntRing2k, N: Rep3Network>(
 where
     Standard: Distribution<T>,
 {
-    match io_context.a2b_type {
-        A2BType::Direct => field_to_ring_a2b(x, io_context),
-        A2BType::Yao => Ok(yao::field_to_ring_many(&[x], io_context)?[0]),
+    match io_context.resolve_a2b_type(1, F::MODULUS_BIT_SIZE as usize) {
+        A2BProtocol::Direct => field_to_ring_a2b(x, io_context),
+        A2BProtocol::Yao => Ok(yao::field_to_ring_many(&[x], io_context)?[0]),
+    }
+}
+
+/// Depending on the `A2BType` of the io_context, this function selects the appropriate implementation for the field_to_ring cast of a vector of shared values.
+pub fn field_to_ring_many_selector<F: PrimeField, T: IntRing2k, N: Rep3Network>(
+    x: &[Rep3PrimeFieldShare<F>],
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Vec<Rep3RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    match io_context.resolve_a2b_type(x.len(), F::MODULUS_BIT_SIZE as usize) {
+        A2BProtocol::Direct => field_to_ring_a2b_many(x, io_context),
+        A2BProtocol::Yao => yao::field_to_ring_many(x, io_context),
     }
 }
 
@@ -109,6 +247,27 @@ where
     conversion::b2a(&binary, io_context)
 }
 
+/// An upcast of a Rep3SignedRingShare from a smaller ring to a larger ring, extending the sign bit.
+/// Does require network interaction
+pub fn signed_upcast_a2b<T, U, N>(
+    share: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Rep3SignedRingShare<U>>
+where
+    T: IntRing2k + AsPrimitive<U>,
+    U: IntRing2k,
+    N: Rep3Network,
+    Standard: Distribution<T> + Distribution<U>,
+{
+    // For y = x + 2^(k-1) we have that y is in [0, 2^k), thus the unsigned upcast of y is correct and we can remove the offset in the larger ring
+    let id = io_context.id;
+    let biased = arithmetic::add_public(share.into_unsigned(), sign_offset(), id);
+    let upcasted = upcast_a2b::<T, U, N>(biased, io_context)?;
+    Ok(Rep3SignedRingShare::from_unsigned(
+        arithmetic::sub_shared_by_public(upcasted, signed_ring_offset::<T, U>(), id),
+    ))
+}
+
 /// A cast of a Rep3RingShare from a ring to another ring. In case of a downcast, the excess bits are just truncated.
 pub fn cast_a2b<T, U, N>(
     share: Rep3RingShare<T>,
@@ -145,6 +304,51 @@ where
     }
 }
 
+/// A cast of a Rep3SignedRingShare from a ring to another ring. In case of an upcast, the sign bit is extended, in case of a downcast, the excess bits are just truncated.
+pub fn signed_cast_a2b<T, U, N>(
+    share: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Rep3SignedRingShare<U>>
+where
+    T: IntRing2k + AsPrimitive<U>,
+    U: IntRing2k,
+    N: Rep3Network,
+    Standard: Distribution<T> + Distribution<U>,
+{
+    if T::K >= U::K {
+        Ok(Rep3SignedRingShare::from_unsigned(downcast(
+            share.into_unsigned(),
+        )))
+    } else {
+        signed_upcast_a2b(share, io_context)
+    }
+}
+
+/// A cast of a Rep3SignedRingShare from a ring to another ring. In case of an upcast, the sign bit is extended, in case of a downcast, the excess bits are just truncated.
+pub fn signed_cast_gc<T, U, N>(
+    share: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Rep3SignedRingShare<U>>
+where
+    T: IntRing2k + AsPrimitive<U>,
+    U: IntRing2k,
+    N: Rep3Network,
+    Standard: Distribution<T> + Distribution<U>,
+{
+    if T::K >= U::K {
+        Ok(Rep3SignedRingShare::from_unsigned(downcast(
+            share.into_unsigned(),
+        )))
+    } else {
+        let id = io_context.id;
+        let biased = arithmetic::add_public(share.into_unsigned(), sign_offset(), id);
+        let upcasted = yao::upcast_many::<T, U, N>(&[biased], io_context)?[0];
+        Ok(Rep3SignedRingShare::from_unsigned(
+            arithmetic::sub_shared_by_public(upcasted, signed_ring_offset::<T, U>(), id),
+        ))
+    }
+}
+
 /// A cast of a Rep3PrimeFieldShare to a Rep3RingShare. Truncates the excess bits.
 pub fn field_to_ring_a2b<F: PrimeField, T: IntRing2k, N: Rep3Network>(
     share: Rep3PrimeFieldShare<F>,
@@ -161,6 +365,43 @@ where
     conversion::b2a(&ring_share, io_context)
 }
 
+/// A cast of a vector of Rep3PrimeFieldShares to Rep3RingShares. Truncates the excess bits. All elements are converted in the same rounds.
+pub fn field_to_ring_a2b_many<F: PrimeField, T: IntRing2k, N: Rep3Network>(
+    shares: &[Rep3PrimeFieldShare<F>],
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Vec<Rep3RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    let binary = rep3::conversion::a2b_many(shares, io_context)?;
+    let ring_shares = binary
+        .iter()
+        .map(|binary| Rep3RingShare {
+            a: RingElement(T::cast_from_biguint(&binary.a)),
+            b: RingElement(T::cast_from_biguint(&binary.b)),
+        })
+        .collect::<Vec<_>>();
+    conversion::b2a_many(&ring_shares, io_context)
+}
+
+/// A cast of a Rep3SignedRingShare to a Rep3PrimeFieldShare. Negative values are mapped to their additive inverse in the field.
+pub fn signed_ring_to_field_a2b<T: IntRing2k, F: PrimeField, N: Rep3Network>(
+    share: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Rep3PrimeFieldShare<F>>
+where
+    Standard: Distribution<T>,
+{
+    let id = io_context.id;
+    let biased = arithmetic::add_public(share.into_unsigned(), sign_offset(), id);
+    let res = ring_to_field_a2b(biased, io_context)?;
+    Ok(rep3::arithmetic::sub_shared_by_public(
+        res,
+        signed_field_offset::<T, F>(),
+        id,
+    ))
+}
+
 /// A cast of a Rep3RingShare to a Rep3PrimeFieldShare
 pub fn ring_to_field_a2b<T: IntRing2k, F: PrimeField, N: Rep3Network>(
     share: Rep3RingShare<T>,
@@ -188,3 +429,54 @@ where
     );
     rep3::conversion::b2a(&biguint_share, io_context)
 }
+
+/// A cast of a vector of Rep3RingShares to Rep3PrimeFieldShares. All elements are converted in the same rounds.
+pub fn ring_to_field_a2b_many<T: IntRing2k, F: PrimeField, N: Rep3Network>(
+    shares: &[Rep3RingShare<T>],
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Vec<Rep3PrimeFieldShare<F>>>
+where
+    Standard: Distribution<T>,
+{
+    // A special case for Bit
+    if TypeId::of::<T>() == TypeId::of::<Bit>() {
+        let biguint_shares = shares
+            .iter()
+            .map(|share| {
+                let share = crate::downcast::<_, Rep3RingShare<Bit>>(share)
+                    .expect("We already checked types");
+                Rep3BigUintShare::new(
+                    BigUint::from(share.a.0.convert() as u64),
+                    BigUint::from(share.b.0.convert() as u64),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        return rep3::conversion::bit_inject_many(&biguint_shares, io_context);
+    }
+
+    let binary = conversion::a2b_many(shares, io_context)?;
+    let biguint_shares = binary
+        .iter()
+        .map(|binary| {
+            Rep3BigUintShare::new(
+                T::cast_to_biguint(&binary.a.0),
+                T::cast_to_biguint(&binary.b.0),
+            )
+        })
+        .collect::<Vec<_>>();
+    rep3::conversion::b2a_many(&biguint_shares, io_context)
+}
+
+// Returns the offset 2^(k-1) of the ring T embedded into the ring U
+fn signed_ring_offset<T: IntRing2k, U: IntRing2k>() -> RingElement<U>
+where
+    T: AsPrimitive<U>,
+{
+    RingElement(sign_offset::<T>().0.as_())
+}
+
+// Returns the offset 2^(k-1) of the ring T embedded into the field F
+fn signed_field_offset<T: IntRing2k, F: PrimeField>() -> F {
+    F::from(T::cast_to_biguint(&sign_offset::<T>().0))
+}
@@ -8,7 +8,7 @@ use crate::{
     protocols::{
         rep3::{
             self,
-            conversion::A2BType,
+            conversion::A2BProtocol,
             network::{IoContext, Rep3Network},
             yao::{
                 GCUtils, circuits::GarbledCircuits, evaluator::Rep3Evaluator, garbler::Rep3Garbler,
@@ -40,9 +40,9 @@ pub fn a2b_selector<T: IntRing2k, N: Rep3Network>(
 where
     Standard: Distribution<T>,
 {
-    match io_context.a2b_type {
-        A2BType::Direct => a2b(x, io_context),
-        A2BType::Yao => a2y2b(x, io_context),
+    match io_context.resolve_a2b_type(1, T::K) {
+        A2BProtocol::Direct => a2b(x, io_context),
+        A2BProtocol::Yao => a2y2b(x, io_context),
     }
 }
 
@@ -54,9 +54,37 @@ pub fn b2a_selector<T: IntRing2k, N: Rep3Network>(
 where
     Standard: Distribution<T>,
 {
-    match io_context.a2b_type {
-        A2BType::Direct => b2a(x, io_context),
-        A2BType::Yao => b2y2a(x, io_context),
+    match io_context.resolve_a2b_type(1, T::K) {
+        A2BProtocol::Direct => b2a(x, io_context),
+        A2BProtocol::Yao => b2y2a(x, io_context),
+    }
+}
+
+/// Depending on the `A2BType` of the io_context, this function selects the appropriate implementation for the arithmetic-to-binary conversion of a vector of shared values.
+pub fn a2b_many_selector<T: IntRing2k, N: Rep3Network>(
+    x: &[Rep3RingShare<T>],
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Vec<Rep3RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    match io_context.resolve_a2b_type(x.len(), T::K) {
+        A2BProtocol::Direct => a2b_many(x, io_context),
+        A2BProtocol::Yao => a2y2b_many(x, io_context),
+    }
+}
+
+/// Depending on the `A2BType` of the io_context, this function selects the appropriate implementation for the binary-to-arithmetic conversion of a vector of shared values.
+pub fn b2a_many_selector<T: IntRing2k, N: Rep3Network>(
+    x: &[Rep3RingShare<T>],
+    io_context: &mut IoContext<N>,
+) -> std::io::Result<Vec<Rep3RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    match io_context.resolve_a2b_type(x.len(), T::K) {
+        A2BProtocol::Direct => b2a_many(x, io_context),
+        A2BProtocol::Yao => b2y2a_many(x, io_context),
     }
 }
 
@@ -97,6 +125,49 @@ where
     detail::low_depth_binary_add(&x01, &x2, io_context)
 }
 
+/// Transforms the provided replicated shared values from an arithmetic sharing to a binary sharing. I.e., x = x_1 + x_2 + x_3 gets transformed into x = x'_1 xor x'_2 xor x'_3. Reduces the mul-depth by batching all elements together.
+pub fn a2b_many<T: IntRing2k, N: Rep3Network>(
+    x: &[Rep3RingShare<T>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    if x.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut x01_a = Vec::with_capacity(x.len());
+    let mut x2 = vec![Rep3RingShare::zero_share(); x.len()];
+
+    for (x, x2) in izip!(x, x2.iter_mut()) {
+        let (mut r, r2) = io_context.rngs.rand.random_elements::<RingElement<T>>();
+        r ^= r2;
+
+        match io_context.id {
+            PartyID::ID0 => {
+                x01_a.push(r);
+                x2.b = x.b;
+            }
+            PartyID::ID1 => {
+                let val = x.a + x.b;
+                x01_a.push(val ^ r);
+            }
+            PartyID::ID2 => {
+                x01_a.push(r);
+                x2.a = x.a;
+            }
+        }
+    }
+
+    // reshare x01
+    let x01_b = io_context.network.reshare_many(&x01_a)?;
+    let x01 = izip!(x01_a, x01_b)
+        .map(|(a, b)| Rep3RingShare::new_ring(a, b))
+        .collect::<Vec<_>>();
+
+    detail::low_depth_binary_add_many(&x01, &x2, io_context)
+}
+
 /// Transforms the replicated shared value x from a binary sharing to an arithmetic sharing. I.e., x = x_1 xor x_2 xor x_3 gets transformed into x = x'_1 + x'_2 + x'_3.
 pub fn b2a<T: IntRing2k, N: Rep3Network>(
     x: &Rep3RingShare<T>,
@@ -173,6 +244,106 @@ where
     Ok(res)
 }
 
+/// Transforms the provided replicated shared values from a binary sharing to an arithmetic sharing. I.e., x = x_1 xor x_2 xor x_3 gets transformed into x = x'_1 + x'_2 + x'_3. Reduces the mul-depth by batching all elements together.
+pub fn b2a_many<T: IntRing2k, N: Rep3Network>(
+    x: &[Rep3RingShare<T>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    if x.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut y_a = Vec::with_capacity(x.len());
+    let mut res = vec![Rep3RingShare::zero_share(); x.len()];
+
+    for res in res.iter_mut() {
+        let (mut r, r2) = io_context.rngs.rand.random_elements::<RingElement<T>>();
+        r ^= r2;
+
+        match io_context.id {
+            PartyID::ID0 => {
+                let k3 = io_context
+                    .rngs
+                    .bitcomp2
+                    .random_elements_3keys::<RingElement<T>>();
+
+                res.b = (k3.0 + k3.1 + k3.2).neg();
+                y_a.push(r);
+            }
+            PartyID::ID1 => {
+                let k2 = io_context
+                    .rngs
+                    .bitcomp1
+                    .random_elements_3keys::<RingElement<T>>();
+
+                res.a = (k2.0 + k2.1 + k2.2).neg();
+                y_a.push(r);
+            }
+            PartyID::ID2 => {
+                let k2 = io_context
+                    .rngs
+                    .bitcomp1
+                    .random_elements_3keys::<RingElement<T>>();
+                let k3 = io_context
+                    .rngs
+                    .bitcomp2
+                    .random_elements_3keys::<RingElement<T>>();
+
+                let k2_comp = k2.0 + k2.1 + k2.2;
+                let k3_comp = k3.0 + k3.1 + k3.2;
+                let val = k2_comp + k3_comp;
+                y_a.push(val ^ r);
+                res.a = k3_comp.neg();
+                res.b = k2_comp.neg();
+            }
+        }
+    }
+
+    // reshare y
+    let y_b = io_context.network.reshare_many(&y_a)?;
+    let y = izip!(y_a, y_b)
+        .map(|(a, b)| Rep3RingShare::new_ring(a, b))
+        .collect::<Vec<_>>();
+
+    let z = detail::low_depth_binary_add_many(x, &y, io_context)?;
+
+    match io_context.id {
+        PartyID::ID0 => {
+            let z_b = z.iter().map(|z| z.b).collect::<Vec<_>>();
+            io_context.network.send_next_many(&z_b)?;
+            let rcv: Vec<RingElement<T>> = io_context.network.recv_prev_many()?;
+            if rcv.len() != z.len() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Invalid number of elements received",
+                ));
+            }
+            for (res, z, rcv) in izip!(res.iter_mut(), z, rcv) {
+                res.a = z.a ^ z.b ^ rcv;
+            }
+        }
+        PartyID::ID1 => {
+            let rcv: Vec<RingElement<T>> = io_context.network.recv_prev_many()?;
+            if rcv.len() != z.len() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Invalid number of elements received",
+                ));
+            }
+            for (res, z, rcv) in izip!(res.iter_mut(), z, rcv) {
+                res.b = z.a ^ z.b ^ rcv;
+            }
+        }
+        PartyID::ID2 => {
+            let z_b = z.iter().map(|z| z.b).collect::<Vec<_>>();
+            io_context.network.send_next_many(&z_b)?;
+        }
+    }
+    Ok(res)
+}
+
 /// Translates one shared bit into an arithmetic sharing of the same bit. I.e., the shared bit x = x_1 xor x_2 xor x_3 gets transformed into x = x'_1 + x'_2 + x'_3, with x being either 0 or 1.
 pub fn bit_inject<T: IntRing2k, N: Rep3Network>(
     x: &Rep3RingShare<T>,
@@ -390,6 +561,51 @@ pub fn a2y<T: IntRing2k, N: Rep3Network>(
     Ok(converted)
 }
 
+/// Transforms a vector of replicated shared values x from an arithmetic sharing to yao sharings. I.e., each x = x_1 + x_2 + x_3 gets transformed into wires, such that the garbler have keys (k_0, delta) for each bit of x, while the evaluator has k_x = k_0 xor delta * x. All conversions are done in one garbled circuit.
+pub fn a2y_many<T: IntRing2k, N: Rep3Network>(
+    x: &[Rep3RingShare<T>],
+    delta: Option<WireMod2>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<BinaryBundle<WireMod2>>> {
+    let [x01, x2] = yao::joint_input_arithmetic_added_many(x, delta, io_context)?;
+
+    let converted = match io_context.id {
+        PartyID::ID0 => {
+            let mut evaluator = Rep3Evaluator::new(io_context);
+            evaluator.receive_circuit()?;
+            let mut res = Vec::with_capacity(x.len());
+            for (x01, x2) in izip!(x01.wires().chunks(T::K), x2.wires().chunks(T::K)) {
+                let x01 = BinaryBundle::new(x01.to_vec());
+                let x2 = BinaryBundle::new(x2.to_vec());
+                let added = GarbledCircuits::adder_mod_2k(&mut evaluator, &x01, &x2);
+                res.push(GCUtils::garbled_circuits_error(added)?);
+            }
+            res
+        }
+        PartyID::ID1 | PartyID::ID2 => {
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+            let mut garbler = Rep3Garbler::new_with_delta(io_context, delta);
+            let mut res = Vec::with_capacity(x.len());
+            for (x01, x2) in izip!(x01.wires().chunks(T::K), x2.wires().chunks(T::K)) {
+                let x01 = BinaryBundle::new(x01.to_vec());
+                let x2 = BinaryBundle::new(x2.to_vec());
+                let added = GarbledCircuits::adder_mod_2k(&mut garbler, &x01, &x2);
+                res.push(GCUtils::garbled_circuits_error(added)?);
+            }
+            garbler.send_circuit()?;
+            res
+        }
+    };
+
+    Ok(converted)
+}
+
 /// Transforms the replicated shared value x from an arithmetic sharing to a yao sharing. I.e., x = x_1 + x_2 + x_3 gets transformed into wires, such that the garbler have keys (k_0, delta) for each bit of x, while the evaluator has k_x = k_0 xor delta * x. Uses the Streaming Garbler/Evaluator.
 pub fn a2y_streaming<T: IntRing2k, N: Rep3Network>(
     x: Rep3RingShare<T>,
@@ -533,6 +749,124 @@ where
     Ok(res)
 }
 
+/// Transforms the shared values x from yao sharings to arithmetic sharings. I.e., the sharing such that the garbler have keys (k_0, delta) for each bit of x, while the evaluator has k_x = k_0 xor delta * x gets transformed into x = x_1 + x_2 + x_3. All conversions are done in one garbled circuit.
+pub fn y2a_many<T: IntRing2k, N: Rep3Network>(
+    x: Vec<BinaryBundle<WireMod2>>,
+    delta: Option<WireMod2>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    let n_inputs = x.len();
+    let mut res = vec![Rep3RingShare::zero_share(); n_inputs];
+
+    match io_context.id {
+        PartyID::ID0 => {
+            for res in res.iter_mut() {
+                let k3 = io_context
+                    .rngs
+                    .bitcomp2
+                    .random_elements_3keys::<RingElement<T>>();
+                res.b = (k3.0 + k3.1 + k3.2).neg();
+            }
+            let x23 = yao::input_ring_id2_many::<T, _>(None, None, n_inputs, io_context)?;
+
+            let mut evaluator = Rep3Evaluator::new(io_context);
+            evaluator.receive_circuit()?;
+            let mut x1 = Vec::with_capacity(n_inputs * T::K);
+            for (x, x23) in izip!(x.iter(), x23.wires().chunks(T::K)) {
+                let x23 = BinaryBundle::new(x23.to_vec());
+                let added = GarbledCircuits::adder_mod_2k(&mut evaluator, x, &x23);
+                x1.extend(GCUtils::garbled_circuits_error(added)?.wires().to_owned());
+            }
+            let x1 = evaluator.output_to_id0_and_id1(&x1)?;
+            for (res, x1) in izip!(res.iter_mut(), x1.chunks(T::K)) {
+                res.a = GCUtils::bits_to_ring(x1)?;
+            }
+        }
+        PartyID::ID1 => {
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+            for res in res.iter_mut() {
+                let k2 = io_context
+                    .rngs
+                    .bitcomp1
+                    .random_elements_3keys::<RingElement<T>>();
+                res.a = (k2.0 + k2.1 + k2.2).neg();
+            }
+            let x23 = yao::input_ring_id2_many::<T, _>(None, None, n_inputs, io_context)?;
+
+            let mut garbler = Rep3Garbler::new_with_delta(io_context, delta);
+            let mut x1 = Vec::with_capacity(n_inputs * T::K);
+            for (x, x23) in izip!(x.iter(), x23.wires().chunks(T::K)) {
+                let x23 = BinaryBundle::new(x23.to_vec());
+                let added = GarbledCircuits::adder_mod_2k(&mut garbler, x, &x23);
+                x1.extend(GCUtils::garbled_circuits_error(added)?.wires().to_owned());
+            }
+            let x1 = garbler.output_to_id0_and_id1(&x1)?;
+            let x1 = match x1 {
+                Some(x1) => x1,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "No output received",
+                ))?,
+            };
+            for (res, x1) in izip!(res.iter_mut(), x1.chunks(T::K)) {
+                res.b = GCUtils::bits_to_ring(x1)?;
+            }
+        }
+        PartyID::ID2 => {
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+            let mut x23 = Vec::with_capacity(n_inputs);
+            for res in res.iter_mut() {
+                let k2 = io_context
+                    .rngs
+                    .bitcomp1
+                    .random_elements_3keys::<RingElement<T>>();
+                let k3 = io_context
+                    .rngs
+                    .bitcomp2
+                    .random_elements_3keys::<RingElement<T>>();
+                let k2_comp = k2.0 + k2.1 + k2.2;
+                let k3_comp = k3.0 + k3.1 + k3.2;
+                x23.push(k2_comp + k3_comp);
+                res.a = k3_comp.neg();
+                res.b = k2_comp.neg();
+            }
+            let x23 = yao::input_ring_id2_many(Some(x23), Some(delta), n_inputs, io_context)?;
+
+            let mut garbler = Rep3Garbler::new_with_delta(io_context, delta);
+            let mut x1 = Vec::with_capacity(n_inputs * T::K);
+            for (x, x23) in izip!(x.iter(), x23.wires().chunks(T::K)) {
+                let x23 = BinaryBundle::new(x23.to_vec());
+                let added = GarbledCircuits::adder_mod_2k(&mut garbler, x, &x23);
+                x1.extend(GCUtils::garbled_circuits_error(added)?.wires().to_owned());
+            }
+            let x1 = garbler.output_to_id0_and_id1(&x1)?;
+            if x1.is_some() {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Unexpected output received",
+                ))?;
+            }
+        }
+    };
+
+    Ok(res)
+}
+
 /// Transforms the shared value x from a yao sharing to an arithmetic sharing. I.e., the sharing such that the garbler have keys (k_0, delta) for each bit of x, while the evaluator has k_x = k_0 xor delta * x gets transformed into x = x_1 + x_2 + x_3. Uses the Streaming Garbler/Evaluator.
 pub fn y2a_streaming<T: IntRing2k, N: Rep3Network>(
     x: BinaryBundle<WireMod2>,
@@ -605,6 +939,45 @@ pub fn b2y<T: IntRing2k, N: Rep3Network>(
     Ok(converted)
 }
 
+/// Transforms the replicated shared values x from binary sharings to yao sharings. I.e., each x = x_1 xor x_2 xor x_3 gets transformed into wires, such that the garbler have keys (k_0, delta) for each bit of x, while the evaluator has k_x = k_0 xor delta * x. The inputs of all values are sent in one round.
+pub fn b2y_many<T: IntRing2k, N: Rep3Network>(
+    x: &[Rep3RingShare<T>],
+    delta: Option<WireMod2>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<BinaryBundle<WireMod2>>> {
+    let [x01, x2] = yao::joint_input_binary_xored_many(x, delta, io_context)?;
+
+    let converted = match io_context.id {
+        PartyID::ID0 => {
+            // There is no code difference between Rep3Evaluator and StreamingRep3Evaluator
+            let mut evaluator = Rep3Evaluator::new(io_context);
+            // evaluator.receive_circuit()?; // No network used here
+            let res = GarbledCircuits::xor_many(&mut evaluator, &x01, &x2);
+            GCUtils::garbled_circuits_error(res)?
+        }
+        PartyID::ID1 | PartyID::ID2 => {
+            // There is no code difference between Rep3Garbler and StreamingRep3Garbler
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+            let mut garbler = Rep3Garbler::new_with_delta(io_context, delta);
+            let res = GarbledCircuits::xor_many(&mut garbler, &x01, &x2);
+            GCUtils::garbled_circuits_error(res)?
+            // garbler.send_circuit()?; // No network used here
+        }
+    };
+
+    Ok(converted
+        .wires()
+        .chunks(T::K)
+        .map(|chunk| BinaryBundle::new(chunk.to_vec()))
+        .collect())
+}
+
 /// Transforms the shared value x from a yao sharing to a binary sharing. I.e., the sharing such that the garbler have keys (k_0, delta) for each bit of x, while the evaluator has k_x = k_0 xor delta * x gets transformed into x = x_1 xor x_2 xor x_3.
 pub fn y2b<T: IntRing2k, N: Rep3Network>(
     x: BinaryBundle<WireMod2>,
@@ -640,6 +1013,56 @@ where
     Ok(converted)
 }
 
+/// Transforms a vector of shared values x from yao sharings to binary sharings. I.e., the values x_i, represented as wires, where the evaluator has k_{x_i} = k_0 xor delta * x_i and the garblers have (k_0, delta) for each bit of x_i, get transformed into x_i = x_i'_1 xor x_i'_2 xor x_i'_3.
+pub fn y2b_many<T: IntRing2k, N: Rep3Network>(
+    x: Vec<BinaryBundle<WireMod2>>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    let collapsed = x
+        .into_iter()
+        .map(GCUtils::collapse_bundle_to_lsb_bits_as_ring)
+        .collect::<IoResult<Vec<RingElement<T>>>>()?;
+
+    let converted = match io_context.id {
+        PartyID::ID0 => {
+            let mut res = Vec::with_capacity(collapsed.len());
+            let mut send = Vec::with_capacity(collapsed.len());
+            for x_xor_px in collapsed {
+                let r = io_context.rngs.rand.random_element_rng1::<RingElement<T>>();
+                let r_xor_x_xor_px = x_xor_px ^ r;
+                send.push(r_xor_x_xor_px);
+                res.push(Rep3RingShare::new_ring(r, r_xor_x_xor_px));
+            }
+            io_context.network.send_many(PartyID::ID2, &send)?;
+            res
+        }
+        PartyID::ID1 => collapsed
+            .into_iter()
+            .map(|px| {
+                let r = io_context.rngs.rand.random_element_rng2::<RingElement<T>>();
+                Rep3RingShare::new_ring(px, r)
+            })
+            .collect(),
+        PartyID::ID2 => {
+            let r_xor_x_xor_px: Vec<RingElement<T>> = io_context.network.recv_many(PartyID::ID0)?;
+            if r_xor_x_xor_px.len() != collapsed.len() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Invalid number of elements received",
+                ));
+            }
+            izip!(r_xor_x_xor_px, collapsed)
+                .map(|(r_xor_x_xor_px, px)| Rep3RingShare::new_ring(r_xor_x_xor_px, px))
+                .collect()
+        }
+    };
+
+    Ok(converted)
+}
+
 /// Transforms the replicated shared value x from an arithmetic sharing to a binary sharing. I.e., x = x_1 + x_2 + x_3 gets transformed into x = x'_1 xor x'_2 xor x'_3.
 pub fn a2y2b<T: IntRing2k, N: Rep3Network>(
     x: Rep3RingShare<T>,
@@ -653,6 +1076,22 @@ where
     y2b(y, io_context)
 }
 
+/// Transforms the replicated shared values x from an arithmetic sharing to a binary sharing. I.e., x = x_1 + x_2 + x_3 gets transformed into x = x'_1 xor x'_2 xor x'_3. All conversions are done in one garbled circuit.
+pub fn a2y2b_many<T: IntRing2k, N: Rep3Network>(
+    x: &[Rep3RingShare<T>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    if x.is_empty() {
+        return Ok(Vec::new());
+    }
+    let delta = io_context.rngs.generate_random_garbler_delta(io_context.id);
+    let y = a2y_many(x, delta, io_context)?;
+    y2b_many(y, io_context)
+}
+
 /// Transforms the replicated shared value x from an arithmetic sharing to a binary sharing. I.e., x = x_1 + x_2 + x_3 gets transformed into x = x'_1 xor x'_2 xor x'_3. Uses the Streaming Garbler/Evaluator. Uses the Streaming Garbler/Evaluator.
 pub fn a2y2b_streaming<T: IntRing2k, N: Rep3Network>(
     x: Rep3RingShare<T>,
@@ -679,6 +1118,22 @@ where
     y2a(y, delta, io_context)
 }
 
+/// Transforms the replicated shared values x from a binary sharing to an arithmetic sharing. I.e., x = x_1 xor x_2 xor x_3 gets transformed into x = x'_1 + x'_2 + x'_3. This implementations goes through the yao protocol, where all conversions are done in one garbled circuit.
+pub fn b2y2a_many<T: IntRing2k, N: Rep3Network>(
+    x: &[Rep3RingShare<T>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    if x.is_empty() {
+        return Ok(Vec::new());
+    }
+    let delta = io_context.rngs.generate_random_garbler_delta(io_context.id);
+    let y = b2y_many(x, delta, io_context)?;
+    y2a_many(y, delta, io_context)
+}
+
 /// Transforms the replicated shared value x from a binary sharing to an arithmetic sharing. I.e., x = x_1 xor x_2 xor x_3 gets transformed into x = x'_1 + x'_2 + x'_3. This implementations goes through the yao protocol. Uses the Streaming Garbler/Evaluator.
 pub fn b2y2a_streaming<T: IntRing2k, N: Rep3Network>(
     x: &Rep3RingShare<T>,
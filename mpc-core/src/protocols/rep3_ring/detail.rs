@@ -3,6 +3,7 @@ use crate::{
     IoResult,
     protocols::rep3::network::{IoContext, Rep3Network},
 };
+use itertools::izip;
 use mpc_types::protocols::rep3_ring::{
     Rep3RingShare,
     ring::{bit::Bit, int_ring::IntRing2k, ring_impl::RingElement},
@@ -24,6 +25,27 @@ where
     kogge_stone_inner(&p, &g, io_context)
 }
 
+pub(super) fn low_depth_binary_add_many<T: IntRing2k, N: Rep3Network>(
+    x1: &[Rep3RingShare<T>],
+    x2: &[Rep3RingShare<T>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    // Add x1 + x2 via a packed Kogge-Stone adder, where all elements share the same rounds
+    let p = izip!(x1, x2).map(|(x1, x2)| x1 ^ x2).collect::<Vec<_>>();
+    let g = binary::and_vec(x1, x2, io_context)?;
+    let g = kogge_stone_loop_many(p.clone(), g, io_context)?;
+    Ok(izip!(g, p)
+        .map(|(mut g, p)| {
+            g <<= 1;
+            g ^= &p;
+            g
+        })
+        .collect())
+}
+
 fn kogge_stone_inner<T: IntRing2k, N: Rep3Network>(
     p: &Rep3RingShare<T>,
     g: &Rep3RingShare<T>,
@@ -78,6 +100,31 @@ where
     Ok(g)
 }
 
+fn kogge_stone_loop_many<T: IntRing2k, N: Rep3Network>(
+    mut p: Vec<Rep3RingShare<T>>,
+    mut g: Vec<Rep3RingShare<T>>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3RingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    let bitlen = T::K;
+    let d: u32 = bitlen.ilog2(); // T is a ring with 2^k elements
+    debug_assert!(bitlen.is_power_of_two());
+
+    for i in 0..d {
+        let shift = 1 << i;
+        let p_ = p.iter().map(|p| *p << shift).collect::<Vec<_>>();
+        let g_ = g.iter().map(|g| *g << shift).collect::<Vec<_>>();
+        let (r1, r2) = and_twice_many(&p, &g_, &p_, io_context)?;
+        p = r2;
+        for (g, r1) in izip!(g.iter_mut(), r1) {
+            *g ^= r1;
+        }
+    }
+    Ok(g)
+}
+
 fn and_twice<T: IntRing2k, N: Rep3Network>(
     a: &Rep3RingShare<T>,
     b1: &Rep3RingShare<T>,
@@ -112,6 +159,44 @@ where
     Ok((r1, r2))
 }
 
+fn and_twice_many<T: IntRing2k, N: Rep3Network>(
+    a: &[Rep3RingShare<T>],
+    b1: &[Rep3RingShare<T>],
+    b2: &[Rep3RingShare<T>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<(Vec<Rep3RingShare<T>>, Vec<Rep3RingShare<T>>)>
+where
+    Standard: Distribution<T>,
+{
+    let mut local_a = Vec::with_capacity(2 * a.len());
+    for (a, b1, b2) in izip!(a, b1, b2) {
+        let (mut mask1, mask_b) = io_context.rngs.rand.random_elements::<RingElement<T>>();
+        mask1 ^= mask_b;
+
+        let (mut mask2, mask_b) = io_context.rngs.rand.random_elements::<RingElement<T>>();
+        mask2 ^= mask_b;
+
+        local_a.push((b1 & a) ^ mask1);
+        local_a.push((a & b2) ^ mask2);
+    }
+    let local_b = io_context.network.reshare_many(&local_a)?;
+    if local_b.len() != local_a.len() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "Invalid number of elements received",
+        ));
+    }
+
+    let mut r1 = Vec::with_capacity(a.len());
+    let mut r2 = Vec::with_capacity(a.len());
+    for (a, b) in izip!(local_a.chunks_exact(2), local_b.chunks_exact(2)) {
+        r1.push(Rep3RingShare::new_ring(a[0], b[0]));
+        r2.push(Rep3RingShare::new_ring(a[1], b[1]));
+    }
+
+    Ok((r1, r2))
+}
+
 // Calculates 2^k + x1 - x2
 fn low_depth_binary_sub_with_carry<T: IntRing2k, N: Rep3Network>(
     x1: &Rep3RingShare<T>,
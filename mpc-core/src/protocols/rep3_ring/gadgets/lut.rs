@@ -286,6 +286,33 @@ pub fn write_lut_from_ohv<F: PrimeField, N: Rep3Network>(
     Ok(())
 }
 
+/// Writes multiple shared values to a shared LUT at once, given the one_hot_vectors of their indices (see [`write_lut_from_ohv`]). The indices need to be distinct, while a one_hot_vector with all elements 0 does not write its value. All values are written with one round of communication.
+pub fn write_lut_from_ohvs<F: PrimeField, N: Rep3Network>(
+    values: &[Rep3PrimeFieldShare<F>],
+    lut: &mut [Rep3PrimeFieldShare<F>],
+    ohvs: &[Vec<Rep3PrimeFieldShare<F>>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<()> {
+    assert_eq!(values.len(), ohvs.len());
+    let n = lut.len();
+    assert!(ohvs.iter().all(|ohv| n <= ohv.len()));
+    let mut local_a = Vec::with_capacity(n);
+    for (j, l) in lut.iter().enumerate() {
+        let mut acc = l.a + io_context.rngs.rand.masking_field_element::<F>();
+        for (value, ohv) in values.iter().zip(ohvs.iter()) {
+            acc += &ohv[j] * &(value - l);
+        }
+        local_a.push(acc);
+    }
+    let local_b = io_context.network.reshare_many(&local_a)?;
+
+    for (des, (src_a, src_b)) in lut.iter_mut().zip(local_a.into_iter().zip(local_b)) {
+        des.a = src_a;
+        des.b = src_b;
+    }
+    Ok(())
+}
+
 /// Takes a secret-shared lookup table containing field elements, and a replicated binary share of an index and puts another secret-shared field element (value) and puts it at lut`\[`index`\]`.
 /// The algorithm is inspired by Protocol 4 from [https://eprint.iacr.org/2024/1317.pdf](https://eprint.iacr.org/2024/1317.pdf).
 pub fn write_lut<F: PrimeField, T: IntRing2k, N: Rep3Network>(
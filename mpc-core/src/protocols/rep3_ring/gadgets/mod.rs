@@ -2,6 +2,17 @@
 //!
 //! This module contains some commonly used gadgets for the Rep3 protocol.
 
+macro_rules! join {
+    ($t1: expr, $t2: expr) => {{
+        std::thread::scope(|s| {
+            let t1 = s.spawn(|| $t1);
+            let t2 = $t2;
+            (t1.join().expect("can join"), t2)
+        })
+    }};
+}
+
 pub mod lut;
 pub mod ohv;
+pub mod shuffle;
 pub mod sort;
@@ -3,6 +3,7 @@
 //! This module contains some algorithms to create a random one-hot encoded vector for the Rep3 protocol.
 
 use ark_ff::{One, Zero};
+use itertools::izip;
 use mpc_types::protocols::rep3_ring::{
     Rep3RingShare,
     ring::{bit::Bit, int_ring::IntRing2k, ring_impl::RingElement},
@@ -74,6 +75,50 @@ pub fn ohv<T: IntRing2k, N: Rep3Network>(
     Ok(f)
 }
 
+/// Generates one-hot-encoded vectors of size k bits for multiple secret shared indices which are already decomposed into shared bits, see [`ohv`].
+/// All vectors are created in parallel, such that the number of communication rounds is the same as for a single index.
+pub fn ohv_many<T: IntRing2k, N: Rep3Network>(
+    k: usize,
+    bits: Vec<Rep3RingShare<T>>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Vec<Rep3RingShare<Bit>>>> {
+    debug_assert!(k > 0);
+    debug_assert!(k <= T::K); // Make sure datatype is large enough for bitsize
+
+    let new_k = k - 1;
+    let vks = bits
+        .iter()
+        .map(|bits| bits.get_bit(new_k))
+        .collect::<Vec<_>>();
+
+    if new_k == 0 {
+        return Ok(vks.into_iter().map(|vk| vec![!vk, vk]).collect());
+    }
+
+    let mask = (RingElement::one() << new_k) - RingElement::one();
+    let bits = bits
+        .into_iter()
+        .map(|mut bits| {
+            bits &= mask; // Remove the vk
+            bits
+        })
+        .collect();
+
+    let mut fs = ohv_many(new_k, bits, io_context)?; // ohv_many is recursively called k - 1 times
+    let inputs = fs.iter().map(|f| &f[..f.len() - 1]).collect::<Vec<_>>();
+    let es = pack_and_many(&inputs, &vks, io_context)?; // This has communication (2^new_k - 1 bits per index)
+
+    for (f, mut e, vk) in izip!(fs.iter_mut(), es, vks) {
+        e.push(e.iter().fold(vk, |a, b| &a ^ b));
+
+        for (e, f) in e.iter().zip(f.iter_mut()) {
+            *f ^= e;
+        }
+        f.extend(e);
+    }
+    Ok(fs)
+}
+
 fn pack<T: IntRing2k>(input: &[Rep3RingShare<Bit>]) -> Rep3RingShare<T> {
     let mut share_a = RingElement::<T>::zero();
     let mut share_b = RingElement::<T>::zero();
@@ -185,3 +230,41 @@ fn pack_and<N: Rep3Network>(
         Ok(result)
     }
 }
+
+// Computes input[i] AND rhs[i] for all i with one round of communication
+fn pack_and_many<N: Rep3Network>(
+    inputs: &[&[Rep3RingShare<Bit>]],
+    rhs: &[Rep3RingShare<Bit>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Vec<Rep3RingShare<Bit>>>> {
+    debug_assert_eq!(inputs.len(), rhs.len());
+    type Packtype = u64;
+    const BITLEN: usize = std::mem::size_of::<Packtype>() * 8;
+
+    let mut to_send = Vec::with_capacity(inputs.iter().map(|i| i.len().div_ceil(BITLEN)).sum());
+    for (input, rhs) in izip!(inputs, rhs) {
+        for els in input.chunks(BITLEN) {
+            let packed = pack::<Packtype>(els);
+            to_send.push(and_pre_bit(&packed, rhs, io_context));
+        }
+    }
+    let received = io_context.network.reshare(to_send.to_owned())?;
+
+    let mut shares = to_send
+        .into_iter()
+        .zip(received)
+        .map(|(a, b)| Rep3RingShare::new_ring(a, b));
+    let mut result = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        let mut res = Vec::with_capacity(input.len());
+        let mut remaining = input.len();
+        while remaining > 0 {
+            let rcv = std::cmp::min(BITLEN, remaining);
+            let share = shares.next().expect("we have the correct amount of shares");
+            res.extend(unpack(share, rcv));
+            remaining -= rcv;
+        }
+        result.push(res);
+    }
+    Ok(result)
+}
@@ -0,0 +1,253 @@
+//! Signed
+//!
+//! This module contains operations with arithmetic shares of signed integers. Signed integers are stored in the ring Z_{2^k} using the two's complement representation (see [`Rep3SignedRingShare`]). Thus, addition, subtraction and multiplication are the same as for unsigned integers and just forward to the functions in [`super::arithmetic`].
+//!
+//! All comparisons are reduced to their unsigned counterparts by adding 2^(k-1) to both operands, which maps the signed range [-2^(k-1), 2^(k-1)) monotonically onto the unsigned range [0, 2^k).
+
+use crate::{
+    IoResult,
+    protocols::rep3::network::{IoContext, Rep3Network},
+};
+use mpc_types::protocols::{
+    rep3::id::PartyID,
+    rep3_ring::{
+        Rep3BitShare, Rep3RingShare, Rep3SignedRingShare,
+        ring::{int_ring::IntRing2k, ring_impl::RingElement},
+    },
+};
+use num_traits::One;
+use rand::{distributions::Standard, prelude::Distribution};
+
+use super::{arithmetic, yao};
+
+/// Returns 2^(k-1), i.e., the value which has to be added to a signed value to map it onto the unsigned range while preserving the order.
+pub(super) fn sign_offset<T: IntRing2k>() -> RingElement<T> {
+    RingElement::one() << (T::K - 1)
+}
+
+fn bias<T: IntRing2k>(x: Rep3SignedRingShare<T>, id: PartyID) -> Rep3RingShare<T> {
+    arithmetic::add_public(x.into_unsigned(), sign_offset(), id)
+}
+
+fn bias_public<T: IntRing2k>(x: T::Signed) -> RingElement<T> {
+    RingElement::from_signed(x) + sign_offset()
+}
+
+/// Performs addition between two shared values.
+pub fn add<T: IntRing2k>(
+    a: Rep3SignedRingShare<T>,
+    b: Rep3SignedRingShare<T>,
+) -> Rep3SignedRingShare<T> {
+    Rep3SignedRingShare::from_unsigned(arithmetic::add(a.into_unsigned(), b.into_unsigned()))
+}
+
+/// Performs addition between a shared value and a public value.
+pub fn add_public<T: IntRing2k>(
+    shared: Rep3SignedRingShare<T>,
+    public: T::Signed,
+    id: PartyID,
+) -> Rep3SignedRingShare<T> {
+    Rep3SignedRingShare::from_unsigned(arithmetic::add_public(
+        shared.into_unsigned(),
+        RingElement::from_signed(public),
+        id,
+    ))
+}
+
+/// Performs subtraction between two shared values, returning a - b.
+pub fn sub<T: IntRing2k>(
+    a: Rep3SignedRingShare<T>,
+    b: Rep3SignedRingShare<T>,
+) -> Rep3SignedRingShare<T> {
+    Rep3SignedRingShare::from_unsigned(arithmetic::sub(a.into_unsigned(), b.into_unsigned()))
+}
+
+/// Performs negation of a shared value.
+pub fn neg<T: IntRing2k>(a: Rep3SignedRingShare<T>) -> Rep3SignedRingShare<T> {
+    Rep3SignedRingShare::from_unsigned(arithmetic::neg(a.into_unsigned()))
+}
+
+/// Performs multiplication of two shared values.
+pub fn mul<T: IntRing2k, N: Rep3Network>(
+    a: Rep3SignedRingShare<T>,
+    b: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Rep3SignedRingShare<T>>
+where
+    Standard: Distribution<T>,
+{
+    let res = arithmetic::mul(a.into_unsigned(), b.into_unsigned(), io_context)?;
+    Ok(Rep3SignedRingShare::from_unsigned(res))
+}
+
+/// Performs multiplication of a shared value and a public value.
+pub fn mul_public<T: IntRing2k>(
+    shared: Rep3SignedRingShare<T>,
+    public: T::Signed,
+) -> Rep3SignedRingShare<T> {
+    Rep3SignedRingShare::from_unsigned(arithmetic::mul_public(
+        shared.into_unsigned(),
+        RingElement::from_signed(public),
+    ))
+}
+
+/// Opens a shared value and returns it as a signed integer.
+pub fn open<T: IntRing2k, N: Rep3Network>(
+    a: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<T::Signed> {
+    Ok(arithmetic::open(a.into_unsigned(), io_context)?.to_signed())
+}
+
+/// Returns 1 if lhs < rhs and 0 otherwise, where both values are interpreted as signed integers.
+pub fn lt<T: IntRing2k, N: Rep3Network>(
+    lhs: Rep3SignedRingShare<T>,
+    rhs: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Rep3BitShare>
+where
+    Standard: Distribution<T>,
+{
+    let id = io_context.id;
+    arithmetic::lt(bias(lhs, id), bias(rhs, id), io_context)
+}
+
+/// Returns 1 if lhs < rhs and 0 otherwise, where both values are interpreted as signed integers.
+pub fn lt_public<T: IntRing2k, N: Rep3Network>(
+    lhs: Rep3SignedRingShare<T>,
+    rhs: T::Signed,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Rep3BitShare>
+where
+    Standard: Distribution<T>,
+{
+    arithmetic::lt_public(bias(lhs, io_context.id), bias_public(rhs), io_context)
+}
+
+/// Returns 1 if lhs <= rhs and 0 otherwise, where both values are interpreted as signed integers.
+pub fn le<T: IntRing2k, N: Rep3Network>(
+    lhs: Rep3SignedRingShare<T>,
+    rhs: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Rep3BitShare>
+where
+    Standard: Distribution<T>,
+{
+    let id = io_context.id;
+    arithmetic::le(bias(lhs, id), bias(rhs, id), io_context)
+}
+
+/// Returns 1 if lhs <= rhs and 0 otherwise, where both values are interpreted as signed integers.
+pub fn le_public<T: IntRing2k, N: Rep3Network>(
+    lhs: Rep3SignedRingShare<T>,
+    rhs: T::Signed,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Rep3BitShare>
+where
+    Standard: Distribution<T>,
+{
+    arithmetic::le_public(bias(lhs, io_context.id), bias_public(rhs), io_context)
+}
+
+/// Returns 1 if lhs > rhs and 0 otherwise, where both values are interpreted as signed integers.
+pub fn gt<T: IntRing2k, N: Rep3Network>(
+    lhs: Rep3SignedRingShare<T>,
+    rhs: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Rep3BitShare>
+where
+    Standard: Distribution<T>,
+{
+    let id = io_context.id;
+    arithmetic::gt(bias(lhs, id), bias(rhs, id), io_context)
+}
+
+/// Returns 1 if lhs > rhs and 0 otherwise, where both values are interpreted as signed integers.
+pub fn gt_public<T: IntRing2k, N: Rep3Network>(
+    lhs: Rep3SignedRingShare<T>,
+    rhs: T::Signed,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Rep3BitShare>
+where
+    Standard: Distribution<T>,
+{
+    arithmetic::gt_public(bias(lhs, io_context.id), bias_public(rhs), io_context)
+}
+
+/// Returns 1 if lhs >= rhs and 0 otherwise, where both values are interpreted as signed integers.
+pub fn ge<T: IntRing2k, N: Rep3Network>(
+    lhs: Rep3SignedRingShare<T>,
+    rhs: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Rep3BitShare>
+where
+    Standard: Distribution<T>,
+{
+    let id = io_context.id;
+    arithmetic::ge(bias(lhs, id), bias(rhs, id), io_context)
+}
+
+/// Returns 1 if lhs >= rhs and 0 otherwise, where both values are interpreted as signed integers.
+pub fn ge_public<T: IntRing2k, N: Rep3Network>(
+    lhs: Rep3SignedRingShare<T>,
+    rhs: T::Signed,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Rep3BitShare>
+where
+    Standard: Distribution<T>,
+{
+    arithmetic::ge_public(bias(lhs, io_context.id), bias_public(rhs), io_context)
+}
+
+/// Returns 1 if the shared signed value is negative and 0 otherwise.
+pub fn is_negative<T: IntRing2k, N: Rep3Network>(
+    x: Rep3SignedRingShare<T>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Rep3BitShare>
+where
+    Standard: Distribution<T>,
+{
+    // x < 0 <=> x + 2^(k-1) < 2^(k-1)
+    arithmetic::lt_public(bias(x, io_context.id), sign_offset(), io_context)
+}
+
+/// Computes the arithmetic right shift of a vector of signed values, i.e., the sign bit is replicated into the vacant most significant bits. This is equivalent to a division by 2^shift which rounds towards negative infinity.
+pub fn arithmetic_shift_right_public_many<T: IntRing2k, N: Rep3Network>(
+    inputs: &[Rep3SignedRingShare<T>],
+    shift: usize,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3SignedRingShare<T>>>
+where
+    Standard: Distribution<T>,
+{
+    if shift == 0 {
+        return Ok(inputs.to_owned());
+    }
+    // Shifting by k-1 already results in either 0 or -1
+    let shift = shift.min(T::K - 1);
+
+    // For y = x + 2^(k-1) we have floor(x / 2^shift) = floor(y / 2^shift) - 2^(k-1-shift)
+    let id = io_context.id;
+    let biased = inputs.iter().map(|x| bias(*x, id)).collect::<Vec<_>>();
+    let shifted = yao::ring_div_power_2_many(&biased, io_context, shift)?;
+    let offset = sign_offset::<T>() >> shift;
+    Ok(shifted
+        .into_iter()
+        .map(|x| {
+            Rep3SignedRingShare::from_unsigned(arithmetic::sub_shared_by_public(x, offset, id))
+        })
+        .collect())
+}
+
+/// Computes the arithmetic right shift of a signed value, i.e., the sign bit is replicated into the vacant most significant bits. This is equivalent to a division by 2^shift which rounds towards negative infinity.
+pub fn arithmetic_shift_right_public<T: IntRing2k, N: Rep3Network>(
+    input: Rep3SignedRingShare<T>,
+    shift: usize,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Rep3SignedRingShare<T>>
+where
+    Standard: Distribution<T>,
+{
+    let res = arithmetic_shift_right_public_many(&[input], shift, io_context)?;
+    Ok(res[0])
+}
@@ -13,7 +13,7 @@ use crate::{
                 garbler::Rep3Garbler,
             },
         },
-        rep3_ring::conversion,
+        rep3_ring::{self, conversion},
     },
 };
 use ark_ff::PrimeField;
@@ -124,9 +124,26 @@ pub fn joint_input_arithmetic_added_many<T: IntRing2k, N: Rep3Network>(
     delta: Option<WireMod2>,
     io_context: &mut IoContext<N>,
 ) -> IoResult<[BinaryBundle<WireMod2>; 2]> {
+    joint_input_arithmetic_added_many_truncated(x, T::K, delta, io_context)
+}
+
+/// Transforms a vector of arithmetically shared inputs x = (x_1, x_2, x_3) into two yao shares x_1^Y, (x_2 + x_3)^Y, where only the lowest `bitlen` bits of each share are input into the garbled circuit. Adding the two yao shares thus results in x mod 2^bitlen. The used delta is an input to the function to allow for the same delta to be used for multiple conversions.
+pub fn joint_input_arithmetic_added_many_truncated<T: IntRing2k, N: Rep3Network>(
+    x: &[Rep3RingShare<T>],
+    bitlen: usize,
+    delta: Option<WireMod2>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<[BinaryBundle<WireMod2>; 2]> {
+    if bitlen == 0 || bitlen > T::K {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Bit length must be in [1, {}]: {}", T::K, bitlen),
+        ));
+    }
+
     let id = io_context.id;
     let n_inputs = x.len();
-    let n_bits = T::K;
+    let n_bits = bitlen;
     let bits = n_inputs * n_bits;
 
     let (x01, x2) = match id {
@@ -153,7 +170,8 @@ pub fn joint_input_arithmetic_added_many<T: IntRing2k, N: Rep3Network>(
             // Input x01
             for x in x.iter() {
                 let sum = x.a + x.b;
-                let bits = GCUtils::ring_to_bits_as_u16(sum);
+                let mut bits = GCUtils::ring_to_bits_as_u16(sum);
+                bits.truncate(n_bits);
                 let (garbler, evaluator) =
                     GCUtils::encode_bits_as_wires(bits, &mut io_context.rng, delta);
                 garbler_bundle.extend(garbler);
@@ -183,7 +201,8 @@ pub fn joint_input_arithmetic_added_many<T: IntRing2k, N: Rep3Network>(
 
             // Input x2
             for x in x.iter() {
-                let bits = GCUtils::ring_to_bits_as_u16(x.a);
+                let mut bits = GCUtils::ring_to_bits_as_u16(x.a);
+                bits.truncate(n_bits);
                 let (garbler, evaluator) =
                     GCUtils::encode_bits_as_wires(bits, &mut io_context.rng, delta);
                 garbler_bundle.extend(garbler);
@@ -339,6 +358,89 @@ pub fn joint_input_binary_xored<T: IntRing2k, N: Rep3Network>(
     Ok([x01, x2])
 }
 
+/// Transforms a vector of binary shared inputs x = (x_1, x_2, x_3) into two yao shares x_1^Y, (x_2 xor x_3)^Y, where the wires of all inputs are concatenated. The used delta is an input to the function to allow for the same delta to be used for multiple conversions.
+pub fn joint_input_binary_xored_many<T: IntRing2k, N: Rep3Network>(
+    x: &[Rep3RingShare<T>],
+    delta: Option<WireMod2>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<[BinaryBundle<WireMod2>; 2]> {
+    let id = io_context.id;
+    let bits = x.len() * T::K;
+
+    let (x01, x2) = match id {
+        PartyID::ID0 => {
+            // Receive x01
+            let x01 = GCUtils::receive_bundle_from(bits, &mut io_context.network, PartyID::ID1)?;
+
+            // Receive x2
+            let x2 = GCUtils::receive_bundle_from(bits, &mut io_context.network, PartyID::ID2)?;
+            (x01, x2)
+        }
+        PartyID::ID1 => {
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+
+            let mut garbler_bundle = Vec::with_capacity(bits);
+            let mut evaluator_bundle = Vec::with_capacity(bits);
+
+            // Input x01
+            for x in x.iter() {
+                let bits = GCUtils::ring_to_bits_as_u16(x.a ^ x.b);
+                let (garbler, evaluator) =
+                    GCUtils::encode_bits_as_wires(bits, &mut io_context.rng, delta);
+                garbler_bundle.extend(garbler);
+                evaluator_bundle.extend(evaluator);
+            }
+            let x01 = GCUtils::wires_to_gcinput(garbler_bundle, evaluator_bundle, delta);
+
+            // Send x01 to the other parties
+            GCUtils::send_inputs(&x01, &mut io_context.network, PartyID::ID2)?;
+            let x01 = x01.garbler_wires;
+
+            // Receive x2
+            let x2 = GCUtils::receive_bundle_from(bits, &mut io_context.network, PartyID::ID2)?;
+            (x01, x2)
+        }
+        PartyID::ID2 => {
+            let delta = match delta {
+                Some(delta) => delta,
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "No delta provided",
+                ))?,
+            };
+
+            let mut garbler_bundle = Vec::with_capacity(bits);
+            let mut evaluator_bundle = Vec::with_capacity(bits);
+
+            // Input x2
+            for x in x.iter() {
+                let bits = GCUtils::ring_to_bits_as_u16(x.a);
+                let (garbler, evaluator) =
+                    GCUtils::encode_bits_as_wires(bits, &mut io_context.rng, delta);
+                garbler_bundle.extend(garbler);
+                evaluator_bundle.extend(evaluator);
+            }
+            let x2 = GCUtils::wires_to_gcinput(garbler_bundle, evaluator_bundle, delta);
+
+            // Send x2 to the other parties
+            GCUtils::send_inputs(&x2, &mut io_context.network, PartyID::ID1)?;
+            let x2 = x2.garbler_wires;
+
+            // Receive x01
+            let x01 = GCUtils::receive_bundle_from(bits, &mut io_context.network, PartyID::ID1)?;
+            (x01, x2)
+        }
+    };
+
+    Ok([x01, x2])
+}
+
 /// A cast of a vector of Rep3RingShare to a vector of Rep3PrimeFieldShare
 pub fn ring_to_field_many<T: IntRing2k, F: PrimeField, N: Rep3Network>(
     inputs: &[Rep3RingShare<T>],
@@ -744,6 +846,101 @@ where
     Ok(res[0])
 }
 
+/// Checks for a vector of ring elements whether they are smaller than a public bound. Only the lowest `bitlen` bits of the ring elements are input into the garbled circuit, i.e., the comparison is performed on x mod 2^bitlen. Thus, the inputs are required to be smaller than 2^bitlen to get the correct result, which reduces the size of the circuit and the communication for small values in large rings. Setting `bitlen` to T::K does not impose any restriction on the inputs. The result is a vector of shared bits which are set iff the corresponding input is smaller than the bound.
+pub fn lt_public_truncated_many<T: IntRing2k, N: Rep3Network>(
+    inputs: &[Rep3RingShare<T>],
+    bound: RingElement<T>,
+    bitlen: usize,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3RingShare<Bit>>>
+where
+    Standard: Distribution<T>,
+{
+    if bitlen == 0 || bitlen > T::K {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Bit length must be in [1, {}]: {}", T::K, bitlen),
+        ));
+    }
+
+    let mut bound_bits = GCUtils::ring_to_bits(bound);
+    if bound_bits.iter().skip(bitlen).any(|b| *b) {
+        // The bound is at least 2^bitlen, so all inputs are smaller
+        let one = RingElement::<Bit>::one();
+        return Ok(vec![
+            rep3_ring::binary::promote_to_trivial_share(
+                io_context.id,
+                &one
+            );
+            inputs.len()
+        ]);
+    }
+    bound_bits.truncate(bitlen);
+    if inputs.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let delta = io_context.rngs.generate_random_garbler_delta(io_context.id);
+    let [x01, x2] = joint_input_arithmetic_added_many_truncated(inputs, bitlen, delta, io_context)?;
+
+    let res = match io_context.id {
+        PartyID::ID0 => {
+            let mut evaluator = Rep3Evaluator::new(io_context);
+            evaluator.receive_circuit()?;
+            let res = GarbledCircuits::ring_lt_public_many(&mut evaluator, &x01, &x2, &bound_bits);
+            GCUtils::garbled_circuits_error(res)?
+        }
+        PartyID::ID1 | PartyID::ID2 => {
+            let mut garbler =
+                Rep3Garbler::new_with_delta(io_context, delta.expect("Delta not provided"));
+            let res = GarbledCircuits::ring_lt_public_many(&mut garbler, &x01, &x2, &bound_bits);
+            let res = GCUtils::garbled_circuits_error(res)?;
+            garbler.send_circuit()?;
+            res
+        }
+    };
+
+    let res = res
+        .wires()
+        .iter()
+        .map(|wire| BinaryBundle::new(vec![wire.to_owned()]))
+        .collect();
+    conversion::y2b_many(res, io_context)
+}
+
+/// Checks for a vector of ring elements whether they are in the public range [lower, upper] (both inclusive). As in [lt_public_truncated_many], only the lowest `bitlen` bits of the ring elements are considered, thus the inputs and `upper` are required to be smaller than 2^bitlen. The result is a vector of shared bits which are set iff the corresponding input is in the range.
+pub fn is_in_range_truncated_many<T: IntRing2k, N: Rep3Network>(
+    inputs: &[Rep3RingShare<T>],
+    lower: RingElement<T>,
+    upper: RingElement<T>,
+    bitlen: usize,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3RingShare<Bit>>>
+where
+    Standard: Distribution<T>,
+{
+    if lower > upper {
+        return Ok(vec![Rep3RingShare::zero_share(); inputs.len()]);
+    }
+    // x in [lower, upper] iff (x - lower) mod 2^bitlen < upper - lower + 1, since x - lower wraps around to a value of at least 2^bitlen - lower > upper - lower for x < lower. If upper - lower + 1 overflows to 0, all values are in range.
+    let bound = upper - lower + RingElement::one();
+    if bound.is_zero() {
+        let one = RingElement::<Bit>::one();
+        return Ok(vec![
+            rep3_ring::binary::promote_to_trivial_share(
+                io_context.id,
+                &one
+            );
+            inputs.len()
+        ]);
+    }
+    let shifted = inputs
+        .iter()
+        .map(|x| rep3_ring::arithmetic::sub_shared_by_public(*x, lower, io_context.id))
+        .collect::<Vec<_>>();
+    lt_public_truncated_many(&shifted, bound, bitlen, io_context)
+}
+
 /// Divides a vector of ring elements by another.
 pub fn ring_div_many<T: IntRing2k, N: Rep3Network>(
     input1: &[Rep3RingShare<T>],
@@ -15,29 +15,66 @@ use crate::RngType;
 
 pub mod arithmetic;
 pub mod network;
+pub mod packed;
 pub mod pointshare;
 pub mod poly;
 mod rngs;
 
 pub use mpc_types::protocols::shamir::{
-    ShamirPointShare, ShamirPrimeFieldShare, combine_curve_point, combine_field_element,
-    combine_field_elements, evaluate_poly, evaluate_poly_point,
-    interpolation_poly_from_zero_points, lagrange_from_coeff, poly_with_zeros_from_precomputed,
-    poly_with_zeros_from_precomputed_point, reconstruct_point, share_curve_point,
-    share_field_element, share_field_elements,
+    RobustReconstruction, SeedRng, SeededType, ShamirPointShare, ShamirPrimeFieldShare,
+    ShamirShareVecType, combine_curve_point, combine_field_element, combine_field_elements,
+    evaluate_poly, evaluate_poly_point, interpolation_poly_from_zero_points, lagrange_from_coeff,
+    poly_with_zeros_from_precomputed, poly_with_zeros_from_precomputed_point, reconstruct_point,
+    share_curve_point, share_field_element, share_field_elements, share_field_elements_seeded, vss,
 };
 
+/// The protocol which is used to reduce the degree of a batch of multiplied shares in [`ShamirProtocol::degree_reduce_vec`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ShamirMulMode {
+    /// A single king (party 0) receives the masked shares of 2t + 1 parties, reconstructs the masked products and sends fresh shares to all parties. The king is the bottleneck, since it receives 2t and sends n - t - 1 elements per multiplication.
+    #[default]
+    King,
+    /// The king is rotated over all parties, i.e., the i-th element of a batch is reduced by party i mod n, which receives the masked shares of itself and the next 2t parties. This only spreads the work of the king evenly over all n parties: the total communication is the same as for [`Self::King`], but the busiest party sends and receives roughly n / 2 times less for large batches, which helps when the bandwidth of the king is the bottleneck. See [`Self::Atlas`] for a mode which also lowers the total communication.
+    RotatingKing,
+    /// The king is rotated as in [`Self::RotatingKing`], but every n elements of a batch only consume t + 1 random double sharings, which are expanded to n double sharings as in ATLAS (Goyal et al., CRYPTO 2021). Since only t + 1 of the expanded double sharings are uniformly random, the kings can not share the masked products with a known polynomial anymore and send a random degree-t sharing to all n parties instead. This costs t additional elements per multiplication, but the preprocessing of the double sharings costs n / (t + 1) times less. For n > 2t + 1 (e.g., n = 7 and t = 2) this lowers the total communication, for n = 2t + 1 the saved preprocessing does not make up for the additional elements.
+    Atlas,
+}
+
+impl ShamirMulMode {
+    /// Returns the number of random double sharings consumed by [`ShamirProtocol::degree_reduce_vec`] to reduce a batch of `amount` elements.
+    pub fn num_pairs(self, amount: usize, threshold: usize, num_parties: usize) -> usize {
+        match self {
+            ShamirMulMode::King | ShamirMulMode::RotatingKing => amount,
+            ShamirMulMode::Atlas => {
+                let full = amount / num_parties * (threshold + 1);
+                full + (amount % num_parties).min(threshold + 1)
+            }
+        }
+    }
+}
+
 /// This type is used to construct a [`ShamirProtocol`].
 /// Preprocess `amount` number of corre;ated randomness pairs that are consumed while using the protocol.
 pub struct ShamirPreprocessing<F: PrimeField, N: ShamirNetwork> {
     threshold: usize,
     rng_buffer: ShamirRng<F>,
     network: N,
+    mul_mode: ShamirMulMode,
 }
 
 impl<F: PrimeField, N: ShamirNetwork> ShamirPreprocessing<F, N> {
     /// Construct a new [`ShamirPreprocessing`] type and generate `amount` number of corr rand pairs
-    pub fn new(threshold: usize, mut network: N, amount: usize) -> eyre::Result<Self> {
+    pub fn new(threshold: usize, network: N, amount: usize) -> eyre::Result<Self> {
+        Self::new_with_mul_mode(threshold, network, amount, ShamirMulMode::default())
+    }
+
+    /// Construct a new [`ShamirPreprocessing`] type with the given [`ShamirMulMode`] and generate the corr rand pairs required for `amount` multiplications. See [`ShamirMulMode::num_pairs`].
+    pub fn new_with_mul_mode(
+        threshold: usize,
+        mut network: N,
+        amount: usize,
+        mul_mode: ShamirMulMode,
+    ) -> eyre::Result<Self> {
         let num_parties = network.get_num_parties();
 
         if 2 * threshold + 1 > num_parties {
@@ -48,6 +85,7 @@ impl<F: PrimeField, N: ShamirNetwork> ShamirPreprocessing<F, N> {
         let mut rng_buffer = ShamirRng::new(seed, threshold, &mut network)?;
 
         let start = Instant::now();
+        let amount = mul_mode.num_pairs(amount, threshold, num_parties);
         // buffer_triple generates amount * batch_size, so we ceil dive the amount we want
         let amount = amount.div_ceil(rng_buffer.get_size_per_batch());
         rng_buffer.buffer_triples(&mut network, amount)?;
@@ -60,8 +98,14 @@ impl<F: PrimeField, N: ShamirNetwork> ShamirPreprocessing<F, N> {
             threshold,
             rng_buffer,
             network,
+            mul_mode,
         })
     }
+
+    /// Sets the [`ShamirMulMode`] of the resulting [`ShamirProtocol`]. All parties need to set the same mode. The already generated corr rand pairs are not adapted to the mode, use [`Self::new_with_mul_mode`] to only generate the required ones.
+    pub fn set_mul_mode(&mut self, mul_mode: ShamirMulMode) {
+        self.mul_mode = mul_mode;
+    }
 }
 
 impl<F: PrimeField, N: ShamirNetwork> From<ShamirPreprocessing<F, N>> for ShamirProtocol<F, N> {
@@ -89,6 +133,33 @@ impl<F: PrimeField, N: ShamirNetwork> From<ShamirPreprocessing<F, N>> for Shamir
         let num_non_zero = num_parties - value.threshold;
         let zero_points = (num_non_zero + 1..=num_parties).collect::<Vec<_>>();
         let mul_reconstruct_with_zeros = interpolation_poly_from_zero_points(&zero_points);
+        let robust_open = RobustReconstruction::new(value.threshold, num_parties);
+
+        // The king k reconstructs from its own share and the ones of the next 2t parties
+        let mul_lagrange_2t_rotating = match value.mul_mode {
+            ShamirMulMode::King => Vec::new(),
+            ShamirMulMode::RotatingKing | ShamirMulMode::Atlas => (0..num_parties)
+                .map(|king| {
+                    lagrange_from_coeff(
+                        &(0..2 * value.threshold + 1)
+                            .map(|i| (king + i) % num_parties + 1)
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .collect(),
+        };
+
+        // Row i evaluates the polynomial with the t + 1 double sharings as coefficients at i + 1
+        let atlas_expansion = match value.mul_mode {
+            ShamirMulMode::King | ShamirMulMode::RotatingKing => Vec::new(),
+            ShamirMulMode::Atlas => (1..=num_parties as u64)
+                .map(|i| {
+                    (0..=value.threshold as u64)
+                        .map(|pow| F::from(i).pow([pow]))
+                        .collect()
+                })
+                .collect(),
+        };
 
         ShamirProtocol {
             threshold: value.threshold,
@@ -96,6 +167,11 @@ impl<F: PrimeField, N: ShamirNetwork> From<ShamirPreprocessing<F, N>> for Shamir
             open_lagrange_2t,
             mul_lagrange_2t,
             mul_reconstruct_with_zeros,
+            mul_mode: value.mul_mode,
+            mul_lagrange_2t_rotating,
+            atlas_expansion,
+            robust_open,
+            degree_reduce_check: None,
             network: value.network,
             rng_buffer: value.rng_buffer,
             generation_amount: Self::DEFAULT_PAIR_GEN_AMOUNT,
@@ -115,6 +191,11 @@ pub struct ShamirProtocol<F: PrimeField, N: ShamirNetwork> {
     pub open_lagrange_2t: Vec<F>,
     mul_lagrange_2t: Vec<F>,
     mul_reconstruct_with_zeros: Vec<F>,
+    mul_mode: ShamirMulMode,
+    mul_lagrange_2t_rotating: Vec<Vec<F>>,
+    atlas_expansion: Vec<Vec<F>>,
+    robust_open: RobustReconstruction<F>,
+    degree_reduce_check: Option<RobustReconstruction<F>>,
     /// The underlying [`ShamirNetwork`]
     pub network: N,
     rng_buffer: ShamirRng<F>,
@@ -133,12 +214,28 @@ impl<F: PrimeField, N: ShamirNetwork> ShamirProtocol<F, N> {
             open_lagrange_2t: self.open_lagrange_2t.clone(),
             mul_lagrange_2t: self.mul_lagrange_2t.clone(),
             mul_reconstruct_with_zeros: self.mul_reconstruct_with_zeros.clone(),
+            mul_mode: self.mul_mode,
+            mul_lagrange_2t_rotating: self.mul_lagrange_2t_rotating.clone(),
+            atlas_expansion: self.atlas_expansion.clone(),
+            robust_open: self.robust_open.clone(),
+            degree_reduce_check: self.degree_reduce_check.clone(),
             network: self.network.fork()?,
             rng_buffer: self.rng_buffer.fork_with_pairs(amount),
             generation_amount: self.generation_amount,
         })
     }
 
+    /// Returns the [`ShamirMulMode`] used in [`Self::degree_reduce_vec`]
+    pub fn mul_mode(&self) -> ShamirMulMode {
+        self.mul_mode
+    }
+
+    /// Enables or disables the consistency check in [`Self::degree_reduce_vec`]. If enabled, all parties send their masked shares to the king, which checks that they lie on a polynomial of degree 2t and returns an error containing the parties with inconsistent shares otherwise. The check needs n > 2t + 1 parties, otherwise there are no redundant shares. All parties need to set the same mode.
+    pub fn set_degree_reduce_check(&mut self, enabled: bool) {
+        self.degree_reduce_check = enabled
+            .then(|| RobustReconstruction::new(2 * self.threshold, self.network.get_num_parties()));
+    }
+
     /// Get a correlated randomness pair
     pub fn get_pair(&mut self) -> std::io::Result<(F, F)> {
         if self.rng_buffer.r_t.is_empty() {
@@ -181,6 +278,10 @@ impl<F: PrimeField, N: ShamirNetwork> ShamirProtocol<F, N> {
         &mut self,
         mut input: F,
     ) -> std::io::Result<ShamirPrimeFieldShare<F>> {
+        if self.degree_reduce_check.is_some() {
+            let mut res = self.degree_reduce_vec(vec![input])?;
+            return Ok(res.pop().expect("we reduced one element"));
+        }
         let num_non_zero = self.network.get_num_parties() - self.threshold;
 
         let (r_t, r_2t) = self.get_pair()?;
@@ -232,24 +333,267 @@ impl<F: PrimeField, N: ShamirNetwork> ShamirProtocol<F, N> {
         Ok(ShamirPrimeFieldShare::new(my_share - r_t))
     }
 
-    /// Degree reduce all inputs
+    // Shares the degree reduced values of the king with a known polynomial
+    fn degree_reduce_send_fresh_shares(&mut self, acc: Vec<F>) -> std::io::Result<Vec<F>> {
+        let num_non_zero = self.network.get_num_parties() - self.threshold;
+        let my_id = self.network.get_id();
+        let mut polys = Vec::with_capacity(acc.len());
+        for acc in acc {
+            let poly =
+                poly_with_zeros_from_precomputed(&acc, self.mul_reconstruct_with_zeros.to_owned());
+            polys.push(poly);
+        }
+
+        let mut my_share = vec![F::zero(); polys.len()];
+        for id in 0..num_non_zero {
+            let id_f = F::from(id as u64 + 1);
+            let vals = polys
+                .iter()
+                .map(|poly| evaluate_poly(poly, id_f))
+                .collect::<Vec<_>>();
+            if id == my_id {
+                my_share = vals;
+            } else {
+                self.network.send_many(id, &vals)?;
+            }
+        }
+        Ok(my_share)
+    }
+
+    // Shares the degree reduced values of the king with a random polynomial, since the values have to stay private in ShamirMulMode::Atlas
+    fn degree_reduce_send_random_shares(&mut self, acc: Vec<F>) -> std::io::Result<Vec<F>> {
+        let num_parties = self.network.get_num_parties();
+        let my_id = self.network.get_id();
+        let mut polys = Vec::with_capacity(acc.len());
+        for acc in acc {
+            let mut poly = Vec::with_capacity(self.threshold + 1);
+            poly.push(acc);
+            for _ in 0..self.threshold {
+                poly.push(F::rand(&mut self.rng_buffer.rng));
+            }
+            polys.push(poly);
+        }
+
+        let mut my_share = vec![F::zero(); polys.len()];
+        for id in 0..num_parties {
+            let id_f = F::from(id as u64 + 1);
+            let vals = polys
+                .iter()
+                .map(|poly| evaluate_poly(poly, id_f))
+                .collect::<Vec<_>>();
+            if id == my_id {
+                my_share = vals;
+            } else {
+                self.network.send_many(id, &vals)?;
+            }
+        }
+        Ok(my_share)
+    }
+
+    // Masks the inputs with double sharings and returns the degree-t sharings. Every n inputs consume at most t + 1 double sharings, which are expanded to n double sharings (ATLAS)
+    fn mask_atlas(&mut self, inputs: &mut [F]) -> std::io::Result<Vec<F>> {
+        let num_parties = self.network.get_num_parties();
+        let mut r_ts = Vec::with_capacity(inputs.len());
+        for chunk in inputs.chunks_mut(num_parties) {
+            let num_pairs = chunk.len().min(self.threshold + 1);
+            let mut pairs_t = Vec::with_capacity(num_pairs);
+            let mut pairs_2t = Vec::with_capacity(num_pairs);
+            for _ in 0..num_pairs {
+                let (r_t, r_2t) = self.get_pair()?;
+                pairs_t.push(r_t);
+                pairs_2t.push(r_2t);
+            }
+
+            if chunk.len() == num_pairs {
+                // Not more elements than uniformly random double sharings, no need to expand
+                for (inp, r_2t) in izip!(chunk.iter_mut(), pairs_2t) {
+                    *inp += r_2t;
+                }
+                r_ts.extend(pairs_t);
+            } else {
+                for (inp, row) in izip!(chunk.iter_mut(), &self.atlas_expansion) {
+                    let mut r_t = F::zero();
+                    let mut r_2t = F::zero();
+                    for (coeff, pair_t, pair_2t) in izip!(row, &pairs_t, &pairs_2t) {
+                        r_t += *coeff * pair_t;
+                        r_2t += *coeff * pair_2t;
+                    }
+                    *inp += r_2t;
+                    r_ts.push(r_t);
+                }
+            }
+        }
+        Ok(r_ts)
+    }
+
+    // The king reconstructs the masked values from the shares of all parties and checks their consistency
+    fn degree_reduce_king_checked(&mut self, inputs: &[F]) -> std::io::Result<Vec<F>> {
+        let check = self
+            .degree_reduce_check
+            .as_ref()
+            .expect("degree reduce check is enabled");
+        let num_parties = self.network.get_num_parties();
+        let len = inputs.len();
+        let mut shares = vec![Vec::with_capacity(num_parties); len];
+        for other_id in 0..num_parties {
+            let r = if other_id == Self::KING_ID {
+                inputs.to_owned()
+            } else {
+                self.network.recv_many::<F>(other_id)?
+            };
+            if r.len() != len {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "During execution of degree_reduce_vec in MPC: Invalid number of elements received",
+                ));
+            }
+            for (shares, r) in izip!(&mut shares, r) {
+                shares.push(r);
+            }
+        }
+
+        let mut acc = Vec::with_capacity(len);
+        for shares in shares {
+            match check.reconstruct(&shares) {
+                Some((val, inconsistent)) if inconsistent.is_empty() => acc.push(val),
+                Some((_, inconsistent)) => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!(
+                            "During execution of degree_reduce_vec in MPC: Parties {inconsistent:?} sent inconsistent shares"
+                        ),
+                    ));
+                }
+                None => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "During execution of degree_reduce_vec in MPC: Received shares do not lie on a polynomial of degree 2t",
+                    ));
+                }
+            }
+        }
+        Ok(acc)
+    }
+
+    // Degree reduction with a rotating king: the i-th element is reduced by party i mod n, which receives the masked shares of itself and the next 2t parties
+    fn degree_reduce_rotating_king(&mut self, inputs: Vec<F>) -> std::io::Result<Vec<F>> {
+        // In ShamirMulMode::Atlas the kings send random shares to all parties
+        let atlas = self.mul_mode == ShamirMulMode::Atlas;
+        let num_parties = self.network.get_num_parties();
+        let num_non_zero = num_parties - self.threshold;
+        let num_senders = 2 * self.threshold + 1;
+        let my_id = self.network.get_id();
+        let len = inputs.len();
+        // The number of elements reduced by the given king
+        let king_len = |king: usize| (len + num_parties - 1 - king) / num_parties;
+
+        let mut per_king = (0..num_parties)
+            .map(|king| Vec::with_capacity(king_len(king)))
+            .collect::<Vec<_>>();
+        for (i, inp) in inputs.into_iter().enumerate() {
+            per_king[i % num_parties].push(inp);
+        }
+
+        // Send the masked shares to all kings which require them
+        for (king, inputs) in per_king.iter().enumerate() {
+            let is_sender = (my_id + num_parties - king) % num_parties < num_senders;
+            if king != my_id && is_sender && !inputs.is_empty() {
+                self.network.send_many(king, inputs)?;
+            }
+        }
+
+        let mut shares = vec![Vec::new(); num_parties];
+        let my_len = king_len(my_id);
+        if my_len != 0 {
+            // Accumulate our part of the batch
+            let lagranges = self.mul_lagrange_2t_rotating[my_id].to_owned();
+            let mut acc = vec![F::zero(); my_len];
+            for (i, lagrange) in lagranges.iter().enumerate() {
+                let other_id = (my_id + i) % num_parties;
+                let r = if other_id == my_id {
+                    std::mem::take(&mut per_king[my_id])
+                } else {
+                    self.network.recv_many::<F>(other_id)?
+                };
+                if r.len() != my_len {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "During execution of degree_reduce_vec in MPC: Invalid number of elements received",
+                    ));
+                }
+                for (acc, r) in izip!(&mut acc, r) {
+                    *acc += r * lagrange;
+                }
+            }
+            shares[my_id] = if atlas {
+                self.degree_reduce_send_random_shares(acc)?
+            } else {
+                self.degree_reduce_send_fresh_shares(acc)?
+            };
+        }
+
+        // Receive the fresh shares from all other kings
+        for (king, shares) in shares.iter_mut().enumerate() {
+            let king_len = king_len(king);
+            if king == my_id || king_len == 0 {
+                continue;
+            }
+            *shares = if my_id < num_non_zero || atlas {
+                let r = self.network.recv_many::<F>(king)?;
+                if r.len() != king_len {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "During execution of degree_reduce_vec in MPC: Invalid number of elements received",
+                    ));
+                }
+                r
+            } else {
+                vec![F::zero(); king_len]
+            };
+        }
+
+        // Restore the original order of the batch
+        let mut shares = shares
+            .into_iter()
+            .map(|shares| shares.into_iter())
+            .collect::<Vec<_>>();
+        Ok((0..len)
+            .map(|i| {
+                shares[i % num_parties]
+                    .next()
+                    .expect("we have the correct amount of shares")
+            })
+            .collect())
+    }
+
+    /// Degree reduce all inputs. Depending on the [`ShamirMulMode`], a single king or a rotating king is used. If the consistency check is enabled (see [`Self::set_degree_reduce_check`]), the single king is always used.
     pub fn degree_reduce_vec(
         &mut self,
         mut inputs: Vec<F>,
     ) -> std::io::Result<Vec<ShamirPrimeFieldShare<F>>> {
         let num_non_zero = self.network.get_num_parties() - self.threshold;
+        let rotating = self.mul_mode != ShamirMulMode::King && self.degree_reduce_check.is_none();
 
         let len = inputs.len();
-        let mut r_ts = Vec::with_capacity(len);
-
-        for inp in inputs.iter_mut() {
-            let (r_t, r_2t) = self.get_pair()?;
-            *inp += r_2t;
-            r_ts.push(r_t);
-        }
+        let r_ts = if rotating && self.mul_mode == ShamirMulMode::Atlas {
+            self.mask_atlas(&mut inputs)?
+        } else {
+            let mut r_ts = Vec::with_capacity(len);
+            for inp in inputs.iter_mut() {
+                let (r_t, r_2t) = self.get_pair()?;
+                *inp += r_2t;
+                r_ts.push(r_t);
+            }
+            r_ts
+        };
 
         let my_id = self.network.get_id();
-        let mut my_shares = if my_id == Self::KING_ID {
+        let mut my_shares = if rotating {
+            self.degree_reduce_rotating_king(inputs)?
+        } else if my_id == Self::KING_ID && self.degree_reduce_check.is_some() {
+            let acc = self.degree_reduce_king_checked(&inputs)?;
+            self.degree_reduce_send_fresh_shares(acc)?
+        } else if my_id == Self::KING_ID {
             // Accumulate the result
             let mut acc = vec![F::zero(); len];
             for (other_id, lagrange) in self.mul_lagrange_2t.iter().enumerate() {
@@ -276,31 +620,9 @@ impl<F: PrimeField, N: ShamirNetwork> ShamirProtocol<F, N> {
             // Since <acc> does not have to be private, we share it as a known polynomial, such that t parties know their share is 0. Consequently we can reduce the amount of communication.
             // Note: When expanding t+1 double shares to n double shares (Atlas) we cannot do this anymore, since <acc> needs to stay private. Atlas also requires rotating the King server.
 
-            let mut polys = Vec::with_capacity(acc.len());
-            for acc in acc {
-                let poly = poly_with_zeros_from_precomputed(
-                    &acc,
-                    self.mul_reconstruct_with_zeros.to_owned(),
-                );
-                polys.push(poly);
-            }
-
-            let mut my_share = Vec::new();
-            for id in 0..num_non_zero {
-                let id_f = F::from(id as u64 + 1);
-                let vals = polys
-                    .iter()
-                    .map(|poly| evaluate_poly(poly, id_f))
-                    .collect::<Vec<_>>();
-                if id == my_id {
-                    my_share = vals;
-                } else {
-                    self.network.send_many(id, &vals)?;
-                }
-            }
-            my_share
+            self.degree_reduce_send_fresh_shares(acc)?
         } else {
-            if my_id <= self.threshold * 2 {
+            if my_id <= self.threshold * 2 || self.degree_reduce_check.is_some() {
                 // Only send if my items are required
                 self.network.send_many(Self::KING_ID, &inputs)?;
             }
@@ -211,6 +211,54 @@ pub fn open_vec<F: PrimeField, N: ShamirNetwork>(
     Ok(res)
 }
 
+/// Opens a shared value robustly, i.e., using the shares of all parties. Returns the opened value and the ids of the parties which sent inconsistent shares. Up to (n - t - 1) / 2 inconsistent shares are corrected. If there are more, or if they can only be detected (e.g., for n = t + 2), an error is returned.
+pub fn open_robust<F: PrimeField, N: ShamirNetwork>(
+    a: ShamirShare<F>,
+    shamir: &mut ShamirProtocol<F, N>,
+) -> IoResult<(F, Vec<usize>)> {
+    let (mut res, inconsistent) = open_vec_robust(&[a], shamir)?;
+    Ok((res.pop().expect("we opened one element"), inconsistent))
+}
+
+/// Opens a vector of shared values robustly, i.e., using the shares of all parties. Returns the opened values and the (sorted) ids of the parties which sent inconsistent shares for at least one of the values. Up to (n - t - 1) / 2 inconsistent shares per value are corrected. If there are more, or if they can only be detected (e.g., for n = t + 2), an error is returned.
+pub fn open_vec_robust<F: PrimeField, N: ShamirNetwork>(
+    a: &[ShamirShare<F>],
+    shamir: &mut ShamirProtocol<F, N>,
+) -> IoResult<(Vec<F>, Vec<usize>)> {
+    let a_a = ShamirShare::convert_slice(a);
+
+    let rcv = shamir.network.broadcast(a_a.to_owned())?;
+    if rcv.iter().any(|r| r.len() != a.len()) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "During execution of open_vec_robust in MPC: Invalid number of elements received",
+        ));
+    }
+
+    let mut transposed = vec![vec![F::zero(); rcv.len()]; a.len()];
+    for (j, r) in rcv.into_iter().enumerate() {
+        for (i, val) in r.into_iter().enumerate() {
+            transposed[i][j] = val;
+        }
+    }
+
+    let mut res = Vec::with_capacity(a.len());
+    let mut inconsistent_parties = Vec::new();
+    for shares in transposed {
+        let (val, inconsistent) = shamir.robust_open.reconstruct(&shares).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "During execution of open_vec_robust in MPC: Detected inconsistent shares which cannot be corrected",
+            )
+        })?;
+        res.push(val);
+        inconsistent_parties.extend(inconsistent);
+    }
+    inconsistent_parties.sort_unstable();
+    inconsistent_parties.dedup();
+    Ok((res, inconsistent_parties))
+}
+
 /*
 fn neg_vec_in_place(vec: &mut ShamirShare<F>Vec) {
     for a in vec.a.iter_mut() {
@@ -0,0 +1,389 @@
+//! # Packed Shamir
+//!
+//! This module implements packed Shamir secret sharing (Franklin-Yung), where one sharing polynomial holds `packing` secrets. Linear operations and multiplications act on all packed secrets at once (SIMD-style), so the per-secret communication shrinks by the packing factor. With n parties and a threshold of t, the packing factor k needs to satisfy 2 * (t + k - 1) + 1 <= n for multiplications.
+
+use ark_ff::PrimeField;
+use itertools::izip;
+use rand::{Rng, SeedableRng};
+use std::time::Instant;
+
+use super::network::ShamirNetwork;
+use crate::RngType;
+
+pub mod arithmetic;
+
+pub use mpc_types::protocols::shamir::packed::{
+    PackedShamirPrimeFieldShare, combine_field_elements_packed, lagrange_at, packed_degree,
+    party_point, secret_point, share_field_elements_packed,
+};
+
+struct PackedShamirRng<F> {
+    rng: RngType,
+    degree: usize,
+    packing: usize,
+    matrix: Vec<Vec<F>>,
+    r_d: Vec<F>,
+    r_2d: Vec<F>,
+}
+
+impl<F: PrimeField> PackedShamirRng<F> {
+    fn new(
+        seed: [u8; crate::SEED_SIZE],
+        threshold: usize,
+        packing: usize,
+        num_parties: usize,
+    ) -> Self {
+        // We use the DN07 (t+1 x n) Vandermonde matrix to extract t+1 random double shares from the random double shares of all n parties
+        let matrix = (0..=threshold)
+            .map(|row| {
+                (1..=num_parties as u64)
+                    .map(|col| F::from(col).pow([row as u64]))
+                    .collect()
+            })
+            .collect();
+        Self {
+            rng: RngType::from_seed(seed),
+            degree: packed_degree(threshold, packing),
+            packing,
+            matrix,
+            r_d: Vec::new(),
+            r_2d: Vec::new(),
+        }
+    }
+
+    fn get_size_per_batch(&self) -> usize {
+        self.matrix.len()
+    }
+
+    fn fork_with_pairs(&mut self, amount: usize) -> Self {
+        Self {
+            rng: RngType::from_seed(self.rng.r#gen()),
+            degree: self.degree,
+            packing: self.packing,
+            matrix: self.matrix.clone(),
+            r_d: self.r_d.drain(..amount).collect(),
+            r_2d: self.r_2d.drain(..amount).collect(),
+        }
+    }
+
+    // Generates amount * matrix.len() random packed double shares, i.e., packed shares of the same random secrets with degree d and 2d
+    fn buffer_double_shares<N: ShamirNetwork>(
+        &mut self,
+        network: &mut N,
+        amount: usize,
+    ) -> std::io::Result<()> {
+        let num_parties = network.get_num_parties();
+        let secrets = (0..amount * self.packing)
+            .map(|_| F::rand(&mut self.rng))
+            .collect::<Vec<_>>();
+        let shares_d = share_field_elements_packed(
+            &secrets,
+            self.degree,
+            self.packing,
+            num_parties,
+            &mut self.rng,
+        );
+        let shares_2d = share_field_elements_packed(
+            &secrets,
+            2 * self.degree,
+            self.packing,
+            num_parties,
+            &mut self.rng,
+        );
+        let to_send = izip!(shares_d, shares_2d)
+            .map(|(d, d2)| d.into_iter().chain(d2).map(|s| s.a).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let rcv = network.send_and_recv_each_many(to_send)?;
+        if rcv.iter().any(|r| r.len() != 2 * amount) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "During generation of packed double shares: Invalid number of elements received",
+            ));
+        }
+
+        for i in 0..amount {
+            for row in self.matrix.iter() {
+                let mut r_d = F::zero();
+                let mut r_2d = F::zero();
+                for (cell, rcv) in izip!(row, rcv.iter()) {
+                    r_d += rcv[i] * cell;
+                    r_2d += rcv[amount + i] * cell;
+                }
+                self.r_d.push(r_d);
+                self.r_2d.push(r_2d);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// This type is used to construct a [`PackedShamirProtocol`].
+/// Preprocess `amount` number of packed correlated randomness pairs that are consumed while using the protocol.
+pub struct PackedShamirPreprocessing<F: PrimeField, N: ShamirNetwork> {
+    threshold: usize,
+    packing: usize,
+    rng_buffer: PackedShamirRng<F>,
+    network: N,
+}
+
+impl<F: PrimeField, N: ShamirNetwork> PackedShamirPreprocessing<F, N> {
+    /// Construct a new [`PackedShamirPreprocessing`] type and generate `amount` number of packed corr rand pairs
+    pub fn new(
+        threshold: usize,
+        packing: usize,
+        mut network: N,
+        amount: usize,
+    ) -> eyre::Result<Self> {
+        let num_parties = network.get_num_parties();
+
+        if packing == 0 {
+            eyre::bail!("Packing factor must be at least 1")
+        }
+        if 2 * packed_degree(threshold, packing) + 1 > num_parties {
+            eyre::bail!("Threshold and packing factor too large for number of parties")
+        }
+
+        let seed: [u8; crate::SEED_SIZE] = RngType::from_entropy().r#gen();
+        let mut rng_buffer = PackedShamirRng::new(seed, threshold, packing, num_parties);
+
+        let start = Instant::now();
+        let amount = amount.div_ceil(rng_buffer.get_size_per_batch());
+        rng_buffer.buffer_double_shares(&mut network, amount)?;
+        tracing::debug!(
+            "generating {amount} packed double shares took {} ms",
+            start.elapsed().as_micros() as f64 / 1000.0
+        );
+
+        Ok(Self {
+            threshold,
+            packing,
+            rng_buffer,
+            network,
+        })
+    }
+}
+
+impl<F: PrimeField, N: ShamirNetwork> From<PackedShamirPreprocessing<F, N>>
+    for PackedShamirProtocol<F, N>
+{
+    fn from(value: PackedShamirPreprocessing<F, N>) -> Self {
+        let num_parties = value.network.get_num_parties();
+        let id = value.network.get_id();
+        let degree = packed_degree(value.threshold, value.packing);
+
+        // We open with the shares of the previous degree + 1 parties
+        let open_points = (0..=degree)
+            .map(|i| party_point((id + num_parties - i) % num_parties))
+            .collect::<Vec<F>>();
+        let open_lagrange = (0..value.packing)
+            .map(|i| lagrange_at(&open_points, secret_point(i)))
+            .collect();
+
+        let mul_points = (0..=2 * degree).map(party_point).collect::<Vec<F>>();
+        let mul_lagrange = (0..value.packing)
+            .map(|i| lagrange_at(&mul_points, secret_point(i)))
+            .collect();
+
+        // The king shares the masked products with a known polynomial, which is zero at the last t parties
+        let num_non_zero = num_parties - value.threshold;
+        let reshare_points = (0..value.packing)
+            .map(secret_point)
+            .chain((num_non_zero..num_parties).map(party_point))
+            .collect::<Vec<F>>();
+        let mul_reshare = (0..num_non_zero)
+            .map(|id| {
+                let mut lagrange = lagrange_at(&reshare_points, party_point(id));
+                lagrange.truncate(value.packing);
+                lagrange
+            })
+            .collect();
+
+        let secret_points = (0..value.packing).map(secret_point).collect::<Vec<F>>();
+        let public_lagrange = lagrange_at(&secret_points, party_point(id));
+
+        PackedShamirProtocol {
+            threshold: value.threshold,
+            packing: value.packing,
+            open_lagrange,
+            mul_lagrange,
+            mul_reshare,
+            public_lagrange,
+            network: value.network,
+            rng_buffer: value.rng_buffer,
+            generation_amount: Self::DEFAULT_PAIR_GEN_AMOUNT,
+        }
+    }
+}
+
+/// This struct holds all necessary information for an MPC protocol based on packed Shamir. It contains a [`ShamirNetwork`], the randomness, the threshold, the packing factor and the lagrange coefficients for opening and multiplication.
+pub struct PackedShamirProtocol<F: PrimeField, N: ShamirNetwork> {
+    /// The threshold, i.e., the maximum number of tolerated colluding parties
+    pub threshold: usize,
+    /// The number of secrets packed into one share
+    pub packing: usize,
+    open_lagrange: Vec<Vec<F>>,
+    mul_lagrange: Vec<Vec<F>>,
+    mul_reshare: Vec<Vec<F>>,
+    public_lagrange: Vec<F>,
+    /// The underlying [`ShamirNetwork`]
+    pub network: N,
+    rng_buffer: PackedShamirRng<F>,
+    generation_amount: usize,
+}
+
+impl<F: PrimeField, N: ShamirNetwork> PackedShamirProtocol<F, N> {
+    const KING_ID: usize = 0;
+    const DEFAULT_PAIR_GEN_AMOUNT: usize = 1024;
+
+    /// Create a forked [`PackedShamirProtocol`] that consumes `amount` number of packed corr rand pairs from its parent
+    pub fn fork_with_pairs(&mut self, amount: usize) -> std::io::Result<Self> {
+        Ok(Self {
+            threshold: self.threshold,
+            packing: self.packing,
+            open_lagrange: self.open_lagrange.clone(),
+            mul_lagrange: self.mul_lagrange.clone(),
+            mul_reshare: self.mul_reshare.clone(),
+            public_lagrange: self.public_lagrange.clone(),
+            network: self.network.fork()?,
+            rng_buffer: self.rng_buffer.fork_with_pairs(amount),
+            generation_amount: self.generation_amount,
+        })
+    }
+
+    /// Returns the degree of the sharing polynomials, i.e., t + k - 1
+    pub fn degree(&self) -> usize {
+        self.rng_buffer.degree
+    }
+
+    /// Get `amount` packed correlated randomness pairs
+    fn get_pairs(&mut self, amount: usize) -> std::io::Result<(Vec<F>, Vec<F>)> {
+        let present = self.rng_buffer.r_d.len();
+        debug_assert_eq!(self.rng_buffer.r_2d.len(), present);
+        if present < amount {
+            tracing::warn!("Precomputed randomness buffer empty, refilling...");
+            let missing = (amount - present).max(self.generation_amount);
+            self.rng_buffer.buffer_double_shares(
+                &mut self.network,
+                missing.div_ceil(self.rng_buffer.get_size_per_batch()),
+            )?;
+            self.generation_amount *= 2; // We increase the amount for preprocessing exponentially
+        }
+        let split = self.rng_buffer.r_d.len() - amount;
+        Ok((
+            self.rng_buffer.r_d.split_off(split),
+            self.rng_buffer.r_2d.split_off(split),
+        ))
+    }
+
+    /// Transforms packed public values into packed shares. The values are packed into chunks of `packing` elements, where the last chunk is padded with zeros.
+    pub fn promote_to_trivial_shares(&self, public: &[F]) -> Vec<PackedShamirPrimeFieldShare<F>> {
+        public
+            .chunks(self.packing)
+            .map(|chunk| {
+                PackedShamirPrimeFieldShare::new(
+                    izip!(chunk, &self.public_lagrange)
+                        .map(|(v, l)| *v * l)
+                        .sum(),
+                )
+            })
+            .collect()
+    }
+
+    /// Reconstructs the packed secrets from the shares of the previous degree + 1 parties, where the shares of party my_id + num_parties - i mod num_parties are at index i.
+    pub(crate) fn reconstruct_packed(&self, shares: &[Vec<F>]) -> Vec<F> {
+        let len = shares.first().map(|s| s.len()).unwrap_or_default();
+        let mut result = Vec::with_capacity(len * self.packing);
+        for i in 0..len {
+            for lagrange in self.open_lagrange.iter() {
+                result.push(izip!(shares, lagrange).map(|(s, l)| s[i] * l).sum());
+            }
+        }
+        result
+    }
+
+    /// Reduces the degree of packed shares from 2d to d, where d = t + k - 1 is the degree of the sharing polynomials.
+    pub fn degree_reduce_vec(
+        &mut self,
+        mut inputs: Vec<F>,
+    ) -> std::io::Result<Vec<PackedShamirPrimeFieldShare<F>>> {
+        let num_parties = self.network.get_num_parties();
+        let num_non_zero = num_parties - self.threshold;
+        let num_senders = 2 * self.degree() + 1;
+        let len = inputs.len();
+
+        let (r_d, r_2d) = self.get_pairs(len)?;
+        for (inp, r) in izip!(&mut inputs, r_2d) {
+            *inp += r;
+        }
+
+        let my_id = self.network.get_id();
+        let mut my_shares = if my_id == Self::KING_ID {
+            // Reconstruct all packed masked products
+            let mut secrets = vec![vec![F::zero(); self.packing]; len];
+            for other_id in 0..num_senders {
+                let r = if other_id == Self::KING_ID {
+                    std::mem::take(&mut inputs)
+                } else {
+                    self.network.recv_many::<F>(other_id)?
+                };
+                if r.len() != len {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "During execution of packed degree_reduce_vec in MPC: Invalid number of elements received",
+                    ));
+                }
+                for (secrets, r) in izip!(&mut secrets, r) {
+                    for (secret, lagrange) in izip!(secrets, &self.mul_lagrange) {
+                        *secret += r * lagrange[other_id];
+                    }
+                }
+            }
+
+            // Send fresh shares
+            // Since the masked products do not have to be private, we share them with a known polynomial, such that t parties know their share is 0.
+            let mut my_share = Vec::new();
+            for (id, reshare) in self.mul_reshare.iter().enumerate() {
+                let vals = secrets
+                    .iter()
+                    .map(|secrets| izip!(secrets, reshare).map(|(s, l)| *s * l).sum())
+                    .collect::<Vec<F>>();
+                if id == my_id {
+                    my_share = vals;
+                } else {
+                    self.network.send_many(id, &vals)?;
+                }
+            }
+            my_share
+        } else {
+            if my_id < num_senders {
+                // Only send if my items are required
+                self.network.send_many(Self::KING_ID, &inputs)?;
+            }
+            if my_id < num_non_zero {
+                let r = self.network.recv_many::<F>(Self::KING_ID)?;
+                if r.len() != len {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "During execution of packed degree_reduce_vec in MPC: Invalid number of elements received",
+                    ));
+                }
+                r
+            } else {
+                vec![F::zero(); len]
+            }
+        };
+
+        for (share, r) in izip!(&mut my_shares, r_d) {
+            *share -= r;
+        }
+        Ok(my_shares
+            .into_iter()
+            .map(PackedShamirPrimeFieldShare::new)
+            .collect())
+    }
+
+    /// Consumes self and returns the network
+    pub fn into_network(self) -> N {
+        self.network
+    }
+}
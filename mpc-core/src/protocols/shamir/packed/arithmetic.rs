@@ -0,0 +1,120 @@
+//! Arithmetic
+//!
+//! This module contains operations with packed arithmetic shares. All operations act on all secrets packed into a share at once.
+
+use ark_ff::PrimeField;
+use itertools::izip;
+
+use crate::IoResult;
+
+use super::{PackedShamirPrimeFieldShare, PackedShamirProtocol};
+use crate::protocols::shamir::network::ShamirNetwork;
+
+type PackedShamirShare<F> = PackedShamirPrimeFieldShare<F>;
+
+/// Performs addition between two packed shares.
+pub fn add<F: PrimeField>(
+    a: PackedShamirShare<F>,
+    b: PackedShamirShare<F>,
+) -> PackedShamirShare<F> {
+    a + b
+}
+
+/// Performs element-wise addition of two slices of packed shares.
+pub fn add_vec<F: PrimeField>(
+    a: &[PackedShamirShare<F>],
+    b: &[PackedShamirShare<F>],
+) -> Vec<PackedShamirShare<F>> {
+    izip!(a, b).map(|(a, b)| *a + *b).collect()
+}
+
+/// Performs subtraction between two packed shares.
+pub fn sub<F: PrimeField>(
+    a: PackedShamirShare<F>,
+    b: PackedShamirShare<F>,
+) -> PackedShamirShare<F> {
+    a - b
+}
+
+/// Performs element-wise subtraction of two slices of packed shares.
+pub fn sub_vec<F: PrimeField>(
+    a: &[PackedShamirShare<F>],
+    b: &[PackedShamirShare<F>],
+) -> Vec<PackedShamirShare<F>> {
+    izip!(a, b).map(|(a, b)| *a - *b).collect()
+}
+
+/// Negates a slice of packed shares.
+pub fn neg_vec<F: PrimeField>(a: &[PackedShamirShare<F>]) -> Vec<PackedShamirShare<F>> {
+    a.iter().map(|a| -*a).collect()
+}
+
+/// Multiplies all secrets of a packed share with the same public value.
+pub fn mul_public<F: PrimeField>(shared: PackedShamirShare<F>, public: F) -> PackedShamirShare<F> {
+    shared * public
+}
+
+/// Performs element-wise addition of a slice of packed shares and public values, where the public values are packed into chunks of `packing` elements.
+pub fn add_public_vec<F: PrimeField, N: ShamirNetwork>(
+    shared: &[PackedShamirShare<F>],
+    public: &[F],
+    packed_shamir: &PackedShamirProtocol<F, N>,
+) -> Vec<PackedShamirShare<F>> {
+    add_vec(shared, &packed_shamir.promote_to_trivial_shares(public))
+}
+
+/// Performs element-wise subtraction of public values from a slice of packed shares, where the public values are packed into chunks of `packing` elements.
+pub fn sub_shared_by_public_vec<F: PrimeField, N: ShamirNetwork>(
+    shared: &[PackedShamirShare<F>],
+    public: &[F],
+    packed_shamir: &PackedShamirProtocol<F, N>,
+) -> Vec<PackedShamirShare<F>> {
+    sub_vec(shared, &packed_shamir.promote_to_trivial_shares(public))
+}
+
+/// Performs element-wise subtraction of a slice of packed shares from public values, where the public values are packed into chunks of `packing` elements.
+pub fn sub_public_by_shared_vec<F: PrimeField, N: ShamirNetwork>(
+    public: &[F],
+    shared: &[PackedShamirShare<F>],
+    packed_shamir: &PackedShamirProtocol<F, N>,
+) -> Vec<PackedShamirShare<F>> {
+    sub_vec(&packed_shamir.promote_to_trivial_shares(public), shared)
+}
+
+/// Performs element-wise multiplication of two slices of packed shares, i.e., all packed secrets are multiplied slot-wise.
+pub fn mul_vec<F: PrimeField, N: ShamirNetwork>(
+    a: &[PackedShamirShare<F>],
+    b: &[PackedShamirShare<F>],
+    packed_shamir: &mut PackedShamirProtocol<F, N>,
+) -> IoResult<Vec<PackedShamirShare<F>>> {
+    let mul = izip!(a, b).map(|(a, b)| a.a * b.a).collect::<Vec<_>>();
+    packed_shamir.degree_reduce_vec(mul)
+}
+
+/// Performs element-wise multiplication of a slice of packed shares and public values, where the public values are packed into chunks of `packing` elements. Since the public values differ per slot, this requires a degree reduction.
+pub fn mul_public_vec<F: PrimeField, N: ShamirNetwork>(
+    shared: &[PackedShamirShare<F>],
+    public: &[F],
+    packed_shamir: &mut PackedShamirProtocol<F, N>,
+) -> IoResult<Vec<PackedShamirShare<F>>> {
+    let public = packed_shamir.promote_to_trivial_shares(public);
+    mul_vec(shared, &public, packed_shamir)
+}
+
+/// Opens a slice of packed shares. The result contains `packing` values per share.
+pub fn open_vec<F: PrimeField, N: ShamirNetwork>(
+    a: &[PackedShamirShare<F>],
+    packed_shamir: &mut PackedShamirProtocol<F, N>,
+) -> IoResult<Vec<F>> {
+    let a_a = a.iter().map(|a| a.a).collect::<Vec<_>>();
+    let rcv = packed_shamir
+        .network
+        .broadcast_next(a_a, packed_shamir.degree() + 1)?;
+    if rcv.iter().any(|r| r.len() != a.len()) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "During execution of packed open_vec in MPC: Invalid number of elements received",
+        ));
+    }
+    Ok(packed_shamir.reconstruct_packed(&rcv))
+}
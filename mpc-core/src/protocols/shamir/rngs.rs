@@ -35,7 +35,7 @@ impl<F: PrimeField> ShamirRng<F> {
         let shared_rngs = Self::get_shared_rngs(network, &mut rng)?;
 
         // We use the DN07 Vandermonde matrix to create t+1 random double shares at once.
-        // We do not use Atlas to create n shares at once, since only t+1 out of n shares would be uniformly random, thus the King server during multiplication would have to be rotated.
+        // We do not use Atlas to create n shares at once, since only t+1 out of n shares would be uniformly random, thus the King server during multiplication would have to be rotated. ShamirMulMode::Atlas expands the double shares during the degree reduction instead.
 
         // let atlas_dn_matrix = Self::generate_atlas_dn_matrix(num_parties, threshold);
         let matrix = Self::create_vandermonde_matrix(num_parties, threshold);
@@ -394,7 +394,7 @@ impl<F: PrimeField> ShamirRng<F> {
     // Generates amount * matrix.len() random double shares
     // We use DN07 to generate t+1 double shares from the randomness of the n parties.
     // With Atlas we would be able to expand this to n double shares, but only t+1 of them would be uniformly random.
-    // Thus, with Atlas we would have to rotate the King server during multiplication.
+    // Thus, with Atlas we would have to rotate the King server during multiplication, which is what ShamirMulMode::Atlas does.
     pub(super) fn buffer_triples<N: ShamirNetwork>(
         &mut self,
         network: &mut N,
@@ -97,6 +97,9 @@ pub struct NetworkPartyConfig {
     pub dns_name: Address,
     /// The path to the public certificate of the party.
     pub cert_path: PathBuf,
+    /// The path to the public key share files for this party are encrypted to, if any.
+    #[serde(default)]
+    pub share_key_path: Option<PathBuf>,
 }
 
 /// A party in the network.
@@ -146,6 +149,9 @@ pub struct NetworkConfigFile {
     pub key_path: PathBuf,
     /// The connect timeout in seconds.
     pub timeout_secs: Option<u64>,
+    /// The path to our secret key for decrypting share files, if any. If set, share files stored in plain are rejected.
+    #[serde(default)]
+    pub share_secret_key_path: Option<PathBuf>,
 }
 
 /// The network configuration.
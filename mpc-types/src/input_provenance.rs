@@ -0,0 +1,567 @@
+//! # Input Provenance
+//!
+//! This module records which client provided which inputs if the inputs of a witness extension are merged from several clients. When splitting its input, a client signs the names of its inputs together with the shares of each party using its [ClientSigningKey]. The parties check the resulting [InputProvenance] against an [InputPolicy], which declares the clients together with their keys and the inputs (or elements of array inputs) each of them is allowed to provide.
+//!
+//! The signature covers the shares of a single party, so it does not ensure that the client distributed consistent shares to the parties. For this, see the commitments in [`vss`](crate::protocols::rep3::vss).
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+use ed25519_dalek::Signer;
+use eyre::{Context, bail, eyre};
+use rand::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+
+use crate::protocols::rep3::{
+    id::PartyID,
+    vss::{self, Rep3Components, Rep3InputCommitment},
+};
+
+pub use ed25519_dalek::{
+    Signature as ClientSignature, SigningKey as ClientSigningKey,
+    VerifyingKey as ClientVerifyingKey,
+};
+
+/// The domain separator of the signed digest of an [InputProvenance]
+const INPUT_PROVENANCE_DOMAIN: &[u8] = b"co-snarks input provenance";
+
+/// The names of a set of inputs. If only some of the elements of an input are given, the indices of these elements are given.
+pub type InputNames = BTreeMap<String, Option<Vec<usize>>>;
+
+/// The signature of a client on the shares of its inputs, as received by one party.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputProvenance {
+    /// The identifier of the client, as declared in the [InputPolicy]
+    pub client: String,
+    /// The inputs provided by the client
+    pub inputs: InputNames,
+    /// The signature of the client on the inputs and the shares of the party
+    pub signature: ClientSignature,
+}
+
+/// Computes the digest which is signed by the client, binding the client, the receiving party, the circuit, the input names and both components of the shares of the party.
+fn provenance_digest<S: Rep3Components>(
+    client: &str,
+    inputs: &InputNames,
+    shares: &[S],
+    id: PartyID,
+    circuit_hash: Option<[u8; 32]>,
+) -> eyre::Result<[u8; 32]> {
+    let mut hasher = Sha3_256::new();
+    hasher.update(INPUT_PROVENANCE_DOMAIN);
+    hasher.update((client.len() as u64).to_le_bytes());
+    hasher.update(client.as_bytes());
+    hasher.update([usize::from(id) as u8]);
+    match circuit_hash {
+        Some(circuit_hash) => {
+            hasher.update([1]);
+            hasher.update(circuit_hash);
+        }
+        None => hasher.update([0]),
+    }
+    vss::hash_inputs(&mut hasher, inputs);
+    let mut buf = Vec::new();
+    for share in shares {
+        share.serialize_a(&mut buf)?;
+        share.serialize_b(&mut buf)?;
+    }
+    hasher.update(&buf);
+    Ok(hasher.finalize().into())
+}
+
+impl InputProvenance {
+    /// Signs the shares of party `id`, given in the order of `inputs`. The signature is bound to the circuit with the given hash (see [`circuit_hash`](crate::share_file::circuit_hash)), so that it cannot be replayed for a different circuit.
+    pub fn sign<S: Rep3Components>(
+        client: String,
+        inputs: InputNames,
+        shares: &[S],
+        id: PartyID,
+        circuit_hash: Option<[u8; 32]>,
+        key: &ClientSigningKey,
+    ) -> eyre::Result<Self> {
+        let digest = provenance_digest(&client, &inputs, shares, id, circuit_hash)?;
+        let signature = key.sign(&digest);
+        Ok(Self {
+            client,
+            inputs,
+            signature,
+        })
+    }
+
+    /// Checks the signature of the client on the shares of party `id` for the circuit with the given hash, where the shares are given in the order of [`Self::inputs`].
+    pub fn verify<S: Rep3Components>(
+        &self,
+        shares: &[S],
+        id: PartyID,
+        circuit_hash: Option<[u8; 32]>,
+        key: &ClientVerifyingKey,
+    ) -> eyre::Result<()> {
+        let digest = provenance_digest(&self.client, &self.inputs, shares, id, circuit_hash)?;
+        key.verify_strict(&digest, &self.signature)
+            .map_err(|_| eyre!("Invalid signature of client \"{}\"", self.client))
+    }
+}
+
+/// The inputs a client is allowed to provide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientPolicy {
+    /// The key the client signs its inputs with
+    pub key: ClientVerifyingKey,
+    /// The inputs the client is allowed to provide. If only some elements of an input are allowed, their indices are given.
+    pub inputs: InputNames,
+}
+
+/// Declares which client is allowed to provide which inputs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputPolicy {
+    /// The clients, indexed by their identifier
+    pub clients: BTreeMap<String, ClientPolicy>,
+}
+
+/// The inputs a client is allowed to provide, as stored in an input policy file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientPolicyConfig {
+    /// The path to the raw 32 byte public key of the client
+    pub key_path: PathBuf,
+    /// The inputs the client is allowed to provide as a whole
+    #[serde(default)]
+    pub inputs: Vec<String>,
+    /// The array inputs of which the client is allowed to provide some elements, together with the indices of these elements
+    #[serde(default)]
+    pub indices: BTreeMap<String, Vec<usize>>,
+}
+
+/// An input policy, as stored in an input policy file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputPolicyConfig {
+    /// The clients, indexed by their identifier
+    pub clients: BTreeMap<String, ClientPolicyConfig>,
+}
+
+impl TryFrom<ClientPolicyConfig> for ClientPolicy {
+    type Error = eyre::Report;
+    fn try_from(value: ClientPolicyConfig) -> eyre::Result<Self> {
+        let key = read_client_verifying_key(&value.key_path)?;
+        let mut inputs = BTreeMap::new();
+        for name in value.inputs {
+            if inputs.insert(name.clone(), None).is_some() {
+                bail!("Input \"{name}\" is listed multiple times");
+            }
+        }
+        for (name, indices) in value.indices {
+            if inputs.insert(name.clone(), Some(indices)).is_some() {
+                bail!("Input \"{name}\" is listed multiple times");
+            }
+        }
+        Ok(Self { key, inputs })
+    }
+}
+
+impl TryFrom<InputPolicyConfig> for InputPolicy {
+    type Error = eyre::Report;
+    fn try_from(value: InputPolicyConfig) -> eyre::Result<Self> {
+        let clients = value
+            .clients
+            .into_iter()
+            .map(|(client, config)| {
+                let policy = ClientPolicy::try_from(config)
+                    .with_context(|| format!("while reading policy of client \"{client}\""))?;
+                Ok((client, policy))
+            })
+            .collect::<eyre::Result<_>>()?;
+        let policy = Self { clients };
+        policy.check_disjoint()?;
+        Ok(policy)
+    }
+}
+
+impl InputPolicy {
+    /// Checks that no input element is assigned to more than one client.
+    fn check_disjoint(&self) -> eyre::Result<()> {
+        let mut owners = BTreeMap::<&str, Vec<(&str, &Option<Vec<usize>>)>>::new();
+        for (client, policy) in self.clients.iter() {
+            for (name, indices) in policy.inputs.iter() {
+                let others = owners.entry(name).or_default();
+                for (other, other_indices) in others.iter() {
+                    let overlap = match (indices, other_indices) {
+                        (Some(a), Some(b)) => a.iter().any(|i| b.contains(i)),
+                        _ => true,
+                    };
+                    if overlap {
+                        bail!(
+                            "Input \"{name}\" is assigned to clients \"{other}\" and \"{client}\""
+                        );
+                    }
+                }
+                others.push((client, indices));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the client of the provenance is allowed to provide its inputs, and returns the key of the client.
+    pub fn check(&self, provenance: &InputProvenance) -> eyre::Result<&ClientVerifyingKey> {
+        let client = &provenance.client;
+        let policy = self
+            .clients
+            .get(client)
+            .ok_or_else(|| eyre!("Unknown client \"{client}\""))?;
+        for (name, indices) in provenance.inputs.iter() {
+            match (policy.inputs.get(name), indices) {
+                (None, _) => {
+                    bail!("Client \"{client}\" is not allowed to provide input \"{name}\"")
+                }
+                (Some(None), _) => {}
+                (Some(Some(_)), None) => {
+                    bail!(
+                        "Client \"{client}\" is only allowed to provide some elements of input \"{name}\""
+                    )
+                }
+                (Some(Some(allowed)), Some(indices)) => {
+                    if let Some(i) = indices.iter().find(|i| !allowed.contains(i)) {
+                        bail!(
+                            "Client \"{client}\" is not allowed to provide element {i} of input \"{name}\""
+                        );
+                    }
+                }
+            }
+        }
+        Ok(&policy.key)
+    }
+}
+
+/// Tracks which elements of the inputs are covered by an [InputProvenance] or a [Rep3InputCommitment], to ensure that every shared input element is provided by exactly one client or committed by exactly one dealer.
+#[derive(Debug, Default)]
+pub struct InputCoverage {
+    covered: BTreeMap<String, BTreeSet<usize>>,
+}
+
+impl InputCoverage {
+    /// Marks the inputs of a provenance as covered, where `len` returns the number of elements of an input. Returns an error if an element is already covered.
+    pub fn add(
+        &mut self,
+        provenance: &InputProvenance,
+        len: impl Fn(&str) -> Option<usize>,
+    ) -> eyre::Result<()> {
+        self.add_inputs(
+            &provenance.inputs,
+            len,
+            "Signed",
+            "provided by multiple clients",
+        )
+    }
+
+    /// Marks the inputs of a commitment as covered, where `len` returns the number of elements of an input. Returns an error if an element is already covered.
+    pub fn add_commitment(
+        &mut self,
+        commitment: &Rep3InputCommitment,
+        len: impl Fn(&str) -> Option<usize>,
+    ) -> eyre::Result<()> {
+        self.add_inputs(
+            &commitment.inputs,
+            len,
+            "Committed",
+            "committed by multiple dealers",
+        )
+    }
+
+    fn add_inputs(
+        &mut self,
+        inputs: &InputNames,
+        len: impl Fn(&str) -> Option<usize>,
+        kind: &str,
+        duplicate: &str,
+    ) -> eyre::Result<()> {
+        for (name, indices) in inputs.iter() {
+            let indices = match indices {
+                Some(indices) => indices.clone(),
+                None => (0..len(name)
+                    .ok_or_else(|| eyre!("{kind} input \"{name}\" is missing"))?)
+                    .collect(),
+            };
+            let covered = self.covered.entry(name.to_owned()).or_default();
+            for i in indices {
+                if !covered.insert(i) {
+                    bail!("Element {i} of input \"{name}\" is {duplicate}");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the given elements of an input are covered by an [InputProvenance].
+    pub fn check(&self, name: &str, indices: impl IntoIterator<Item = usize>) -> eyre::Result<()> {
+        self.check_inputs(name, indices, "signed by any client")
+    }
+
+    /// Checks that the given elements of an input are covered by a [Rep3InputCommitment].
+    pub fn check_committed(
+        &self,
+        name: &str,
+        indices: impl IntoIterator<Item = usize>,
+    ) -> eyre::Result<()> {
+        self.check_inputs(name, indices, "committed by any dealer")
+    }
+
+    fn check_inputs(
+        &self,
+        name: &str,
+        indices: impl IntoIterator<Item = usize>,
+        missing: &str,
+    ) -> eyre::Result<()> {
+        let covered = self.covered.get(name);
+        for i in indices {
+            if !covered.is_some_and(|covered| covered.contains(&i)) {
+                bail!("Element {i} of input \"{name}\" is not {missing}");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Generates a new key pair for a client to sign its inputs with.
+pub fn generate_client_key<R: RngCore + CryptoRng>(rng: &mut R) -> ClientSigningKey {
+    ClientSigningKey::generate(rng)
+}
+
+/// Reads a raw 32 byte secret key for signing inputs from the given path.
+pub fn read_client_signing_key(
+    path: impl AsRef<std::path::Path>,
+) -> eyre::Result<ClientSigningKey> {
+    let bytes = std::fs::read(path).context("while reading client signing key")?;
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| eyre!("client signing key must be exactly 32 bytes"))?;
+    Ok(ClientSigningKey::from_bytes(&bytes))
+}
+
+/// Reads a raw 32 byte public key for verifying the signatures of a client from the given path.
+pub fn read_client_verifying_key(
+    path: impl AsRef<std::path::Path>,
+) -> eyre::Result<ClientVerifyingKey> {
+    let bytes = std::fs::read(path).context("while reading client verifying key")?;
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| eyre!("client verifying key must be exactly 32 bytes"))?;
+    ClientVerifyingKey::from_bytes(&bytes).context("invalid client verifying key")
+}
+
+#[cfg(test)]
+mod input_provenance_test {
+    use super::*;
+    use crate::protocols::rep3::{self, Rep3PrimeFieldShare};
+    use ark_ff::UniformRand;
+    use itertools::Itertools;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha12Rng;
+
+    type F = ark_bn254::Fr;
+
+    fn policy(alice: &ClientSigningKey, bob: &ClientSigningKey) -> InputPolicy {
+        InputPolicy {
+            clients: BTreeMap::from([
+                (
+                    "alice".to_owned(),
+                    ClientPolicy {
+                        key: alice.verifying_key(),
+                        inputs: BTreeMap::from([
+                            ("a".to_owned(), None),
+                            ("c".to_owned(), Some(vec![0, 1])),
+                        ]),
+                    },
+                ),
+                (
+                    "bob".to_owned(),
+                    ClientPolicy {
+                        key: bob.verifying_key(),
+                        inputs: BTreeMap::from([("c".to_owned(), Some(vec![2]))]),
+                    },
+                ),
+            ]),
+        }
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let alice = generate_client_key(&mut rng);
+        let bob = generate_client_key(&mut rng);
+        let policy = policy(&alice, &bob);
+
+        let vals = (0..5).map(|_| F::rand(&mut rng)).collect_vec();
+        let [s0, s1, _] = rep3::share_field_elements(&vals, &mut rng);
+        let r0 = s0.iter().collect_vec();
+        let r1 = s1.iter().collect_vec();
+        let inputs = BTreeMap::from([("a".to_owned(), None), ("c".to_owned(), Some(vec![0, 1]))]);
+        let circuit: [u8; 32] = rng.r#gen();
+        let provenance = InputProvenance::sign(
+            "alice".to_owned(),
+            inputs,
+            &r0,
+            PartyID::ID0,
+            Some(circuit),
+            &alice,
+        )
+        .unwrap();
+        let key = policy.check(&provenance).unwrap();
+        provenance
+            .verify(&r0, PartyID::ID0, Some(circuit), key)
+            .unwrap();
+
+        // the signature is bound to the shares, the party and the circuit
+        assert!(
+            provenance
+                .verify(&r1, PartyID::ID1, Some(circuit), key)
+                .is_err()
+        );
+        assert!(
+            provenance
+                .verify(&r0, PartyID::ID1, Some(circuit), key)
+                .is_err()
+        );
+        assert!(provenance.verify(&r0, PartyID::ID0, None, key).is_err());
+        assert!(
+            provenance
+                .verify(&r0, PartyID::ID0, Some(rng.r#gen()), key)
+                .is_err()
+        );
+        let mut tampered = s0.clone();
+        tampered[3] = Rep3PrimeFieldShare::new(tampered[3].a + F::from(1u64), tampered[3].b);
+        let tampered = tampered.iter().collect_vec();
+        assert!(
+            provenance
+                .verify(&tampered, PartyID::ID0, Some(circuit), key)
+                .is_err()
+        );
+        // bob cannot claim the inputs of alice
+        let forged = InputProvenance {
+            client: "bob".to_owned(),
+            ..provenance.clone()
+        };
+        assert!(policy.check(&forged).is_err());
+    }
+
+    #[test]
+    fn policy_restricts_inputs() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let alice = generate_client_key(&mut rng);
+        let bob = generate_client_key(&mut rng);
+        let policy = policy(&alice, &bob);
+        let shares: Vec<&Rep3PrimeFieldShare<F>> = Vec::new();
+        let sign = |client: &str, inputs: InputNames, key| {
+            InputProvenance::sign(client.to_owned(), inputs, &shares, PartyID::ID0, None, key)
+                .unwrap()
+        };
+
+        let allowed = sign(
+            "bob",
+            BTreeMap::from([("c".to_owned(), Some(vec![2]))]),
+            &bob,
+        );
+        policy.check(&allowed).unwrap();
+        let other_input = sign("bob", BTreeMap::from([("a".to_owned(), None)]), &bob);
+        assert!(policy.check(&other_input).is_err());
+        let other_index = sign(
+            "bob",
+            BTreeMap::from([("c".to_owned(), Some(vec![1]))]),
+            &bob,
+        );
+        assert!(policy.check(&other_index).is_err());
+        let whole_input = sign("bob", BTreeMap::from([("c".to_owned(), None)]), &bob);
+        assert!(policy.check(&whole_input).is_err());
+        let unknown = sign("eve", BTreeMap::new(), &bob);
+        assert!(policy.check(&unknown).is_err());
+
+        let mut overlapping = policy.clone();
+        overlapping
+            .clients
+            .get_mut("bob")
+            .unwrap()
+            .inputs
+            .insert("c".to_owned(), Some(vec![1, 2]));
+        assert!(overlapping.check_disjoint().is_err());
+        policy.check_disjoint().unwrap();
+    }
+
+    #[test]
+    fn coverage() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let alice = generate_client_key(&mut rng);
+        let shares: Vec<&Rep3PrimeFieldShare<F>> = Vec::new();
+        let sign = |inputs: InputNames| {
+            InputProvenance::sign(
+                "alice".to_owned(),
+                inputs,
+                &shares,
+                PartyID::ID0,
+                None,
+                &alice,
+            )
+            .unwrap()
+        };
+        let len = |name: &str| (name == "c").then_some(3);
+
+        let mut coverage = InputCoverage::default();
+        coverage
+            .add(
+                &sign(BTreeMap::from([("c".to_owned(), Some(vec![0, 2]))])),
+                len,
+            )
+            .unwrap();
+        assert!(coverage.check("c", 0..3).is_err());
+        coverage
+            .add(
+                &sign(BTreeMap::from([("c".to_owned(), Some(vec![1]))])),
+                len,
+            )
+            .unwrap();
+        coverage.check("c", 0..3).unwrap();
+        assert!(
+            coverage
+                .add(&sign(BTreeMap::from([("c".to_owned(), None)])), len)
+                .is_err()
+        );
+        assert!(
+            coverage
+                .add(&sign(BTreeMap::from([("d".to_owned(), None)])), len)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn commitment_coverage() {
+        let commitment = |inputs: InputNames| Rep3InputCommitment {
+            inputs,
+            hashes: [[0; 32]; 3],
+            salts: [[0; 32]; 2],
+        };
+        let len = |name: &str| (name == "c").then_some(3);
+
+        let mut coverage = InputCoverage::default();
+        coverage
+            .add_commitment(
+                &commitment(BTreeMap::from([("c".to_owned(), Some(vec![1]))])),
+                len,
+            )
+            .unwrap();
+        assert!(coverage.check_committed("c", 0..3).is_err());
+        coverage
+            .add_commitment(
+                &commitment(BTreeMap::from([("c".to_owned(), Some(vec![0, 2]))])),
+                len,
+            )
+            .unwrap();
+        coverage.check_committed("c", 0..3).unwrap();
+        assert!(
+            coverage
+                .add_commitment(&commitment(BTreeMap::from([("c".to_owned(), None)])), len)
+                .is_err()
+        );
+        assert!(
+            coverage
+                .add_commitment(&commitment(BTreeMap::from([("d".to_owned(), None)])), len)
+                .is_err()
+        );
+    }
+}
@@ -4,5 +4,7 @@
 
 #![warn(missing_docs)]
 
+pub mod input_provenance;
 pub mod protocols;
 pub mod serde_compat;
+pub mod share_file;
@@ -6,6 +6,7 @@ pub mod arithmetic;
 pub mod binary;
 pub mod id;
 pub mod pointshare;
+pub mod vss;
 
 use std::marker::PhantomData;
 
@@ -17,6 +18,7 @@ use ark_ff::{One, PrimeField};
 use rand::{CryptoRng, Rng, SeedableRng, distributions::Standard, prelude::Distribution};
 
 pub use arithmetic::types::Rep3PrimeFieldShare;
+pub use binary::bitvec::Rep3BitVecShare;
 pub use binary::types::Rep3BigUintShare;
 pub use pointshare::types::Rep3PointShare;
 use serde::{Deserialize, Serialize};
@@ -446,6 +448,25 @@ pub fn share_biguint<F: PrimeField, R: Rng + CryptoRng>(
     [share1, share2, share3]
 }
 
+/// Secret shares the `len` least significant bits of a value using replicated binary secret sharing and the provided random number generator. The bits are split into three additive (XOR) shares, where each party holds two. The outputs are of type [Rep3BitVecShare].
+pub fn share_bitvec<R: Rng + CryptoRng>(
+    val: &BigUint,
+    len: usize,
+    rng: &mut R,
+) -> [Rep3BitVecShare; 3] {
+    let num_words = binary::bitvec::num_words(len);
+    let val = binary::bitvec::biguint_to_words(val, len);
+    let a = (0..num_words).map(|_| rng.r#gen()).collect::<Vec<u64>>();
+    let b = (0..num_words).map(|_| rng.r#gen()).collect::<Vec<u64>>();
+    let c = itertools::izip!(val, &a, &b)
+        .map(|(val, a, b)| val ^ a ^ b)
+        .collect::<Vec<_>>();
+    let share1 = Rep3BitVecShare::new(a.clone(), c.clone(), len);
+    let share2 = Rep3BitVecShare::new(b.clone(), a, len);
+    let share3 = Rep3BitVecShare::new(c, b, len);
+    [share1, share2, share3]
+}
+
 /// Secret shares a curve point using replicated secret sharing and the provided random number generator. The point is split into three additive shares, where each party holds two. The outputs are of type [Rep3PointShare].
 pub fn share_curve_point<C: CurveGroup, R: Rng + CryptoRng>(
     val: C,
@@ -495,6 +516,18 @@ pub fn combine_binary_element<F: PrimeField>(
 }
 
 
+/// Reconstructs a value from its packed binary replicated shares.
+pub fn combine_bitvec(
+    share1: Rep3BitVecShare,
+    share2: Rep3BitVecShare,
+    share3: Rep3BitVecShare,
+) -> BigUint {
+    let words = itertools::izip!(share1.a, share2.a, share3.a)
+        .map(|(a, b, c)| a ^ b ^ c)
+        .collect::<Vec<_>>();
+    binary::bitvec::words_to_biguint(&words)
+}
+
 /// Reconstructs a curve point from its arithmetic replicated shares.
 pub fn combine_curve_point<C: CurveGroup>(
     share1: Rep3PointShare<C>,
@@ -2,5 +2,6 @@
 //!
 //! This module contains binary share types
 
+pub mod bitvec;
 mod ops;
 pub(super) mod types;
@@ -0,0 +1,552 @@
+//! Bit vectors
+//!
+//! This module contains the fixed-width packed bit vector share type
+
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError, Valid};
+use num_bigint::BigUint;
+use serde::{Deserialize, Deserializer, Serialize, de};
+
+use crate::protocols::rep3_ring::{Rep3RingShare, ring::int_ring::IntRing2k};
+
+use super::types::Rep3BigUintShare;
+
+/// The number of bits in a word of a [Rep3BitVecShare].
+pub const WORD_BITS: usize = u64::BITS as usize;
+
+/// This type represents a fixed-width vector of replicated shared bits, which is packed into `u64` words in little-endian order. In contrast to [Rep3BigUintShare], the width of the vector is known, so operations only touch as many words as required and do not allocate for small widths. Bits above the width are always zero.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, CanonicalSerialize)]
+pub struct Rep3BitVecShare {
+    /// Share of this party
+    pub a: Vec<u64>,
+    /// Share of the prev party
+    pub b: Vec<u64>,
+    len: usize,
+}
+
+/// Returns the number of words required to store `len` bits.
+pub fn num_words(len: usize) -> usize {
+    len.div_ceil(WORD_BITS)
+}
+
+/// Returns the mask of the valid bits in the last word of a vector with `len` bits.
+fn last_word_mask(len: usize) -> u64 {
+    match len % WORD_BITS {
+        0 => u64::MAX,
+        rem => (1 << rem) - 1,
+    }
+}
+
+/// Clears all bits above `len`.
+fn mask_words(words: &mut [u64], len: usize) {
+    if let Some(last) = words.last_mut() {
+        *last &= last_word_mask(len);
+    }
+}
+
+/// Extracts `len` bits starting at bit `start`. Bits outside of `words` are treated as zero.
+fn extract_bits(words: &[u64], start: usize, len: usize) -> Vec<u64> {
+    let word_offset = start / WORD_BITS;
+    let bit_offset = start % WORD_BITS;
+    let word = |i: usize| words.get(word_offset + i).copied().unwrap_or(0);
+    let mut res = (0..num_words(len))
+        .map(|i| {
+            if bit_offset == 0 {
+                word(i)
+            } else {
+                (word(i) >> bit_offset) | (word(i + 1) << (WORD_BITS - bit_offset))
+            }
+        })
+        .collect::<Vec<_>>();
+    mask_words(&mut res, len);
+    res
+}
+
+/// XORs the bits of `src` into `dst`, starting at bit `start`. Bits which do not fit into `dst` are dropped.
+fn xor_bits_at(dst: &mut [u64], src: &[u64], start: usize) {
+    let word_offset = start / WORD_BITS;
+    let bit_offset = start % WORD_BITS;
+    for (i, word) in src.iter().enumerate() {
+        if let Some(dst) = dst.get_mut(word_offset + i) {
+            *dst ^= word << bit_offset;
+        }
+        if bit_offset != 0 {
+            if let Some(dst) = dst.get_mut(word_offset + i + 1) {
+                *dst ^= word >> (WORD_BITS - bit_offset);
+            }
+        }
+    }
+}
+
+/// Converts a [BigUint] to `len` bits packed into words.
+pub(crate) fn biguint_to_words(val: &BigUint, len: usize) -> Vec<u64> {
+    let mut words = val
+        .iter_u64_digits()
+        .chain(std::iter::repeat(0))
+        .take(num_words(len))
+        .collect::<Vec<_>>();
+    mask_words(&mut words, len);
+    words
+}
+
+/// Converts words to a [BigUint].
+pub(crate) fn words_to_biguint(words: &[u64]) -> BigUint {
+    BigUint::new(
+        words
+            .iter()
+            .flat_map(|word| [*word as u32, (*word >> 32) as u32])
+            .collect(),
+    )
+}
+
+/// Checks that the words of a share of `len` bits are exactly the number of words required for `len` bits and that all bits above `len` are zero.
+fn check_words(words: &[u64], len: usize) -> Result<(), String> {
+    if words.len() != num_words(len) {
+        return Err(format!(
+            "invalid number of words for {len} bits: expected {}, got {}",
+            num_words(len),
+            words.len()
+        ));
+    }
+    if words
+        .last()
+        .is_some_and(|last| last & !last_word_mask(len) != 0)
+    {
+        return Err(format!("bits above width {len} are set"));
+    }
+    Ok(())
+}
+
+impl<'de> Deserialize<'de> for Rep3BitVecShare {
+    /// Deserializes the share and checks that both shares consist of exactly the number of words required for the width and that all bits above the width are zero.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Raw {
+            a: Vec<u64>,
+            b: Vec<u64>,
+            len: usize,
+        }
+
+        let Raw { a, b, len } = Raw::deserialize(deserializer)?;
+        check_words(&a, len).map_err(de::Error::custom)?;
+        check_words(&b, len).map_err(de::Error::custom)?;
+        Ok(Self { a, b, len })
+    }
+}
+
+impl Valid for Rep3BitVecShare {
+    fn check(&self) -> Result<(), SerializationError> {
+        check_words(&self.a, self.len).map_err(|_| SerializationError::InvalidData)?;
+        check_words(&self.b, self.len).map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+impl CanonicalDeserialize for Rep3BitVecShare {
+    /// Deserializes the share with the same checks as the serde implementation. Since the operations on the share rely on the width, the checks are done regardless of `validate`.
+    fn deserialize_with_mode<R: std::io::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> Result<Self, SerializationError> {
+        let a = Vec::<u64>::deserialize_with_mode(&mut reader, compress, validate)?;
+        let b = Vec::<u64>::deserialize_with_mode(&mut reader, compress, validate)?;
+        let len = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let share = Self { a, b, len };
+        share.check()?;
+        Ok(share)
+    }
+}
+
+impl Rep3BitVecShare {
+    /// Constructs the type from two additive shares of `len` bits. Bits above `len` are cleared.
+    ///
+    /// # Panics
+    /// Panics if the shares do not consist of exactly the number of words required for `len` bits.
+    pub fn new(mut a: Vec<u64>, mut b: Vec<u64>, len: usize) -> Self {
+        assert_eq!(a.len(), num_words(len), "invalid number of words");
+        assert_eq!(b.len(), num_words(len), "invalid number of words");
+        mask_words(&mut a, len);
+        mask_words(&mut b, len);
+        Self { a, b, len }
+    }
+
+    /// Constructs a zero share of `len` bits.
+    pub fn zero_share(len: usize) -> Self {
+        Self {
+            a: vec![0; num_words(len)],
+            b: vec![0; num_words(len)],
+            len,
+        }
+    }
+
+    /// Unwraps the type into two additive shares.
+    pub fn ab(self) -> (Vec<u64>, Vec<u64>) {
+        (self.a, self.b)
+    }
+
+    /// Returns the number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the vector does not contain any bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bits `start..start + len` as a new share.
+    ///
+    /// # Panics
+    /// Panics if the range exceeds the width of the vector.
+    pub fn slice(&self, start: usize, len: usize) -> Self {
+        assert!(start + len <= self.len, "slice out of bounds");
+        Self {
+            a: extract_bits(&self.a, start, len),
+            b: extract_bits(&self.b, start, len),
+            len,
+        }
+    }
+
+    /// Splits the vector into chunks of `width` bits. The last chunk is shorter if the width does not divide the length.
+    pub fn chunks(&self, width: usize) -> Vec<Self> {
+        assert!(width > 0, "width must be positive");
+        (0..self.len)
+            .step_by(width)
+            .map(|start| self.slice(start, width.min(self.len - start)))
+            .collect()
+    }
+
+    /// Concatenates the shares, where the first share contains the least significant bits.
+    pub fn concat(shares: &[Self]) -> Self {
+        let len = shares.iter().map(|share| share.len).sum();
+        let mut res = Self::zero_share(len);
+        let mut start = 0;
+        for share in shares {
+            xor_bits_at(&mut res.a, &share.a, start);
+            xor_bits_at(&mut res.b, &share.b, start);
+            start += share.len;
+        }
+        res
+    }
+
+    /// Returns a share of `len` bits, where all bits are equal to the bit at `index`.
+    pub fn repeat_bit(&self, index: usize, len: usize) -> Self {
+        assert!(index < self.len, "index out of bounds");
+        let repeat = |words: &[u64]| {
+            let bit = (words[index / WORD_BITS] >> (index % WORD_BITS)) & 1;
+            let mut res = vec![bit.wrapping_neg(); num_words(len)];
+            mask_words(&mut res, len);
+            res
+        };
+        Self {
+            a: repeat(&self.a),
+            b: repeat(&self.b),
+            len,
+        }
+    }
+
+    /// Rotates the bits to the left by `n` positions within the width of the vector.
+    pub fn rotate_left(&self, n: usize) -> Self {
+        if self.len == 0 {
+            return self.clone();
+        }
+        let n = n % self.len;
+        &(self << n) ^ &(self >> (self.len - n))
+    }
+
+    /// Rotates the bits to the right by `n` positions within the width of the vector.
+    pub fn rotate_right(&self, n: usize) -> Self {
+        if self.len == 0 {
+            return self.clone();
+        }
+        self.rotate_left(self.len - n % self.len)
+    }
+
+    /// Converts a [Rep3BigUintShare] to a share of its `len` least significant bits.
+    pub fn from_biguint_share<F: PrimeField>(share: &Rep3BigUintShare<F>, len: usize) -> Self {
+        Self {
+            a: biguint_to_words(&share.a, len),
+            b: biguint_to_words(&share.b, len),
+            len,
+        }
+    }
+
+    /// Converts the share to a [Rep3BigUintShare].
+    pub fn to_biguint_share<F: PrimeField>(&self) -> Rep3BigUintShare<F> {
+        Rep3BigUintShare::new(words_to_biguint(&self.a), words_to_biguint(&self.b))
+    }
+
+    /// Packs binary ring shares into a single share, where the first share contains the least significant bits. The resulting share has `T::K` bits per ring share.
+    pub fn from_ring_shares<T: IntRing2k>(shares: &[Rep3RingShare<T>]) -> Self {
+        let mut res = Self::zero_share(shares.len() * T::K);
+        let words = num_words(T::K);
+        for (i, share) in shares.iter().enumerate() {
+            let a: u128 = share.a.0.into();
+            let b: u128 = share.b.0.into();
+            xor_bits_at(&mut res.a, &[a as u64, (a >> 64) as u64][..words], i * T::K);
+            xor_bits_at(&mut res.b, &[b as u64, (b >> 64) as u64][..words], i * T::K);
+        }
+        res
+    }
+
+    /// Unpacks the share into binary ring shares of `T::K` bits each, where the first share contains the least significant bits.
+    ///
+    /// # Panics
+    /// Panics if the width of the vector is not a multiple of `T::K`.
+    pub fn to_ring_shares<T: IntRing2k>(&self) -> Vec<Rep3RingShare<T>> {
+        assert_eq!(
+            self.len % T::K,
+            0,
+            "width must be a multiple of the ring size"
+        );
+        let to_ring = |words: &[u64], start: usize| {
+            let words = extract_bits(words, start, T::K);
+            let val = words
+                .iter()
+                .rev()
+                .fold(0u128, |acc, word| (acc << 64) | u128::from(*word));
+            T::try_from(val).expect("value fits into ring")
+        };
+        (0..self.len)
+            .step_by(T::K)
+            .map(|start| Rep3RingShare::new(to_ring(&self.a, start), to_ring(&self.b, start)))
+            .collect()
+    }
+}
+
+impl<T: IntRing2k> From<Rep3RingShare<T>> for Rep3BitVecShare {
+    fn from(share: Rep3RingShare<T>) -> Self {
+        Self::from_ring_shares(&[share])
+    }
+}
+
+impl std::ops::BitXor for Rep3BitVecShare {
+    type Output = Self;
+
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= &rhs;
+        self
+    }
+}
+
+impl std::ops::BitXor<&Rep3BitVecShare> for &'_ Rep3BitVecShare {
+    type Output = Rep3BitVecShare;
+
+    fn bitxor(self, rhs: &Rep3BitVecShare) -> Self::Output {
+        let mut res = self.clone();
+        res ^= rhs;
+        res
+    }
+}
+
+impl std::ops::BitXorAssign<&Self> for Rep3BitVecShare {
+    fn bitxor_assign(&mut self, rhs: &Self) {
+        assert_eq!(self.len, rhs.len, "widths must match");
+        for (a, b) in self.a.iter_mut().zip(rhs.a.iter()) {
+            *a ^= b;
+        }
+        for (a, b) in self.b.iter_mut().zip(rhs.b.iter()) {
+            *a ^= b;
+        }
+    }
+}
+
+impl std::ops::BitXorAssign for Rep3BitVecShare {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self ^= &rhs;
+    }
+}
+
+impl std::ops::BitAnd<&[u64]> for &Rep3BitVecShare {
+    type Output = Rep3BitVecShare;
+
+    fn bitand(self, rhs: &[u64]) -> Self::Output {
+        assert_eq!(self.a.len(), rhs.len(), "widths must match");
+        let mut a = self
+            .a
+            .iter()
+            .zip(rhs)
+            .map(|(a, b)| a & b)
+            .collect::<Vec<_>>();
+        let mut b = self
+            .b
+            .iter()
+            .zip(rhs)
+            .map(|(a, b)| a & b)
+            .collect::<Vec<_>>();
+        mask_words(&mut a, self.len);
+        mask_words(&mut b, self.len);
+        Rep3BitVecShare {
+            a,
+            b,
+            len: self.len,
+        }
+    }
+}
+
+impl std::ops::BitAnd for &Rep3BitVecShare {
+    type Output = Vec<u64>;
+
+    /// Computes the local part of the AND of two shares, which is an additive share of the result.
+    fn bitand(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.len, rhs.len, "widths must match");
+        itertools::izip!(&self.a, &self.b, &rhs.a, &rhs.b)
+            .map(|(a0, b0, a1, b1)| (a0 & a1) ^ (a0 & b1) ^ (b0 & a1))
+            .collect()
+    }
+}
+
+impl std::ops::Shl<usize> for &Rep3BitVecShare {
+    type Output = Rep3BitVecShare;
+
+    /// Shifts the bits to the left within the width of the vector, where the most significant bits are dropped.
+    fn shl(self, rhs: usize) -> Self::Output {
+        let mut res = Rep3BitVecShare::zero_share(self.len);
+        if rhs < self.len {
+            xor_bits_at(&mut res.a, &self.a, rhs);
+            xor_bits_at(&mut res.b, &self.b, rhs);
+            mask_words(&mut res.a, self.len);
+            mask_words(&mut res.b, self.len);
+        }
+        res
+    }
+}
+
+impl std::ops::Shr<usize> for &Rep3BitVecShare {
+    type Output = Rep3BitVecShare;
+
+    fn shr(self, rhs: usize) -> Self::Output {
+        Rep3BitVecShare {
+            a: extract_bits(&self.a, rhs, self.len),
+            b: extract_bits(&self.b, rhs, self.len),
+            len: self.len,
+        }
+    }
+}
+
+#[cfg(test)]
+mod bitvec_test {
+    use super::*;
+    use crate::protocols::rep3::{combine_bitvec, share_bitvec};
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha12Rng;
+
+    const WIDTH: usize = 150;
+
+    fn random_value<R: Rng>(rng: &mut R, len: usize) -> BigUint {
+        let words = (0..num_words(len)).map(|_| rng.r#gen()).collect::<Vec<_>>();
+        words_to_biguint(&biguint_to_words(&words_to_biguint(&words), len))
+    }
+
+    #[test]
+    fn bitvec_share_combine() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let val = random_value(&mut rng, WIDTH);
+        let [s1, s2, s3] = share_bitvec(&val, WIDTH, &mut rng);
+        assert_eq!(s1.b, s3.a);
+        assert_eq!(combine_bitvec(s1, s2, s3), val);
+    }
+
+    #[test]
+    fn bitvec_slice_concat_shift() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let val = random_value(&mut rng, WIDTH);
+        let mask = (BigUint::from(1u64) << WIDTH) - BigUint::from(1u64);
+        let [s1, s2, s3] = share_bitvec(&val, WIDTH, &mut rng);
+        let apply = |f: &dyn Fn(&Rep3BitVecShare) -> Rep3BitVecShare| {
+            combine_bitvec(f(&s1), f(&s2), f(&s3))
+        };
+
+        assert_eq!(
+            apply(&|s| s.slice(3, 70)),
+            (&val >> 3) & ((BigUint::from(1u64) << 70) - BigUint::from(1u64))
+        );
+        assert_eq!(
+            apply(&|s| Rep3BitVecShare::concat(&s.chunks(64))),
+            val.clone()
+        );
+        assert_eq!(apply(&|s| s << 17), (&val << 17) & &mask);
+        assert_eq!(apply(&|s| s >> 17), &val >> 17);
+        assert_eq!(
+            apply(&|s| s.rotate_left(17)),
+            ((&val << 17) | (&val >> (WIDTH - 17))) & &mask
+        );
+        assert_eq!(apply(&|s| s.rotate_right(17).rotate_left(17)), val);
+    }
+
+    #[test]
+    fn bitvec_conversions() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let val = random_value(&mut rng, 4 * 32);
+        let shares = share_bitvec(&val, 4 * 32, &mut rng);
+
+        let biguint = shares
+            .iter()
+            .map(|s| s.to_biguint_share::<ark_bn254::Fr>())
+            .collect::<Vec<_>>();
+        for (share, biguint) in shares.iter().zip(biguint.iter()) {
+            assert_eq!(&Rep3BitVecShare::from_biguint_share(biguint, 4 * 32), share);
+        }
+
+        for share in shares.iter() {
+            let ring = share.to_ring_shares::<u32>();
+            assert_eq!(ring.len(), 4);
+            assert_eq!(&Rep3BitVecShare::from_ring_shares(&ring), share);
+        }
+    }
+
+    #[test]
+    fn bitvec_and_public_masks_tail() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let val = random_value(&mut rng, WIDTH);
+        let [s1, s2, s3] = share_bitvec(&val, WIDTH, &mut rng);
+        let public = vec![u64::MAX; num_words(WIDTH)];
+        let res = [
+            &s1 & public.as_slice(),
+            &s2 & public.as_slice(),
+            &s3 & public.as_slice(),
+        ];
+        for res in res.iter() {
+            assert_eq!(res.a.last().unwrap() & !last_word_mask(WIDTH), 0);
+            assert_eq!(res.b.last().unwrap() & !last_word_mask(WIDTH), 0);
+        }
+        let [r1, r2, r3] = res;
+        assert_eq!(combine_bitvec(r1, r2, r3), val);
+    }
+
+    #[test]
+    fn bitvec_deserialize_checks_width() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let val = random_value(&mut rng, WIDTH);
+        let [share, _, _] = share_bitvec(&val, WIDTH, &mut rng);
+        let bytes = bincode::serialize(&share).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Rep3BitVecShare>(&bytes).unwrap(),
+            share
+        );
+
+        let mut too_short = share.clone();
+        too_short.a.pop();
+        let bytes = bincode::serialize(&too_short).unwrap();
+        assert!(bincode::deserialize::<Rep3BitVecShare>(&bytes).is_err());
+
+        let mut tail_set = share.clone();
+        *tail_set.b.last_mut().unwrap() |= 1 << 63;
+        let bytes = bincode::serialize(&tail_set).unwrap();
+        assert!(bincode::deserialize::<Rep3BitVecShare>(&bytes).is_err());
+
+        // The same checks are done by the canonical deserialization
+        let mut bytes = Vec::new();
+        share.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            Rep3BitVecShare::deserialize_compressed(bytes.as_slice()).unwrap(),
+            share
+        );
+        for invalid in [too_short, tail_set] {
+            let mut bytes = Vec::new();
+            invalid.serialize_compressed(&mut bytes).unwrap();
+            assert!(Rep3BitVecShare::deserialize_compressed(bytes.as_slice()).is_err());
+            assert!(Rep3BitVecShare::deserialize_compressed_unchecked(bytes.as_slice()).is_err());
+        }
+    }
+}
@@ -2,11 +2,9 @@ use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use itertools::Itertools;
 use std::marker::PhantomData;
 
-use ark_ff::{PrimeField, Zero};
+use ark_ff::PrimeField;
 use num_bigint::BigUint;
 
-use crate::protocols::rep3;
-
 /// This type represents a packed vector of replicated shared bits. Each additively shared vector is represented as [BigUint]. Thus, this type contains two [BigUint]s.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Rep3BigUintShare<F: PrimeField> {
@@ -0,0 +1,317 @@
+//! # Verifiable REP3 Sharing
+//!
+//! This module contains commitments which allow the parties to verify REP3 shares produced by an untrusted dealer. In REP3, each of the three additive components of a value is held by two parties. Alongside the shares, the dealer publishes salted hashes of the three components, where the salt of a component is only given to the two parties holding it. Each party checks its two components against the hashes, and the parties check that they received the same hashes. Thus, a dealer which hands out inconsistent copies of a component is detected before any computation starts.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalSerialize, SerializationError};
+use rand::{CryptoRng, Rng};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+
+use super::{Rep3PrimeFieldShare, id::PartyID};
+use crate::protocols::rep3_ring::{Rep3RingShare, Rep3TypedRingShare, ring::int_ring::IntRing2k};
+
+/// A salt or hash of a [`Rep3InputCommitment`].
+pub type Rep3Digest = [u8; 32];
+
+/// A replicated share, whose two components can be serialized separately.
+pub trait Rep3Components {
+    /// Serializes the component of this party.
+    fn serialize_a(&self, writer: &mut dyn Write) -> Result<(), SerializationError>;
+    /// Serializes the component of the previous party.
+    fn serialize_b(&self, writer: &mut dyn Write) -> Result<(), SerializationError>;
+}
+
+impl<T: Rep3Components + ?Sized> Rep3Components for &T {
+    fn serialize_a(&self, writer: &mut dyn Write) -> Result<(), SerializationError> {
+        (**self).serialize_a(writer)
+    }
+
+    fn serialize_b(&self, writer: &mut dyn Write) -> Result<(), SerializationError> {
+        (**self).serialize_b(writer)
+    }
+}
+
+impl<F: PrimeField> Rep3Components for Rep3PrimeFieldShare<F> {
+    fn serialize_a(&self, writer: &mut dyn Write) -> Result<(), SerializationError> {
+        self.a.serialize_compressed(writer)
+    }
+
+    fn serialize_b(&self, writer: &mut dyn Write) -> Result<(), SerializationError> {
+        self.b.serialize_compressed(writer)
+    }
+}
+
+impl<T: IntRing2k> Rep3Components for Rep3RingShare<T> {
+    fn serialize_a(&self, writer: &mut dyn Write) -> Result<(), SerializationError> {
+        self.a.serialize_compressed(writer)
+    }
+
+    fn serialize_b(&self, writer: &mut dyn Write) -> Result<(), SerializationError> {
+        self.b.serialize_compressed(writer)
+    }
+}
+
+macro_rules! typed_ring_components {
+    ($share:expr, $writer:expr, $component:ident) => {{
+        $share
+            .ring_type()
+            .tag()
+            .serialize_compressed(&mut *$writer)?;
+        match $share {
+            Rep3TypedRingShare::Bit(share) => share.$component($writer),
+            Rep3TypedRingShare::U8(share) => share.$component($writer),
+            Rep3TypedRingShare::U16(share) => share.$component($writer),
+            Rep3TypedRingShare::U32(share) => share.$component($writer),
+            Rep3TypedRingShare::U64(share) => share.$component($writer),
+            Rep3TypedRingShare::U128(share) => share.$component($writer),
+        }
+    }};
+}
+
+impl Rep3Components for Rep3TypedRingShare {
+    fn serialize_a(&self, writer: &mut dyn Write) -> Result<(), SerializationError> {
+        typed_ring_components!(self, writer, serialize_a)
+    }
+
+    fn serialize_b(&self, writer: &mut dyn Write) -> Result<(), SerializationError> {
+        typed_ring_components!(self, writer, serialize_b)
+    }
+}
+
+/// The commitment of a dealer to the REP3 shares of a set of named inputs, as received by one party.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rep3InputCommitment {
+    /// The names of the committed inputs. If the dealer only shared some of the elements of an input, the indices of these elements are given.
+    pub inputs: BTreeMap<String, Option<Vec<usize>>>,
+    /// The salted hashes of the three additive components of the shares. These are the same for all parties.
+    pub hashes: [Rep3Digest; 3],
+    /// The salts of the two components held by this party, i.e., of its own component and the one of the previous party.
+    pub salts: [Rep3Digest; 2],
+}
+
+/// Adds the names and indices of the committed inputs to the hasher.
+pub(crate) fn hash_inputs(hasher: &mut Sha3_256, inputs: &BTreeMap<String, Option<Vec<usize>>>) {
+    hasher.update((inputs.len() as u64).to_le_bytes());
+    for (name, indices) in inputs {
+        hasher.update((name.len() as u64).to_le_bytes());
+        hasher.update(name.as_bytes());
+        match indices {
+            Some(indices) => {
+                hasher.update([1]);
+                hasher.update((indices.len() as u64).to_le_bytes());
+                for index in indices {
+                    hasher.update((*index as u64).to_le_bytes());
+                }
+            }
+            None => hasher.update([0]),
+        }
+    }
+}
+
+/// Computes the salted hash of the first (`own == true`) or second component of the shares.
+fn hash_component<S: Rep3Components>(
+    salt: &Rep3Digest,
+    inputs: &BTreeMap<String, Option<Vec<usize>>>,
+    shares: &[S],
+    own: bool,
+) -> eyre::Result<Rep3Digest> {
+    let mut hasher = Sha3_256::new();
+    hasher.update(salt);
+    hash_inputs(&mut hasher, inputs);
+    let mut buf = Vec::new();
+    for share in shares {
+        if own {
+            share.serialize_a(&mut buf)?;
+        } else {
+            share.serialize_b(&mut buf)?;
+        }
+    }
+    hasher.update(&buf);
+    Ok(hasher.finalize().into())
+}
+
+/// Commits to the REP3 shares of the given inputs, where `shares[i]` contains the shares of party i in the order of `inputs`. The i-th output is the commitment which is sent to party i alongside its shares.
+pub fn commit<S: Rep3Components, R: Rng + CryptoRng>(
+    inputs: BTreeMap<String, Option<Vec<usize>>>,
+    shares: [&[S]; 3],
+    rng: &mut R,
+) -> eyre::Result<[Rep3InputCommitment; 3]> {
+    if shares.iter().any(|s| s.len() != shares[0].len()) {
+        eyre::bail!("all parties must have the same number of shares");
+    }
+    let salts: [Rep3Digest; 3] = rng.r#gen();
+    let mut hashes = [[0; 32]; 3];
+    for (hash, salt, shares) in itertools::izip!(hashes.iter_mut(), salts.iter(), shares) {
+        *hash = hash_component(salt, &inputs, shares, true)?;
+    }
+    Ok(
+        [PartyID::ID0, PartyID::ID1, PartyID::ID2].map(|id| Rep3InputCommitment {
+            inputs: inputs.clone(),
+            hashes,
+            salts: [salts[usize::from(id)], salts[usize::from(id.prev_id())]],
+        }),
+    )
+}
+
+impl Rep3InputCommitment {
+    /// Checks that the shares of party `id`, given in the order of [`Self::inputs`], match the commitment.
+    ///
+    /// This only ensures consistency if all parties received the same hashes, which can be checked by comparing [`commitments_digest`].
+    pub fn verify<S: Rep3Components>(&self, shares: &[S], id: PartyID) -> eyre::Result<()> {
+        let own = hash_component(&self.salts[0], &self.inputs, shares, true)?;
+        if own != self.hashes[usize::from(id)] {
+            eyre::bail!("The shares of party {id} do not match the commitment of the dealer");
+        }
+        let prev = hash_component(&self.salts[1], &self.inputs, shares, false)?;
+        if prev != self.hashes[usize::from(id.prev_id())] {
+            eyre::bail!(
+                "The shares of party {} held by party {id} do not match the commitment of the dealer",
+                id.prev_id()
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Computes a digest over the public parts of the commitments, i.e., everything but the salts. All parties need to have the same digest, otherwise the dealer distributed different commitments.
+///
+/// The digest does not depend on the order of the commitments, as the parties may merge the inputs of several dealers in a different order.
+pub fn commitments_digest(commitments: &[Rep3InputCommitment]) -> Rep3Digest {
+    let mut commitments = commitments.iter().collect::<Vec<_>>();
+    commitments.sort_by_key(|c| c.hashes);
+    let mut hasher = Sha3_256::new();
+    hasher.update((commitments.len() as u64).to_le_bytes());
+    for commitment in commitments {
+        hash_inputs(&mut hasher, &commitment.inputs);
+        for hash in commitment.hashes.iter() {
+            hasher.update(hash);
+        }
+    }
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod vss_test {
+    use super::*;
+    use crate::protocols::rep3;
+    use ark_ff::UniformRand;
+    use itertools::Itertools;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha12Rng;
+
+    type F = ark_bn254::Fr;
+
+    fn inputs() -> BTreeMap<String, Option<Vec<usize>>> {
+        BTreeMap::from([("a".to_owned(), None), ("b".to_owned(), Some(vec![1, 3]))])
+    }
+
+    fn as_refs(shares: &[Rep3PrimeFieldShare<F>]) -> Vec<&Rep3PrimeFieldShare<F>> {
+        shares.iter().collect()
+    }
+
+    #[test]
+    fn commit_and_verify() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let vals = (0..10).map(|_| F::rand(&mut rng)).collect_vec();
+        let [s0, s1, s2] = rep3::share_field_elements(&vals, &mut rng);
+        let (r0, r1, r2) = (as_refs(&s0), as_refs(&s1), as_refs(&s2));
+        let commitments = commit(inputs(), [&r0, &r1, &r2], &mut rng).unwrap();
+
+        let digests = commitments
+            .iter()
+            .map(|c| commitments_digest(std::slice::from_ref(c)))
+            .collect_vec();
+        assert!(digests.iter().all_equal());
+
+        for (id, (commitment, shares)) in commitments.iter().zip([&r0, &r1, &r2]).enumerate() {
+            let id = PartyID::try_from(id).unwrap();
+            commitment.verify(shares, id).unwrap();
+        }
+        // shares of a different party are rejected
+        assert!(commitments[0].verify(&r1, PartyID::ID0).is_err());
+    }
+
+    #[test]
+    fn inconsistent_dealer_is_detected() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let vals = (0..10).map(|_| F::rand(&mut rng)).collect_vec();
+        let [s0, mut s1, s2] = rep3::share_field_elements(&vals, &mut rng);
+        let (r0, r1, r2) = (as_refs(&s0), as_refs(&s1), as_refs(&s2));
+        let commitments = commit(inputs(), [&r0, &r1, &r2], &mut rng).unwrap();
+
+        // the copy of component 0 held by party 1 differs from the one of party 0
+        s1[4].b += F::from(1u64);
+        let r1 = as_refs(&s1);
+        commitments[0].verify(&r0, PartyID::ID0).unwrap();
+        assert!(commitments[1].verify(&r1, PartyID::ID1).is_err());
+
+        // a dealer which hands party 1 a commitment matching its inconsistent shares is detected by comparing the digests
+        let fake0 = s1
+            .iter()
+            .map(|s| Rep3PrimeFieldShare::new(s.b, s.b))
+            .collect_vec();
+        let fake0 = as_refs(&fake0);
+        let other = commit(inputs(), [&fake0, &r1, &r2], &mut rng).unwrap();
+        other[1].verify(&r1, PartyID::ID1).unwrap();
+        assert_ne!(
+            commitments_digest(std::slice::from_ref(&commitments[0])),
+            commitments_digest(std::slice::from_ref(&other[1]))
+        );
+    }
+
+    #[test]
+    fn digest_is_independent_of_order() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let commitments = (0..3)
+            .map(|_| {
+                let vals = (0..4).map(|_| F::rand(&mut rng)).collect_vec();
+                let [s0, s1, s2] = rep3::share_field_elements(&vals, &mut rng);
+                let (r0, r1, r2) = (as_refs(&s0), as_refs(&s1), as_refs(&s2));
+                let [c0, _, _] = commit(inputs(), [&r0, &r1, &r2], &mut rng).unwrap();
+                c0
+            })
+            .collect_vec();
+        let digest = commitments_digest(&commitments);
+        for permutation in commitments.iter().cloned().permutations(commitments.len()) {
+            assert_eq!(commitments_digest(&permutation), digest);
+        }
+        assert_ne!(commitments_digest(&commitments[..2]), digest);
+    }
+
+    #[test]
+    fn typed_ring_shares() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let shares = [
+            crate::protocols::rep3_ring::typed::share_typed_ring_element(
+                200,
+                crate::protocols::rep3_ring::RingType::U8,
+                &mut rng,
+            )
+            .unwrap(),
+            crate::protocols::rep3_ring::typed::share_typed_ring_element(
+                1,
+                crate::protocols::rep3_ring::RingType::Bit,
+                &mut rng,
+            )
+            .unwrap(),
+        ];
+        let per_party = (0..3)
+            .map(|i| shares.iter().map(|s| &s[i]).collect_vec())
+            .collect_vec();
+        let inputs = BTreeMap::from([("x".to_owned(), None)]);
+        let commitments = commit(
+            inputs,
+            [&per_party[0], &per_party[1], &per_party[2]],
+            &mut rng,
+        )
+        .unwrap();
+        for (id, (commitment, shares)) in commitments.iter().zip(&per_party).enumerate() {
+            let id = PartyID::try_from(id).unwrap();
+            commitment.verify(shares, id).unwrap();
+        }
+    }
+}
@@ -8,10 +8,14 @@ use ring::{int_ring::IntRing2k, ring_impl::RingElement};
 pub mod arithmetic;
 pub mod binary;
 pub mod ring;
+pub mod signed;
+pub mod typed;
 
 /// Shorthand type for a secret shared bit.
 pub type Rep3BitShare = Rep3RingShare<ring::bit::Bit>;
 pub use arithmetic::types::Rep3RingShare;
+pub use signed::Rep3SignedRingShare;
+pub use typed::{Rep3TypedRingShare, RingType};
 
 /// Secret shares a ring element using replicated secret sharing and the provided random number generator. The ring element is split into three additive shares, where each party holds two. The outputs are of type [`Rep3RingShare`].
 pub fn share_ring_element<T: IntRing2k, R: Rng + CryptoRng>(
@@ -51,6 +55,17 @@ where
     [shares1, shares2, shares3]
 }
 
+/// Secret shares a signed integer using replicated secret sharing and the provided random number generator. The value is embedded into the ring using the two's complement representation and then split into three additive shares, where each party holds two. The outputs are of type [`Rep3SignedRingShare`].
+pub fn share_signed_ring_element<T: IntRing2k, R: Rng + CryptoRng>(
+    val: T::Signed,
+    rng: &mut R,
+) -> [Rep3SignedRingShare<T>; 3]
+where
+    Standard: Distribution<T>,
+{
+    share_ring_element(RingElement::from_signed(val), rng).map(Rep3SignedRingShare::from_unsigned)
+}
+
 /// Secret shares a ring element using replicated secret sharing and the provided random number generator. The ring element is split into three binary shares, where each party holds two. The outputs are of type [`Rep3RingShare`].
 pub fn share_ring_element_binary<T: IntRing2k, R: Rng + CryptoRng>(
     val: RingElement<T>,
@@ -101,3 +116,17 @@ pub fn combine_ring_element_binary<T: IntRing2k>(
 ) -> RingElement<T> {
     share1.a ^ share2.a ^ share3.a
 }
+
+/// Reconstructs a signed integer from its arithmetic replicated shares, interpreting the result in two's complement representation.
+pub fn combine_signed_ring_element<T: IntRing2k>(
+    share1: Rep3SignedRingShare<T>,
+    share2: Rep3SignedRingShare<T>,
+    share3: Rep3SignedRingShare<T>,
+) -> T::Signed {
+    combine_ring_element(
+        share1.into_unsigned(),
+        share2.into_unsigned(),
+        share3.into_unsigned(),
+    )
+    .to_signed()
+}
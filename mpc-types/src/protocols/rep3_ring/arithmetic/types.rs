@@ -2,6 +2,7 @@ use crate::protocols::{
     rep3::id::PartyID,
     rep3_ring::ring::{bit::Bit, int_ring::IntRing2k, ring_impl::RingElement},
 };
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Valid};
 use num_traits::Zero;
 use serde::{Deserialize, Serialize};
 
@@ -71,3 +72,45 @@ impl<T: IntRing2k> Rep3RingShare<T> {
         }
     }
 }
+
+impl<T: IntRing2k> CanonicalSerialize for Rep3RingShare<T> {
+    fn serialize_with_mode<W: std::io::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> Result<(), ark_serialize::SerializationError> {
+        self.a.serialize_with_mode(&mut writer, compress)?;
+        self.b.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.a.serialized_size(compress) + self.b.serialized_size(compress)
+    }
+}
+
+impl<T: IntRing2k> Valid for Rep3RingShare<T> {
+    fn check(&self) -> Result<(), ark_serialize::SerializationError> {
+        Ok(())
+    }
+
+    fn batch_check<'a>(
+        _batch: impl Iterator<Item = &'a Self> + Send,
+    ) -> Result<(), ark_serialize::SerializationError>
+    where
+        Self: 'a,
+    {
+        Ok(())
+    }
+}
+
+impl<T: IntRing2k> CanonicalDeserialize for Rep3RingShare<T> {
+    fn deserialize_with_mode<R: std::io::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> Result<Self, ark_serialize::SerializationError> {
+        let a = RingElement::deserialize_with_mode(&mut reader, compress, validate)?;
+        let b = RingElement::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self { a, b })
+    }
+}
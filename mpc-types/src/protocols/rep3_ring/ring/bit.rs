@@ -11,7 +11,9 @@ use std::ops::{
 };
 
 /// Bit is a sharable wrapper for a boolean value
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(
+    Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
 #[repr(transparent)]
 // This transparent is important due to some typecasts!
 pub struct Bit(pub(super) bool);
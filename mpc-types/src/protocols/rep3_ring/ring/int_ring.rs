@@ -47,7 +47,13 @@ pub trait IntRing2k:
     + 'static
 {
     /// Specifies the signed version of this type
-    type Signed: Neg<Output = Self::Signed> + From<bool> + AsPrimitive<Self>;
+    type Signed: Neg<Output = Self::Signed>
+        + From<bool>
+        + AsPrimitive<Self>
+        + Copy
+        + Debug
+        + PartialOrd
+        + std::fmt::Display;
 
     /// Specifies the number of bits in this type
     const K: usize;
@@ -71,6 +77,15 @@ pub trait IntRing2k:
     /// Thus if the value is larger than this type, it will be truncated
     fn cast_from_biguint(biguint: &BigUint) -> Self;
 
+    /// Reinterprets this value as a signed integer in two's complement representation
+    fn to_signed(self) -> Self::Signed;
+
+    /// Reinterprets a signed integer as this type using the two's complement representation
+    #[inline(always)]
+    fn from_signed(value: Self::Signed) -> Self {
+        value.as_()
+    }
+
     /// a += b
     #[inline(always)]
     fn wrapping_add_assign(&mut self, rhs: &Self) {
@@ -122,6 +137,10 @@ impl IntRing2k for Bit {
     fn cast_from_biguint(biguint: &BigUint) -> Self {
         biguint.iter_u64_digits().next().unwrap_or_default().as_()
     }
+
+    fn to_signed(self) -> Self::Signed {
+        self
+    }
 }
 
 impl IntRing2k for u8 {
@@ -153,6 +172,10 @@ impl IntRing2k for u8 {
     fn cast_from_biguint(biguint: &BigUint) -> Self {
         biguint.iter_u64_digits().next().unwrap_or_default() as Self
     }
+
+    fn to_signed(self) -> Self::Signed {
+        self as Self::Signed
+    }
 }
 
 impl IntRing2k for u16 {
@@ -184,6 +207,10 @@ impl IntRing2k for u16 {
     fn cast_from_biguint(biguint: &BigUint) -> Self {
         biguint.iter_u64_digits().next().unwrap_or_default() as Self
     }
+
+    fn to_signed(self) -> Self::Signed {
+        self as Self::Signed
+    }
 }
 
 impl IntRing2k for u32 {
@@ -215,6 +242,10 @@ impl IntRing2k for u32 {
     fn cast_from_biguint(biguint: &BigUint) -> Self {
         biguint.iter_u64_digits().next().unwrap_or_default() as Self
     }
+
+    fn to_signed(self) -> Self::Signed {
+        self as Self::Signed
+    }
 }
 
 impl IntRing2k for u64 {
@@ -246,6 +277,10 @@ impl IntRing2k for u64 {
     fn cast_from_biguint(biguint: &BigUint) -> Self {
         biguint.iter_u64_digits().next().unwrap_or_default() as Self
     }
+
+    fn to_signed(self) -> Self::Signed {
+        self as Self::Signed
+    }
 }
 
 impl IntRing2k for u128 {
@@ -280,4 +315,8 @@ impl IntRing2k for u128 {
         let x1 = iter.next().unwrap_or_default();
         ((x1 as u128) << 64) | x0 as u128
     }
+
+    fn to_signed(self) -> Self::Signed {
+        self as Self::Signed
+    }
 }
@@ -65,6 +65,16 @@ impl<T: IntRing2k> RingElement<T> {
     pub fn get_bit(&self, index: usize) -> Self {
         RingElement((self.0 >> index) & T::one())
     }
+
+    /// Reinterprets the RingElement as a signed integer in two's complement representation
+    pub fn to_signed(self) -> T::Signed {
+        self.0.to_signed()
+    }
+
+    /// Constructs a RingElement from a signed integer using the two's complement representation
+    pub fn from_signed(value: T::Signed) -> Self {
+        RingElement(T::from_signed(value))
+    }
 }
 
 impl<T: IntRing2k + std::fmt::Display> std::fmt::Display for RingElement<T> {
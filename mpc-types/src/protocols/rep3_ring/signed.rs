@@ -0,0 +1,55 @@
+//! Signed
+//!
+//! This module contains the share type for signed integers
+
+use crate::protocols::{
+    rep3::id::PartyID,
+    rep3_ring::{
+        Rep3RingShare,
+        ring::{int_ring::IntRing2k, ring_impl::RingElement},
+    },
+};
+use serde::{Deserialize, Serialize};
+
+/// This type represents a replicated share of a signed integer. The value is stored in the ring Z_{2^k} of the corresponding unsigned type using the two's complement representation, i.e., the underlying shares are the same as the ones of the unsigned reinterpretation of the value.
+///
+/// Signed and unsigned shares can not be mixed implicitly. Use [`Rep3SignedRingShare::from_unsigned`] and [`Rep3SignedRingShare::into_unsigned`] to reinterpret a share.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "", transparent)]
+pub struct Rep3SignedRingShare<T: IntRing2k>(Rep3RingShare<T>);
+
+impl<T: IntRing2k> Default for Rep3SignedRingShare<T> {
+    fn default() -> Self {
+        Self::zero_share()
+    }
+}
+
+impl<T: IntRing2k> Rep3SignedRingShare<T> {
+    /// Reinterprets an unsigned share as the two's complement representation of a signed integer.
+    pub fn from_unsigned(share: Rep3RingShare<T>) -> Self {
+        Self(share)
+    }
+
+    /// Reinterprets the share as an unsigned share of the two's complement representation of the value.
+    pub fn into_unsigned(self) -> Rep3RingShare<T> {
+        self.0
+    }
+
+    /// Returns a reference to the unsigned share of the two's complement representation of the value.
+    pub fn as_unsigned(&self) -> &Rep3RingShare<T> {
+        &self.0
+    }
+
+    /// Constructs a zero share.
+    pub fn zero_share() -> Self {
+        Self(Rep3RingShare::zero_share())
+    }
+
+    /// Promotes a public signed integer to a replicated share by setting the additive share of the party with id=0 and leaving all other shares to be 0. Thus, the replicated shares of party 0 and party 1 are set.
+    pub fn promote_from_trivial(val: T::Signed, id: PartyID) -> Self {
+        Self(Rep3RingShare::promote_from_trivial(
+            &RingElement::from_signed(val),
+            id,
+        ))
+    }
+}
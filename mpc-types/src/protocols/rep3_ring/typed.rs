@@ -0,0 +1,339 @@
+//! # Typed Ring Shares
+//!
+//! This module contains a wrapper for [`Rep3RingShare`]s of all supported ring widths, which allows storing integer shares in input and witness files without fixing the ring at compile time.
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Valid};
+use rand::{CryptoRng, Rng};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Rep3BitShare, Rep3RingShare, combine_ring_element, ring::bit::Bit,
+    ring::ring_impl::RingElement, share_ring_element,
+};
+
+/// The rings Z_{2^k} supported by [`Rep3TypedRingShare`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum RingType {
+    /// The ring Z_2
+    Bit,
+    /// The ring Z_{2^8}
+    U8,
+    /// The ring Z_{2^16}
+    U16,
+    /// The ring Z_{2^32}
+    U32,
+    /// The ring Z_{2^64}
+    U64,
+    /// The ring Z_{2^128}
+    U128,
+}
+
+impl RingType {
+    /// Returns the number of bits of the ring.
+    pub fn bits(&self) -> usize {
+        match self {
+            RingType::Bit => 1,
+            RingType::U8 => 8,
+            RingType::U16 => 16,
+            RingType::U32 => 32,
+            RingType::U64 => 64,
+            RingType::U128 => 128,
+        }
+    }
+
+    /// Returns the ring with exactly `bits` bits, if it is supported.
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        match bits {
+            1 => Some(RingType::Bit),
+            8 => Some(RingType::U8),
+            16 => Some(RingType::U16),
+            32 => Some(RingType::U32),
+            64 => Some(RingType::U64),
+            128 => Some(RingType::U128),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn tag(&self) -> u8 {
+        match self {
+            RingType::Bit => 0,
+            RingType::U8 => 1,
+            RingType::U16 => 2,
+            RingType::U32 => 3,
+            RingType::U64 => 4,
+            RingType::U128 => 5,
+        }
+    }
+}
+
+impl std::fmt::Display for RingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RingType::Bit => f.write_str("bool"),
+            ring => write!(f, "u{}", ring.bits()),
+        }
+    }
+}
+
+impl std::str::FromStr for RingType {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "bool" | "u1" => Ok(RingType::Bit),
+            "u8" => Ok(RingType::U8),
+            "u16" => Ok(RingType::U16),
+            "u32" => Ok(RingType::U32),
+            "u64" => Ok(RingType::U64),
+            "u128" => Ok(RingType::U128),
+            _ => eyre::bail!(
+                "unsupported ring type \"{s}\", expected one of bool, u8, u16, u32, u64, u128"
+            ),
+        }
+    }
+}
+
+/// A replicated share of an integer in one of the supported rings. In contrast to [`Rep3RingShare`], the ring is only known at runtime, which allows storing integers of different widths in the same input or witness file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Rep3TypedRingShare {
+    /// A share in Z_2
+    Bit(Rep3BitShare),
+    /// A share in Z_{2^8}
+    U8(Rep3RingShare<u8>),
+    /// A share in Z_{2^16}
+    U16(Rep3RingShare<u16>),
+    /// A share in Z_{2^32}
+    U32(Rep3RingShare<u32>),
+    /// A share in Z_{2^64}
+    U64(Rep3RingShare<u64>),
+    /// A share in Z_{2^128}
+    U128(Rep3RingShare<u128>),
+}
+
+impl Rep3TypedRingShare {
+    /// Returns the ring of the share.
+    pub fn ring_type(&self) -> RingType {
+        match self {
+            Rep3TypedRingShare::Bit(_) => RingType::Bit,
+            Rep3TypedRingShare::U8(_) => RingType::U8,
+            Rep3TypedRingShare::U16(_) => RingType::U16,
+            Rep3TypedRingShare::U32(_) => RingType::U32,
+            Rep3TypedRingShare::U64(_) => RingType::U64,
+            Rep3TypedRingShare::U128(_) => RingType::U128,
+        }
+    }
+}
+
+macro_rules! impl_from_ring_share {
+    ($($t:ty => $variant:ident),*) => {
+        $(
+            impl From<Rep3RingShare<$t>> for Rep3TypedRingShare {
+                fn from(share: Rep3RingShare<$t>) -> Self {
+                    Rep3TypedRingShare::$variant(share)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_ring_share!(Bit => Bit, u8 => U8, u16 => U16, u32 => U32, u64 => U64, u128 => U128);
+
+impl CanonicalSerialize for Rep3TypedRingShare {
+    fn serialize_with_mode<W: std::io::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> Result<(), ark_serialize::SerializationError> {
+        self.ring_type()
+            .tag()
+            .serialize_with_mode(&mut writer, compress)?;
+        match self {
+            Rep3TypedRingShare::Bit(share) => share.serialize_with_mode(writer, compress),
+            Rep3TypedRingShare::U8(share) => share.serialize_with_mode(writer, compress),
+            Rep3TypedRingShare::U16(share) => share.serialize_with_mode(writer, compress),
+            Rep3TypedRingShare::U32(share) => share.serialize_with_mode(writer, compress),
+            Rep3TypedRingShare::U64(share) => share.serialize_with_mode(writer, compress),
+            Rep3TypedRingShare::U128(share) => share.serialize_with_mode(writer, compress),
+        }
+    }
+
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        1 + match self {
+            Rep3TypedRingShare::Bit(share) => share.serialized_size(compress),
+            Rep3TypedRingShare::U8(share) => share.serialized_size(compress),
+            Rep3TypedRingShare::U16(share) => share.serialized_size(compress),
+            Rep3TypedRingShare::U32(share) => share.serialized_size(compress),
+            Rep3TypedRingShare::U64(share) => share.serialized_size(compress),
+            Rep3TypedRingShare::U128(share) => share.serialized_size(compress),
+        }
+    }
+}
+
+impl Valid for Rep3TypedRingShare {
+    fn check(&self) -> Result<(), ark_serialize::SerializationError> {
+        Ok(())
+    }
+
+    fn batch_check<'a>(
+        _batch: impl Iterator<Item = &'a Self> + Send,
+    ) -> Result<(), ark_serialize::SerializationError>
+    where
+        Self: 'a,
+    {
+        Ok(())
+    }
+}
+
+impl CanonicalDeserialize for Rep3TypedRingShare {
+    fn deserialize_with_mode<R: std::io::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> Result<Self, ark_serialize::SerializationError> {
+        let tag = u8::deserialize_with_mode(&mut reader, compress, validate)?;
+        let share = match tag {
+            0 => Rep3TypedRingShare::Bit(Rep3RingShare::deserialize_with_mode(
+                reader, compress, validate,
+            )?),
+            1 => Rep3TypedRingShare::U8(Rep3RingShare::deserialize_with_mode(
+                reader, compress, validate,
+            )?),
+            2 => Rep3TypedRingShare::U16(Rep3RingShare::deserialize_with_mode(
+                reader, compress, validate,
+            )?),
+            3 => Rep3TypedRingShare::U32(Rep3RingShare::deserialize_with_mode(
+                reader, compress, validate,
+            )?),
+            4 => Rep3TypedRingShare::U64(Rep3RingShare::deserialize_with_mode(
+                reader, compress, validate,
+            )?),
+            5 => Rep3TypedRingShare::U128(Rep3RingShare::deserialize_with_mode(
+                reader, compress, validate,
+            )?),
+            _ => return Err(ark_serialize::SerializationError::InvalidData),
+        };
+        Ok(share)
+    }
+}
+
+/// Converts the value into the ring `T`, returning an error if it does not fit.
+fn to_ring<T: super::IntRing2k>(val: u128, ring_type: RingType) -> eyre::Result<RingElement<T>> {
+    T::try_from(val)
+        .map(RingElement)
+        .map_err(|_| eyre::eyre!("value {val} does not fit into {ring_type}"))
+}
+
+/// Secret shares an integer in the given ring using replicated secret sharing and the provided random number generator. The value is split into three additive shares, where each party holds two.
+///
+/// Returns an error if the value does not fit into the ring.
+pub fn share_typed_ring_element<R: Rng + CryptoRng>(
+    val: u128,
+    ring_type: RingType,
+    rng: &mut R,
+) -> eyre::Result<[Rep3TypedRingShare; 3]> {
+    let shares: [Rep3TypedRingShare; 3] = match ring_type {
+        RingType::Bit => share_ring_element(to_ring::<Bit>(val, ring_type)?, rng).map(Into::into),
+        RingType::U8 => share_ring_element(to_ring::<u8>(val, ring_type)?, rng).map(Into::into),
+        RingType::U16 => share_ring_element(to_ring::<u16>(val, ring_type)?, rng).map(Into::into),
+        RingType::U32 => share_ring_element(to_ring::<u32>(val, ring_type)?, rng).map(Into::into),
+        RingType::U64 => share_ring_element(to_ring::<u64>(val, ring_type)?, rng).map(Into::into),
+        RingType::U128 => share_ring_element(to_ring::<u128>(val, ring_type)?, rng).map(Into::into),
+    };
+    Ok(shares)
+}
+
+/// Reconstructs an integer from its typed replicated ring shares.
+///
+/// # Panics
+/// Panics if the shares are from different rings.
+pub fn combine_typed_ring_element(
+    share1: Rep3TypedRingShare,
+    share2: Rep3TypedRingShare,
+    share3: Rep3TypedRingShare,
+) -> u128 {
+    match (share1, share2, share3) {
+        (Rep3TypedRingShare::Bit(a), Rep3TypedRingShare::Bit(b), Rep3TypedRingShare::Bit(c)) => {
+            combine_ring_element(a, b, c).0.into()
+        }
+        (Rep3TypedRingShare::U8(a), Rep3TypedRingShare::U8(b), Rep3TypedRingShare::U8(c)) => {
+            combine_ring_element(a, b, c).0.into()
+        }
+        (Rep3TypedRingShare::U16(a), Rep3TypedRingShare::U16(b), Rep3TypedRingShare::U16(c)) => {
+            combine_ring_element(a, b, c).0.into()
+        }
+        (Rep3TypedRingShare::U32(a), Rep3TypedRingShare::U32(b), Rep3TypedRingShare::U32(c)) => {
+            combine_ring_element(a, b, c).0.into()
+        }
+        (Rep3TypedRingShare::U64(a), Rep3TypedRingShare::U64(b), Rep3TypedRingShare::U64(c)) => {
+            combine_ring_element(a, b, c).0.into()
+        }
+        (Rep3TypedRingShare::U128(a), Rep3TypedRingShare::U128(b), Rep3TypedRingShare::U128(c)) => {
+            combine_ring_element(a, b, c).0
+        }
+        _ => panic!("shares must be from the same ring"),
+    }
+}
+
+#[cfg(test)]
+mod typed_test {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha12Rng;
+
+    #[test]
+    fn typed_ring_share_roundtrip() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let vals = [
+            (1, RingType::Bit),
+            (200, RingType::U8),
+            (u16::MAX.into(), RingType::U16),
+            (12345678, RingType::U32),
+            (u64::MAX.into(), RingType::U64),
+            (u128::MAX, RingType::U128),
+        ];
+        for (val, ring_type) in vals {
+            let shares = share_typed_ring_element(val, ring_type, &mut rng).unwrap();
+            let shares = shares.map(|share| {
+                assert_eq!(share.ring_type(), ring_type);
+                let mut bytes = Vec::new();
+                share.serialize_uncompressed(&mut bytes).unwrap();
+                assert_eq!(bytes.len(), share.uncompressed_size());
+                let share = Rep3TypedRingShare::deserialize_uncompressed(bytes.as_slice()).unwrap();
+                let bytes = bincode::serialize(&share).unwrap();
+                bincode::deserialize(&bytes).unwrap()
+            });
+            let [share1, share2, share3] = shares;
+            assert_eq!(combine_typed_ring_element(share1, share2, share3), val);
+        }
+    }
+
+    #[test]
+    fn typed_ring_share_out_of_range() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        assert!(share_typed_ring_element(2, RingType::Bit, &mut rng).is_err());
+        assert!(share_typed_ring_element(256, RingType::U8, &mut rng).is_err());
+        assert!(share_typed_ring_element(u64::MAX.into(), RingType::U32, &mut rng).is_err());
+    }
+
+    #[test]
+    fn ring_type_from_str() {
+        for ring_type in [
+            RingType::Bit,
+            RingType::U8,
+            RingType::U16,
+            RingType::U32,
+            RingType::U64,
+            RingType::U128,
+        ] {
+            assert_eq!(
+                ring_type.to_string().parse::<RingType>().unwrap(),
+                ring_type
+            );
+            assert_eq!(
+                RingType::from_bits(ring_type.bits() as u32),
+                Some(ring_type)
+            );
+        }
+        assert!("i32".parse::<RingType>().is_err());
+    }
+}
@@ -2,20 +2,64 @@
 //!
 //! This module implements the shamir share and combine opertions and shamir preprocessing
 
+use std::marker::PhantomData;
+
 use ark_ec::CurveGroup;
 use ark_ff::PrimeField;
 use itertools::izip;
 
-use rand::{CryptoRng, Rng};
+use rand::{CryptoRng, Rng, SeedableRng, distributions::Standard, prelude::Distribution};
+use serde::{Deserialize, Serialize};
+
+use crate::serde_compat::{ark_de, ark_se};
 
 pub mod arithmetic;
+pub mod packed;
 pub mod pointshare;
+pub mod vss;
 
 pub use arithmetic::types::ShamirPrimeFieldShare;
+pub use packed::PackedShamirPrimeFieldShare;
 pub use pointshare::types::ShamirPointShare;
 
+pub use crate::protocols::rep3::{SeedRng, SeededType};
+
 type ShamirShare<F> = ShamirPrimeFieldShare<F>;
 
+/// A type representing the different states a vector of Shamir shares can have. Either the fully expanded shares, or the shares of one of the parties whose shares are derived from a seed (see [share_field_elements_seeded]).
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub enum ShamirShareVecType<F: PrimeField> {
+    /// Fully expanded shares.
+    Shares(#[serde(serialize_with = "ark_se", deserialize_with = "ark_de")] Vec<ShamirShare<F>>),
+    /// Shares which are either compressed to a seed or given in full.
+    Seeded(SeededType<Vec<F>, SeedRng>),
+}
+
+impl<F: PrimeField> ShamirShareVecType<F> {
+    /// Expands the (potentially compressed) shares.
+    pub fn expand_vec(self) -> Vec<ShamirShare<F>> {
+        match self {
+            ShamirShareVecType::Shares(vec) => vec,
+            ShamirShareVecType::Seeded(seeded) => ShamirShare::convert_vec_rev(seeded.expand_vec()),
+        }
+    }
+
+    /// Returns the number of shares
+    pub fn length(&self) -> usize {
+        match self {
+            ShamirShareVecType::Shares(vec) => vec.len(),
+            ShamirShareVecType::Seeded(seeded) => seeded.length(),
+        }
+    }
+}
+
+impl<F: PrimeField> From<Vec<ShamirShare<F>>> for ShamirShareVecType<F> {
+    fn from(value: Vec<ShamirShare<F>>) -> Self {
+        ShamirShareVecType::Shares(value)
+    }
+}
+
 /// Share a field element into Shamir shares with given `degree` and `num_parties`
 pub fn share_field_element<F: PrimeField, R: Rng + CryptoRng>(
     val: F,
@@ -76,6 +120,60 @@ pub fn share_field_elements<F: PrimeField, R: Rng + CryptoRng>(
     result
 }
 
+/// Secret shares a vector of field elements using Shamir secret sharing, whereas the shares of the first `degree` parties are compressed as seeds derived from the provided random number generator. Since a sharing polynomial of degree `degree` is fully determined by the secret and `degree` further evaluations, the shares of these parties can be chosen at random, and only the shares of the remaining num_parties - degree parties are computed by interpolation and stored in full. The outputs are of type [SeededType], where the i-th element belongs to party i.
+pub fn share_field_elements_seeded<
+    F: PrimeField,
+    R: Rng + CryptoRng,
+    U: Rng + SeedableRng + CryptoRng,
+>(
+    vals: &[F],
+    degree: usize,
+    num_parties: usize,
+    rng: &mut R,
+) -> Vec<SeededType<Vec<F>, U>>
+where
+    U::Seed: std::fmt::Debug + Clone + Serialize + for<'a> Deserialize<'a>,
+    Standard: Distribution<U::Seed>,
+{
+    assert!(
+        num_parties > degree,
+        "Not enough parties to share with a polynomial of degree {degree}"
+    );
+    let len = vals.len();
+    let seeds = (0..degree)
+        .map(|_| rng.r#gen::<U::Seed>())
+        .collect::<Vec<_>>();
+    let mut seeded_rngs = seeds
+        .iter()
+        .map(|seed| U::from_seed(seed.to_owned()))
+        .collect::<Vec<_>>();
+
+    // The polynomial is defined by the secret at 0 and the random shares at 1..=degree
+    let points = (0..=degree).map(|i| F::from(i as u64)).collect::<Vec<_>>();
+    let lagrange = (degree + 1..=num_parties)
+        .map(|x| packed::lagrange_at(&points, F::from(x as u64)))
+        .collect::<Vec<Vec<F>>>();
+
+    let mut shares = (degree..num_parties)
+        .map(|_| Vec::with_capacity(len))
+        .collect::<Vec<_>>();
+    let mut evals = Vec::with_capacity(degree + 1);
+    for val in vals {
+        evals.clear();
+        evals.push(*val);
+        evals.extend(seeded_rngs.iter_mut().map(|rng| F::rand(rng)));
+        for (share, lagrange) in izip!(shares.iter_mut(), lagrange.iter()) {
+            share.push(reconstruct(&evals, lagrange));
+        }
+    }
+
+    seeds
+        .into_iter()
+        .map(|seed| SeededType::Seed(seed, len, PhantomData))
+        .chain(shares.into_iter().map(SeededType::Shares))
+        .collect()
+}
+
 /// Reconstructs a vector of field elements from its Shamir shares and lagrange coefficients. The input is a slice of `Vecs` of [ShamirPrimeFieldShare] per party. Thus, shares\[i\]\[j\] represents the j-th share of party i. Thereby at least `degree` + 1 shares need to be present per field element (i.e., i > degree).
 pub fn combine_field_elements<F: PrimeField>(
     shares: &[Vec<ShamirShare<F>>],
@@ -439,6 +537,167 @@ pub fn reconstruct_point<C: CurveGroup>(shares: &[C], lagrange: &[C::ScalarField
     res
 }
 
+/// Precomputed values to robustly reconstruct a secret from the Shamir shares of all parties. Inconsistent shares, i.e., shares which do not lie on a polynomial of the given degree, are detected if there are more than `degree` + 1 shares and up to ([RobustReconstruction::max_correctable_errors]) of them are corrected using Reed-Solomon decoding (Berlekamp-Welch).
+#[derive(Debug, Clone)]
+pub struct RobustReconstruction<F: PrimeField> {
+    degree: usize,
+    num_parties: usize,
+    // Lagrange coefficients to interpolate the first degree + 1 shares at 0 and at the points of the remaining parties
+    lagrange: Vec<F>,
+    lagrange_check: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> RobustReconstruction<F> {
+    /// Precompute the values for reconstructing from the shares of `num_parties` parties, where the sharing polynomial is of degree `degree`.
+    /// # Panics
+    /// Panics if `num_parties` <= `degree`.
+    pub fn new(degree: usize, num_parties: usize) -> Self {
+        assert!(
+            num_parties > degree,
+            "Not enough parties to reconstruct a polynomial of degree {degree}"
+        );
+        let coeffs = (1..=degree + 1).collect::<Vec<_>>();
+        let lagrange = lagrange_from_coeff(&coeffs);
+        let polys = precompute_interpolation_polys::<F>(&coeffs);
+        let lagrange_check = (degree + 2..=num_parties)
+            .map(|x| {
+                let x = F::from(x as u64);
+                polys.iter().map(|poly| evaluate_poly(poly, x)).collect()
+            })
+            .collect();
+        Self {
+            degree,
+            num_parties,
+            lagrange,
+            lagrange_check,
+        }
+    }
+
+    /// Returns the number of inconsistent shares which can be corrected, i.e., (n - degree - 1) / 2
+    pub fn max_correctable_errors(&self) -> usize {
+        (self.num_parties - self.degree - 1) / 2
+    }
+
+    /// Reconstructs the secret from the shares of all parties, where shares\[i\] is the share of party i. Returns the secret and the (sorted) indices of the parties with inconsistent shares, or `None` if the shares are inconsistent and cannot be corrected.
+    /// # Panics
+    /// Panics if the number of shares does not match the number of parties.
+    pub fn reconstruct(&self, shares: &[F]) -> Option<(F, Vec<usize>)> {
+        assert_eq!(shares.len(), self.num_parties);
+        let (first, rest) = shares.split_at(self.degree + 1);
+        let consistent = izip!(rest, &self.lagrange_check)
+            .all(|(share, lagrange)| *share == reconstruct(first, lagrange));
+        if consistent {
+            return Some((reconstruct(first, &self.lagrange), Vec::new()));
+        }
+
+        let max_errors = self.max_correctable_errors();
+        if max_errors == 0 {
+            return None;
+        }
+        let poly = berlekamp_welch(shares, self.degree, max_errors)?;
+        let inconsistent = shares
+            .iter()
+            .enumerate()
+            .filter(|(i, share)| evaluate_poly(&poly, F::from(*i as u64 + 1)) != **share)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        if inconsistent.len() > max_errors {
+            return None;
+        }
+        Some((poly[0], inconsistent))
+    }
+}
+
+// Decodes the Reed-Solomon codeword given by the evaluations of a polynomial of degree `degree` at 1..=n with at most `num_errors` errors. Returns the coefficients of the polynomial.
+fn berlekamp_welch<F: PrimeField>(
+    shares: &[F],
+    degree: usize,
+    num_errors: usize,
+) -> Option<Vec<F>> {
+    // We search for the monic error locator E of degree num_errors and Q = P * E of degree degree + num_errors, such that Q(x_i) = y_i * E(x_i)
+    let num_q = degree + num_errors + 1;
+    let num_unknowns = num_q + num_errors;
+    let mut matrix = shares
+        .iter()
+        .enumerate()
+        .map(|(i, y)| {
+            let x = F::from(i as u64 + 1);
+            let mut row = Vec::with_capacity(num_unknowns + 1);
+            let mut pow = F::one();
+            for _ in 0..num_q {
+                row.push(pow);
+                pow *= x;
+            }
+            let mut pow = F::one();
+            for _ in 0..num_errors {
+                row.push(-*y * pow);
+                pow *= x;
+            }
+            row.push(*y * pow);
+            row
+        })
+        .collect::<Vec<_>>();
+
+    let solution = solve_linear_system(&mut matrix, num_unknowns)?;
+    let q = &solution[..num_q];
+    let mut e = solution[num_q..].to_vec();
+    e.push(F::one());
+
+    // Divide Q by E, which needs to have no remainder
+    let mut remainder = q.to_vec();
+    let mut quotient = vec![F::zero(); degree + 1];
+    for i in (0..=degree).rev() {
+        let coeff = remainder[i + num_errors];
+        quotient[i] = coeff;
+        for (r, e) in remainder[i..=i + num_errors].iter_mut().zip(&e) {
+            *r -= coeff * e;
+        }
+    }
+    if remainder.iter().any(|r| !r.is_zero()) {
+        return None;
+    }
+    Some(quotient)
+}
+
+// Solves the linear system given by the augmented matrix using Gaussian elimination. Free variables are set to zero. Returns None if the system has no solution.
+fn solve_linear_system<F: PrimeField>(
+    matrix: &mut [Vec<F>],
+    num_unknowns: usize,
+) -> Option<Vec<F>> {
+    let mut pivots = Vec::with_capacity(num_unknowns);
+    let mut row = 0;
+    for col in 0..num_unknowns {
+        let Some(pivot) = (row..matrix.len()).find(|r| !matrix[*r][col].is_zero()) else {
+            continue;
+        };
+        matrix.swap(row, pivot);
+        let inv = matrix[row][col].inverse().expect("pivot is not zero");
+        for v in matrix[row].iter_mut() {
+            *v *= inv;
+        }
+        for r in 0..matrix.len() {
+            if r != row && !matrix[r][col].is_zero() {
+                let factor = matrix[r][col];
+                let pivot_row = matrix[row].clone();
+                for (v, p) in matrix[r].iter_mut().zip(pivot_row) {
+                    *v -= factor * p;
+                }
+            }
+        }
+        pivots.push(col);
+        row += 1;
+    }
+    // All remaining rows are zero on the left hand side
+    if matrix[row..].iter().any(|r| !r[num_unknowns].is_zero()) {
+        return None;
+    }
+    let mut solution = vec![F::zero(); num_unknowns];
+    for (r, col) in pivots.into_iter().enumerate() {
+        solution[col] = matrix[r][num_unknowns];
+    }
+    Some(solution)
+}
+
 #[cfg(test)]
 mod shamir_test {
     use super::*;
@@ -551,6 +810,72 @@ mod shamir_test {
         }
     }
 
+    fn test_robust_reconstruction<F: PrimeField, const NUM_PARTIES: usize, const DEGREE: usize>() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let robust = RobustReconstruction::<F>::new(DEGREE, NUM_PARTIES);
+        let max_errors = robust.max_correctable_errors();
+
+        for _ in 0..TESTRUNS {
+            let secret = F::rand(&mut rng);
+            let mut shares = super::share(secret, NUM_PARTIES, DEGREE, &mut rng);
+            assert_eq!(robust.reconstruct(&shares), Some((secret, vec![])));
+
+            // Corrupt max_errors random shares
+            let mut corrupted = (0..NUM_PARTIES).choose_multiple(&mut rng, max_errors);
+            corrupted.sort();
+            for i in corrupted.iter() {
+                shares[*i] += F::rand(&mut rng);
+            }
+            assert_eq!(robust.reconstruct(&shares), Some((secret, corrupted.clone())));
+
+            // One more inconsistent share can only be detected
+            if NUM_PARTIES > DEGREE + 1 {
+                let i = (0..NUM_PARTIES)
+                    .find(|i| !corrupted.contains(i))
+                    .expect("there is a consistent share");
+                shares[i] += F::rand(&mut rng);
+                assert_eq!(robust.reconstruct(&shares), None);
+            }
+        }
+    }
+
+    fn test_shamir_seeded<F: PrimeField, const NUM_PARTIES: usize, const DEGREE: usize>() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let secrets = (0..TESTRUNS).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+        let shares = super::share_field_elements_seeded::<_, _, ChaCha12Rng>(
+            &secrets,
+            DEGREE,
+            NUM_PARTIES,
+            &mut rng,
+        );
+        assert_eq!(shares.len(), NUM_PARTIES);
+        for (i, share) in shares.iter().enumerate() {
+            assert_eq!(share.length(), TESTRUNS);
+            assert_eq!(matches!(share, SeededType::Seed(..)), i < DEGREE);
+        }
+        let shares = shares
+            .into_iter()
+            .map(|share| ShamirShareVecType::Seeded(share).expand_vec())
+            .collect::<Vec<_>>();
+
+        // Test random D+1 shares
+        let parties = (1..=NUM_PARTIES).choose_multiple(&mut rng, DEGREE + 1);
+        let party_shares = parties
+            .iter()
+            .map(|&i| shares[i - 1].clone())
+            .collect::<Vec<_>>();
+        let reconstructed = super::combine_field_elements(&party_shares, &parties, DEGREE)
+            .expect("enough shares are present");
+        assert_eq!(secrets, reconstructed);
+
+        // All shares lie on a polynomial of degree D
+        let robust = RobustReconstruction::<F>::new(DEGREE, NUM_PARTIES);
+        for (i, secret) in secrets.iter().enumerate() {
+            let shares = shares.iter().map(|s| s[i].a).collect::<Vec<_>>();
+            assert_eq!(robust.reconstruct(&shares), Some((*secret, vec![])));
+        }
+    }
+
     #[test]
     fn test_shamir_3_1() {
         const NUM_PARTIES: usize = 3;
@@ -559,6 +884,8 @@ mod shamir_test {
         test_shamir_point::<ark_bn254::G1Projective, NUM_PARTIES, DEGREE>();
         test_shamir_field_to_point::<ark_bn254::G1Projective, NUM_PARTIES, DEGREE>();
         test_shamir_poly::<ark_bn254::Fr, NUM_PARTIES, DEGREE>();
+        test_robust_reconstruction::<ark_bn254::Fr, NUM_PARTIES, DEGREE>();
+        test_shamir_seeded::<ark_bn254::Fr, NUM_PARTIES, DEGREE>();
     }
 
     #[test]
@@ -569,5 +896,12 @@ mod shamir_test {
         test_shamir_point::<ark_bn254::G1Projective, NUM_PARTIES, DEGREE>();
         test_shamir_field_to_point::<ark_bn254::G1Projective, NUM_PARTIES, DEGREE>();
         test_shamir_poly::<ark_bn254::Fr, NUM_PARTIES, DEGREE>();
+        test_robust_reconstruction::<ark_bn254::Fr, NUM_PARTIES, DEGREE>();
+        test_shamir_seeded::<ark_bn254::Fr, NUM_PARTIES, DEGREE>();
+    }
+
+    #[test]
+    fn test_robust_reconstruction_7_2() {
+        test_robust_reconstruction::<ark_bn254::Fr, 7, 2>();
     }
 }
@@ -0,0 +1,267 @@
+//! Packed
+//!
+//! This module contains the packed Shamir share type, where one polynomial shares multiple secrets (Franklin-Yung). With a packing factor k, the k secrets are stored as evaluations of the sharing polynomial at the points 0, -1, ..., -(k-1), while party i holds the evaluation at the point i + 1. To tolerate t colluding parties, the sharing polynomial is of degree t + k - 1.
+
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use rand::{CryptoRng, Rng};
+
+/// This type represents a packed Shamir-shared value, i.e., a share of a polynomial which holds multiple secrets. Since the share itself is a field element, this is a wrapper over a field element.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize,
+)]
+#[repr(transparent)]
+pub struct PackedShamirPrimeFieldShare<F: PrimeField> {
+    /// The packed shamir share.
+    pub a: F,
+}
+
+impl<F: PrimeField> PackedShamirPrimeFieldShare<F> {
+    /// Wraps the field element into a PackedShamirPrimeFieldShare
+    pub fn new(a: F) -> Self {
+        Self { a }
+    }
+
+    /// Unwraps a PackedShamirPrimeFieldShare into a field element
+    pub fn inner(self) -> F {
+        self.a
+    }
+
+    /// Returns a zero share.
+    pub fn zero_share() -> Self {
+        Self { a: F::zero() }
+    }
+}
+
+impl<F: PrimeField> std::ops::Add for PackedShamirPrimeFieldShare<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { a: self.a + rhs.a }
+    }
+}
+
+impl<F: PrimeField> std::ops::AddAssign for PackedShamirPrimeFieldShare<F> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.a += rhs.a;
+    }
+}
+
+impl<F: PrimeField> std::ops::Sub for PackedShamirPrimeFieldShare<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { a: self.a - rhs.a }
+    }
+}
+
+impl<F: PrimeField> std::ops::SubAssign for PackedShamirPrimeFieldShare<F> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.a -= rhs.a;
+    }
+}
+
+impl<F: PrimeField> std::ops::Neg for PackedShamirPrimeFieldShare<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { a: -self.a }
+    }
+}
+
+impl<F: PrimeField> std::ops::Mul<F> for PackedShamirPrimeFieldShare<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        Self { a: self.a * rhs }
+    }
+}
+
+/// Returns the degree of the sharing polynomial for the given `threshold` and `packing` factor, i.e., t + k - 1.
+pub fn packed_degree(threshold: usize, packing: usize) -> usize {
+    threshold + packing - 1
+}
+
+/// Returns the evaluation point of the i-th secret, i.e., -i.
+pub fn secret_point<F: PrimeField>(i: usize) -> F {
+    -F::from(i as u64)
+}
+
+/// Returns the evaluation point of the share of the party with the given id, i.e., id + 1.
+pub fn party_point<F: PrimeField>(id: usize) -> F {
+    F::from(id as u64 + 1)
+}
+
+/// Computes the lagrange coefficients for interpolating a polynomial from its evaluations at `points` and evaluating it at `x`.
+pub fn lagrange_at<F: PrimeField>(points: &[F], x: F) -> Vec<F> {
+    let mut res = Vec::with_capacity(points.len());
+    for (i, p_i) in points.iter().enumerate() {
+        let mut num = F::one();
+        let mut den = F::one();
+        for (j, p_j) in points.iter().enumerate() {
+            if i != j {
+                num *= x - p_j;
+                den *= *p_i - p_j;
+            }
+        }
+        res.push(num * den.inverse().expect("evaluation points are distinct"));
+    }
+    res
+}
+
+/// Secret shares a vector of field elements using packed Shamir secret sharing with the provided random number generator. The values are packed into chunks of `packing` elements (the last chunk is padded with zeros), where each chunk is shared using a polynomial of the given `degree`, which has to be at least `packing` - 1. The output is a `Vec` of `Vecs` of type [`PackedShamirPrimeFieldShare`], one for each of the `num_parties` parties.
+pub fn share_field_elements_packed<F: PrimeField, R: Rng + CryptoRng>(
+    vals: &[F],
+    degree: usize,
+    packing: usize,
+    num_parties: usize,
+    rng: &mut R,
+) -> Vec<Vec<PackedShamirPrimeFieldShare<F>>> {
+    assert!(packing > 0 && degree + 1 >= packing);
+    // The polynomial is fixed by the secrets and degree + 1 - packing random evaluations at the points -packing, -packing - 1, ...
+    let points = (0..=degree).map(secret_point).collect::<Vec<F>>();
+    let lagranges = (0..num_parties)
+        .map(|id| lagrange_at(&points, party_point(id)))
+        .collect::<Vec<_>>();
+
+    let num_chunks = vals.len().div_ceil(packing);
+    let mut result = vec![Vec::with_capacity(num_chunks); num_parties];
+    let mut evals = vec![F::zero(); degree + 1];
+    for chunk in vals.chunks(packing) {
+        evals[..chunk.len()].copy_from_slice(chunk);
+        for eval in evals[chunk.len()..packing].iter_mut() {
+            *eval = F::zero();
+        }
+        for eval in evals[packing..].iter_mut() {
+            *eval = F::rand(rng);
+        }
+        for (res, lagrange) in result.iter_mut().zip(lagranges.iter()) {
+            let share = evals.iter().zip(lagrange).map(|(e, l)| *e * l).sum();
+            res.push(PackedShamirPrimeFieldShare::new(share));
+        }
+    }
+    result
+}
+
+/// Reconstructs a vector of `len` field elements from their packed Shamir shares, where the i-th `Vec` of `shares` belongs to the party with id `coeffs[i] - 1`. Thereby at least `degree` + 1 shares need to be present.
+pub fn combine_field_elements_packed<F: PrimeField>(
+    shares: &[Vec<PackedShamirPrimeFieldShare<F>>],
+    coeffs: &[usize],
+    degree: usize,
+    packing: usize,
+    len: usize,
+) -> eyre::Result<Vec<F>> {
+    if shares.len() != coeffs.len() {
+        eyre::bail!(
+            "Number of shares ({}) does not match number of party indices ({})",
+            shares.len(),
+            coeffs.len()
+        );
+    }
+    if shares.len() <= degree {
+        eyre::bail!(
+            "Not enough shares to reconstruct the secret. Expected {}, got {}",
+            degree + 1,
+            shares.len()
+        );
+    }
+    let num_chunks = len.div_ceil(packing);
+    for share in shares.iter().take(degree + 1) {
+        if share.len() != num_chunks {
+            eyre::bail!(
+                "Number of packed shares ({}) does not match the expected number ({})",
+                share.len(),
+                num_chunks
+            );
+        }
+    }
+
+    let points = coeffs[..=degree]
+        .iter()
+        .map(|c| F::from(*c as u64))
+        .collect::<Vec<_>>();
+    let lagranges = (0..packing)
+        .map(|i| lagrange_at(&points, secret_point(i)))
+        .collect::<Vec<_>>();
+
+    let mut result = Vec::with_capacity(num_chunks * packing);
+    for chunk in 0..num_chunks {
+        for lagrange in lagranges.iter() {
+            let secret = shares
+                .iter()
+                .zip(lagrange)
+                .map(|(share, l)| share[chunk].a * l)
+                .sum();
+            result.push(secret);
+        }
+    }
+    result.truncate(len);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod packed_test {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha12Rng;
+
+    const ELEMENTS: usize = 50;
+
+    fn share_combine_inner<F: PrimeField>(num_parties: usize, threshold: usize, packing: usize) {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let degree = packed_degree(threshold, packing);
+        let vals = (0..ELEMENTS).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+        let shares = share_field_elements_packed(&vals, degree, packing, num_parties, &mut rng);
+        assert_eq!(shares.len(), num_parties);
+        assert!(shares.iter().all(|s| s.len() == ELEMENTS.div_ceil(packing)));
+
+        // Any degree + 1 parties can reconstruct
+        let coeffs = (1..=num_parties).rev().collect::<Vec<_>>();
+        let shares_rev = shares.iter().rev().cloned().collect::<Vec<_>>();
+        let rec =
+            combine_field_elements_packed(&shares_rev, &coeffs, degree, packing, ELEMENTS).unwrap();
+        assert_eq!(rec, vals);
+
+        // Too few parties can not
+        assert!(
+            combine_field_elements_packed(
+                &shares[..degree],
+                &coeffs[..degree],
+                degree,
+                packing,
+                ELEMENTS
+            )
+            .is_err()
+        );
+
+        // The product of two sharings is a sharing of degree 2 * degree of the elementwise product
+        let vals2 = (0..ELEMENTS).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+        let shares2 = share_field_elements_packed(&vals2, degree, packing, num_parties, &mut rng);
+        let mul = shares
+            .iter()
+            .zip(shares2.iter())
+            .map(|(a, b)| {
+                a.iter()
+                    .zip(b)
+                    .map(|(a, b)| PackedShamirPrimeFieldShare::new(a.a * b.a))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let coeffs = (1..=num_parties).collect::<Vec<_>>();
+        let rec =
+            combine_field_elements_packed(&mul, &coeffs, 2 * degree, packing, ELEMENTS).unwrap();
+        let should = vals
+            .iter()
+            .zip(vals2)
+            .map(|(a, b)| *a * b)
+            .collect::<Vec<_>>();
+        assert_eq!(rec, should);
+    }
+
+    #[test]
+    fn share_combine_packed() {
+        share_combine_inner::<ark_bn254::Fr>(3, 1, 1);
+        share_combine_inner::<ark_bn254::Fr>(7, 1, 3);
+        share_combine_inner::<ark_bn254::Fr>(10, 2, 3);
+    }
+}
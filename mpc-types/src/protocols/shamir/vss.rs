@@ -0,0 +1,414 @@
+//! # Feldman and Pedersen VSS
+//!
+//! This module contains verifiable Shamir sharing based on Feldman and Pedersen commitments. Alongside the shares, the dealer publishes commitments to the coefficients of the sharing polynomials in a group in which the discrete logarithm is hard. This allows each party to check that its share lies on the committed polynomial.
+//!
+//! Feldman commitments are not hiding: the commitment to the constant coefficient is `g^secret`, so anyone seeing the commitments can check guesses of the secret. Thus, they must not be used for low-entropy secrets (e.g., small integers, booleans, or values from a small set). Pedersen commitments additionally commit to a random blinding polynomial with a second generator `h`, such that the commitments are perfectly hiding. In exchange, each party receives a share of the blinding polynomial as well.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::UniformRand;
+use itertools::izip;
+use rand::{CryptoRng, Rng};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+
+use super::{ShamirPrimeFieldShare, evaluate_poly, evaluate_poly_point};
+use crate::serde_compat::{ark_de, ark_se};
+
+type ShamirShare<F> = ShamirPrimeFieldShare<F>;
+
+/// A Feldman commitment to a sharing polynomial, i.e., the coefficients of the polynomial multiplied with the generator of the group.
+///
+/// The commitment is not hiding, since it contains `g^secret`. Use a [`PedersenCommitment`] if the secret may have low entropy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct FeldmanCommitment<C: CurveGroup> {
+    /// The commitments to the coefficients, starting with the constant coefficient.
+    #[serde(serialize_with = "ark_se", deserialize_with = "ark_de")]
+    pub coeffs: Vec<C::Affine>,
+}
+
+impl<C: CurveGroup> FeldmanCommitment<C> {
+    /// Commits to the coefficients of the polynomial.
+    pub fn new(poly: &[C::ScalarField]) -> Self {
+        let coeffs = poly.iter().map(|c| C::generator() * c).collect::<Vec<_>>();
+        Self {
+            coeffs: C::normalize_batch(&coeffs),
+        }
+    }
+
+    /// Returns the degree of the committed polynomial.
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    /// Returns the commitment to the shared value, i.e., `g^secret`. This reveals the secret if it can be guessed.
+    pub fn secret_commitment(&self) -> C::Affine {
+        self.coeffs[0]
+    }
+
+    /// Checks whether the share of party `id` lies on the committed polynomial. Thereby, the share of party `id` is the evaluation of the polynomial at `id + 1`.
+    pub fn verify(&self, share: ShamirShare<C::ScalarField>, id: usize) -> bool {
+        if self.coeffs.is_empty() {
+            return false;
+        }
+        let coeffs = self.coeffs.iter().map(|c| C::from(*c)).collect::<Vec<_>>();
+        let expected = evaluate_poly_point(&coeffs, C::ScalarField::from(id as u64 + 1));
+        expected == C::generator() * share.a
+    }
+}
+
+/// Shares a field element into Shamir shares with given `degree` and `num_parties`, and commits to the sharing polynomial. The commitment is not hiding, see [`FeldmanCommitment`].
+pub fn share_field_element<C: CurveGroup, R: Rng + CryptoRng>(
+    val: C::ScalarField,
+    degree: usize,
+    num_parties: usize,
+    rng: &mut R,
+) -> (Vec<ShamirShare<C::ScalarField>>, FeldmanCommitment<C>) {
+    let mut poly = Vec::with_capacity(degree + 1);
+    poly.push(val);
+    for _ in 0..degree {
+        poly.push(C::ScalarField::rand(rng));
+    }
+    let shares = (1..=num_parties)
+        .map(|i| ShamirShare::new(evaluate_poly(&poly, C::ScalarField::from(i as u64))))
+        .collect();
+    (shares, FeldmanCommitment::new(&poly))
+}
+
+/// Secret shares a vector of field elements using Shamir secret sharing and commits to the sharing polynomials. The first output contains the shares of each party, the second output the commitments, which are sent to all parties. The commitments are not hiding, see [`FeldmanCommitment`].
+#[allow(clippy::type_complexity)]
+pub fn share_field_elements<C: CurveGroup, R: Rng + CryptoRng>(
+    vals: &[C::ScalarField],
+    degree: usize,
+    num_parties: usize,
+    rng: &mut R,
+) -> (
+    Vec<Vec<ShamirShare<C::ScalarField>>>,
+    Vec<FeldmanCommitment<C>>,
+) {
+    let mut result = (0..num_parties)
+        .map(|_| Vec::with_capacity(vals.len()))
+        .collect::<Vec<_>>();
+    let mut commitments = Vec::with_capacity(vals.len());
+
+    for val in vals {
+        let (shares, commitment) = share_field_element(*val, degree, num_parties, rng);
+        for (r, s) in izip!(&mut result, shares) {
+            r.push(s);
+        }
+        commitments.push(commitment);
+    }
+
+    (result, commitments)
+}
+
+/// Checks the shares of party `id` against the commitments of the dealer. Returns an error if a commitment is not of the expected `degree`, or a share does not lie on the committed polynomial.
+pub fn verify_shares<C: CurveGroup>(
+    shares: &[ShamirShare<C::ScalarField>],
+    commitments: &[FeldmanCommitment<C>],
+    degree: usize,
+    id: usize,
+) -> eyre::Result<()> {
+    if shares.len() != commitments.len() {
+        eyre::bail!(
+            "Number of shares ({}) does not match number of commitments ({})",
+            shares.len(),
+            commitments.len()
+        );
+    }
+    for (idx, (share, commitment)) in izip!(shares, commitments).enumerate() {
+        if commitment.coeffs.len() != degree + 1 {
+            eyre::bail!(
+                "Commitment {idx} is of degree {}, expected {degree}",
+                commitment.degree()
+            );
+        }
+        if !commitment.verify(*share, id) {
+            eyre::bail!("Share {idx} of party {id} does not match the commitment of the dealer");
+        }
+    }
+    Ok(())
+}
+
+/// The domain separator for deriving the second generator of Pedersen commitments
+const PEDERSEN_GENERATOR_DST: &[u8] = b"co-snarks pedersen vss generator";
+
+/// Returns the second generator `h` of [`PedersenCommitment`]s. The generator is derived by hashing a fixed domain separator to a point of the group (try-and-increment), such that nobody knows its discrete logarithm to the base of the generator of the group.
+pub fn pedersen_generator<C: CurveGroup>() -> C::Affine {
+    for counter in 0u32.. {
+        let mut hasher = Sha3_256::new();
+        hasher.update(PEDERSEN_GENERATOR_DST);
+        hasher.update(counter.to_le_bytes());
+        let digest = hasher.finalize();
+        if let Some(point) = C::Affine::from_random_bytes(&digest) {
+            let point = point.clear_cofactor();
+            if !point.is_zero() {
+                return point;
+            }
+        }
+    }
+    unreachable!("a point is found after a few tries")
+}
+
+/// A Pedersen commitment to a sharing polynomial f and a random blinding polynomial r of the same degree, i.e., `g^f_i * h^r_i` for all coefficients, where `h` is the [`pedersen_generator`]. In contrast to a [`FeldmanCommitment`], the commitment is perfectly hiding, so it can be used for secrets with low entropy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct PedersenCommitment<C: CurveGroup> {
+    /// The commitments to the coefficients, starting with the constant coefficient.
+    #[serde(serialize_with = "ark_se", deserialize_with = "ark_de")]
+    pub coeffs: Vec<C::Affine>,
+}
+
+impl<C: CurveGroup> PedersenCommitment<C> {
+    /// Commits to the coefficients of the polynomial, blinded by the coefficients of the blinding polynomial.
+    pub fn new(poly: &[C::ScalarField], blinding: &[C::ScalarField]) -> Self {
+        assert_eq!(poly.len(), blinding.len());
+        let h = pedersen_generator::<C>();
+        let coeffs = izip!(poly, blinding)
+            .map(|(c, r)| C::generator() * c + h * r)
+            .collect::<Vec<_>>();
+        Self {
+            coeffs: C::normalize_batch(&coeffs),
+        }
+    }
+
+    /// Returns the degree of the committed polynomial.
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    /// Checks whether the share and the blinding share of party `id` lie on the committed polynomials. Thereby, the shares of party `id` are the evaluations of the polynomials at `id + 1`.
+    pub fn verify(
+        &self,
+        share: ShamirShare<C::ScalarField>,
+        blinding: ShamirShare<C::ScalarField>,
+        id: usize,
+    ) -> bool {
+        self.verify_with_generator(share, blinding, id, pedersen_generator::<C>())
+    }
+
+    fn verify_with_generator(
+        &self,
+        share: ShamirShare<C::ScalarField>,
+        blinding: ShamirShare<C::ScalarField>,
+        id: usize,
+        h: C::Affine,
+    ) -> bool {
+        if self.coeffs.is_empty() {
+            return false;
+        }
+        let coeffs = self.coeffs.iter().map(|c| C::from(*c)).collect::<Vec<_>>();
+        let expected = evaluate_poly_point(&coeffs, C::ScalarField::from(id as u64 + 1));
+        expected == C::generator() * share.a + h * blinding.a
+    }
+}
+
+/// Shares a field element into Shamir shares with given `degree` and `num_parties`, and commits to the sharing polynomial with a hiding [`PedersenCommitment`]. The first output contains the shares, the second output the shares of the blinding polynomial, which are given to the parties alongside their shares.
+#[allow(clippy::type_complexity)]
+pub fn share_field_element_pedersen<C: CurveGroup, R: Rng + CryptoRng>(
+    val: C::ScalarField,
+    degree: usize,
+    num_parties: usize,
+    rng: &mut R,
+) -> (
+    Vec<ShamirShare<C::ScalarField>>,
+    Vec<ShamirShare<C::ScalarField>>,
+    PedersenCommitment<C>,
+) {
+    let mut poly = Vec::with_capacity(degree + 1);
+    poly.push(val);
+    for _ in 0..degree {
+        poly.push(C::ScalarField::rand(rng));
+    }
+    let blinding = (0..=degree)
+        .map(|_| C::ScalarField::rand(rng))
+        .collect::<Vec<_>>();
+    let (shares, blinding_shares) = (1..=num_parties)
+        .map(|i| {
+            let x = C::ScalarField::from(i as u64);
+            (
+                ShamirShare::new(evaluate_poly(&poly, x)),
+                ShamirShare::new(evaluate_poly(&blinding, x)),
+            )
+        })
+        .unzip();
+    (
+        shares,
+        blinding_shares,
+        PedersenCommitment::new(&poly, &blinding),
+    )
+}
+
+/// Secret shares a vector of field elements using Shamir secret sharing and commits to the sharing polynomials with hiding [`PedersenCommitment`]s. The first output contains the shares of each party, the second output the blinding shares of each party, and the third output the commitments, which are sent to all parties.
+#[allow(clippy::type_complexity)]
+pub fn share_field_elements_pedersen<C: CurveGroup, R: Rng + CryptoRng>(
+    vals: &[C::ScalarField],
+    degree: usize,
+    num_parties: usize,
+    rng: &mut R,
+) -> (
+    Vec<Vec<ShamirShare<C::ScalarField>>>,
+    Vec<Vec<ShamirShare<C::ScalarField>>>,
+    Vec<PedersenCommitment<C>>,
+) {
+    let mut result = (0..num_parties)
+        .map(|_| Vec::with_capacity(vals.len()))
+        .collect::<Vec<_>>();
+    let mut blinding_result = (0..num_parties)
+        .map(|_| Vec::with_capacity(vals.len()))
+        .collect::<Vec<_>>();
+    let mut commitments = Vec::with_capacity(vals.len());
+
+    for val in vals {
+        let (shares, blinding_shares, commitment) =
+            share_field_element_pedersen(*val, degree, num_parties, rng);
+        for (r, b, s, t) in izip!(&mut result, &mut blinding_result, shares, blinding_shares) {
+            r.push(s);
+            b.push(t);
+        }
+        commitments.push(commitment);
+    }
+
+    (result, blinding_result, commitments)
+}
+
+/// Checks the shares and blinding shares of party `id` against the Pedersen commitments of the dealer. Returns an error if a commitment is not of the expected `degree`, or a share does not lie on the committed polynomial.
+pub fn verify_shares_pedersen<C: CurveGroup>(
+    shares: &[ShamirShare<C::ScalarField>],
+    blinding_shares: &[ShamirShare<C::ScalarField>],
+    commitments: &[PedersenCommitment<C>],
+    degree: usize,
+    id: usize,
+) -> eyre::Result<()> {
+    if shares.len() != commitments.len() || blinding_shares.len() != commitments.len() {
+        eyre::bail!(
+            "Number of shares ({}) and blinding shares ({}) does not match number of commitments ({})",
+            shares.len(),
+            blinding_shares.len(),
+            commitments.len()
+        );
+    }
+    let h = pedersen_generator::<C>();
+    for (idx, (share, blinding, commitment)) in
+        izip!(shares, blinding_shares, commitments).enumerate()
+    {
+        if commitment.coeffs.len() != degree + 1 {
+            eyre::bail!(
+                "Commitment {idx} is of degree {}, expected {degree}",
+                commitment.degree()
+            );
+        }
+        if !commitment.verify_with_generator(*share, *blinding, id, h) {
+            eyre::bail!("Share {idx} of party {id} does not match the commitment of the dealer");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod vss_test {
+    use super::*;
+    use crate::protocols::shamir;
+    use ark_ec::PrimeGroup;
+    use itertools::Itertools;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha12Rng;
+
+    type C = ark_bn254::G1Projective;
+    type F = ark_bn254::Fr;
+
+    const NUM_PARTIES: usize = 7;
+    const DEGREE: usize = 3;
+
+    #[test]
+    fn feldman_share_and_verify() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let vals = (0..10).map(|_| F::rand(&mut rng)).collect_vec();
+        let (shares, commitments) =
+            share_field_elements::<C, _>(&vals, DEGREE, NUM_PARTIES, &mut rng);
+
+        for (id, shares) in shares.iter().enumerate() {
+            verify_shares(shares, &commitments, DEGREE, id).unwrap();
+        }
+        for (val, commitment) in izip!(&vals, &commitments) {
+            assert_eq!(
+                commitment.secret_commitment(),
+                (C::generator() * val).into_affine()
+            );
+        }
+
+        let reconstructed = shamir::combine_field_elements(
+            &shares[..=DEGREE],
+            &(1..=DEGREE + 1).collect_vec(),
+            DEGREE,
+        )
+        .unwrap();
+        assert_eq!(reconstructed, vals);
+    }
+
+    #[test]
+    fn feldman_detects_inconsistent_shares() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let vals = (0..10).map(|_| F::rand(&mut rng)).collect_vec();
+        let (mut shares, commitments) =
+            share_field_elements::<C, _>(&vals, DEGREE, NUM_PARTIES, &mut rng);
+
+        shares[2][5].a += F::from(1u64);
+        assert!(verify_shares(&shares[2], &commitments, DEGREE, 2).is_err());
+        // shares of another party are rejected
+        assert!(verify_shares(&shares[1], &commitments, DEGREE, 0).is_err());
+        // a polynomial of higher degree is rejected
+        assert!(verify_shares(&shares[0], &commitments, DEGREE - 1, 0).is_err());
+    }
+
+    #[test]
+    fn pedersen_share_and_verify() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        // low-entropy secrets, for which Feldman commitments are not hiding
+        let vals = (0..10).map(|i| F::from(i % 2)).collect_vec();
+        let (shares, blinding_shares, commitments) =
+            share_field_elements_pedersen::<C, _>(&vals, DEGREE, NUM_PARTIES, &mut rng);
+
+        for (id, (shares, blinding_shares)) in izip!(&shares, &blinding_shares).enumerate() {
+            verify_shares_pedersen(shares, blinding_shares, &commitments, DEGREE, id).unwrap();
+        }
+        // the commitments do not reveal equal secrets
+        assert_ne!(commitments[0].coeffs[0], commitments[2].coeffs[0]);
+        assert_ne!(
+            commitments[0].coeffs[0],
+            (C::generator() * vals[0]).into_affine()
+        );
+
+        let reconstructed = shamir::combine_field_elements(
+            &shares[..=DEGREE],
+            &(1..=DEGREE + 1).collect_vec(),
+            DEGREE,
+        )
+        .unwrap();
+        assert_eq!(reconstructed, vals);
+    }
+
+    #[test]
+    fn pedersen_detects_inconsistent_shares() {
+        let mut rng = ChaCha12Rng::from_entropy();
+        let vals = (0..10).map(|_| F::rand(&mut rng)).collect_vec();
+        let (mut shares, mut blinding_shares, commitments) =
+            share_field_elements_pedersen::<C, _>(&vals, DEGREE, NUM_PARTIES, &mut rng);
+
+        shares[2][5].a += F::from(1u64);
+        assert!(
+            verify_shares_pedersen(&shares[2], &blinding_shares[2], &commitments, DEGREE, 2)
+                .is_err()
+        );
+        blinding_shares[3][1].a += F::from(1u64);
+        assert!(
+            verify_shares_pedersen(&shares[3], &blinding_shares[3], &commitments, DEGREE, 3)
+                .is_err()
+        );
+        // shares of another party are rejected
+        assert!(
+            verify_shares_pedersen(&shares[1], &blinding_shares[1], &commitments, DEGREE, 0)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,608 @@
+//! # Share Files
+//!
+//! This module implements the versioned on-disk container for share files. Each file starts with the magic bytes [SHARE_FILE_MAGIC] and the format version [SHARE_FILE_VERSION], followed by a [ShareFileHeader] describing the share and the serialized share itself.
+//! Thereby, loading a share of the wrong protocol, curve, or party fails with a descriptive error before the share itself is parsed.
+//!
+//! Share files can optionally be encrypted to the [SharePublicKey] of the receiving party (X25519 key agreement with an ephemeral key, HKDF-SHA256 and AES-256-GCM). The header stays readable and is authenticated together with the encrypted share, so shares can be stored on untrusted storage.
+
+use std::io::{Read, Write};
+
+use aes_gcm::{
+    Aes256Gcm, Key, KeyInit,
+    aead::{Aead, Nonce, Payload},
+};
+use eyre::{Context, bail, eyre};
+use hkdf::Hkdf;
+use rand::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use sha2::Sha256;
+use sha3::{Digest, Sha3_256};
+use x25519_dalek::SharedSecret;
+
+pub use x25519_dalek::{PublicKey as SharePublicKey, StaticSecret as ShareSecretKey};
+
+/// The magic bytes at the start of every share file
+pub const SHARE_FILE_MAGIC: [u8; 8] = *b"COSHARE\0";
+
+/// The current version of the share file format
+pub const SHARE_FILE_VERSION: u16 = 1;
+
+/// The info string for deriving the symmetric key of encrypted share files
+const SHARE_FILE_KDF_INFO: &[u8] = b"co-snarks share file encryption";
+
+/// The kind of data stored in a share file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShareKind {
+    /// A shared input for the witness extension
+    Input,
+    /// A shared witness
+    Witness,
+    /// A shared proving key
+    ProvingKey,
+}
+
+/// The MPC protocol a share file belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShareProtocol {
+    /// Replicated secret sharing for 3 parties
+    Rep3,
+    /// Shamir secret sharing for n parties
+    Shamir,
+}
+
+/// The curve a share file belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShareCurve {
+    /// The BN254 curve
+    Bn254,
+    /// The BLS12-381 curve
+    Bls12_381,
+    /// The BLS12-377 curve
+    Bls12_377,
+}
+
+/// The compression of the shares in a share file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShareCompression {
+    /// No compression
+    #[default]
+    None,
+    /// Additive half shares, which need to be replicated before use
+    HalfShares,
+    /// Shares which are (partially) compressed to seeds
+    SeededShares,
+    /// Additive half shares, which are (partially) compressed to seeds
+    SeededHalfShares,
+}
+
+/// The encryption of the share in a share file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShareEncryption {
+    /// The share is stored in plain
+    #[default]
+    None,
+    /// The share is encrypted to the [SharePublicKey] of the party using an ephemeral X25519 key, HKDF-SHA256 and AES-256-GCM
+    X25519Aes256Gcm,
+}
+
+/// The header of a share file, describing the share stored in the file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShareFileHeader {
+    /// The kind of data stored in the file
+    pub kind: ShareKind,
+    /// The MPC protocol
+    pub protocol: ShareProtocol,
+    /// The curve
+    pub curve: ShareCurve,
+    /// The id of the party the share belongs to
+    pub party_id: usize,
+    /// The number of parties
+    pub num_parties: usize,
+    /// The threshold of tolerated colluding parties
+    pub threshold: usize,
+    /// The hash of the circuit the share was created for (see [circuit_hash]), if known
+    pub circuit_hash: Option<[u8; 32]>,
+    /// The compression of the shares
+    pub compression: ShareCompression,
+    /// The encryption of the share
+    pub encryption: ShareEncryption,
+}
+
+/// The properties a share file is expected to have when it is loaded. Properties which are `None` are not checked.
+#[derive(Debug, Clone, Default)]
+pub struct ExpectedShareFile {
+    /// The expected kind of data
+    pub kind: Option<ShareKind>,
+    /// The expected MPC protocol
+    pub protocol: Option<ShareProtocol>,
+    /// The expected curve
+    pub curve: Option<ShareCurve>,
+    /// The expected party id
+    pub party_id: Option<usize>,
+    /// The expected number of parties
+    pub num_parties: Option<usize>,
+    /// The expected threshold
+    pub threshold: Option<usize>,
+    /// The expected circuit hash. It is only checked if the share file contains a circuit hash as well.
+    pub circuit_hash: Option<[u8; 32]>,
+}
+
+impl ShareFileHeader {
+    /// Creates a new header without circuit hash, compression and encryption.
+    pub fn new(
+        kind: ShareKind,
+        protocol: ShareProtocol,
+        curve: ShareCurve,
+        party_id: usize,
+        num_parties: usize,
+        threshold: usize,
+    ) -> Self {
+        Self {
+            kind,
+            protocol,
+            curve,
+            party_id,
+            num_parties,
+            threshold,
+            circuit_hash: None,
+            compression: ShareCompression::None,
+            encryption: ShareEncryption::None,
+        }
+    }
+
+    /// Sets the circuit hash of the header.
+    pub fn with_circuit_hash(mut self, circuit_hash: [u8; 32]) -> Self {
+        self.circuit_hash = Some(circuit_hash);
+        self
+    }
+
+    /// Sets the compression of the header.
+    pub fn with_compression(mut self, compression: ShareCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Returns the header for the share of the party with the given id, where all other properties are unchanged.
+    pub fn for_party(&self, party_id: usize) -> Self {
+        Self {
+            party_id,
+            ..self.clone()
+        }
+    }
+
+    /// Checks that the header matches the expected properties.
+    pub fn verify(&self, expected: &ExpectedShareFile) -> eyre::Result<()> {
+        if let Some(kind) = expected.kind {
+            if self.kind != kind {
+                bail!(
+                    "expected a {kind:?} share, but the file contains a {:?} share",
+                    self.kind
+                );
+            }
+        }
+        if let Some(protocol) = expected.protocol {
+            if self.protocol != protocol {
+                bail!(
+                    "expected a share for the {protocol:?} protocol, but the file contains a share for the {:?} protocol",
+                    self.protocol
+                );
+            }
+        }
+        if let Some(curve) = expected.curve {
+            if self.curve != curve {
+                bail!(
+                    "expected a share for the {curve:?} curve, but the file contains a share for the {:?} curve",
+                    self.curve
+                );
+            }
+        }
+        if let Some(party_id) = expected.party_id {
+            if self.party_id != party_id {
+                bail!(
+                    "expected the share of party {party_id}, but the file contains the share of party {}",
+                    self.party_id
+                );
+            }
+        }
+        if let Some(num_parties) = expected.num_parties {
+            if self.num_parties != num_parties {
+                bail!(
+                    "expected a share for {num_parties} parties, but the file contains a share for {} parties",
+                    self.num_parties
+                );
+            }
+        }
+        if let Some(threshold) = expected.threshold {
+            if self.threshold != threshold {
+                bail!(
+                    "expected a share with threshold {threshold}, but the file contains a share with threshold {}",
+                    self.threshold
+                );
+            }
+        }
+        if let (Some(expected), Some(actual)) = (expected.circuit_hash, self.circuit_hash) {
+            if expected != actual {
+                bail!("the share was created for a different circuit");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Computes the hash of a circuit from the raw bytes of its artifact, which is stored in the [ShareFileHeader].
+pub fn circuit_hash(data: &[u8]) -> [u8; 32] {
+    Sha3_256::digest(data).into()
+}
+
+/// Generates a new secret key to decrypt share files with.
+pub fn generate_share_key<R: RngCore + CryptoRng>(rng: &mut R) -> ShareSecretKey {
+    ShareSecretKey::random_from_rng(rng)
+}
+
+/// Reads a raw 32 byte secret key for decrypting share files from the given path.
+pub fn read_share_secret_key(path: impl AsRef<std::path::Path>) -> eyre::Result<ShareSecretKey> {
+    let bytes = std::fs::read(path).context("while reading share secret key")?;
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| eyre!("share secret key must be exactly 32 bytes"))?;
+    Ok(ShareSecretKey::from(bytes))
+}
+
+/// Writes a secret key to the given path. On unix the file is created with mode `0o600`, so that it is only readable by its owner.
+pub fn write_secret_key(path: impl AsRef<std::path::Path>, bytes: &[u8]) -> eyre::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(path)
+        .context("while creating secret key file")?;
+    file.write_all(bytes)
+        .context("while writing secret key file")?;
+    Ok(())
+}
+
+/// Reads a raw 32 byte public key for encrypting share files from the given path.
+pub fn read_share_public_key(path: impl AsRef<std::path::Path>) -> eyre::Result<SharePublicKey> {
+    let bytes = std::fs::read(path).context("while reading share public key")?;
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| eyre!("share public key must be exactly 32 bytes"))?;
+    Ok(SharePublicKey::from(bytes))
+}
+
+/// Encodes the magic bytes, the format version and the header. These bytes are also the associated data of encrypted shares.
+fn encode_header(header: &ShareFileHeader) -> eyre::Result<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(SHARE_FILE_MAGIC.len() + 2);
+    bytes.extend_from_slice(&SHARE_FILE_MAGIC);
+    bytes.extend_from_slice(&SHARE_FILE_VERSION.to_le_bytes());
+    bincode::serialize_into(&mut bytes, header).context("while serializing share header")?;
+    Ok(bytes)
+}
+
+/// Derives the symmetric key of an encrypted share file, bound to both the ephemeral and the recipient's public key.
+fn derive_share_file_key(
+    shared_secret: &SharedSecret,
+    ephemeral: &SharePublicKey,
+    recipient: &SharePublicKey,
+) -> Key<Aes256Gcm> {
+    let mut salt = [0u8; 64];
+    salt[..32].copy_from_slice(ephemeral.as_bytes());
+    salt[32..].copy_from_slice(recipient.as_bytes());
+    let hkdf = Hkdf::<Sha256>::new(Some(&salt), shared_secret.as_bytes());
+    let mut key = Key::<Aes256Gcm>::default();
+    hkdf.expand(SHARE_FILE_KDF_INFO, &mut key)
+        .expect("32 bytes is a valid output length for HKDF-SHA256");
+    key
+}
+
+/// Writes the share together with the magic bytes, the format version and the header to the writer. The share is stored in plain, regardless of the encryption in the given header.
+pub fn write_share_file<W: Write, T: Serialize>(
+    mut writer: W,
+    header: &ShareFileHeader,
+    share: &T,
+) -> eyre::Result<()> {
+    let header = ShareFileHeader {
+        encryption: ShareEncryption::None,
+        ..header.clone()
+    };
+    writer
+        .write_all(&encode_header(&header)?)
+        .context("while writing share header")?;
+    bincode::serialize_into(&mut writer, share).context("while serializing share")?;
+    writer.flush().context("while flushing share file")?;
+    Ok(())
+}
+
+/// Writes the share like [write_share_file], but encrypts it to the public key of the receiving party. The header is not encrypted, but authenticated together with the share.
+pub fn write_encrypted_share_file<W: Write, T: Serialize, R: RngCore + CryptoRng>(
+    mut writer: W,
+    header: &ShareFileHeader,
+    share: &T,
+    recipient: &SharePublicKey,
+    rng: &mut R,
+) -> eyre::Result<()> {
+    let header = ShareFileHeader {
+        encryption: ShareEncryption::X25519Aes256Gcm,
+        ..header.clone()
+    };
+    let associated_data = encode_header(&header)?;
+    let plaintext = bincode::serialize(share).context("while serializing share")?;
+
+    let ephemeral_secret = ShareSecretKey::random_from_rng(&mut *rng);
+    let ephemeral = SharePublicKey::from(&ephemeral_secret);
+    let key = derive_share_file_key(
+        &ephemeral_secret.diffie_hellman(recipient),
+        &ephemeral,
+        recipient,
+    );
+    let mut nonce = Nonce::<Aes256Gcm>::default();
+    rng.fill_bytes(&mut nonce);
+    let ciphertext = Aes256Gcm::new(&key)
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: &plaintext,
+                aad: &associated_data,
+            },
+        )
+        .map_err(|_| eyre!("failed to encrypt share"))?;
+
+    writer
+        .write_all(&associated_data)
+        .context("while writing share header")?;
+    writer
+        .write_all(ephemeral.as_bytes())
+        .context("while writing ephemeral key")?;
+    writer.write_all(&nonce).context("while writing nonce")?;
+    writer
+        .write_all(&ciphertext)
+        .context("while writing encrypted share")?;
+    writer.flush().context("while flushing share file")?;
+    Ok(())
+}
+
+/// Reads the header of a share file and checks the magic bytes and the format version. The reader is left at the start of the share.
+pub fn read_share_file_header<R: Read>(mut reader: R) -> eyre::Result<ShareFileHeader> {
+    let mut magic = [0u8; SHARE_FILE_MAGIC.len()];
+    reader
+        .read_exact(&mut magic)
+        .context("while reading magic bytes")?;
+    if magic != SHARE_FILE_MAGIC {
+        bail!("not a share file, it might have been created by an older version without header");
+    }
+    let mut version = [0u8; 2];
+    reader
+        .read_exact(&mut version)
+        .context("while reading format version")?;
+    let version = u16::from_le_bytes(version);
+    if version != SHARE_FILE_VERSION {
+        bail!(
+            "unsupported share file format version {version}, expected version {SHARE_FILE_VERSION}"
+        );
+    }
+    bincode::deserialize_from(reader).context("while deserializing share header")
+}
+
+/// Reads a share file, checks its header against the expected properties and deserializes the share. Fails for encrypted share files, see [read_share_file_with_key].
+pub fn read_share_file<R: Read, T: DeserializeOwned>(
+    reader: R,
+    expected: &ExpectedShareFile,
+) -> eyre::Result<(ShareFileHeader, T)> {
+    read_share_file_with_key(reader, expected, None)
+}
+
+/// Reads a share file like [read_share_file], but decrypts the share with the given key. If a key is given, the share file needs to be encrypted, i.e., share files stored in plain are rejected, such that an encrypted share can not be replaced by a plain one. Without a key, this is the same as [read_share_file].
+pub fn read_share_file_with_key<R: Read, T: DeserializeOwned>(
+    mut reader: R,
+    expected: &ExpectedShareFile,
+    key: Option<&ShareSecretKey>,
+) -> eyre::Result<(ShareFileHeader, T)> {
+    let header = read_share_file_header(&mut reader)?;
+    header
+        .verify(expected)
+        .context("while checking share file header")?;
+    let share = match header.encryption {
+        ShareEncryption::None => {
+            if key.is_some() {
+                bail!("the share file is not encrypted, but a key was provided");
+            }
+            bincode::deserialize_from(reader).context("while deserializing share")?
+        }
+        ShareEncryption::X25519Aes256Gcm => {
+            let key =
+                key.ok_or_else(|| eyre!("the share file is encrypted, but no key was provided"))?;
+            let mut ephemeral = [0u8; 32];
+            reader
+                .read_exact(&mut ephemeral)
+                .context("while reading ephemeral key")?;
+            let ephemeral = SharePublicKey::from(ephemeral);
+            let mut nonce = Nonce::<Aes256Gcm>::default();
+            reader
+                .read_exact(&mut nonce)
+                .context("while reading nonce")?;
+            let mut ciphertext = Vec::new();
+            reader
+                .read_to_end(&mut ciphertext)
+                .context("while reading encrypted share")?;
+
+            let shared_secret = key.diffie_hellman(&ephemeral);
+            if !shared_secret.was_contributory() {
+                bail!("the share file contains an invalid ephemeral key");
+            }
+            let key = derive_share_file_key(&shared_secret, &ephemeral, &SharePublicKey::from(key));
+            let plaintext = Aes256Gcm::new(&key)
+                .decrypt(
+                    &nonce,
+                    Payload {
+                        msg: &ciphertext,
+                        aad: &encode_header(&header)?,
+                    },
+                )
+                .map_err(|_| {
+                    eyre!(
+                        "failed to decrypt share, it is encrypted for another key or was modified"
+                    )
+                })?;
+            bincode::deserialize(&plaintext).context("while deserializing share")?
+        }
+    };
+    Ok((header, share))
+}
+
+#[cfg(test)]
+mod share_file_test {
+    use super::*;
+
+    fn header() -> ShareFileHeader {
+        ShareFileHeader::new(
+            ShareKind::Witness,
+            ShareProtocol::Shamir,
+            ShareCurve::Bn254,
+            1,
+            5,
+            2,
+        )
+        .with_circuit_hash(circuit_hash(b"circuit"))
+        .with_compression(ShareCompression::SeededShares)
+    }
+
+    #[test]
+    fn share_file_roundtrip() {
+        let share = vec![1u64, 2, 3];
+        let mut file = Vec::new();
+        write_share_file(&mut file, &header(), &share).unwrap();
+
+        let expected = ExpectedShareFile {
+            kind: Some(ShareKind::Witness),
+            protocol: Some(ShareProtocol::Shamir),
+            curve: Some(ShareCurve::Bn254),
+            party_id: Some(1),
+            num_parties: Some(5),
+            threshold: Some(2),
+            circuit_hash: Some(circuit_hash(b"circuit")),
+        };
+        let (read_header, read_share): (_, Vec<u64>) =
+            read_share_file(file.as_slice(), &expected).unwrap();
+        assert_eq!(read_header, header());
+        assert_eq!(read_share, share);
+    }
+
+    #[test]
+    fn share_file_mismatch() {
+        let mut file = Vec::new();
+        write_share_file(&mut file, &header(), &0u64).unwrap();
+
+        let mismatches = [
+            ExpectedShareFile {
+                kind: Some(ShareKind::Input),
+                ..Default::default()
+            },
+            ExpectedShareFile {
+                protocol: Some(ShareProtocol::Rep3),
+                ..Default::default()
+            },
+            ExpectedShareFile {
+                curve: Some(ShareCurve::Bls12_381),
+                ..Default::default()
+            },
+            ExpectedShareFile {
+                party_id: Some(0),
+                ..Default::default()
+            },
+            ExpectedShareFile {
+                num_parties: Some(3),
+                ..Default::default()
+            },
+            ExpectedShareFile {
+                threshold: Some(1),
+                ..Default::default()
+            },
+            ExpectedShareFile {
+                circuit_hash: Some(circuit_hash(b"other circuit")),
+                ..Default::default()
+            },
+        ];
+        for expected in mismatches {
+            assert!(read_share_file::<_, u64>(file.as_slice(), &expected).is_err());
+        }
+
+        // Files without header and with unknown versions are rejected
+        let mut legacy = Vec::new();
+        bincode::serialize_into(&mut legacy, &0u64).unwrap();
+        assert!(read_share_file::<_, u64>(legacy.as_slice(), &Default::default()).is_err());
+        file[SHARE_FILE_MAGIC.len()] += 1;
+        assert!(read_share_file::<_, u64>(file.as_slice(), &Default::default()).is_err());
+    }
+
+    #[test]
+    fn share_file_encrypted() {
+        let mut rng = rand::thread_rng();
+        let key = generate_share_key(&mut rng);
+        let other_key = generate_share_key(&mut rng);
+        let share = vec![1u64, 2, 3];
+        let mut file = Vec::new();
+        write_encrypted_share_file(
+            &mut file,
+            &header(),
+            &share,
+            &SharePublicKey::from(&key),
+            &mut rng,
+        )
+        .unwrap();
+
+        let expected = ExpectedShareFile::default();
+        let (read_header, read_share): (_, Vec<u64>) =
+            read_share_file_with_key(file.as_slice(), &expected, Some(&key)).unwrap();
+        assert_eq!(read_header.encryption, ShareEncryption::X25519Aes256Gcm);
+        assert_eq!(read_share, share);
+
+        // Decryption requires the key of the recipient
+        assert!(read_share_file::<_, Vec<u64>>(file.as_slice(), &expected).is_err());
+        assert!(
+            read_share_file_with_key::<_, Vec<u64>>(file.as_slice(), &expected, Some(&other_key))
+                .is_err()
+        );
+
+        // The header is authenticated together with the share
+        let header_len = encode_header(&read_header).unwrap().len();
+        let mut modified = encode_header(&read_header.for_party(2)).unwrap();
+        modified.extend_from_slice(&file[header_len..]);
+        assert!(
+            read_share_file_with_key::<_, Vec<u64>>(modified.as_slice(), &expected, Some(&key))
+                .is_err()
+        );
+        let mut modified = file.clone();
+        *modified.last_mut().unwrap() ^= 1;
+        assert!(
+            read_share_file_with_key::<_, Vec<u64>>(modified.as_slice(), &expected, Some(&key))
+                .is_err()
+        );
+
+        // Plain share files are rejected if a key is provided
+        let mut file = Vec::new();
+        write_share_file(&mut file, &header(), &share).unwrap();
+        assert!(
+            read_share_file_with_key::<_, Vec<u64>>(file.as_slice(), &expected, Some(&key))
+                .is_err()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn secret_key_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+        let mut rng = rand::thread_rng();
+        let key = generate_share_key(&mut rng);
+        let path = std::env::temp_dir().join(format!("share_key_{}", rng.next_u64()));
+        write_secret_key(&path, &key.to_bytes()).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        let read_key = read_share_secret_key(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(read_key.to_bytes(), key.to_bytes());
+    }
+}
@@ -83,7 +83,7 @@ fn main() -> eyre::Result<()> {
 
     let should_plain_witness = parsed
         .clone()
-        .to_plain_vm(VMConfig::default())
+        .to_plain_vm(VMConfig::default())?
         .run(plain_input.clone())?
         .into_shared_witness();
 
@@ -64,7 +64,7 @@ impl Rep3TestNetwork {
             recv_prev: self.p3_p1_receiver,
             send_next: self.p1_p2_sender,
             recv_next: self.p2_p1_receiver,
-            _stats: [0; 4],
+            stats: [0; 4],
         };
 
         let party2 = PartyTestNetwork {
@@ -73,7 +73,7 @@ impl Rep3TestNetwork {
             recv_prev: self.p1_p2_receiver,
             send_next: self.p2_p3_sender,
             recv_next: self.p3_p2_receiver,
-            _stats: [0; 4],
+            stats: [0; 4],
         };
 
         let party3 = PartyTestNetwork {
@@ -82,7 +82,7 @@ impl Rep3TestNetwork {
             recv_prev: self.p2_p3_receiver,
             send_next: self.p3_p1_sender,
             recv_next: self.p1_p3_receiver,
-            _stats: [0; 4],
+            stats: [0; 4],
         };
 
         [party1, party2, party3]
@@ -96,7 +96,14 @@ pub struct PartyTestNetwork {
     pub send_next: Sender<Msg>,
     pub recv_prev: Receiver<Msg>,
     pub recv_next: Receiver<Msg>,
-    pub _stats: [usize; 4], // [sent_prev, sent_next, recv_prev, recv_next]
+    pub stats: [usize; 4], // [sent_prev, sent_next, recv_prev, recv_next]
+}
+
+impl PartyTestNetwork {
+    /// Returns the number of messages this party has sent to the other parties so far.
+    pub fn num_sent_messages(&self) -> usize {
+        self.stats[0] + self.stats[1]
+    }
 }
 
 impl Rep3Network for PartyTestNetwork {
@@ -147,10 +154,12 @@ impl Rep3Network for PartyTestNetwork {
         let mut to_send = Vec::with_capacity(size);
         data.serialize_uncompressed(&mut to_send).unwrap();
         if self.id.next_id() == target {
+            self.stats[1] += 1;
             self.send_next
                 .send(Msg::Data(Bytes::from(to_send)))
                 .expect("can send to next")
         } else if self.id.prev_id() == target {
+            self.stats[0] += 1;
             self.send_prev
                 .send(Msg::Data(Bytes::from(to_send)))
                 .expect("can send to next");
@@ -162,9 +171,11 @@ impl Rep3Network for PartyTestNetwork {
 
     fn recv_many<F: CanonicalDeserialize>(&mut self, from: PartyID) -> std::io::Result<Vec<F>> {
         if self.id.next_id() == from {
+            self.stats[3] += 1;
             let data = Vec::from(self.recv_next.recv().unwrap().into_data().unwrap());
             Ok(Vec::<F>::deserialize_uncompressed(data.as_slice()).unwrap())
         } else if self.id.prev_id() == from {
+            self.stats[2] += 1;
             let data = Vec::from(self.recv_prev.recv().unwrap().into_data().unwrap());
             Ok(Vec::<F>::deserialize_uncompressed(data.as_slice()).unwrap())
         } else {
@@ -193,7 +204,7 @@ impl Rep3Network for PartyTestNetwork {
             send_next: ch_next.0,
             recv_prev,
             recv_next,
-            _stats: [0; 4],
+            stats: [0; 4],
         })
     }
 }
@@ -206,7 +217,7 @@ impl RepToShamirNetwork<ShamirPartyTestNetwork> for PartyTestNetwork {
             send_next,
             recv_prev,
             recv_next,
-            _stats,
+            stats: _,
         } = self;
 
         let mut send = Vec::with_capacity(2);
@@ -238,6 +249,7 @@ impl RepToShamirNetwork<ShamirPartyTestNetwork> for PartyTestNetwork {
             num_parties: 3,
             send,
             recv,
+            sent_bytes: 0,
         }
     }
 }
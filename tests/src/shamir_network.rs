@@ -62,6 +62,7 @@ impl ShamirTestNetwork {
                 num_parties: self.num_parties,
                 send,
                 recv,
+                sent_bytes: 0,
             };
             res.push(network);
         }
@@ -79,6 +80,13 @@ pub struct PartyTestNetwork {
     pub num_parties: usize,
     pub send: Vec<Sender<Msg>>,
     pub recv: Vec<Receiver<Msg>>,
+    pub sent_bytes: usize,
+}
+
+impl PartyTestNetwork {
+    pub fn num_sent_bytes(&self) -> usize {
+        self.sent_bytes
+    }
 }
 
 impl ShamirNetwork for PartyTestNetwork {
@@ -113,6 +121,7 @@ impl ShamirNetwork for PartyTestNetwork {
         let size = data.serialized_size(ark_serialize::Compress::No);
         let mut to_send = Vec::with_capacity(size);
         data.serialize_uncompressed(&mut to_send).unwrap();
+        self.sent_bytes += to_send.len();
 
         self.send[target]
             .send(Msg::Data(Bytes::from(to_send)))
@@ -159,6 +168,7 @@ impl ShamirNetwork for PartyTestNetwork {
             .serialize_uncompressed(&mut ser_data)
             .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
         let send_data = Bytes::from(ser_data);
+        self.sent_bytes += send_data.len() * self.send.len();
 
         // Send
         for send in self.send.iter_mut() {
@@ -209,6 +219,7 @@ impl ShamirNetwork for PartyTestNetwork {
             self.send[other_id]
                 .send(Msg::Data(send_data.to_owned()))
                 .expect("can send");
+            self.sent_bytes += send_data.len();
         }
 
         // Receive
@@ -256,6 +267,7 @@ impl ShamirNetwork for PartyTestNetwork {
             num_parties,
             send,
             recv,
+            sent_bytes: 0,
         })
     }
 
@@ -1,2 +1,3 @@
+mod packed_shamir;
 mod plain;
 mod rep3;
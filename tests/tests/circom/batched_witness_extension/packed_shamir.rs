@@ -0,0 +1,252 @@
+use circom_mpc_compiler::{CoCircomCompiler, CompilerConfig};
+use circom_mpc_vm::mpc_vm::VMConfig;
+use co_circom_types::SharedInput;
+use co_noir::Bn254;
+use itertools::{izip, Itertools};
+use mpc_core::protocols::shamir::packed;
+use rand::{thread_rng, Rng as _};
+use tests::shamir_network::ShamirTestNetwork;
+
+fn batched_packed_shamir_mul_inner(
+    num_parties: usize,
+    threshold: usize,
+    packing: usize,
+    batch_size: usize,
+) -> eyre::Result<()> {
+    let root = std::env!("CARGO_MANIFEST_DIR");
+    let mul_circuit = format!("{root}/../test_vectors/WitnessExtension/tests/multiplier2.circom");
+
+    let mut compiler_config = CompilerConfig::new();
+    compiler_config.simplification = circom_mpc_compiler::SimplificationLevel::O2(usize::MAX);
+    let parsed = CoCircomCompiler::<Bn254>::parse(&mul_circuit, compiler_config)?;
+
+    let test_network = ShamirTestNetwork::new(num_parties);
+    let degree = packed::packed_degree(threshold, packing);
+
+    let mut rng = thread_rng();
+    let a = (0..batch_size)
+        .map(|_| rng.gen::<ark_bn254::Fr>())
+        .collect_vec();
+    let b = (0..batch_size)
+        .map(|_| rng.gen::<ark_bn254::Fr>())
+        .collect_vec();
+
+    let mut should_witness = Vec::with_capacity(batch_size);
+    for (a, b) in izip!(&a, &b) {
+        let mut plain_input = SharedInput::default();
+        plain_input.add_shared_input("a".to_string(), vec![*a]);
+        plain_input.add_shared_input("b".to_string(), vec![*b]);
+        let wtns = parsed
+            .clone()
+            .to_plain_vm(VMConfig::default())?
+            .run(plain_input)?
+            .into_shared_witness();
+        should_witness.push(wtns);
+    }
+
+    let a_shares = packed::share_field_elements_packed(&a, degree, packing, num_parties, &mut rng);
+    let b_shares = packed::share_field_elements_packed(&b, degree, packing, num_parties, &mut rng);
+
+    let mut threads = vec![];
+    for (net, a, b) in izip!(test_network.get_party_networks(), a_shares, b_shares) {
+        let parsed = parsed.clone();
+        threads.push(std::thread::spawn(move || {
+            let mut input = SharedInput::default();
+            input.add_shared_input("a".to_string(), vec![a]);
+            input.add_shared_input("b".to_string(), vec![b]);
+            parsed
+                .to_batched_packed_shamir_vm_with_network(
+                    net,
+                    VMConfig::default(),
+                    threshold,
+                    packing,
+                    batch_size,
+                )
+                .unwrap()
+                .run(input)
+                .unwrap()
+                .into_shared_witness()
+        }));
+    }
+    let results = threads.into_iter().map(|t| t.join().unwrap()).collect_vec();
+
+    // The public inputs are opened for all parties
+    for result in results.iter() {
+        for (i, public_input) in result.public_inputs.iter().enumerate() {
+            assert_eq!(public_input.len(), batch_size);
+            for (public_input, should) in izip!(public_input, &should_witness) {
+                assert_eq!(*public_input, should.public_inputs[i]);
+            }
+        }
+    }
+
+    let num_witness = results[0].witness.len();
+    let coeffs = (1..=num_parties).collect_vec();
+    for i in 0..num_witness {
+        let shares = results
+            .iter()
+            .map(|result| result.witness[i].to_owned())
+            .collect_vec();
+        let is_witness =
+            packed::combine_field_elements_packed(&shares, &coeffs, degree, packing, batch_size)?;
+        for (is, should) in izip!(is_witness, &should_witness) {
+            assert_eq!(is, should.witness[i]);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn batched_packed_shamir_mul() -> eyre::Result<()> {
+    batched_packed_shamir_mul_inner(3, 1, 1, 10)?;
+    batched_packed_shamir_mul_inner(5, 1, 2, 7)?;
+    batched_packed_shamir_mul_inner(9, 2, 3, 100)?;
+    Ok(())
+}
+
+#[test]
+fn batched_packed_shamir_empty_batch() -> eyre::Result<()> {
+    let root = std::env!("CARGO_MANIFEST_DIR");
+    let mul_circuit = format!("{root}/../test_vectors/WitnessExtension/tests/multiplier2.circom");
+    let parsed = CoCircomCompiler::<Bn254>::parse(&mul_circuit, CompilerConfig::new())?;
+
+    let test_network = ShamirTestNetwork::new(3);
+    let net = test_network
+        .get_party_networks()
+        .into_iter()
+        .next()
+        .unwrap();
+    let vm = parsed.to_batched_packed_shamir_vm_with_network(net, VMConfig::default(), 1, 1, 0);
+    assert!(vm.is_err());
+    Ok(())
+}
+
+#[test]
+fn batched_packed_shamir_unsupported_op() -> eyre::Result<()> {
+    const NUM_PARTIES: usize = 3;
+    const THRESHOLD: usize = 1;
+    const PACKING: usize = 1;
+    const BATCH_SIZE: usize = 2;
+
+    let root = std::env!("CARGO_MANIFEST_DIR");
+    let lt_circuit = format!("{root}/../test_vectors/WitnessExtension/tests/lessthan.circom");
+    let parsed = CoCircomCompiler::<Bn254>::parse(&lt_circuit, CompilerConfig::new())?;
+
+    let test_network = ShamirTestNetwork::new(NUM_PARTIES);
+    let degree = packed::packed_degree(THRESHOLD, PACKING);
+
+    let mut rng = thread_rng();
+    let a = (0..BATCH_SIZE)
+        .map(|_| ark_bn254::Fr::from(rng.gen::<u32>()))
+        .collect_vec();
+    let b = (0..BATCH_SIZE)
+        .map(|_| ark_bn254::Fr::from(rng.gen::<u32>()))
+        .collect_vec();
+    let a_shares = packed::share_field_elements_packed(&a, degree, PACKING, NUM_PARTIES, &mut rng);
+    let b_shares = packed::share_field_elements_packed(&b, degree, PACKING, NUM_PARTIES, &mut rng);
+
+    let mut threads = vec![];
+    for (net, a, b) in izip!(test_network.get_party_networks(), a_shares, b_shares) {
+        let parsed = parsed.clone();
+        threads.push(std::thread::spawn(move || {
+            let mut input = SharedInput::default();
+            input.add_shared_input("in".to_string(), vec![a, b]);
+            parsed
+                .to_batched_packed_shamir_vm_with_network(
+                    net,
+                    VMConfig::default(),
+                    THRESHOLD,
+                    PACKING,
+                    BATCH_SIZE,
+                )
+                .unwrap()
+                .run(input)
+                .map(|_| ())
+        }));
+    }
+
+    // The comparison of shared values returns an error instead of panicking
+    for thread in threads {
+        let err = thread.join().unwrap().unwrap_err();
+        assert!(
+            format!("{err:?}").contains("is not supported in packed Shamir mode"),
+            "{err:?}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn batched_packed_shamir_public_ops() -> eyre::Result<()> {
+    const NUM_PARTIES: usize = 3;
+    const THRESHOLD: usize = 1;
+    const PACKING: usize = 1;
+    const BATCH_SIZE: usize = 2;
+
+    let root = std::env!("CARGO_MANIFEST_DIR");
+    let lt_circuit = format!("{root}/../test_vectors/WitnessExtension/tests/lessthan.circom");
+    let parsed = CoCircomCompiler::<Bn254>::parse(&lt_circuit, CompilerConfig::new())?;
+
+    let test_network = ShamirTestNetwork::new(NUM_PARTIES);
+    let degree = packed::packed_degree(THRESHOLD, PACKING);
+
+    let mut rng = thread_rng();
+    let a = (0..BATCH_SIZE)
+        .map(|_| ark_bn254::Fr::from(rng.gen::<u32>()))
+        .collect_vec();
+    let b = (0..BATCH_SIZE)
+        .map(|_| ark_bn254::Fr::from(rng.gen::<u32>()))
+        .collect_vec();
+
+    let mut should_witness = Vec::with_capacity(BATCH_SIZE);
+    for (a, b) in izip!(&a, &b) {
+        let mut plain_input = SharedInput::default();
+        plain_input.add_public_input("in".to_string(), vec![*a, *b]);
+        let wtns = parsed
+            .clone()
+            .to_plain_vm(VMConfig::default())?
+            .run(plain_input)?
+            .into_shared_witness();
+        should_witness.push(wtns);
+    }
+
+    let mut threads = vec![];
+    for net in test_network.get_party_networks() {
+        let parsed = parsed.clone();
+        let (a, b) = (a.clone(), b.clone());
+        threads.push(std::thread::spawn(move || {
+            let mut input = SharedInput::default();
+            input.add_public_input("in".to_string(), vec![a, b]);
+            parsed
+                .to_batched_packed_shamir_vm_with_network(
+                    net,
+                    VMConfig::default(),
+                    THRESHOLD,
+                    PACKING,
+                    BATCH_SIZE,
+                )
+                .unwrap()
+                .run(input)
+                .unwrap()
+                .into_shared_witness()
+        }));
+    }
+    let results = threads.into_iter().map(|t| t.join().unwrap()).collect_vec();
+
+    // Comparisons and bit decompositions of public values are computed with the plain VM
+    let num_witness = results[0].witness.len();
+    let coeffs = (1..=NUM_PARTIES).collect_vec();
+    for i in 0..num_witness {
+        let shares = results
+            .iter()
+            .map(|result| result.witness[i].to_owned())
+            .collect_vec();
+        let is_witness =
+            packed::combine_field_elements_packed(&shares, &coeffs, degree, PACKING, BATCH_SIZE)?;
+        for (is, should) in izip!(is_witness, &should_witness) {
+            assert_eq!(is, should.witness[i]);
+        }
+    }
+    Ok(())
+}
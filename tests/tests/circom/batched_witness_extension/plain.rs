@@ -27,14 +27,14 @@ fn batched_add_1() -> eyre::Result<()> {
         batch.push(shared_input.clone());
         let parsed = parsed.clone();
         let current_wtns = parsed
-            .to_plain_vm(VMConfig::default())
+            .to_plain_vm(VMConfig::default())?
             .run(shared_input)?
             .into_shared_witness();
         should_witness.push(current_wtns);
     }
 
     let is_wts = parsed
-        .to_batched_plain_vm(VMConfig::default(), batch_size)
+        .to_batched_plain_vm(VMConfig::default(), batch_size)?
         .run(BatchedSharedInput::try_from(batch.clone())?)?
         .into_shared_witness()
         .unbatch();
@@ -62,14 +62,14 @@ fn batched_add_100() -> eyre::Result<()> {
         batch.push(shared_input.clone());
         let parsed = parsed.clone();
         let current_wtns = parsed
-            .to_plain_vm(VMConfig::default())
+            .to_plain_vm(VMConfig::default())?
             .run(shared_input)?
             .into_shared_witness();
         should_witness.push(current_wtns);
     }
 
     let is_wts = parsed
-        .to_batched_plain_vm(VMConfig::default(), batch_size)
+        .to_batched_plain_vm(VMConfig::default(), batch_size)?
         .run(BatchedSharedInput::try_from(batch.clone())?)?
         .into_shared_witness()
         .unbatch();
@@ -111,12 +111,12 @@ fn batched_chacha20_1() -> eyre::Result<()> {
 
     let should_witness = parsed
         .clone()
-        .to_plain_vm(VMConfig::default())
+        .to_plain_vm(VMConfig::default())?
         .run(shared_input.clone())?
         .into_shared_witness();
 
     let is_wts = parsed
-        .to_batched_plain_vm(VMConfig::default(), batch_size)
+        .to_batched_plain_vm(VMConfig::default(), batch_size)?
         .run(BatchedSharedInput::try_from(vec![
             shared_input;
             batch_size
@@ -162,12 +162,12 @@ fn batched_chacha20_30() -> eyre::Result<()> {
 
     let should_witness = parsed
         .clone()
-        .to_plain_vm(VMConfig::default())
+        .to_plain_vm(VMConfig::default())?
         .run(shared_input.clone())?
         .into_shared_witness();
 
     let is_wts = parsed
-        .to_batched_plain_vm(VMConfig::default(), batch_size)
+        .to_batched_plain_vm(VMConfig::default(), batch_size)?
         .run(BatchedSharedInput::try_from(vec![
             shared_input;
             batch_size
@@ -51,7 +51,7 @@ fn batched_add_1() -> eyre::Result<()> {
 
         let parsed = parsed.clone();
         let current_wtns = parsed
-            .to_plain_vm(VMConfig::default())
+            .to_plain_vm(VMConfig::default())?
             .run(plain_input)?
             .into_shared_witness();
         should_witness.push(current_wtns);
@@ -134,7 +134,7 @@ fn batched_add_100() -> eyre::Result<()> {
 
         let parsed = parsed.clone();
         let current_wtns = parsed
-            .to_plain_vm(VMConfig::default())
+            .to_plain_vm(VMConfig::default())?
             .run(plain_input)?
             .into_shared_witness();
         should_witness.push(current_wtns);
@@ -226,7 +226,7 @@ fn batched_chacha20_1() -> eyre::Result<()> {
 
     let should_plain_witness = parsed
         .clone()
-        .to_plain_vm(VMConfig::default())
+        .to_plain_vm(VMConfig::default())?
         .run(plain_input.clone())?
         .into_shared_witness();
 
@@ -314,7 +314,7 @@ fn batched_chacha20_30() -> eyre::Result<()> {
 
     let should_plain_witness = parsed
         .clone()
-        .to_plain_vm(VMConfig::default())
+        .to_plain_vm(VMConfig::default())?
         .run(plain_input.clone())?
         .into_shared_witness();
 
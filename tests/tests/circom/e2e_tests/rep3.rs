@@ -1,19 +1,30 @@
+use ark_bls12_377::Bls12_377;
 use ark_bls12_381::Bls12_381;
 use ark_bn254::Bn254;
 use circom_types::plonk::PlonkProof;
 use circom_types::Witness;
 use circom_types::{
     groth16::{
-        CircomGroth16Proof, JsonPublicInput, JsonVerificationKey as Groth16VK, ZKey as Groth16ZK,
+        CircomGroth16Proof, JsonPublicInput, JsonVerificationKey as Groth16VK,
+        MmapZKey as Groth16MmapZK, ZKey as Groth16ZK,
     },
     plonk::{JsonVerificationKey as PlonkVK, ZKey as PlonkZK},
     R1CS,
 };
 use std::sync::Arc;
 
+use ark_relations::{
+    lc,
+    r1cs::{ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, SynthesisError},
+};
+use ark_std::UniformRand;
+use circom_mpc_compiler::{CoCircomCompiler, CompilerConfig, SimplificationLevel};
+use circom_mpc_vm::mpc_vm::VMConfig;
 use circom_types::traits::CheckElement;
 use co_circom_types::SharedWitness;
-use co_groth16::{CircomReduction, ConstraintMatrices, Groth16, ProvingKey, Rep3CoGroth16};
+use co_groth16::{
+    CircomReduction, ConstraintMatrices, Groth16, LibSnarkReduction, ProvingKey, Rep3CoGroth16,
+};
 use co_plonk::Plonk;
 use co_plonk::Rep3CoPlonk;
 use itertools::izip;
@@ -82,6 +93,51 @@ macro_rules! add_test_impl_g16 {
                 Groth16::<$curve>::verify(&vk, &der_proof, &public_input).expect("can verify");
             }
 
+            #[test]
+            fn [< e2e_proof_mmap_zkey_ $name _ $curve:lower _ groth16>] () {
+                let zkey_path =
+                    format!("../test_vectors/{}/{}/{}/circuit.zkey", "Groth16", stringify!([< $curve:lower >]), $name);
+                let r1cs_file =
+                    File::open(format!("../test_vectors/{}/{}/{}/circuit.r1cs", "Groth16", stringify!([< $curve:lower >]), $name)).unwrap();
+                let witness_file =
+                    File::open(format!("../test_vectors/{}/{}/{}/witness.wtns", "Groth16", stringify!([< $curve:lower >]), $name)).unwrap();
+                let witness = Witness::<[< ark_ $curve:lower >]::Fr>::from_reader(witness_file).unwrap();
+                let mut zkey1 = Groth16MmapZK::<$curve>::open(zkey_path, CheckElement::No).unwrap();
+                // use a small chunk size such that the MSMs are split into multiple chunks
+                zkey1.set_chunk_size(3);
+                let zkey1 = Arc::new(zkey1);
+                let zkey2 = Arc::clone(&zkey1);
+                let zkey3 = Arc::clone(&zkey1);
+                let r1cs = R1CS::<$curve>::from_reader(r1cs_file).unwrap();
+                //ignore leading 1 for verification
+                let public_input = witness.values[1..r1cs.num_inputs].to_vec();
+                let mut rng = thread_rng();
+                let [witness_share1, witness_share2, witness_share3] =
+                    SharedWitness::share_rep3(witness, r1cs.num_inputs, &mut rng);
+                let test_network = Rep3TestNetwork::default();
+                let mut threads = vec![];
+                for (net, x, zkey) in izip!(
+                    test_network.get_party_networks(),
+                    [witness_share1, witness_share2, witness_share3].into_iter(),
+                    [zkey1, zkey2, zkey3].into_iter()
+                ) {
+                    threads.push(thread::spawn(move || {
+                        Rep3CoGroth16::<$curve, PartyTestNetwork>::prove::<CircomReduction>(net, &*zkey, &zkey.matrices, x).unwrap().0
+                    }));
+                }
+                let result3 = threads.pop().unwrap().join().unwrap();
+                let result2 = threads.pop().unwrap().join().unwrap();
+                let result1 = threads.pop().unwrap().join().unwrap();
+                assert_eq!(result1, result2);
+                assert_eq!(result2, result3);
+                let vk: Groth16VK<$curve> = serde_json::from_reader(
+                    File::open(format!("../test_vectors/{}/{}/{}/verification_key.json", "Groth16", stringify!([< $curve:lower >]), $name)).unwrap(),
+                )
+                .unwrap();
+                let vk = vk.into();
+                Groth16::<$curve>::verify(&vk, &result1, &public_input).expect("can verify");
+            }
+
             #[test]
             fn [< e2e_proof_verify_snarkjs_proof_ $name _ $curve:lower _ groth16>] () {
                 let snarkjs_proof_file =
@@ -174,3 +230,91 @@ macro_rules! add_test_impl_plonk {
 }
 e2e_test!("multiplier2");
 e2e_test!("poseidon");
+
+// snarkjs cannot create zkeys for BLS12-377, so we create the Groth16 keys for multiplier2 with arkworks
+struct Multiplier2;
+
+impl<F: ark_ff::PrimeField> ConstraintSynthesizer<F> for Multiplier2 {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        // same order as the witness of circom: 1, c (output), a, b
+        let c = cs.new_input_variable(|| Ok(F::zero()))?;
+        let a = cs.new_witness_variable(|| Ok(F::zero()))?;
+        let b = cs.new_witness_variable(|| Ok(F::zero()))?;
+        cs.enforce_constraint(lc!() + a, lc!() + b, lc!() + c)
+    }
+}
+
+#[test]
+fn e2e_proof_multiplier2_bls12_377_groth16() {
+    let mut rng = thread_rng();
+    let pkey = ark_groth16::Groth16::<Bls12_377>::generate_random_parameters_with_reduction(
+        Multiplier2,
+        &mut rng,
+    )
+    .unwrap();
+    let cs = ConstraintSystem::<ark_bls12_377::Fr>::new_ref();
+    Multiplier2.generate_constraints(cs.clone()).unwrap();
+    cs.finalize();
+    let matrices = Arc::new(cs.to_matrices().unwrap());
+    let pkey = Arc::new(pkey);
+
+    // split the input
+    let a = ark_bls12_377::Fr::rand(&mut rng);
+    let b = ark_bls12_377::Fr::rand(&mut rng);
+    let input = serde_json::json!({ "a": a.to_string(), "b": b.to_string() });
+    let input = input.as_object().unwrap().to_owned();
+    let input_shares =
+        co_circom_types::split_input::<ark_bls12_377::Fr>(input, &[], &Default::default()).unwrap();
+
+    // generate the witness in MPC
+    let mut compiler_config = CompilerConfig::default();
+    compiler_config.simplification = SimplificationLevel::O2(usize::MAX);
+    let parsed = CoCircomCompiler::<Bls12_377>::parse(
+        "../test_vectors/WitnessExtension/tests/multiplier2.circom".to_owned(),
+        compiler_config,
+    )
+    .unwrap();
+    let test_network = Rep3TestNetwork::default();
+    let mut threads = vec![];
+    for (net, input) in izip!(test_network.get_party_networks(), input_shares) {
+        let parsed = parsed.clone();
+        threads.push(thread::spawn(move || {
+            parsed
+                .to_rep3_vm_with_network(net, VMConfig::default())
+                .unwrap()
+                .run(input)
+                .unwrap()
+                .into_shared_witness()
+        }));
+    }
+    let witness3 = threads.pop().unwrap().join().unwrap();
+    let witness2 = threads.pop().unwrap().join().unwrap();
+    let witness1 = threads.pop().unwrap().join().unwrap();
+    // ignore leading 1 for verification
+    let public_input = witness1.public_inputs[1..].to_vec();
+    assert_eq!(public_input, vec![a * b]);
+
+    // generate the proof in MPC
+    let test_network = Rep3TestNetwork::default();
+    let mut threads = vec![];
+    for (net, witness) in izip!(
+        test_network.get_party_networks(),
+        [witness1, witness2, witness3]
+    ) {
+        let pkey = Arc::clone(&pkey);
+        let matrices = Arc::clone(&matrices);
+        threads.push(thread::spawn(move || {
+            Rep3CoGroth16::<Bls12_377, PartyTestNetwork>::prove::<LibSnarkReduction>(
+                net, &*pkey, &matrices, witness,
+            )
+            .unwrap()
+            .0
+        }));
+    }
+    let result3 = threads.pop().unwrap().join().unwrap();
+    let result2 = threads.pop().unwrap().join().unwrap();
+    let result1 = threads.pop().unwrap().join().unwrap();
+    assert_eq!(result1, result2);
+    assert_eq!(result2, result3);
+    Groth16::<Bls12_377>::verify(&pkey.vk, &result1, &public_input).expect("can verify");
+}
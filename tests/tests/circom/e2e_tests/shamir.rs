@@ -3,7 +3,8 @@ use ark_bn254::Bn254;
 use circom_types::Witness;
 use circom_types::{
     groth16::{
-        CircomGroth16Proof, JsonPublicInput, JsonVerificationKey as Groth16VK, ZKey as Groth16ZK,
+        CircomGroth16Proof, JsonPublicInput, JsonVerificationKey as Groth16VK,
+        MmapZKey as Groth16MmapZK, ZKey as Groth16ZK,
     },
     plonk::{JsonVerificationKey as PlonkVK, PlonkProof, ZKey as PlonkZK},
     R1CS,
@@ -11,7 +12,7 @@ use circom_types::{
 use std::sync::Arc;
 
 use circom_types::traits::CheckElement;
-use co_circom_types::SharedWitness;
+use co_circom_types::{CompressedShamirSharedWitness, SharedWitness};
 use co_groth16::ShamirCoGroth16;
 use co_groth16::{CircomReduction, ConstraintMatrices, Groth16, ProvingKey};
 use co_plonk::Plonk;
@@ -82,6 +83,51 @@ macro_rules! add_test_impl_g16 {
                 Groth16::<$curve>::verify(&vk, &der_proof, &public_input).expect("can verify");
             }
 
+            #[test]
+            fn [< e2e_proof_mmap_zkey_ $name _ $curve:lower _ groth16>] () {
+                let zkey_path =
+                    format!("../test_vectors/{}/{}/{}/circuit.zkey", "Groth16", stringify!([< $curve:lower >]), $name);
+                let r1cs_file =
+                    File::open(format!("../test_vectors/{}/{}/{}/circuit.r1cs", "Groth16", stringify!([< $curve:lower >]), $name)).unwrap();
+                let witness_file =
+                    File::open(format!("../test_vectors/{}/{}/{}/witness.wtns", "Groth16", stringify!([< $curve:lower >]), $name)).unwrap();
+                let witness = Witness::<[< ark_ $curve:lower >]::Fr>::from_reader(witness_file).unwrap();
+                let mut zkey1 = Groth16MmapZK::<$curve>::open(zkey_path, CheckElement::No).unwrap();
+                // use a small chunk size such that the MSMs are split into multiple chunks
+                zkey1.set_chunk_size(3);
+                let zkey1 = Arc::new(zkey1);
+                let zkey2 = Arc::clone(&zkey1);
+                let zkey3 = Arc::clone(&zkey1);
+                let r1cs = R1CS::<$curve>::from_reader(r1cs_file).unwrap();
+                //ignore leading 1 for verification
+                let public_input = witness.values[1..r1cs.num_inputs].to_vec();
+                let mut rng = thread_rng();
+                let witness_shares =
+                    SharedWitness::share_shamir(witness, r1cs.num_inputs, 1, 3, &mut rng);
+                let test_network = ShamirTestNetwork::new(3);
+                let mut threads = vec![];
+                for (net, x, zkey) in izip!(
+                    test_network.get_party_networks(),
+                    witness_shares.into_iter(),
+                    [zkey1, zkey2, zkey3].into_iter()
+                ) {
+                    threads.push(thread::spawn(move || {
+                        ShamirCoGroth16::<$curve, PartyTestNetwork>::prove::<CircomReduction>(net, 1, &*zkey, &zkey.matrices, x).unwrap().0
+                    }));
+                }
+                let result3 = threads.pop().unwrap().join().unwrap();
+                let result2 = threads.pop().unwrap().join().unwrap();
+                let result1 = threads.pop().unwrap().join().unwrap();
+                assert_eq!(result1, result2);
+                assert_eq!(result2, result3);
+                let vk: Groth16VK<$curve> = serde_json::from_reader(
+                    File::open(format!("../test_vectors/{}/{}/{}/verification_key.json", "Groth16", stringify!([< $curve:lower >]), $name)).unwrap(),
+                )
+                .unwrap();
+                let vk = vk.into();
+                Groth16::<$curve>::verify(&vk, &result1, &public_input).expect("can verify");
+            }
+
             #[test]
             fn [< e2e_proof_verify_snarkjs_proof_ $name _ $curve:lower _ groth16>] () {
                 let snarkjs_proof_file =
@@ -153,6 +199,55 @@ macro_rules! add_test_impl_plonk {
                 Plonk::<$curve>::verify(&vk, &der_proof, &public_input).expect("can verify");
             }
 
+            #[test]
+            fn [< e2e_proof_ $name _ $curve:lower _ plonk_seeded>] () {
+                let zkey_file =
+                    File::open(format!("../test_vectors/{}/{}/{}/circuit.zkey", "Plonk", stringify!([< $curve:lower >]), $name)).unwrap();
+                let r1cs_file =
+                    File::open(format!("../test_vectors/{}/{}/{}/circuit.r1cs", "Plonk", stringify!([< $curve:lower >]), $name)).unwrap();
+                let witness_file =
+                    File::open(format!("../test_vectors/{}/{}/{}/witness.wtns", "Plonk", stringify!([< $curve:lower >]), $name)).unwrap();
+                let witness = Witness::<[< ark_ $curve:lower >]::Fr>::from_reader(witness_file).unwrap();
+                let zkey1 = PlonkZK::<$curve>::from_reader(zkey_file, CheckElement::No).unwrap();
+                let zkey1 = Arc::new(zkey1);
+                let zkey2 = Arc::clone(&zkey1);
+                let zkey3 = Arc::clone(&zkey1);
+                let r1cs = R1CS::<$curve>::from_reader(r1cs_file).unwrap();
+                //ignore leading 1 for verification
+                let public_input = witness.values[1..r1cs.num_inputs].to_vec();
+                let mut rng = thread_rng();
+                // the shares of t parties are only stored as seeds
+                let witness_shares = CompressedShamirSharedWitness::share_shamir(
+                    witness,
+                    r1cs.num_inputs,
+                    1,
+                    3,
+                    &mut rng,
+                    true,
+                );
+                let test_network = ShamirTestNetwork::new(3);
+                let mut threads = vec![];
+                for (net, x, zkey) in izip!(
+                    test_network.get_party_networks(),
+                    witness_shares.into_iter().map(SharedWitness::from),
+                    [zkey1, zkey2, zkey3].into_iter()
+                ) {
+                    threads.push(thread::spawn(move || {
+                        ShamirCoPlonk::<$curve, PartyTestNetwork>::prove(net, 1, zkey, x).unwrap().0
+                    }));
+                }
+                let result3 = threads.pop().unwrap().join().unwrap();
+                let result2 = threads.pop().unwrap().join().unwrap();
+                let result1 = threads.pop().unwrap().join().unwrap();
+                assert_eq!(result1, result2);
+                assert_eq!(result2, result3);
+                let vk: PlonkVK<$curve> = serde_json::from_reader(
+                    File::open(format!("../test_vectors/{}/{}/{}/verification_key.json", "Plonk", stringify!([< $curve:lower >]), $name)).unwrap(),
+                )
+                .unwrap();
+                Plonk::<$curve>::verify(&vk, &result1, &public_input).expect("can verify");
+            }
+
             #[test]
             fn [< e2e_proof_verify_snarkjs_proof_ $name _ $curve:lower _ plonk>] () {
                 let snarkjs_proof_file =
@@ -48,6 +48,7 @@ macro_rules! witness_extension_test_plain {
                 .unwrap();
                 let is_witness = parsed
                     .to_plain_vm(VMConfig::default())
+                    .unwrap()
                     .run_with_flat(inp.inputs[i].to_owned(), 0)
                     .unwrap()
                     .into_shared_witness();
@@ -170,3 +171,18 @@ witness_extension_test_plain!(bitonic_sort);
 witness_extension_test_plain!(num2bits_accelerator);
 witness_extension_test_plain!(reclaim_addbits_accelerator);
 witness_extension_test_plain!(reclaim_addbits_accelerator_small);
+
+#[test]
+fn bristol_fashion_accelerator_missing_circuit() {
+    let parsed = CoCircomCompiler::<Bn254>::parse(
+        "../test_vectors/WitnessExtension/tests/multiplier2.circom".to_owned(),
+        CompilerConfig::default(),
+    )
+    .unwrap();
+    let mut vm_config = VMConfig::default();
+    vm_config.bristol_fashion.insert(
+        "sqrt_0".to_owned(),
+        "../test_vectors/WitnessExtension/tests/does_not_exist.txt".into(),
+    );
+    assert!(parsed.to_plain_vm(vm_config).is_err());
+}
@@ -2,8 +2,9 @@ use ark_bn254::Bn254;
 use circom_mpc_compiler::CoCircomCompiler;
 use circom_types::Witness;
 use itertools::izip;
-use mpc_core::protocols::rep3::{self};
+use mpc_core::protocols::{rep3, rep3_ring::RingType};
 use rand::thread_rng;
+use std::collections::BTreeMap;
 use std::fs;
 use std::str::FromStr;
 use std::{fs::File, thread};
@@ -122,6 +123,71 @@ macro_rules! run_test {
     }};
 }
 
+/// Shares the input `in` of the test case as integers in the given ring and casts it to field elements before the witness extension, like `co-circom generate-witness` does for inputs given with `--integer-input`.
+fn rep3_ring_inputs(name: &str, ring_type: RingType) {
+    let inp: TestInputs = from_test_name(name);
+    let mut compiler_config = CompilerConfig::default();
+    compiler_config.simplification = circom_mpc_compiler::SimplificationLevel::O2(usize::MAX);
+    compiler_config
+        .link_library
+        .push("../test_vectors/WitnessExtension/tests/libs/".into());
+    let parsed = CoCircomCompiler::<Bn254>::parse(
+        format!("../test_vectors/WitnessExtension/tests/{name}.circom"),
+        compiler_config,
+    )
+    .unwrap();
+    let ring_inputs = BTreeMap::from([("in".to_owned(), ring_type)]);
+    for (i, should_witness) in inp.witnesses.into_iter().enumerate() {
+        let input_file = File::open(format!(
+            "../test_vectors/WitnessExtension/kats/{name}/input{i}.json"
+        ))
+        .unwrap();
+        let input: co_circom_types::Input = serde_json::from_reader(input_file).unwrap();
+        let shares = co_circom::split_input::<Bn254>(input, &[], &ring_inputs).unwrap();
+        let test_network = Rep3TestNetwork::default();
+        let mut threads = vec![];
+        for (net, share, parsed) in izip!(
+            test_network.get_party_networks(),
+            shares,
+            [parsed.clone(), parsed.clone(), parsed.clone()]
+        ) {
+            threads.push(thread::spawn(move || {
+                assert!(share.shared_inputs.is_empty());
+                assert_eq!(share.ring_inputs.len(), 1);
+                let (share, net) = co_circom::cast_ring_inputs(share, net).unwrap();
+                assert!(share.ring_inputs.is_empty());
+                parsed
+                    .to_rep3_vm_with_network(net, VMConfig::default())
+                    .unwrap()
+                    .run(share)
+                    .unwrap()
+                    .into_shared_witness()
+            }));
+        }
+        let result3 = threads.pop().unwrap().join().unwrap();
+        let result2 = threads.pop().unwrap().join().unwrap();
+        let result1 = threads.pop().unwrap().join().unwrap();
+        let is_witness =
+            tests::test_utils::combine_field_elements_for_vm(result1, result2, result3);
+        assert_eq!(is_witness, should_witness.values);
+    }
+}
+
+#[test]
+fn rep3_ring_inputs_binsum_test() {
+    rep3_ring_inputs("binsum_test", RingType::Bit);
+}
+
+#[test]
+fn rep3_ring_inputs_sum_test_u32() {
+    rep3_ring_inputs("sum_test", RingType::U32);
+}
+
+#[test]
+fn rep3_ring_inputs_sum_test_u64() {
+    rep3_ring_inputs("sum_test", RingType::U64);
+}
+
 macro_rules! witness_extension_test_rep3 {
     ($name: ident) => {
         #[test]
@@ -1,4 +1,5 @@
 mod translate_share {
+    use ark_ff::{Field, PrimeField};
     use ark_std::UniformRand;
     use itertools::Itertools;
     use mpc_core::protocols::{
@@ -6,9 +7,9 @@ mod translate_share {
         rep3::{self},
         shamir::{self, ShamirPreprocessing, ShamirProtocol},
     };
-    use rand::thread_rng;
+    use rand::{thread_rng, Rng};
     use std::{sync::mpsc, thread};
-    use tests::rep3_network::Rep3TestNetwork;
+    use tests::{rep3_network::Rep3TestNetwork, shamir_network::ShamirTestNetwork};
 
     const VEC_SIZE: usize = 10;
 
@@ -113,4 +114,214 @@ mod translate_share {
 
         assert_eq!(is_result, x);
     }
+
+    #[test]
+    fn shamir_to_rep3_fieldshare() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = ark_bn254::Fr::rand(&mut rng);
+        let x_shares = shamir::share_field_element(x, 1, 3, &mut rng);
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for ((net, tx), x) in test_network
+            .get_party_networks()
+            .into_iter()
+            .zip([tx1, tx2, tx3])
+            .zip(x_shares.into_iter())
+        {
+            thread::spawn(move || {
+                let preprocessing = ShamirPreprocessing::new(1, net.to_shamir_net(), 1).unwrap();
+                let mut shamir = ShamirProtocol::from(preprocessing);
+                let share = shamir.translate_primefield_shamirshare(x);
+                tx.send(share.unwrap())
+            });
+        }
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+
+        assert_eq!(result1.b, result3.a);
+        assert_eq!(result2.b, result1.a);
+        assert_eq!(result3.b, result2.a);
+        let is_result = rep3::combine_field_element(result1, result2, result3);
+        assert_eq!(is_result, x);
+    }
+
+    #[test]
+    fn shamir_to_rep3_fieldshare_vec() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let x_shares = shamir::share_field_elements(&x, 1, 3, &mut rng);
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for ((net, tx), x) in test_network
+            .get_party_networks()
+            .into_iter()
+            .zip([tx1, tx2, tx3])
+            .zip(x_shares.into_iter())
+        {
+            thread::spawn(move || {
+                let preprocessing = ShamirPreprocessing::new(1, net.to_shamir_net(), 1).unwrap();
+                let mut shamir = ShamirProtocol::from(preprocessing);
+                let share = shamir.translate_primefield_shamirshare_vec(x);
+                tx.send(share.unwrap())
+            });
+        }
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+
+        let is_result = rep3::combine_field_elements(&result1, &result2, &result3);
+        assert_eq!(is_result, x);
+    }
+
+    #[test]
+    fn shamir_to_rep3_pointshare() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = ark_bn254::G1Projective::rand(&mut rng);
+        let x_shares = shamir::share_curve_point(x, 1, 3, &mut rng);
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for ((net, tx), x) in test_network
+            .get_party_networks()
+            .into_iter()
+            .zip([tx1, tx2, tx3])
+            .zip(x_shares.into_iter())
+        {
+            thread::spawn(move || {
+                let preprocessing = ShamirPreprocessing::new(1, net.to_shamir_net(), 1).unwrap();
+                let mut shamir = ShamirProtocol::<ark_bn254::Fr, _>::from(preprocessing);
+                let share = shamir.translate_point_shamirshare(x);
+                tx.send(share.unwrap())
+            });
+        }
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+
+        let is_result = rep3::combine_curve_point(result1, result2, result3);
+        assert_eq!(is_result, x);
+    }
+
+    #[test]
+    fn shamir_committee_lt() {
+        const NUM_PARTIES: usize = 5;
+        const THRESHOLD: usize = 2;
+        const COMMITTEE: [usize; 3] = [4, 1, 2];
+        let test_network = ShamirTestNetwork::new(NUM_PARTIES);
+        let mut rng = thread_rng();
+        let x = (0..2 * VEC_SIZE)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let x_shares = shamir::share_field_elements(&x, THRESHOLD, NUM_PARTIES, &mut rng);
+        let mut rx = Vec::with_capacity(NUM_PARTIES);
+        for (net, x) in test_network
+            .get_party_networks()
+            .into_iter()
+            .zip(x_shares.into_iter())
+        {
+            let (tx, rx_) = mpsc::channel();
+            rx.push(rx_);
+            thread::spawn(move || {
+                let preprocessing = ShamirPreprocessing::new(THRESHOLD, net, 0).unwrap();
+                let mut shamir = ShamirProtocol::from(preprocessing);
+                let share = shamir.run_on_rep3_committee(x, COMMITTEE, |x, io_context| {
+                    let (lhs, rhs) = x.split_at(VEC_SIZE);
+                    lhs.iter()
+                        .zip(rhs)
+                        .map(|(lhs, rhs)| rep3::arithmetic::lt(*lhs, *rhs, io_context))
+                        .collect()
+                });
+                tx.send(share.unwrap())
+            });
+        }
+        let results = rx.into_iter().map(|rx| rx.recv().unwrap()).collect_vec();
+
+        let is_result = shamir::combine_field_elements(
+            &results[..THRESHOLD + 1],
+            &(1..=THRESHOLD + 1).collect_vec(),
+            THRESHOLD,
+        )
+        .unwrap();
+        let should_result = x[..VEC_SIZE]
+            .iter()
+            .zip(&x[VEC_SIZE..])
+            .map(|(lhs, rhs)| ark_bn254::Fr::from(lhs < rhs))
+            .collect_vec();
+        assert_eq!(is_result, should_result);
+        // all parties hold a consistent sharing
+        let is_result = shamir::combine_field_elements(
+            &results[NUM_PARTIES - THRESHOLD - 1..],
+            &(NUM_PARTIES - THRESHOLD..=NUM_PARTIES).collect_vec(),
+            THRESHOLD,
+        )
+        .unwrap();
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn shamir_committee_shift_l_with_forks() {
+        const NUM_PARTIES: usize = 5;
+        const THRESHOLD: usize = 2;
+        const COMMITTEE: [usize; 3] = [3, 0, 4];
+        // shift_l_public_by_shared forks the IoContext 8 times per call
+        const NUM_FORKS: usize = 8 * VEC_SIZE;
+        let test_network = ShamirTestNetwork::new(NUM_PARTIES);
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| ark_bn254::Fr::from(rng.gen_range(0..64u64)))
+            .collect_vec();
+        let x_shares = shamir::share_field_elements(&x, THRESHOLD, NUM_PARTIES, &mut rng);
+        let mut rx = Vec::with_capacity(NUM_PARTIES);
+        for (net, x) in test_network
+            .get_party_networks()
+            .into_iter()
+            .zip(x_shares.into_iter())
+        {
+            let (tx, rx_) = mpsc::channel();
+            rx.push(rx_);
+            thread::spawn(move || {
+                let preprocessing = ShamirPreprocessing::new(THRESHOLD, net, 0).unwrap();
+                let mut shamir = ShamirProtocol::from(preprocessing);
+                let share = shamir.run_on_rep3_committee_with_forks(
+                    x,
+                    COMMITTEE,
+                    NUM_FORKS,
+                    |x, io_context| {
+                        x.into_iter()
+                            .map(|x| {
+                                let x = rep3::conversion::a2b(x, io_context)?;
+                                rep3::binary::shift_l_public_by_shared(
+                                    ark_bn254::Fr::from(3u64),
+                                    &x,
+                                    io_context,
+                                )
+                            })
+                            .collect()
+                    },
+                );
+                tx.send(share.unwrap())
+            });
+        }
+        let results = rx.into_iter().map(|rx| rx.recv().unwrap()).collect_vec();
+
+        let is_result = shamir::combine_field_elements(
+            &results[..THRESHOLD + 1],
+            &(1..=THRESHOLD + 1).collect_vec(),
+            THRESHOLD,
+        )
+        .unwrap();
+        let should_result = x
+            .iter()
+            .map(|x| ark_bn254::Fr::from(3u64) * ark_bn254::Fr::from(2u64).pow(x.into_bigint()))
+            .collect_vec();
+        assert_eq!(is_result, should_result);
+    }
 }
@@ -10,28 +10,35 @@ mod field_share {
     use itertools::izip;
     use itertools::Itertools;
     use libaes::Cipher;
+    use mpc_core::gadgets::merkle_tree::IncrementalMerkleTree;
+    use mpc_core::gadgets::poseidon::{Poseidon, POSEIDON_CIRCOM_BN254_T3_PARAMS};
     use mpc_core::gadgets::poseidon2::Poseidon2;
     use mpc_core::protocols::rep3::conversion;
     use mpc_core::protocols::rep3::gadgets;
     use mpc_core::protocols::rep3::network::Rep3Network;
     use mpc_core::protocols::rep3::yao;
+    use mpc_core::protocols::rep3::yao::bristol_fashion::{self, BristolFashionCircuit};
     use mpc_core::protocols::rep3::yao::circuits::GarbledCircuits;
     use mpc_core::protocols::rep3::yao::circuits::SHA256Table;
     use mpc_core::protocols::rep3::yao::evaluator::Rep3Evaluator;
     use mpc_core::protocols::rep3::yao::garbler::Rep3Garbler;
+    use mpc_core::protocols::rep3::yao::stats::{self, GarbledCircuitStats};
     use mpc_core::protocols::rep3::yao::streaming_evaluator::StreamingRep3Evaluator;
     use mpc_core::protocols::rep3::yao::streaming_garbler::StreamingRep3Garbler;
     use mpc_core::protocols::rep3::yao::GCUtils;
     use mpc_core::protocols::rep3::PartyID;
     use mpc_core::protocols::rep3::{self, arithmetic, network::IoContext};
     use mpc_core::protocols::rep3_ring;
+    use mpc_core::protocols::rep3_ring::ring::ring_impl::RingElement;
     use num_bigint::BigUint;
+    use rand::seq::SliceRandom;
     use rand::thread_rng;
     use rand::Rng;
     use std::array;
     use std::ops::BitXor;
     use std::str::FromStr;
     use std::sync::mpsc;
+    use std::sync::Arc;
     use std::thread;
     use tests::rep3_network::Rep3TestNetwork;
 
@@ -473,6 +480,48 @@ mod field_share {
         assert!(is_result == x_ || is_result == -x_);
     }
 
+    #[test]
+    fn rep3_sqrt_or_zero() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let square = ark_bn254::Fr::rand(&mut rng).square();
+        let mut non_residue = ark_bn254::Fr::rand(&mut rng);
+        while non_residue.sqrt().is_some() {
+            non_residue = ark_bn254::Fr::rand(&mut rng);
+        }
+        let x = vec![square, non_residue, ark_bn254::Fr::zero()];
+        let x_shares = rep3::share_field_elements(&x, &mut rng);
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (net, tx, x) in izip!(test_network.get_party_networks(), [tx1, tx2, tx3], x_shares,) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                let mut roots = Vec::with_capacity(x.len());
+                let mut flags = Vec::with_capacity(x.len());
+                for x in x {
+                    let is_qr = arithmetic::is_quadratic_residue(x, &mut rep3).unwrap();
+                    let (root, flag) = arithmetic::sqrt_or_zero(x, &mut rep3).unwrap();
+                    roots.push(root);
+                    flags.push(is_qr);
+                    flags.push(flag);
+                }
+                tx.send((roots, flags))
+            });
+        }
+        let (roots1, flags1) = rx1.recv().unwrap();
+        let (roots2, flags2) = rx2.recv().unwrap();
+        let (roots3, flags3) = rx3.recv().unwrap();
+        let roots = rep3::combine_field_elements(&roots1, &roots2, &roots3);
+        let flags = rep3::combine_field_elements(&flags1, &flags2, &flags3);
+        assert_eq!(roots[0].square(), square);
+        assert_eq!(roots[1], ark_bn254::Fr::zero());
+        assert_eq!(roots[2], ark_bn254::Fr::zero());
+        let one = ark_bn254::Fr::one();
+        let zero = ark_bn254::Fr::zero();
+        assert_eq!(flags, vec![one, one, zero, zero, one, one]);
+    }
+
     #[test]
     fn rep3_bit_inject() {
         let test_network = Rep3TestNetwork::default();
@@ -1371,6 +1420,87 @@ mod field_share {
         assert_eq!(is_result, should_result);
     }
 
+    // Runs a garbled circuit call and returns the sizes of all messages the garbler ID1 sends to the evaluator ID0
+    fn garbler_message_sizes(
+        x: &[ark_bn254::Fr],
+        call: fn(
+            &[rep3::Rep3PrimeFieldShare<ark_bn254::Fr>],
+            &mut IoContext<tests::rep3_network::PartyTestNetwork>,
+        ) -> std::io::Result<Vec<rep3::Rep3PrimeFieldShare<ark_bn254::Fr>>>,
+    ) -> Vec<usize> {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x_shares = rep3::share_field_elements(x, &mut rng);
+        let [mut net0, net1, net2] = test_network.get_party_networks();
+
+        // forward the messages of ID1 to ID0 and record their sizes
+        let (forward_tx, forward_rx) = mpsc::channel();
+        let from_id1 = std::mem::replace(&mut net0.recv_next, forward_rx);
+        let recorder = thread::spawn(move || {
+            let mut sizes = Vec::new();
+            while let Ok(msg) = from_id1.recv() {
+                if let tests::Msg::Data(data) = &msg {
+                    sizes.push(data.len());
+                }
+                if forward_tx.send(msg).is_err() {
+                    break;
+                }
+            }
+            sizes
+        });
+
+        let parties = izip!([net0, net1, net2], x_shares)
+            .map(|(net, x)| {
+                thread::spawn(move || {
+                    let mut rep3 = IoContext::init(net).unwrap();
+                    call(&x, &mut rep3).unwrap();
+                })
+            })
+            .collect_vec();
+        for party in parties {
+            party.join().unwrap();
+        }
+        recorder.join().unwrap()
+    }
+
+    // The garbled circuit sent by ID1 consists of two ciphertexts per AND gate, two output decoding blocks per output wire and at most two constant wires
+    fn assert_stats_match_garbling(recorded: GarbledCircuitStats, sizes: &[usize]) {
+        const BLOCK_BYTES: usize = 16;
+        const LENGTH_PREFIX_BYTES: usize = 8;
+        let circuit_bytes = sizes.iter().max().unwrap() - LENGTH_PREFIX_BYTES;
+        let expected = recorded.ciphertext_bytes() + 2 * BLOCK_BYTES * recorded.output_wires;
+        assert!(
+            (expected..=expected + 2 * BLOCK_BYTES).contains(&circuit_bytes),
+            "recorded {recorded}, but the garbler sent {circuit_bytes} bytes"
+        );
+    }
+
+    #[test]
+    fn rep3_yao_stats_match_garbling() {
+        const VEC_SIZE: usize = 4;
+        let mut rng = thread_rng();
+        let x = (0..2 * VEC_SIZE)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+
+        let sizes = garbler_message_sizes(&x[..VEC_SIZE], |x, io_context| {
+            yao::decompose_arithmetic_many(x, io_context, 64, 16)
+        });
+        let recorded = GarbledCircuitStats::from_circuit(
+            &stats::decompose_arithmetic_many::<ark_bn254::Fr>(VEC_SIZE, 64, 16).unwrap(),
+        );
+        assert_stats_match_garbling(recorded, &sizes);
+
+        let sizes = garbler_message_sizes(&x, |x, io_context| {
+            let (input1, input2) = x.split_at(x.len() / 2);
+            yao::field_int_div_many(input1, input2, io_context)
+        });
+        let recorded = GarbledCircuitStats::from_circuit(
+            &stats::field_int_div_many::<ark_bn254::Fr>(VEC_SIZE).unwrap(),
+        );
+        assert_stats_match_garbling(recorded, &sizes);
+    }
+
     fn rep3_slice_shared_field_many_via_yao_inner(msb: usize, lsb: usize, bitsize: usize) {
         const VEC_SIZE: usize = 10;
 
@@ -1694,6 +1824,92 @@ mod field_share {
         assert_eq!(is_result, should_result);
     }
 
+    #[test]
+    fn rep3_shuffle() {
+        const VEC_SIZE: usize = 20;
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let x_shares = rep3::share_field_elements(&x, &mut rng);
+        let mut should_result = x.clone();
+        should_result.sort();
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let shuffled = rep3_ring::gadgets::shuffle::shuffle_fields(&x, &mut rep3).unwrap();
+                tx.send(shuffled)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let mut is_result = rep3::combine_field_elements(&result1, &result2, &result3);
+        is_result.sort();
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_apply_shared_permutation() {
+        const VEC_SIZE: usize = 20;
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let mut perm = (1..=VEC_SIZE as u32).collect_vec();
+        perm.shuffle(&mut rng);
+        let mut should_result = vec![ark_bn254::Fr::zero(); VEC_SIZE];
+        for (x, p) in x.iter().zip(perm.iter()) {
+            should_result[*p as usize - 1] = *x;
+        }
+
+        let x_shares = rep3::share_field_elements(&x, &mut rng);
+        let perm = perm.into_iter().map(RingElement).collect_vec();
+        let perm_shares = rep3_ring::share_ring_elements(&perm, &mut rng);
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x, perm) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter(),
+            perm_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                let mut forked = rep3.fork().unwrap();
+
+                let permuted =
+                    rep3_ring::gadgets::shuffle::apply_inv_field(&perm, &x, &mut rep3, &mut forked)
+                        .unwrap();
+                tx.send(permuted)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(&result1, &result2, &result3);
+        assert_eq!(is_result, should_result);
+    }
+
     #[test]
     fn rep3_sha256() {
         let test_network = Rep3TestNetwork::default();
@@ -1754,6 +1970,74 @@ mod field_share {
         assert_eq!(is_result, should_result);
     }
 
+    const BRISTOL_XOR_AND_INV: &str = "9 17
+2 4 4
+3 4 4 1
+
+2 1 0 4 8 XOR
+2 1 1 5 9 XOR
+2 1 2 6 10 XOR
+2 1 3 7 11 XOR
+2 1 0 4 12 AND
+2 1 1 5 13 AND
+2 1 2 6 14 AND
+2 1 3 7 15 AND
+1 1 0 16 INV
+";
+
+    #[test]
+    fn rep3_bristol_fashion_user_circuit() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let circuit = BristolFashionCircuit::from_reader(BRISTOL_XOR_AND_INV.as_bytes()).unwrap();
+        let a = rng.gen_range(0..16u64);
+        let b = rng.gen_range(0..16u64);
+        let should_result = vec![
+            ark_bn254::Fr::from(a ^ b),
+            ark_bn254::Fr::from(a & b),
+            ark_bn254::Fr::from(1 - (a & 1)),
+        ];
+
+        let x = [ark_bn254::Fr::from(a), ark_bn254::Fr::from(b)];
+        let plain = bristol_fashion::execute_on_field_elements(&circuit, &x).unwrap();
+        assert_eq!(plain, should_result);
+        let x_shares = rep3::share_field_elements(&x, &mut rng);
+        let [a0, a1, a2] = rep3::share_biguint(x[0], &mut rng);
+        let [b0, b1, b2] = rep3::share_biguint(x[1], &mut rng);
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x, y) in izip!(
+            test_network.get_party_networks(),
+            [tx1, tx2, tx3],
+            x_shares,
+            [[a0, b0], [a1, b1], [a2, b2]],
+        ) {
+            let circuit = circuit.clone();
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                let arithmetic =
+                    bristol_fashion::execute_on_arithmetic_shares(&circuit, &x, &mut rep3).unwrap();
+                let binary =
+                    bristol_fashion::execute_on_binary_shares(&circuit, &y, &mut rep3).unwrap();
+                tx.send((arithmetic, binary))
+            });
+        }
+
+        let (arithmetic1, binary1) = rx1.recv().unwrap();
+        let (arithmetic2, binary2) = rx2.recv().unwrap();
+        let (arithmetic3, binary3) = rx3.recv().unwrap();
+
+        let is_result = rep3::combine_field_elements(&arithmetic1, &arithmetic2, &arithmetic3);
+        assert_eq!(is_result, should_result);
+        let is_result = izip!(binary1, binary2, binary3)
+            .map(|(x1, x2, x3)| ark_bn254::Fr::from(rep3::combine_binary_element(x1, x2, x3)))
+            .collect_vec();
+        assert_eq!(is_result, should_result);
+    }
+
     fn slice_and_get_sparse_table_with_rotation_values<const BASE: u64>(
         slice_sizes: Vec<u64>,
         rotation_values: Vec<u32>,
@@ -2461,6 +2745,135 @@ mod field_share {
         );
     }
 
+    type ByteFunction = fn(
+        &[Vec<rep3::Rep3PrimeFieldShare<ark_bn254::Fr>>],
+        &mut IoContext<tests::rep3_network::PartyTestNetwork>,
+    ) -> Vec<rep3::Rep3PrimeFieldShare<ark_bn254::Fr>>;
+
+    // Shares the inputs as bytes, applies the function in MPC and opens the resulting bytes
+    fn rep3_on_bytes(inputs: &[Vec<u8>], function: ByteFunction) -> Vec<u8> {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let mut shares = [Vec::new(), Vec::new(), Vec::new()];
+        for input in inputs {
+            let input = input.iter().map(|&x| ark_bn254::Fr::from(x)).collect_vec();
+            let [x0, x1, x2] = rep3::share_field_elements(&input, &mut rng);
+            shares[0].push(x0);
+            shares[1].push(x1);
+            shares[2].push(x2);
+        }
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (net, tx, x) in izip!(test_network.get_party_networks(), [tx1, tx2, tx3], shares) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                tx.send(function(&x, &mut rep3))
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        rep3::combine_field_elements(&result1, &result2, &result3)
+            .into_iter()
+            .map(|x| u8::try_from(BigUint::from(x)).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn rep3_aes_ctr() {
+        let mut rng = thread_rng();
+        let plaintext_size: usize = rng.gen::<u8>() as usize % 128;
+        let key: Vec<u8> = (0..16).map(|_| rng.gen()).collect();
+        let mut iv: Vec<u8> = (0..16).map(|_| rng.gen()).collect();
+        // make sure the counter overflows into the next bytes
+        iv[15] = 0xFF;
+        let pt: Vec<u8> = (0..plaintext_size).map(|_| rng.gen()).collect();
+
+        let cipher = Cipher::new_128(key.as_slice().try_into().unwrap());
+        let mut counter = u128::from_be_bytes(iv.as_slice().try_into().unwrap());
+        let mut should_result = Vec::with_capacity(pt.len());
+        for block in pt.chunks(16) {
+            // encrypting a zero block in CBC mode yields the encryption of the iv
+            let keystream = cipher.cbc_encrypt(&counter.to_be_bytes(), &[0; 16]);
+            should_result.extend(izip!(block, keystream).map(|(p, k)| p ^ k));
+            counter = counter.wrapping_add(1);
+        }
+
+        let is_result = rep3_on_bytes(&[pt, key, iv], |x, rep3| {
+            rep3::yao::aes128_ctr_from_bristol(&x[0], &x[1], &x[2], rep3).unwrap()
+        });
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_sha256_hash() {
+        use sha2::Sha256;
+        let mut rng = thread_rng();
+        let message_size: usize = rng.gen::<u8>() as usize % 200;
+        let message: Vec<u8> = (0..message_size).map(|_| rng.gen()).collect();
+        let should_result = Sha256::digest(&message).to_vec();
+
+        let is_result = rep3_on_bytes(&[message], |x, rep3| {
+            rep3::yao::sha256_hash_from_bristol(&x[0], rep3).unwrap()
+        });
+        assert_eq!(is_result, should_result);
+    }
+
+    fn rep3_hmac_sha256(key_size: usize) {
+        use sha2::Sha256;
+        let mut rng = thread_rng();
+        let key: Vec<u8> = (0..key_size).map(|_| rng.gen()).collect();
+        let message: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
+
+        let mut padded_key = if key.len() > 64 {
+            Sha256::digest(&key).to_vec()
+        } else {
+            key.clone()
+        };
+        padded_key.resize(64, 0);
+        let inner = Sha256::new()
+            .chain_update(padded_key.iter().map(|k| k ^ 0x36).collect_vec())
+            .chain_update(&message)
+            .finalize();
+        let should_result = Sha256::new()
+            .chain_update(padded_key.iter().map(|k| k ^ 0x5c).collect_vec())
+            .chain_update(inner)
+            .finalize()
+            .to_vec();
+
+        let is_result = rep3_on_bytes(&[key, message], |x, rep3| {
+            rep3::yao::hmac_sha256_from_bristol(&x[0], &x[1], rep3).unwrap()
+        });
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_hmac_sha256_short_key() {
+        rep3_hmac_sha256(32);
+    }
+
+    #[test]
+    fn rep3_hmac_sha256_long_key() {
+        rep3_hmac_sha256(100);
+    }
+
+    #[test]
+    fn rep3_sha512() {
+        use sha2::Sha512;
+        let mut rng = thread_rng();
+        let message_size: usize = rng.gen::<u8>() as usize % 200;
+        let message: Vec<u8> = (0..message_size).map(|_| rng.gen()).collect();
+        let should_result = Sha512::digest(&message).to_vec();
+
+        let is_result = rep3_on_bytes(&[message], |x, rep3| {
+            rep3::yao::sha512(&x[0], rep3).unwrap()
+        });
+        assert_eq!(is_result, should_result);
+    }
+
     fn rep3_mod_red(a: u64, b: u64) {
         let test_network = Rep3TestNetwork::default();
         let should_result = ark_bn254::Fr::from(a % b);
@@ -3057,6 +3470,67 @@ mod field_share {
         }
     }
 
+    #[test]
+    fn rep3_poseidon_circom_gadget_precomp_packed() {
+        const NUM_POSEIDON: usize = 10;
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let mut input = vec![ark_bn254::Fr::default(); NUM_POSEIDON * 3];
+        for input in input.chunks_exact_mut(3) {
+            input[0] = ark_bn254::Fr::from(0);
+            input[1] = ark_bn254::Fr::from(1);
+            input[2] = ark_bn254::Fr::from(2);
+        }
+
+        let input_shares = rep3::share_field_elements(&input, &mut rng);
+
+        let poseidon = Poseidon::new(Arc::clone(&POSEIDON_CIRCOM_BN254_T3_PARAMS));
+        let expected = poseidon.permutation(&[
+            ark_bn254::Fr::from(0),
+            ark_bn254::Fr::from(1),
+            ark_bn254::Fr::from(2),
+        ]);
+        // circomlib's Poseidon(2) on the inputs [1, 2]
+        assert_eq!(
+            expected[0],
+            ark_bn254::Fr::from_str(
+                "7853200120776062878684798364095072458815029376092732009249414926327459813530"
+            )
+            .unwrap()
+        );
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            input_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let poseidon = Poseidon::new(Arc::clone(&POSEIDON_CIRCOM_BN254_T3_PARAMS));
+                let mut precomp = poseidon.precompute_rep3(NUM_POSEIDON, &mut rep3).unwrap();
+                let output = poseidon
+                    .rep3_permutation_with_precomputation_packed(&x, &mut precomp, &mut rep3)
+                    .unwrap();
+                tx.send(output)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(&result1, &result2, &result3);
+
+        for r in is_result.chunks_exact(3) {
+            assert_eq!(r, expected);
+        }
+    }
+
     #[test]
     fn rep3_poseidon2_gadget_kat1_precomp_additive() {
         let test_network = Rep3TestNetwork::default();
@@ -3172,15 +3646,204 @@ mod field_share {
     }
 
     #[test]
-    fn rep3_field_mod_pow2() {
+    fn rep3_incremental_merkle_tree() {
+        const DEPTH: usize = 4;
+        const NUM_LEAVES: usize = 10;
+        const SHARED_UPDATE_INDEX: usize = 5;
+        const PROOF_INDEX: usize = 11;
+
         let test_network = Rep3TestNetwork::default();
         let mut rng = thread_rng();
-        let x = ark_bn254::Fr::rand(&mut rng);
-        let bit = rng.gen_range(1..ark_bn254::Fr::MODULUS_BIT_SIZE);
-        let y = ark_bn254::Fr::from(BigUint::one() << bit);
-        let x_shares = rep3::share_field_element(x.to_owned(), &mut rng);
-
-        let should_result = ark_bn254::Fr::from(BigUint::from(x) % BigUint::from(y));
+        let leaves = (0..NUM_LEAVES)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let appended = (0..2).map(|_| ark_bn254::Fr::rand(&mut rng)).collect_vec();
+        let updated = ark_bn254::Fr::rand(&mut rng);
+
+        let leaf_shares = rep3::share_field_elements(&leaves, &mut rng);
+        let appended_shares = rep3::share_field_elements(&appended, &mut rng);
+        let updated_shares = rep3::share_field_element(updated, &mut rng);
+        let update_index_shares =
+            rep3::share_field_element(ark_bn254::Fr::from(SHARED_UPDATE_INDEX as u64), &mut rng);
+        let proof_index_shares =
+            rep3::share_field_element(ark_bn254::Fr::from(PROOF_INDEX as u64), &mut rng);
+
+        let mut expected_leaves = vec![ark_bn254::Fr::zero(); 1 << DEPTH];
+        expected_leaves[..NUM_LEAVES].copy_from_slice(&leaves);
+        expected_leaves[NUM_LEAVES..NUM_LEAVES + 2].copy_from_slice(&appended);
+        expected_leaves[SHARED_UPDATE_INDEX] = updated;
+        let poseidon2 = Poseidon2::<ark_bn254::Fr, 2, 5>::default();
+        let expected_root = poseidon2.merkle_tree_compression::<2>(expected_leaves.clone());
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, leaves, appended, updated, update_index, proof_index) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            leaf_shares.into_iter(),
+            appended_shares.into_iter(),
+            updated_shares,
+            update_index_shares,
+            proof_index_shares
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let poseidon2 = Poseidon2::<_, 2, 5>::default();
+                let mut tree =
+                    IncrementalMerkleTree::from_leaves(poseidon2, DEPTH, leaves, &mut rep3)
+                        .unwrap();
+                tree.append(&appended, &mut rep3).unwrap();
+                tree.update_shared(update_index, updated, &mut rep3)
+                    .unwrap();
+                let proof = tree
+                    .membership_proof_shared(proof_index, &mut rep3)
+                    .unwrap();
+                let public_proof = tree.membership_proof(PROOF_INDEX, rep3.id);
+                tx.send((proof, public_proof))
+            });
+        }
+
+        let (proof1, public_proof1) = rx1.recv().unwrap();
+        let (proof2, public_proof2) = rx2.recv().unwrap();
+        let (proof3, public_proof3) = rx3.recv().unwrap();
+
+        for (proof1, proof2, proof3) in [
+            (proof1, proof2, proof3),
+            (public_proof1, public_proof2, public_proof3),
+        ] {
+            let root = rep3::combine_field_element(proof1.root, proof2.root, proof3.root);
+            let leaf = rep3::combine_field_element(proof1.leaf, proof2.leaf, proof3.leaf);
+            let path_elements = rep3::combine_field_elements(
+                &proof1.path_elements,
+                &proof2.path_elements,
+                &proof3.path_elements,
+            );
+            let path_indices = rep3::combine_field_elements(
+                &proof1.path_indices,
+                &proof2.path_indices,
+                &proof3.path_indices,
+            );
+            assert_eq!(root, expected_root);
+            assert_eq!(leaf, expected_leaves[PROOF_INDEX]);
+
+            // Verify the authentication path
+            let hash = |left: ark_bn254::Fr, right: ark_bn254::Fr| {
+                poseidon2.permutation(&[left, right])[0] + left
+            };
+            let mut node = leaf;
+            for (level, (sibling, bit)) in path_elements.into_iter().zip(path_indices).enumerate() {
+                if (PROOF_INDEX >> level) & 1 == 1 {
+                    assert_eq!(bit, ark_bn254::Fr::one());
+                    node = hash(sibling, node);
+                } else {
+                    assert_eq!(bit, ark_bn254::Fr::zero());
+                    node = hash(node, sibling);
+                }
+            }
+            assert_eq!(node, expected_root);
+        }
+    }
+
+    #[test]
+    fn rep3_incremental_merkle_tree_update_shared_batch() {
+        const DEPTH: usize = 3;
+        const NUM_LEAVES: usize = 6;
+        // Updates 0 and 3 hit the same leaf (the last one wins) and updates 1 and 2 are siblings
+        const UPDATE_INDICES: [usize; 4] = [2, 4, 5, 2];
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let leaves = (0..NUM_LEAVES)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let updated = (0..UPDATE_INDICES.len())
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let indices = UPDATE_INDICES
+            .iter()
+            .map(|i| ark_bn254::Fr::from(*i as u64))
+            .collect_vec();
+
+        let leaf_shares = rep3::share_field_elements(&leaves, &mut rng);
+        let updated_shares = rep3::share_field_elements(&updated, &mut rng);
+        let index_shares = rep3::share_field_elements(&indices, &mut rng);
+
+        let mut expected_leaves = vec![ark_bn254::Fr::zero(); 1 << DEPTH];
+        expected_leaves[..NUM_LEAVES].copy_from_slice(&leaves);
+        for (index, leaf) in UPDATE_INDICES.iter().zip(updated.iter()) {
+            expected_leaves[*index] = *leaf;
+        }
+        let poseidon2 = Poseidon2::<ark_bn254::Fr, 2, 5>::default();
+        let expected_root = poseidon2.merkle_tree_compression::<2>(expected_leaves.clone());
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, leaves, updated, indices) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            leaf_shares.into_iter(),
+            updated_shares.into_iter(),
+            index_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let poseidon2 = Poseidon2::<_, 2, 5>::default();
+                let mut sequential =
+                    IncrementalMerkleTree::from_leaves(poseidon2, DEPTH, leaves, &mut rep3)
+                        .unwrap();
+                let mut batched = sequential.clone();
+                let updates = indices.into_iter().zip(updated).collect_vec();
+
+                let before = rep3.network.num_sent_messages();
+                for (index, leaf) in updates.iter() {
+                    sequential.update_shared(*index, *leaf, &mut rep3).unwrap();
+                }
+                let sent_sequential = rep3.network.num_sent_messages() - before;
+
+                let before = rep3.network.num_sent_messages();
+                batched.update_shared_batch(&updates, &mut rep3).unwrap();
+                let sent_batched = rep3.network.num_sent_messages() - before;
+
+                tx.send((
+                    [sequential.leaves().to_vec(), batched.leaves().to_vec()],
+                    [sequential.root(), batched.root()],
+                    sent_sequential,
+                    sent_batched,
+                ))
+            });
+        }
+
+        let (leaves1, roots1, sent_sequential, sent_batched) = rx1.recv().unwrap();
+        let (leaves2, roots2, _, _) = rx2.recv().unwrap();
+        let (leaves3, roots3, _, _) = rx3.recv().unwrap();
+
+        for (leaves1, leaves2, leaves3) in izip!(leaves1, leaves2, leaves3) {
+            let is_leaves = rep3::combine_field_elements(&leaves1, &leaves2, &leaves3);
+            assert_eq!(is_leaves, expected_leaves);
+        }
+        for (root1, root2, root3) in izip!(roots1, roots2, roots3) {
+            let is_root = rep3::combine_field_element(root1, root2, root3);
+            assert_eq!(is_root, expected_root);
+        }
+        assert!(sent_batched < sent_sequential);
+    }
+
+    #[test]
+    fn rep3_field_mod_pow2() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = ark_bn254::Fr::rand(&mut rng);
+        let bit = rng.gen_range(1..ark_bn254::Fr::MODULUS_BIT_SIZE);
+        let y = ark_bn254::Fr::from(BigUint::one() << bit);
+        let x_shares = rep3::share_field_element(x.to_owned(), &mut rng);
+
+        let should_result = ark_bn254::Fr::from(BigUint::from(x) % BigUint::from(y));
 
         let (tx1, rx1) = mpsc::channel();
         let (tx2, rx2) = mpsc::channel();
@@ -3303,6 +3966,62 @@ mod field_share {
         let is_result = rep3::combine_field_elements(&result1, &result2, &result3);
         assert_eq!(is_result, should_result);
     }
+
+    #[test]
+    fn rep3_blake2s_exported_bristol() {
+        const IV: [u32; 8] = [
+            0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB,
+            0x5BE0CD19,
+        ];
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let input: Vec<u8> = (0..64).map(|_| rng.gen()).collect();
+        let output_bytes: [u8; 32] = Blake2s256::digest(&input).into();
+        let should_result = output_bytes
+            .chunks(4)
+            .map(|word| BigUint::from(u32::from_le_bytes(word.try_into().unwrap())))
+            .collect_vec();
+
+        // a single final block with the default parameters for a 32 byte digest
+        let mut words = input
+            .chunks(4)
+            .map(|word| u32::from_le_bytes(word.try_into().unwrap()))
+            .collect_vec();
+        words.extend(IV);
+        words[16] ^= 0x01010020;
+        let circuit = bristol_fashion::export::blake2s_compress([64, 0], [0xFFFFFFFF, 0]).unwrap();
+
+        let mut shares = [Vec::new(), Vec::new(), Vec::new()];
+        for word in words {
+            let [x0, x1, x2] = rep3::share_biguint(ark_bn254::Fr::from(word), &mut rng);
+            shares[0].push(x0);
+            shares[1].push(x1);
+            shares[2].push(x2);
+        }
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x) in izip!(test_network.get_party_networks(), [tx1, tx2, tx3], shares) {
+            let circuit = circuit.clone();
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                let res =
+                    bristol_fashion::execute_on_binary_shares(&circuit, &x, &mut rep3).unwrap();
+                tx.send(res)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+
+        let is_result = izip!(result1, result2, result3)
+            .map(|(x1, x2, x3)| rep3::combine_binary_element(x1, x2, x3))
+            .collect_vec();
+        assert_eq!(is_result, should_result);
+    }
 }
 
 mod curve_share {
@@ -3318,11 +4037,10 @@ mod curve_share {
     use std::{sync::mpsc, thread};
     use tests::rep3_network::Rep3TestNetwork;
 
-    #[test]
-    fn rep3_add() {
+    fn add<C: CurveGroup>() {
         let mut rng = thread_rng();
-        let x = ark_bn254::G1Projective::rand(&mut rng);
-        let y = ark_bn254::G1Projective::rand(&mut rng);
+        let x = C::rand(&mut rng);
+        let y = C::rand(&mut rng);
         let x_shares = rep3::share_curve_point(x, &mut rng);
         let y_shares = rep3::share_curve_point(y, &mut rng);
         let should_result = x + y;
@@ -3336,15 +4054,21 @@ mod curve_share {
         let result1 = rx1.recv().unwrap();
         let result2 = rx2.recv().unwrap();
         let result3 = rx3.recv().unwrap();
-        let is_result = rep3::combine_curve_point(result1, result2, result3);
+        let is_result: C = rep3::combine_curve_point(result1, result2, result3);
         assert_eq!(is_result, should_result);
     }
 
     #[test]
-    fn rep3_sub() {
+    fn rep3_add() {
+        add::<ark_bn254::G1Projective>();
+        add::<ark_bls12_377::G1Projective>();
+        add::<ark_secp256k1::Projective>();
+    }
+
+    fn sub<C: CurveGroup>() {
         let mut rng = thread_rng();
-        let x = ark_bn254::G1Projective::rand(&mut rng);
-        let y = ark_bn254::G1Projective::rand(&mut rng);
+        let x = C::rand(&mut rng);
+        let y = C::rand(&mut rng);
         let x_shares = rep3::share_curve_point(x, &mut rng);
         let y_shares = rep3::share_curve_point(y, &mut rng);
         let should_result = x - y;
@@ -3357,15 +4081,21 @@ mod curve_share {
         let result1 = rx1.recv().unwrap();
         let result2 = rx2.recv().unwrap();
         let result3 = rx3.recv().unwrap();
-        let is_result = rep3::combine_curve_point(result1, result2, result3);
+        let is_result: C = rep3::combine_curve_point(result1, result2, result3);
         assert_eq!(is_result, should_result);
     }
 
     #[test]
-    fn rep3_scalar_mul_public_point() {
+    fn rep3_sub() {
+        sub::<ark_bn254::G1Projective>();
+        sub::<ark_bls12_377::G1Projective>();
+        sub::<ark_secp256k1::Projective>();
+    }
+
+    fn scalar_mul_public_point<C: CurveGroup>() {
         let mut rng = thread_rng();
-        let public_point = ark_bn254::G1Projective::rand(&mut rng);
-        let scalar = ark_bn254::Fr::rand(&mut rng);
+        let public_point = C::rand(&mut rng);
+        let scalar = C::ScalarField::rand(&mut rng);
         let scalar_shares = rep3::share_field_element(scalar, &mut rng);
         let should_result = public_point * scalar;
         let (tx1, rx1) = mpsc::channel();
@@ -3381,15 +4111,21 @@ mod curve_share {
         let result1 = rx1.recv().unwrap();
         let result2 = rx2.recv().unwrap();
         let result3 = rx3.recv().unwrap();
-        let is_result = rep3::combine_curve_point(result1, result2, result3);
+        let is_result: C = rep3::combine_curve_point(result1, result2, result3);
         assert_eq!(is_result, should_result);
     }
 
     #[test]
-    fn rep3_scalar_mul_public_scalar() {
+    fn rep3_scalar_mul_public_point() {
+        scalar_mul_public_point::<ark_bn254::G1Projective>();
+        scalar_mul_public_point::<ark_bls12_377::G1Projective>();
+        scalar_mul_public_point::<ark_secp256k1::Projective>();
+    }
+
+    fn scalar_mul_public_scalar<C: CurveGroup>() {
         let mut rng = thread_rng();
-        let point = ark_bn254::G1Projective::rand(&mut rng);
-        let public_scalar = ark_bn254::Fr::rand(&mut rng);
+        let point = C::rand(&mut rng);
+        let public_scalar = C::ScalarField::rand(&mut rng);
         let point_shares = rep3::share_curve_point(point, &mut rng);
         let should_result = point * public_scalar;
         let (tx1, rx1) = mpsc::channel();
@@ -3404,10 +4140,17 @@ mod curve_share {
         let result1 = rx1.recv().unwrap();
         let result2 = rx2.recv().unwrap();
         let result3 = rx3.recv().unwrap();
-        let is_result = rep3::combine_curve_point(result1, result2, result3);
+        let is_result: C = rep3::combine_curve_point(result1, result2, result3);
         assert_eq!(is_result, should_result);
     }
 
+    #[test]
+    fn rep3_scalar_mul_public_scalar() {
+        scalar_mul_public_scalar::<ark_bn254::G1Projective>();
+        scalar_mul_public_scalar::<ark_bls12_377::G1Projective>();
+        scalar_mul_public_scalar::<ark_secp256k1::Projective>();
+    }
+
     #[test]
     fn rep3_a2b_many_single() {
         let mut rng = thread_rng();
@@ -3486,6 +4229,199 @@ mod curve_share {
         );
     }
 
+    #[test]
+    fn rep3_a2b_type_auto_resolve() {
+        use conversion::{A2BProtocol, A2BType, NetworkProfile};
+        let lan = NetworkProfile::lan();
+        let wan = NetworkProfile::wan();
+        let no_latency = NetworkProfile {
+            latency_micros: 0,
+            bandwidth_bytes_per_sec: lan.bandwidth_bytes_per_sec,
+        };
+        for profile in [lan, wan, no_latency] {
+            for batch_size in [0, 1, 1000] {
+                assert_eq!(
+                    A2BType::Direct.resolve(batch_size, 254, &profile),
+                    A2BProtocol::Direct
+                );
+                assert_eq!(
+                    A2BType::Yao.resolve(batch_size, 254, &profile),
+                    A2BProtocol::Yao
+                );
+            }
+        }
+
+        // (profile, batch size, bit size, expected protocol)
+        let table = [
+            // Small batches on low-latency networks
+            (lan, 1, 254, A2BProtocol::Direct),
+            (lan, 2, 254, A2BProtocol::Direct),
+            (lan, 1, 64, A2BProtocol::Direct),
+            (lan, 10, 64, A2BProtocol::Direct),
+            (lan, 1000, 8, A2BProtocol::Direct),
+            (no_latency, 10, 254, A2BProtocol::Direct),
+            // Large batches
+            (lan, 10, 254, A2BProtocol::Yao),
+            (lan, 1000, 254, A2BProtocol::Yao),
+            (lan, 1000, 64, A2BProtocol::Yao),
+            (no_latency, 1000, 254, A2BProtocol::Yao),
+            (no_latency, 1000, 64, A2BProtocol::Yao),
+            // High-latency networks
+            (wan, 1, 254, A2BProtocol::Yao),
+            (wan, 100, 254, A2BProtocol::Yao),
+            (wan, 1, 64, A2BProtocol::Yao),
+            (wan, 100, 64, A2BProtocol::Yao),
+            (wan, 1000, 8, A2BProtocol::Yao),
+            // Very large batches on low-bandwidth networks
+            (wan, 10000, 254, A2BProtocol::Direct),
+        ];
+        for (profile, batch_size, bit_size, expected) in table {
+            assert_eq!(
+                A2BType::Auto.resolve(batch_size, bit_size, &profile),
+                expected,
+                "batch size {batch_size}, bit size {bit_size}, {profile:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn rep3_a2b_many_selector_auto() {
+        let mut rng = thread_rng();
+        let batch_size = 10;
+        let should_batch = (0..batch_size)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let batch_shares = rep3::share_field_elements(&should_batch, &mut rng);
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        let net = Rep3TestNetwork::new();
+
+        for (tx, x, net) in izip!(
+            [tx1, tx2, tx3],
+            batch_shares.into_iter(),
+            net.get_party_networks()
+        ) {
+            thread::spawn(move || {
+                let mut io_context = IoContext::init(net).unwrap();
+                io_context.set_a2b_type(conversion::A2BType::Auto);
+                io_context.measure_network_profile().unwrap();
+                let auto = conversion::a2b_many_selector(&x, &mut io_context).unwrap();
+                let yao = conversion::a2y2b_many(&x, &mut io_context).unwrap();
+                let back = auto
+                    .iter()
+                    .map(|x| conversion::b2a_selector(x, &mut io_context).unwrap())
+                    .collect_vec();
+                tx.send((auto, yao, back))
+            });
+        }
+        let (auto1, yao1, back1) = rx1.recv().unwrap();
+        let (auto2, yao2, back2) = rx2.recv().unwrap();
+        let (auto3, yao3, back3) = rx3.recv().unwrap();
+        let auto_batch = izip!(auto1, auto2, auto3)
+            .map(|(a, b, c)| rep3::combine_binary_element(a, b, c))
+            .collect_vec();
+        let yao_batch = izip!(yao1, yao2, yao3)
+            .map(|(a, b, c)| rep3::combine_binary_element(a, b, c))
+            .collect_vec();
+        let back_batch = rep3::combine_field_elements(&back1, &back2, &back3);
+        assert_eq!(auto_batch, yao_batch);
+        assert_eq!(
+            auto_batch,
+            should_batch
+                .iter()
+                .copied()
+                .map(BigUint::from)
+                .collect_vec()
+        );
+        assert_eq!(back_batch, should_batch);
+    }
+
+    #[test]
+    fn rep3_many_selectors_batched_cost() {
+        use conversion::{A2BProtocol, A2BType, NetworkProfile};
+        const VEC_SIZE: usize = 10;
+        let profile = NetworkProfile::lan();
+        // The cost model has to choose different protocols for the two batch sizes, otherwise this test does not check that Auto runs the protocol it priced
+        assert_eq!(
+            A2BType::Auto.resolve(1, ark_bn254::Fr::MODULUS_BIT_SIZE as usize, &profile),
+            A2BProtocol::Direct
+        );
+        assert_eq!(
+            A2BType::Auto.resolve(VEC_SIZE, ark_bn254::Fr::MODULUS_BIT_SIZE as usize, &profile),
+            A2BProtocol::Yao
+        );
+
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let x_shares = rep3::share_field_elements(&x, &mut rng);
+        let test_network = Rep3TestNetwork::default();
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (net, tx, x) in izip!(test_network.get_party_networks(), [tx1, tx2, tx3], x_shares) {
+            thread::spawn(move || {
+                let mut io_context = IoContext::init(net).unwrap();
+                io_context.set_network_profile(profile);
+                // (a2b type, batch size, sent messages, converted values)
+                let mut results = Vec::new();
+                for a2b_type in [A2BType::Direct, A2BType::Yao, A2BType::Auto] {
+                    io_context.set_a2b_type(a2b_type);
+                    for batch_size in [1, VEC_SIZE] {
+                        let before = io_context.network.num_sent_messages();
+                        let binary =
+                            conversion::a2b_many_selector(&x[..batch_size], &mut io_context)
+                                .unwrap();
+                        let arithmetic =
+                            conversion::b2a_many_selector(&binary, &mut io_context).unwrap();
+                        let sent = io_context.network.num_sent_messages() - before;
+                        results.push((a2b_type, batch_size, sent, arithmetic));
+                    }
+                }
+                tx.send(results)
+            });
+        }
+        let results1 = rx1.recv().unwrap();
+        let results2 = rx2.recv().unwrap();
+        let results3 = rx3.recv().unwrap();
+        let sent = |results: &Vec<(A2BType, usize, usize, _)>, a2b_type, batch_size| {
+            results
+                .iter()
+                .find(|(t, b, _, _)| *t == a2b_type && *b == batch_size)
+                .map(|(_, _, sent, _)| *sent)
+                .unwrap()
+        };
+        for results in [&results1, &results2, &results3] {
+            // The batched conversions need the same number of messages, i.e., rounds, for any batch size
+            for a2b_type in [A2BType::Direct, A2BType::Yao] {
+                assert_eq!(
+                    sent(results, a2b_type, 1),
+                    sent(results, a2b_type, VEC_SIZE),
+                    "{a2b_type:?}"
+                );
+            }
+            assert_ne!(
+                sent(results, A2BType::Direct, VEC_SIZE),
+                sent(results, A2BType::Yao, VEC_SIZE)
+            );
+            // Auto runs the batched protocol it chose with the cost model
+            assert_eq!(
+                sent(results, A2BType::Auto, 1),
+                sent(results, A2BType::Direct, 1)
+            );
+            assert_eq!(
+                sent(results, A2BType::Auto, VEC_SIZE),
+                sent(results, A2BType::Yao, VEC_SIZE)
+            );
+        }
+        for (r1, r2, r3) in izip!(results1, results2, results3) {
+            let is_result = rep3::combine_field_elements(&r1.3, &r2.3, &r3.3);
+            assert_eq!(is_result, x[..r1.1]);
+        }
+    }
+
     fn to_fieldshares<C: CurveGroup>(point: C)
     where
         C::BaseField: PrimeField,
@@ -3542,6 +4478,22 @@ mod curve_share {
         }
     }
 
+    #[test]
+    fn bls12_377_to_fieldshares() {
+        for _ in 0..10 {
+            to_fieldshares(ark_bls12_377::G1Projective::zero());
+            to_fieldshares(ark_bls12_377::G1Projective::rand(&mut thread_rng()));
+        }
+    }
+
+    #[test]
+    fn secp256k1_to_fieldshares() {
+        for _ in 0..10 {
+            to_fieldshares(ark_secp256k1::Projective::zero());
+            to_fieldshares(ark_secp256k1::Projective::rand(&mut thread_rng()));
+        }
+    }
+
     fn from_fieldshares<C: CurveGroup>(point: C)
     where
         C::BaseField: PrimeField,
@@ -3597,6 +4549,22 @@ mod curve_share {
         }
     }
 
+    #[test]
+    fn bls12_377_from_fieldshares() {
+        for _ in 0..10 {
+            from_fieldshares(ark_bls12_377::G1Projective::zero());
+            from_fieldshares(ark_bls12_377::G1Projective::rand(&mut thread_rng()));
+        }
+    }
+
+    #[test]
+    fn secp256k1_from_fieldshares() {
+        for _ in 0..10 {
+            from_fieldshares(ark_secp256k1::Projective::zero());
+            from_fieldshares(ark_secp256k1::Projective::rand(&mut thread_rng()));
+        }
+    }
+
     fn point_is_zero<C: CurveGroup>(point: C)
     where
         C::BaseField: PrimeField,
@@ -3651,4 +4619,216 @@ mod curve_share {
             point_is_zero(ark_grumpkin::Projective::rand(&mut thread_rng()));
         }
     }
+
+    #[test]
+    fn bls12_377_point_is_zero() {
+        for _ in 0..10 {
+            point_is_zero(ark_bls12_377::G1Projective::zero());
+            point_is_zero(ark_bls12_377::G1Projective::rand(&mut thread_rng()));
+        }
+    }
+
+    #[test]
+    fn secp256k1_point_is_zero() {
+        for _ in 0..10 {
+            point_is_zero(ark_secp256k1::Projective::zero());
+            point_is_zero(ark_secp256k1::Projective::rand(&mut thread_rng()));
+        }
+    }
+}
+
+mod bitvec_share {
+    use itertools::izip;
+    use mpc_core::protocols::rep3::{self, bitvec, network::IoContext, Rep3BitVecShare};
+    use num_bigint::BigUint;
+    use rand::thread_rng;
+    use rand::Rng;
+    use std::sync::mpsc;
+    use std::thread;
+    use tests::rep3_network::Rep3TestNetwork;
+
+    const WIDTH: usize = 100;
+
+    fn random_value<R: Rng>(rng: &mut R, len: usize) -> BigUint {
+        let mask = (BigUint::from(1u64) << len) - BigUint::from(1u64);
+        BigUint::from_bytes_le(&rng.gen::<[u8; 16]>()) & mask
+    }
+
+    #[test]
+    fn rep3_bitvec_and_vec() {
+        const VEC_SIZE: usize = 10;
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| random_value(&mut rng, WIDTH))
+            .collect::<Vec<_>>();
+        let y = (0..VEC_SIZE)
+            .map(|_| random_value(&mut rng, WIDTH))
+            .collect::<Vec<_>>();
+        let mut x_shares = [Vec::new(), Vec::new(), Vec::new()];
+        let mut y_shares = [Vec::new(), Vec::new(), Vec::new()];
+        for (x, y) in x.iter().zip(y.iter()) {
+            for (shares, share) in x_shares
+                .iter_mut()
+                .zip(rep3::share_bitvec(x, WIDTH, &mut rng))
+            {
+                shares.push(share);
+            }
+            for (shares, share) in y_shares
+                .iter_mut()
+                .zip(rep3::share_bitvec(y, WIDTH, &mut rng))
+            {
+                shares.push(share);
+            }
+        }
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (net, tx, x, y) in izip!(
+            test_network.get_party_networks(),
+            [tx1, tx2, tx3],
+            x_shares,
+            y_shares
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                let and = bitvec::and_vec(&x, &y, &mut rep3).unwrap();
+                let or = bitvec::or_vec(&x, &y, &mut rep3).unwrap();
+                tx.send((and, or))
+            });
+        }
+        let (and1, or1) = rx1.recv().unwrap();
+        let (and2, or2) = rx2.recv().unwrap();
+        let (and3, or3) = rx3.recv().unwrap();
+        for (x, y, and1, and2, and3, or1, or2, or3) in
+            izip!(&x, &y, and1, and2, and3, or1, or2, or3)
+        {
+            assert_eq!(rep3::combine_bitvec(and1, and2, and3), x & y);
+            assert_eq!(rep3::combine_bitvec(or1, or2, or3), x | y);
+        }
+    }
+
+    #[test]
+    fn rep3_bitvec_or_tree() {
+        const VEC_SIZE: usize = 7;
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| random_value(&mut rng, WIDTH))
+            .collect::<Vec<_>>();
+        let should_result = x.iter().fold(BigUint::ZERO, |acc, x| acc | x);
+        let mut x_shares = [Vec::new(), Vec::new(), Vec::new()];
+        for x in x.iter() {
+            for (shares, share) in x_shares
+                .iter_mut()
+                .zip(rep3::share_bitvec(x, WIDTH, &mut rng))
+            {
+                shares.push(share);
+            }
+        }
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (net, tx, x) in izip!(test_network.get_party_networks(), [tx1, tx2, tx3], x_shares) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                tx.send(bitvec::or_tree(x, &mut rep3).unwrap())
+            });
+        }
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_bitvec(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_bitvec_is_zero_many() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = [
+            BigUint::ZERO,
+            random_value(&mut rng, WIDTH) | BigUint::from(1u64),
+            BigUint::from(1u64) << (WIDTH - 1),
+        ];
+        let widths = [WIDTH, 37, WIDTH];
+        let mut x_shares = [Vec::new(), Vec::new(), Vec::new()];
+        for (x, width) in x.iter().zip(widths) {
+            for (shares, share) in x_shares
+                .iter_mut()
+                .zip(rep3::share_bitvec(x, width, &mut rng))
+            {
+                shares.push(share);
+            }
+        }
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (net, tx, x) in izip!(test_network.get_party_networks(), [tx1, tx2, tx3], x_shares) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                tx.send(bitvec::is_zero_many(&x, &mut rep3).unwrap())
+            });
+        }
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = izip!(result1, result2, result3)
+            .map(|(r1, r2, r3)| {
+                assert_eq!(r1.len(), 1);
+                rep3::combine_bitvec(r1, r2, r3)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            is_result,
+            vec![BigUint::from(1u64), BigUint::ZERO, BigUint::ZERO]
+        );
+    }
+
+    #[test]
+    fn rep3_bitvec_cmux_and_open() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let c = rng.gen::<bool>();
+        let x_t = random_value(&mut rng, WIDTH);
+        let x_f = random_value(&mut rng, WIDTH);
+        let c_shares = rep3::share_bitvec(&BigUint::from(c as u64), 1, &mut rng);
+        let x_t_shares = rep3::share_bitvec(&x_t, WIDTH, &mut rng);
+        let x_f_shares = rep3::share_bitvec(&x_f, WIDTH, &mut rng);
+        let should_result = if c { x_t } else { x_f };
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (net, tx, c, x_t, x_f) in izip!(
+            test_network.get_party_networks(),
+            [tx1, tx2, tx3],
+            c_shares,
+            x_t_shares,
+            x_f_shares
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                let res: Rep3BitVecShare = bitvec::cmux(&c, &x_t, &x_f, &mut rep3).unwrap();
+                tx.send(bitvec::open(&res, &mut rep3).unwrap())
+            });
+        }
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        assert_eq!(result1, result2);
+        assert_eq!(result2, result3);
+        assert_eq!(words_to_biguint(&result1), should_result);
+    }
+
+    fn words_to_biguint(words: &[u64]) -> BigUint {
+        words.iter().rev().fold(BigUint::ZERO, |acc, word| {
+            (acc << 64) | BigUint::from(*word)
+        })
+    }
 }
@@ -20,6 +20,7 @@ mod ring_share {
     use mpc_core::protocols::rep3_ring::ring::bit::Bit;
     use mpc_core::protocols::rep3_ring::ring::int_ring::IntRing2k;
     use mpc_core::protocols::rep3_ring::ring::ring_impl::RingElement;
+    use mpc_core::protocols::rep3_ring::signed;
     use mpc_core::protocols::rep3_ring::yao;
     use num_bigint::BigUint;
     use num_traits::{AsPrimitive, One, Zero};
@@ -1461,6 +1462,181 @@ mod ring_share {
         );
     }
 
+    #[test]
+    fn rep3_signed_cmp() {
+        let mut rng = thread_rng();
+        let values = [i32::MIN, -1000, -1, 0, 1, 1000, i32::MAX];
+        for (x, y) in values.into_iter().cartesian_product(values) {
+            let test_network = Rep3TestNetwork::default();
+            let x_shares = rep3_ring::share_signed_ring_element::<u32, _>(x, &mut rng);
+            let y_shares = rep3_ring::share_signed_ring_element::<u32, _>(y, &mut rng);
+            let should_result = [x < y, x <= y, x > y, x >= y, x < y, x < 0];
+            let (tx1, rx1) = mpsc::channel();
+            let (tx2, rx2) = mpsc::channel();
+            let (tx3, rx3) = mpsc::channel();
+            for (net, tx, x_, y_) in izip!(
+                test_network.get_party_networks(),
+                [tx1, tx2, tx3],
+                x_shares,
+                y_shares
+            ) {
+                thread::spawn(move || {
+                    let mut rep3 = IoContext::init(net).unwrap();
+                    let lt = signed::lt(x_, y_, &mut rep3).unwrap();
+                    let le = signed::le(x_, y_, &mut rep3).unwrap();
+                    let gt = signed::gt(x_, y_, &mut rep3).unwrap();
+                    let ge = signed::ge(x_, y_, &mut rep3).unwrap();
+                    let lt_public = signed::lt_public(x_, y, &mut rep3).unwrap();
+                    let is_negative = signed::is_negative(x_, &mut rep3).unwrap();
+                    tx.send([lt, le, gt, ge, lt_public, is_negative])
+                });
+            }
+            let results1 = rx1.recv().unwrap();
+            let results2 = rx2.recv().unwrap();
+            let results3 = rx3.recv().unwrap();
+            for (a, b, c, should) in izip!(results1, results2, results3, should_result) {
+                let is_result = rep3_ring::combine_ring_element(a, b, c);
+                assert_eq!(is_result.0.convert(), should, "{x} {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn rep3_signed_arithmetic() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = rng.gen_range(-1000..1000i32);
+        let y = rng.gen_range(-1000..1000i32);
+        let x_shares = rep3_ring::share_signed_ring_element::<u32, _>(x, &mut rng);
+        let y_shares = rep3_ring::share_signed_ring_element::<u32, _>(y, &mut rng);
+        let should_result = [x + y, x - y, -x, x * y, x * -3];
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (net, tx, x_, y_) in izip!(
+            test_network.get_party_networks(),
+            [tx1, tx2, tx3],
+            x_shares,
+            y_shares
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                let add = signed::add(x_, y_);
+                let sub = signed::sub(x_, y_);
+                let neg = signed::neg(x_);
+                let mul = signed::mul(x_, y_, &mut rep3).unwrap();
+                let mul_public = signed::mul_public(x_, -3);
+                tx.send([add, sub, neg, mul, mul_public])
+            });
+        }
+        let results1 = rx1.recv().unwrap();
+        let results2 = rx2.recv().unwrap();
+        let results3 = rx3.recv().unwrap();
+        for (a, b, c, should) in izip!(results1, results2, results3, should_result) {
+            let is_result = rep3_ring::combine_signed_ring_element(a, b, c);
+            assert_eq!(is_result, should, "{x} {y}");
+        }
+    }
+
+    #[test]
+    fn rep3_signed_arithmetic_shift_right() {
+        const VEC_SIZE: usize = 10;
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE).map(|_| rng.gen::<i64>()).collect_vec();
+        let shift = rng.gen_range(0..64);
+        let x_shares = x
+            .iter()
+            .map(|x| rep3_ring::share_signed_ring_element::<u64, _>(*x, &mut rng))
+            .collect_vec();
+        let should_result = x.iter().map(|x| x >> shift).collect_vec();
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (i, (net, tx)) in izip!(test_network.get_party_networks(), [tx1, tx2, tx3]).enumerate()
+        {
+            let x = x_shares.iter().map(|x| x[i]).collect_vec();
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                let shifted =
+                    signed::arithmetic_shift_right_public_many(&x, shift, &mut rep3).unwrap();
+                tx.send(shifted)
+            });
+        }
+        let results1 = rx1.recv().unwrap();
+        let results2 = rx2.recv().unwrap();
+        let results3 = rx3.recv().unwrap();
+        for (a, b, c, should) in izip!(results1, results2, results3, should_result) {
+            let is_result = rep3_ring::combine_signed_ring_element(a, b, c);
+            assert_eq!(is_result, should);
+        }
+    }
+
+    #[test]
+    fn rep3_signed_upcast_a2b() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = rng.gen::<i8>();
+        let x_shares = rep3_ring::share_signed_ring_element::<u8, _>(x, &mut rng);
+        let should_result = [x as i64, x as i64];
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (net, tx, x) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter(),
+        ) {
+            thread::spawn(move || {
+                let mut ctx = IoContext::init(net).unwrap();
+                let y = casts::signed_cast_a2b::<u8, u64, _>(x, &mut ctx).unwrap();
+                let z = casts::signed_cast_gc::<u8, u64, _>(x, &mut ctx).unwrap();
+                tx.send([y, z])
+            });
+        }
+        let results1 = rx1.recv().unwrap();
+        let results2 = rx2.recv().unwrap();
+        let results3 = rx3.recv().unwrap();
+        for (a, b, c, should) in izip!(results1, results2, results3, should_result) {
+            let is_result = rep3_ring::combine_signed_ring_element(a, b, c);
+            assert_eq!(is_result, should);
+        }
+    }
+
+    #[test]
+    fn rep3_signed_ring_to_field_a2b() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = rng.gen::<i32>();
+        let x_shares = rep3_ring::share_signed_ring_element::<u32, _>(x, &mut rng);
+        let should_result = if x < 0 {
+            -ark_bn254::Fr::from(x.unsigned_abs())
+        } else {
+            ark_bn254::Fr::from(x as u32)
+        };
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (net, tx, x) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter(),
+        ) {
+            thread::spawn(move || {
+                let mut ctx = IoContext::init(net).unwrap();
+                let y =
+                    casts::signed_ring_to_field_a2b::<_, ark_bn254::Fr, _>(x, &mut ctx).unwrap();
+                tx.send(y)
+            });
+        }
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_element(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
     fn rep3_field_to_ring_cast_gc_t<T: IntRing2k>()
     where
         Standard: Distribution<T>,
@@ -1548,6 +1724,148 @@ mod ring_share {
         apply_to_all!(rep3_ring_to_field_cast_gc_t, [Bit, u8, u16, u32, u64, u128]);
     }
 
+    fn rep3_many_selectors_t<T: IntRing2k>()
+    where
+        Standard: Distribution<T>,
+    {
+        const VEC_SIZE: usize = 10;
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| rng.gen::<RingElement<T>>())
+            .collect::<Vec<_>>();
+        let x_shares = rep3_ring::share_ring_elements(&x, &mut rng);
+        let should_field = x
+            .iter()
+            .map(|x| ark_bn254::Fr::from(T::cast_to_biguint(&x.0)))
+            .collect::<Vec<_>>();
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (net, tx, x) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter(),
+        ) {
+            thread::spawn(move || {
+                let mut ctx = IoContext::init(net).unwrap();
+                let mut results = Vec::new();
+                for a2b_type in [
+                    rep3::conversion::A2BType::Direct,
+                    rep3::conversion::A2BType::Yao,
+                    rep3::conversion::A2BType::Auto,
+                ] {
+                    ctx.set_a2b_type(a2b_type);
+                    let binary = conversion::a2b_many_selector(&x, &mut ctx).unwrap();
+                    let arithmetic = conversion::b2a_many_selector(&binary, &mut ctx).unwrap();
+                    let field =
+                        casts::ring_to_field_many_selector::<_, ark_bn254::Fr, _>(&x, &mut ctx)
+                            .unwrap();
+                    let ring =
+                        casts::field_to_ring_many_selector::<_, T, _>(&field, &mut ctx).unwrap();
+                    results.push((binary, arithmetic, field, ring));
+                }
+                tx.send(results)
+            });
+        }
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        for (r1, r2, r3) in izip!(result1, result2, result3) {
+            let binary = izip!(r1.0, r2.0, r3.0)
+                .map(|(a, b, c)| rep3_ring::combine_ring_element_binary(a, b, c))
+                .collect::<Vec<_>>();
+            assert_eq!(binary, x);
+            assert_eq!(rep3_ring::combine_ring_elements(&r1.1, &r2.1, &r3.1), x);
+            assert_eq!(
+                rep3::combine_field_elements(&r1.2, &r2.2, &r3.2),
+                should_field
+            );
+            assert_eq!(rep3_ring::combine_ring_elements(&r1.3, &r2.3, &r3.3), x);
+        }
+    }
+
+    #[test]
+    fn rep3_many_selectors_batched_cost() {
+        use rep3::conversion::{A2BProtocol, A2BType, NetworkProfile};
+        const VEC_SIZE: usize = 1000;
+        let profile = NetworkProfile::lan();
+        let expected = A2BType::Auto.resolve(VEC_SIZE, u64::K, &profile);
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| rng.gen::<RingElement<u64>>())
+            .collect::<Vec<_>>();
+        let x_shares = rep3_ring::share_ring_elements(&x, &mut rng);
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+        for (net, tx, x) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter(),
+        ) {
+            thread::spawn(move || {
+                let mut ctx = IoContext::init(net).unwrap();
+                ctx.set_network_profile(profile);
+                // (a2b type, batch size, sent messages, converted values)
+                let mut results = Vec::new();
+                for a2b_type in [A2BType::Direct, A2BType::Yao, A2BType::Auto] {
+                    ctx.set_a2b_type(a2b_type);
+                    for batch_size in [1, VEC_SIZE] {
+                        let before = ctx.network.num_sent_messages();
+                        let binary =
+                            conversion::a2b_many_selector(&x[..batch_size], &mut ctx).unwrap();
+                        let arithmetic = conversion::b2a_many_selector(&binary, &mut ctx).unwrap();
+                        let sent = ctx.network.num_sent_messages() - before;
+                        results.push((a2b_type, batch_size, sent, arithmetic));
+                    }
+                }
+                tx.send(results)
+            });
+        }
+        let results1 = rx1.recv().unwrap();
+        let results2 = rx2.recv().unwrap();
+        let results3 = rx3.recv().unwrap();
+        let sent = |results: &Vec<(A2BType, usize, usize, _)>, a2b_type, batch_size| {
+            results
+                .iter()
+                .find(|(t, b, _, _)| *t == a2b_type && *b == batch_size)
+                .map(|(_, _, sent, _)| *sent)
+                .unwrap()
+        };
+        let expected = match expected {
+            A2BProtocol::Direct => A2BType::Direct,
+            A2BProtocol::Yao => A2BType::Yao,
+        };
+        for results in [&results1, &results2, &results3] {
+            // The batched conversions need the same number of messages, i.e., rounds, for any batch size
+            for a2b_type in [A2BType::Direct, A2BType::Yao] {
+                assert_eq!(
+                    sent(results, a2b_type, 1),
+                    sent(results, a2b_type, VEC_SIZE),
+                    "{a2b_type:?}"
+                );
+            }
+            // Auto runs the batched protocol it chose with the cost model
+            assert_eq!(
+                sent(results, A2BType::Auto, VEC_SIZE),
+                sent(results, expected, VEC_SIZE)
+            );
+        }
+        for (r1, r2, r3) in izip!(results1, results2, results3) {
+            let is_result = rep3_ring::combine_ring_elements(&r1.3, &r2.3, &r3.3);
+            assert_eq!(is_result, x[..r1.1]);
+        }
+    }
+
+    #[test]
+    fn rep3_many_selectors() {
+        apply_to_all!(rep3_many_selectors_t, [Bit, u8, u16, u32, u64, u128]);
+    }
+
     fn rep3_ring_upcast_gc_t<T, U>()
     where
         Standard: Distribution<T> + Distribution<U>,
@@ -1746,6 +2064,68 @@ mod ring_share {
         apply_to_all!(rep3_div_power_2_via_yao_t, [Bit, u8, u16, u32, u64, u128]);
     }
 
+    fn rep3_lt_public_and_range_bitlen_t<T: IntRing2k>()
+    where
+        Standard: Distribution<T>,
+    {
+        const VEC_SIZE: usize = 10;
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let bitlen: usize = rng.gen_range(1..=T::K);
+        let x = (0..VEC_SIZE)
+            .map(|_| rng.gen::<RingElement<T>>() >> (T::K - bitlen))
+            .collect_vec();
+        let x_shares = rep3_ring::share_ring_elements(&x, &mut rng);
+        let bound = rng.gen::<RingElement<T>>() >> (T::K - bitlen);
+        let a = rng.gen::<RingElement<T>>() >> (T::K - bitlen);
+        let b = rng.gen::<RingElement<T>>() >> (T::K - bitlen);
+        let (lower, upper) = if a < b { (a, b) } else { (b, a) };
+
+        let mut should_result = Vec::with_capacity(2 * VEC_SIZE);
+        for x in x.iter() {
+            should_result.push(RingElement(Bit::new(*x < bound)));
+        }
+        for x in x.iter() {
+            should_result.push(RingElement(Bit::new(lower <= *x && *x <= upper)));
+        }
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let mut res =
+                    arithmetic::lt_public_bitlen_many(&x, bound, bitlen, &mut rep3).unwrap();
+                res.extend(
+                    arithmetic::is_in_range_many(&x, lower, upper, bitlen, &mut rep3).unwrap(),
+                );
+                tx.send(res)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3_ring::combine_ring_elements(&result1, &result2, &result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_lt_public_and_range_bitlen() {
+        apply_to_all!(
+            rep3_lt_public_and_range_bitlen_t,
+            [Bit, u8, u16, u32, u64, u128]
+        );
+    }
+
     fn rep3_bin_div_via_yao_t<T: IntRing2k>()
     where
         Standard: Distribution<T>,
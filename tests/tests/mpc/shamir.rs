@@ -3,11 +3,18 @@ mod field_share {
     use ark_std::{UniformRand, Zero};
     use itertools::{izip, Itertools};
     use mpc_core::{
-        gadgets::poseidon2::Poseidon2,
-        protocols::shamir::{self, arithmetic, ShamirPreprocessing},
+        gadgets::{
+            poseidon::{Poseidon, POSEIDON_CIRCOM_BN254_T6_PARAMS},
+            poseidon2::Poseidon2,
+        },
+        protocols::shamir::{self, arithmetic, ShamirMulMode, ShamirPreprocessing, ShamirProtocol},
     };
     use rand::thread_rng;
-    use std::{str::FromStr, sync::mpsc, thread};
+    use std::{
+        str::FromStr,
+        sync::{mpsc, Arc},
+        thread,
+    };
     use tests::shamir_network::ShamirTestNetwork;
 
     fn shamir_add_inner(num_parties: usize, threshold: usize) {
@@ -596,6 +603,353 @@ mod field_share {
         shamir_poseidon2_gadget_kat1_precomp_packed_inner(10, 4);
     }
 
+    fn shamir_poseidon_circom_gadget_precomp_packed_inner(num_parties: usize, threshold: usize) {
+        const NUM_POSEIDON: usize = 10;
+
+        let test_network = ShamirTestNetwork::new(num_parties);
+        let mut rng = thread_rng();
+        let mut input = vec![ark_bn254::Fr::default(); NUM_POSEIDON * 6];
+        for input in input.chunks_exact_mut(6) {
+            for (i, input) in input.iter_mut().enumerate() {
+                *input = ark_bn254::Fr::from(i as u64);
+            }
+        }
+
+        let input_shares = shamir::share_field_elements(&input, threshold, num_parties, &mut rng);
+
+        let poseidon = Poseidon::new(Arc::clone(&POSEIDON_CIRCOM_BN254_T6_PARAMS));
+        let expected =
+            poseidon.permutation(&std::array::from_fn(|i| ark_bn254::Fr::from(i as u64)));
+        // circomlib's Poseidon(5) on the inputs [1, 2, 3, 4, 5]
+        assert_eq!(
+            expected[0],
+            ark_bn254::Fr::from_str(
+                "6183221330272524995739186171720101788151706631170188140075976616310159254464"
+            )
+            .unwrap()
+        );
+
+        let mut tx = Vec::with_capacity(num_parties);
+        let mut rx = Vec::with_capacity(num_parties);
+        for _ in 0..num_parties {
+            let (t, r) = mpsc::channel();
+            tx.push(t);
+            rx.push(r);
+        }
+
+        for (net, tx, x) in izip!(test_network.get_party_networks(), tx, input_shares) {
+            thread::spawn(move || {
+                let poseidon = Poseidon::new(Arc::clone(&POSEIDON_CIRCOM_BN254_T6_PARAMS));
+                let mut shamir = ShamirPreprocessing::new(
+                    threshold,
+                    net,
+                    poseidon.rand_required(NUM_POSEIDON, true),
+                )
+                .unwrap()
+                .into();
+                let mut precomp = poseidon
+                    .precompute_shamir(NUM_POSEIDON, &mut shamir)
+                    .unwrap();
+                let output = poseidon
+                    .shamir_permutation_with_precomputation_packed(&x, &mut precomp, &mut shamir)
+                    .unwrap();
+
+                tx.send(output)
+            });
+        }
+
+        let mut results = Vec::with_capacity(num_parties);
+        for r in rx {
+            results.push(r.recv().unwrap());
+        }
+
+        let is_result =
+            shamir::combine_field_elements(&results, &(1..=num_parties).collect_vec(), threshold)
+                .unwrap();
+
+        for r in is_result.chunks_exact(6) {
+            assert_eq!(r, expected);
+        }
+    }
+
+    #[test]
+    fn shamir_poseidon_circom_gadget_precomp_packed() {
+        shamir_poseidon_circom_gadget_precomp_packed_inner(3, 1);
+        shamir_poseidon_circom_gadget_precomp_packed_inner(10, 4);
+    }
+
+    fn shamir_open_robust_inner(num_parties: usize, threshold: usize, corrupted: &[usize]) {
+        const VEC_SIZE: usize = 10;
+
+        let test_network = ShamirTestNetwork::new(num_parties);
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let mut x_shares = shamir::share_field_elements(&x, threshold, num_parties, &mut rng);
+        for party in corrupted {
+            x_shares[*party][0] += ark_bn254::Fr::rand(&mut rng);
+        }
+
+        let mut tx = Vec::with_capacity(num_parties);
+        let mut rx = Vec::with_capacity(num_parties);
+        for _ in 0..num_parties {
+            let (t, r) = mpsc::channel();
+            tx.push(t);
+            rx.push(r);
+        }
+
+        for (net, tx, x) in izip!(test_network.get_party_networks(), tx, x_shares) {
+            thread::spawn(move || {
+                let mut shamir = ShamirPreprocessing::new(threshold, net, 0).unwrap().into();
+                tx.send(arithmetic::open_vec_robust(&x, &mut shamir).unwrap())
+            });
+        }
+
+        for r in rx {
+            let (is_result, inconsistent) = r.recv().unwrap();
+            assert_eq!(is_result, x);
+            assert_eq!(inconsistent, corrupted);
+        }
+    }
+
+    #[test]
+    fn shamir_open_robust() {
+        shamir_open_robust_inner(3, 1, &[]);
+        shamir_open_robust_inner(7, 2, &[]);
+        shamir_open_robust_inner(7, 2, &[1, 4]);
+        shamir_open_robust_inner(10, 4, &[9]);
+    }
+
+    #[test]
+    fn shamir_open_robust_detects_uncorrectable() {
+        const NUM_PARTIES: usize = 3;
+        const THRESHOLD: usize = 1;
+
+        let test_network = ShamirTestNetwork::new(NUM_PARTIES);
+        let mut rng = thread_rng();
+        let x = ark_bn254::Fr::rand(&mut rng);
+        let mut x_shares = shamir::share_field_element(x, THRESHOLD, NUM_PARTIES, &mut rng);
+        x_shares[2] += ark_bn254::Fr::rand(&mut rng);
+
+        let mut tx = Vec::with_capacity(NUM_PARTIES);
+        let mut rx = Vec::with_capacity(NUM_PARTIES);
+        for _ in 0..NUM_PARTIES {
+            let (t, r) = mpsc::channel();
+            tx.push(t);
+            rx.push(r);
+        }
+
+        for (net, tx, x) in izip!(test_network.get_party_networks(), tx, x_shares) {
+            thread::spawn(move || {
+                let mut shamir = ShamirPreprocessing::new(THRESHOLD, net, 0).unwrap().into();
+                tx.send(arithmetic::open_robust(x, &mut shamir).is_err())
+            });
+        }
+
+        for r in rx {
+            assert!(r.recv().unwrap());
+        }
+    }
+
+    fn shamir_mul_degree_reduce_check_inner(
+        num_parties: usize,
+        threshold: usize,
+        corrupted: Option<usize>,
+    ) {
+        let test_network = ShamirTestNetwork::new(num_parties);
+        let mut rng = thread_rng();
+        let x = ark_bn254::Fr::rand(&mut rng);
+        let y = ark_bn254::Fr::rand(&mut rng);
+        let mut x_shares = shamir::share_field_element(x, threshold, num_parties, &mut rng);
+        let y_shares = shamir::share_field_element(y, threshold, num_parties, &mut rng);
+        if let Some(party) = corrupted {
+            x_shares[party] += ark_bn254::Fr::rand(&mut rng);
+        }
+        let should_result = x * y;
+
+        let mut tx = Vec::with_capacity(num_parties);
+        let mut rx = Vec::with_capacity(num_parties);
+        for _ in 0..num_parties {
+            let (t, r) = mpsc::channel();
+            tx.push(t);
+            rx.push(r);
+        }
+
+        for (net, tx, x, y) in izip!(test_network.get_party_networks(), tx, x_shares, y_shares) {
+            thread::spawn(move || {
+                let mut shamir: ShamirProtocol<_, _> =
+                    ShamirPreprocessing::new(threshold, net, 1).unwrap().into();
+                shamir.set_degree_reduce_check(true);
+                tx.send(arithmetic::mul(x, y, &mut shamir))
+            });
+        }
+
+        match corrupted {
+            None => {
+                let mut results = Vec::with_capacity(num_parties);
+                for r in rx {
+                    results.push(r.recv().unwrap().unwrap());
+                }
+                let is_result = shamir::combine_field_element(
+                    &results,
+                    &(1..=num_parties).collect_vec(),
+                    threshold,
+                )
+                .unwrap();
+                assert_eq!(is_result, should_result);
+            }
+            Some(party) => {
+                // The king detects the inconsistent share and aborts
+                let err = rx[0].recv().unwrap().unwrap_err();
+                assert!(err.to_string().contains(&format!("[{party}]")));
+            }
+        }
+    }
+
+    #[test]
+    fn shamir_mul_degree_reduce_check() {
+        shamir_mul_degree_reduce_check_inner(3, 1, None);
+        shamir_mul_degree_reduce_check_inner(7, 2, None);
+        shamir_mul_degree_reduce_check_inner(7, 2, Some(5));
+    }
+
+    fn shamir_mul_vec_rotating_king_inner(
+        num_parties: usize,
+        threshold: usize,
+        len: usize,
+        mul_mode: ShamirMulMode,
+    ) {
+        let test_network = ShamirTestNetwork::new(num_parties);
+        let mut rng = thread_rng();
+        let x = (0..len)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let y = (0..len)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let x_shares = shamir::share_field_elements(&x, threshold, num_parties, &mut rng);
+        let y_shares = shamir::share_field_elements(&y, threshold, num_parties, &mut rng);
+        let should_result = izip!(x, y).map(|(x, y)| x * y).collect_vec();
+
+        let mut tx = Vec::with_capacity(num_parties);
+        let mut rx = Vec::with_capacity(num_parties);
+        for _ in 0..num_parties {
+            let (t, r) = mpsc::channel();
+            tx.push(t);
+            rx.push(r);
+        }
+
+        for (net, tx, x, y) in izip!(test_network.get_party_networks(), tx, x_shares, y_shares) {
+            thread::spawn(move || {
+                let mut preprocessing = ShamirPreprocessing::new(threshold, net, len).unwrap();
+                preprocessing.set_mul_mode(mul_mode);
+                let mut shamir: ShamirProtocol<_, _> = preprocessing.into();
+                tx.send(arithmetic::mul_vec(&x, &y, &mut shamir).unwrap())
+            });
+        }
+
+        let mut results = Vec::with_capacity(num_parties);
+        for r in rx {
+            results.push(r.recv().unwrap());
+        }
+
+        let is_result =
+            shamir::combine_field_elements(&results, &(1..=num_parties).collect_vec(), threshold)
+                .unwrap();
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn shamir_mul_vec_rotating_king() {
+        shamir_mul_vec_rotating_king_inner(3, 1, 10, ShamirMulMode::RotatingKing);
+        shamir_mul_vec_rotating_king_inner(7, 2, 20, ShamirMulMode::RotatingKing);
+        // Fewer elements than parties, some kings are idle
+        shamir_mul_vec_rotating_king_inner(10, 4, 3, ShamirMulMode::RotatingKing);
+    }
+
+    #[test]
+    fn shamir_mul_vec_atlas() {
+        shamir_mul_vec_rotating_king_inner(3, 1, 10, ShamirMulMode::Atlas);
+        shamir_mul_vec_rotating_king_inner(7, 2, 20, ShamirMulMode::Atlas);
+        // Fewer elements than uniformly random double sharings, nothing is expanded
+        shamir_mul_vec_rotating_king_inner(10, 4, 3, ShamirMulMode::Atlas);
+        // The last chunk is shorter than n, but longer than t + 1
+        shamir_mul_vec_rotating_king_inner(10, 2, 17, ShamirMulMode::Atlas);
+    }
+
+    // Returns the bytes sent by all parties for the preprocessing and the multiplications
+    fn shamir_mul_vec_sent_bytes(
+        num_parties: usize,
+        threshold: usize,
+        len: usize,
+        mul_mode: ShamirMulMode,
+    ) -> usize {
+        let test_network = ShamirTestNetwork::new(num_parties);
+        let mut rng = thread_rng();
+        let x = (0..len)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let y = (0..len)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let x_shares = shamir::share_field_elements(&x, threshold, num_parties, &mut rng);
+        let y_shares = shamir::share_field_elements(&y, threshold, num_parties, &mut rng);
+        let should_result = izip!(x, y).map(|(x, y)| x * y).collect_vec();
+
+        let mut tx = Vec::with_capacity(num_parties);
+        let mut rx = Vec::with_capacity(num_parties);
+        for _ in 0..num_parties {
+            let (t, r) = mpsc::channel();
+            tx.push(t);
+            rx.push(r);
+        }
+
+        for (net, tx, x, y) in izip!(test_network.get_party_networks(), tx, x_shares, y_shares) {
+            thread::spawn(move || {
+                let preprocessing =
+                    ShamirPreprocessing::new_with_mul_mode(threshold, net, len, mul_mode).unwrap();
+                let mut shamir: ShamirProtocol<_, _> = preprocessing.into();
+                let res = arithmetic::mul_vec(&x, &y, &mut shamir).unwrap();
+                tx.send((res, shamir.network.num_sent_bytes()))
+            });
+        }
+
+        let mut results = Vec::with_capacity(num_parties);
+        let mut sent_bytes = 0;
+        for r in rx {
+            let (res, bytes) = r.recv().unwrap();
+            results.push(res);
+            sent_bytes += bytes;
+        }
+
+        let is_result =
+            shamir::combine_field_elements(&results, &(1..=num_parties).collect_vec(), threshold)
+                .unwrap();
+        assert_eq!(is_result, should_result);
+        sent_bytes
+    }
+
+    #[test]
+    fn shamir_mul_vec_atlas_communication() {
+        // For n = 7 and t = 2, a multiplication costs 8 elements with a (rotating) king and 10 with Atlas. Generating a double sharing costs 35/3 elements, of which Atlas needs 3/7 per multiplication. In total, we expect 13790 elements with a king and 10500 with Atlas.
+        const NUM_PARTIES: usize = 7;
+        const THRESHOLD: usize = 2;
+        const LEN: usize = 700;
+        let king = shamir_mul_vec_sent_bytes(NUM_PARTIES, THRESHOLD, LEN, ShamirMulMode::King);
+        let rotating =
+            shamir_mul_vec_sent_bytes(NUM_PARTIES, THRESHOLD, LEN, ShamirMulMode::RotatingKing);
+        let atlas = shamir_mul_vec_sent_bytes(NUM_PARTIES, THRESHOLD, LEN, ShamirMulMode::Atlas);
+        assert!(
+            atlas * 5 < king * 4,
+            "Atlas sent {atlas} bytes, King sent {king} bytes"
+        );
+        assert!(
+            atlas * 5 < rotating * 4,
+            "Atlas sent {atlas} bytes, RotatingKing sent {rotating} bytes"
+        );
+    }
+
     fn shamir_poseidon2_merkle_tree_inner(num_parties: usize, threshold: usize) {
         const NUM_LEAVES: usize = 4usize.pow(3);
 
@@ -659,15 +1013,16 @@ mod field_share {
 mod curve_share {
     use std::{sync::mpsc, thread};
 
+    use ark_ec::CurveGroup;
     use ark_ff::UniformRand;
     use itertools::{izip, Itertools};
     use mpc_core::protocols::shamir::{self, pointshare};
     use rand::thread_rng;
 
-    fn shamir_add_inner(num_parties: usize, threshold: usize) {
+    fn shamir_add_inner<C: CurveGroup>(num_parties: usize, threshold: usize) {
         let mut rng = thread_rng();
-        let x = ark_bn254::G1Projective::rand(&mut rng);
-        let y = ark_bn254::G1Projective::rand(&mut rng);
+        let x = C::rand(&mut rng);
+        let y = C::rand(&mut rng);
         let x_shares = shamir::share_curve_point(x, threshold, num_parties, &mut rng);
         let y_shares = shamir::share_curve_point(y, threshold, num_parties, &mut rng);
         let should_result = x + y;
@@ -689,7 +1044,7 @@ mod curve_share {
             results.push(r.recv().unwrap());
         }
 
-        let is_result =
+        let is_result: C =
             shamir::combine_curve_point(&results, &(1..=num_parties).collect_vec(), threshold)
                 .unwrap();
 
@@ -698,14 +1053,18 @@ mod curve_share {
 
     #[test]
     fn shamir_add() {
-        shamir_add_inner(3, 1);
-        shamir_add_inner(10, 4);
+        shamir_add_inner::<ark_bn254::G1Projective>(3, 1);
+        shamir_add_inner::<ark_bls12_377::G1Projective>(3, 1);
+        shamir_add_inner::<ark_secp256k1::Projective>(3, 1);
+        shamir_add_inner::<ark_bn254::G1Projective>(10, 4);
+        shamir_add_inner::<ark_bls12_377::G1Projective>(10, 4);
+        shamir_add_inner::<ark_secp256k1::Projective>(10, 4);
     }
 
-    fn shamir_sub_inner(num_parties: usize, threshold: usize) {
+    fn shamir_sub_inner<C: CurveGroup>(num_parties: usize, threshold: usize) {
         let mut rng = thread_rng();
-        let x = ark_bn254::G1Projective::rand(&mut rng);
-        let y = ark_bn254::G1Projective::rand(&mut rng);
+        let x = C::rand(&mut rng);
+        let y = C::rand(&mut rng);
         let x_shares = shamir::share_curve_point(x, threshold, num_parties, &mut rng);
         let y_shares = shamir::share_curve_point(y, threshold, num_parties, &mut rng);
         let should_result = x - y;
@@ -727,7 +1086,7 @@ mod curve_share {
             results.push(r.recv().unwrap());
         }
 
-        let is_result =
+        let is_result: C =
             shamir::combine_curve_point(&results, &(1..=num_parties).collect_vec(), threshold)
                 .unwrap();
 
@@ -736,14 +1095,18 @@ mod curve_share {
 
     #[test]
     fn shamir_sub() {
-        shamir_sub_inner(3, 1);
-        shamir_sub_inner(10, 4);
+        shamir_sub_inner::<ark_bn254::G1Projective>(3, 1);
+        shamir_sub_inner::<ark_bls12_377::G1Projective>(3, 1);
+        shamir_sub_inner::<ark_secp256k1::Projective>(3, 1);
+        shamir_sub_inner::<ark_bn254::G1Projective>(10, 4);
+        shamir_sub_inner::<ark_bls12_377::G1Projective>(10, 4);
+        shamir_sub_inner::<ark_secp256k1::Projective>(10, 4);
     }
 
-    fn shamir_scalar_mul_public_point_inner(num_parties: usize, threshold: usize) {
+    fn shamir_scalar_mul_public_point_inner<C: CurveGroup>(num_parties: usize, threshold: usize) {
         let mut rng = thread_rng();
-        let public_point = ark_bn254::G1Projective::rand(&mut rng);
-        let scalar = ark_bn254::Fr::rand(&mut rng);
+        let public_point = C::rand(&mut rng);
+        let scalar = C::ScalarField::rand(&mut rng);
         let scalar_shares = shamir::share_field_element(scalar, threshold, num_parties, &mut rng);
         let should_result = public_point * scalar;
 
@@ -766,7 +1129,7 @@ mod curve_share {
             results.push(r.recv().unwrap());
         }
 
-        let is_result =
+        let is_result: C =
             shamir::combine_curve_point(&results, &(1..=num_parties).collect_vec(), threshold)
                 .unwrap();
 
@@ -775,14 +1138,18 @@ mod curve_share {
 
     #[test]
     fn shamir_scalar_mul_public_point() {
-        shamir_scalar_mul_public_point_inner(3, 1);
-        shamir_scalar_mul_public_point_inner(10, 4);
+        shamir_scalar_mul_public_point_inner::<ark_bn254::G1Projective>(3, 1);
+        shamir_scalar_mul_public_point_inner::<ark_bls12_377::G1Projective>(3, 1);
+        shamir_scalar_mul_public_point_inner::<ark_secp256k1::Projective>(3, 1);
+        shamir_scalar_mul_public_point_inner::<ark_bn254::G1Projective>(10, 4);
+        shamir_scalar_mul_public_point_inner::<ark_bls12_377::G1Projective>(10, 4);
+        shamir_scalar_mul_public_point_inner::<ark_secp256k1::Projective>(10, 4);
     }
 
-    fn shamir_scalar_mul_public_scalar_inner(num_parties: usize, threshold: usize) {
+    fn shamir_scalar_mul_public_scalar_inner<C: CurveGroup>(num_parties: usize, threshold: usize) {
         let mut rng = thread_rng();
-        let point = ark_bn254::G1Projective::rand(&mut rng);
-        let public_scalar = ark_bn254::Fr::rand(&mut rng);
+        let point = C::rand(&mut rng);
+        let public_scalar = C::ScalarField::rand(&mut rng);
         let point_shares = shamir::share_curve_point(point, threshold, num_parties, &mut rng);
         let should_result = point * public_scalar;
 
@@ -805,7 +1172,7 @@ mod curve_share {
             results.push(r.recv().unwrap());
         }
 
-        let is_result =
+        let is_result: C =
             shamir::combine_curve_point(&results, &(1..=num_parties).collect_vec(), threshold)
                 .unwrap();
 
@@ -814,7 +1181,96 @@ mod curve_share {
 
     #[test]
     fn shamir_scalar_mul_public_scalar() {
-        shamir_scalar_mul_public_scalar_inner(3, 1);
-        shamir_scalar_mul_public_scalar_inner(10, 4);
+        shamir_scalar_mul_public_scalar_inner::<ark_bn254::G1Projective>(3, 1);
+        shamir_scalar_mul_public_scalar_inner::<ark_bls12_377::G1Projective>(3, 1);
+        shamir_scalar_mul_public_scalar_inner::<ark_secp256k1::Projective>(3, 1);
+        shamir_scalar_mul_public_scalar_inner::<ark_bn254::G1Projective>(10, 4);
+        shamir_scalar_mul_public_scalar_inner::<ark_bls12_377::G1Projective>(10, 4);
+        shamir_scalar_mul_public_scalar_inner::<ark_secp256k1::Projective>(10, 4);
+    }
+}
+
+mod packed_share {
+    use ark_std::UniformRand;
+    use itertools::{izip, Itertools};
+    use mpc_core::protocols::shamir::packed::{
+        self, arithmetic, PackedShamirPreprocessing, PackedShamirProtocol,
+    };
+    use rand::thread_rng;
+    use std::{sync::mpsc, thread};
+    use tests::shamir_network::ShamirTestNetwork;
+
+    fn packed_shamir_mul_vec_inner(
+        num_parties: usize,
+        threshold: usize,
+        packing: usize,
+        len: usize,
+    ) {
+        let test_network = ShamirTestNetwork::new(num_parties);
+        let mut rng = thread_rng();
+        let degree = packed::packed_degree(threshold, packing);
+        let x = (0..len)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let y = (0..len)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let public = (0..len)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let x_shares =
+            packed::share_field_elements_packed(&x, degree, packing, num_parties, &mut rng);
+        let y_shares =
+            packed::share_field_elements_packed(&y, degree, packing, num_parties, &mut rng);
+        let should_result = izip!(x, y, public.iter())
+            .map(|(x, y, p)| x * y + p)
+            .collect_vec();
+
+        let mut tx = Vec::with_capacity(num_parties);
+        let mut rx = Vec::with_capacity(num_parties);
+        for _ in 0..num_parties {
+            let (t, r) = mpsc::channel();
+            tx.push(t);
+            rx.push(r);
+        }
+
+        for (net, tx, x, y) in izip!(test_network.get_party_networks(), tx, x_shares, y_shares) {
+            let public = public.clone();
+            thread::spawn(move || {
+                let mut shamir: PackedShamirProtocol<_, _> =
+                    PackedShamirPreprocessing::new(threshold, packing, net, x.len())
+                        .unwrap()
+                        .into();
+                let mul = arithmetic::mul_vec(&x, &y, &mut shamir).unwrap();
+                let res = arithmetic::add_public_vec(&mul, &public, &shamir);
+                let opened = arithmetic::open_vec(&res, &mut shamir).unwrap();
+                tx.send((res, opened))
+            });
+        }
+
+        let mut results = Vec::with_capacity(num_parties);
+        for r in rx {
+            let (res, mut opened) = r.recv().unwrap();
+            opened.truncate(len);
+            assert_eq!(opened, should_result);
+            results.push(res);
+        }
+
+        let is_result = packed::combine_field_elements_packed(
+            &results,
+            &(1..=num_parties).collect_vec(),
+            degree,
+            packing,
+            len,
+        )
+        .unwrap();
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn packed_shamir_mul_vec() {
+        packed_shamir_mul_vec_inner(3, 1, 1, 10);
+        packed_shamir_mul_vec_inner(5, 1, 2, 11);
+        packed_shamir_mul_vec_inner(9, 2, 3, 100);
     }
 }
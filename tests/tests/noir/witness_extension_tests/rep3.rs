@@ -6,6 +6,7 @@ use co_acvm::solver::PlainCoSolver;
 use co_acvm::solver::Rep3CoSolver;
 use itertools::izip;
 use noirc_artifacts::program::ProgramArtifact;
+use rand::Rng;
 use std::path::PathBuf;
 use tests::rep3_network::PartyTestNetwork;
 use tests::rep3_network::Rep3TestNetwork;
@@ -33,3 +34,333 @@ add_rep3_acvm_test!("unconstrained_fn_field");
 add_rep3_acvm_test!("write_access");
 add_rep3_acvm_test!("bb_sha256_compression");
 add_rep3_acvm_test!("aes128");
+
+fn rep3_acvm_ring_inputs(name: &str) {
+    let root = std::env!("CARGO_MANIFEST_DIR");
+    let program = std::fs::read_to_string(format!(
+        "{root}/../test_vectors/noir/{name}/kat/{name}.json"
+    ))
+    .unwrap();
+    let program_artifact = serde_json::from_str::<ProgramArtifact>(&program)
+        .expect("failed to parse program artifact");
+
+    let should_witness =
+        std::fs::read(format!("{root}/../test_vectors/noir/{name}/kat/{name}.gz")).unwrap();
+    let should_witness = WitnessStack::<FieldElement>::try_from(should_witness.as_slice()).unwrap();
+    let input = PathBuf::from(format!("{root}/../test_vectors/noir/{name}/Prover.toml"));
+    // read the input file
+    let inputs = Rep3CoSolver::<_, PartyTestNetwork>::partially_read_abi_bn254_fieldelement(
+        &input,
+        &program_artifact.abi,
+        &program_artifact.bytecode,
+    )
+    .expect("can share field elements for noir witness extension");
+
+    // create input shares, keeping the integers in their rings
+    let ring_types = co_noir::input_ring_types(&program_artifact.abi);
+    assert!(!ring_types.is_empty());
+    let mut rng = rand::thread_rng();
+    let shares =
+        co_noir::split_input_rep3_with_ring_types::<Bn254, _>(inputs, &ring_types, &mut rng)
+            .unwrap();
+    let shares = co_noir::commit_input_shares(shares, &mut rng).unwrap();
+    let test_network = Rep3TestNetwork::default();
+    let mut threads = vec![];
+    for (net, program_artifact, share) in izip!(
+        test_network.get_party_networks(),
+        [
+            program_artifact.clone(),
+            program_artifact.clone(),
+            program_artifact
+        ],
+        shares
+    ) {
+        threads.push(thread::spawn(move || {
+            let mut net = net;
+            co_noir::verify_input_commitments(&share, &mut net).unwrap();
+            let (share, net) = co_noir::cast_input_shares(share.inputs, net).unwrap();
+            let input_share = co_noir::witness_to_witness_map(share, &program_artifact.abi)
+                .expect("can translate witness for noir witness extension");
+            let solver =
+                Rep3CoSolver::from_network_with_witness(net, program_artifact, input_share)
+                    .unwrap();
+            let (proof, _) = solver.solve().unwrap();
+            proof
+        }));
+    }
+
+    let result3 = threads.pop().unwrap().join().unwrap();
+    let result2 = threads.pop().unwrap().join().unwrap();
+    let result1 = threads.pop().unwrap().join().unwrap();
+    let is_witness = super::combine_field_elements_for_acvm(result1, result2, result3);
+    let is_witness = PlainCoSolver::convert_to_plain_acvm_witness(is_witness);
+    assert_eq!(should_witness, is_witness)
+}
+
+#[test]
+fn test_rep3_ring_inputs_add3u64() {
+    rep3_acvm_ring_inputs("add3u64");
+}
+
+#[test]
+fn test_rep3_ring_inputs_bb_sha256_compression() {
+    rep3_acvm_ring_inputs("bb_sha256_compression");
+}
+
+#[test]
+fn test_rep3_ring_inputs_if_then() {
+    rep3_acvm_ring_inputs("if_then");
+}
+
+#[test]
+fn test_rep3_inconsistent_input_shares_are_rejected() {
+    let root = std::env!("CARGO_MANIFEST_DIR");
+    let name = "add3u64";
+    let program = std::fs::read_to_string(format!(
+        "{root}/../test_vectors/noir/{name}/kat/{name}.json"
+    ))
+    .unwrap();
+    let program_artifact = serde_json::from_str::<ProgramArtifact>(&program)
+        .expect("failed to parse program artifact");
+    let input = PathBuf::from(format!("{root}/../test_vectors/noir/{name}/Prover.toml"));
+    let inputs = Rep3CoSolver::<_, PartyTestNetwork>::partially_read_abi_bn254_fieldelement(
+        &input,
+        &program_artifact.abi,
+        &program_artifact.bytecode,
+    )
+    .expect("can share field elements for noir witness extension");
+
+    let mut rng = rand::thread_rng();
+    let shares = co_noir::split_input_rep3_with_ring_types::<Bn254, _>(
+        inputs,
+        &Default::default(),
+        &mut rng,
+    )
+    .unwrap();
+    let [share0, mut share1, share2] = co_noir::commit_input_shares(shares, &mut rng).unwrap();
+    share0.verify_commitments(co_noir::PartyID::ID0).unwrap();
+    share2.verify_commitments(co_noir::PartyID::ID2).unwrap();
+
+    // the dealer hands party 1 a share which does not match the ones of the other parties
+    let share = share1
+        .inputs
+        .values_mut()
+        .find_map(|share| match share {
+            co_noir::Rep3InputShare::Field(co_noir::Rep3AcvmType::Shared(share)) => Some(share),
+            _ => None,
+        })
+        .expect("there is a private input");
+    share.b += ark_bn254::Fr::from(1u64);
+    assert!(share1.verify_commitments(co_noir::PartyID::ID1).is_err());
+}
+
+#[test]
+fn test_rep3_input_provenance() {
+    let root = std::env!("CARGO_MANIFEST_DIR");
+    let name = "add3u64";
+    let program = std::fs::read_to_string(format!(
+        "{root}/../test_vectors/noir/{name}/kat/{name}.json"
+    ))
+    .unwrap();
+    let program_artifact = serde_json::from_str::<ProgramArtifact>(&program)
+        .expect("failed to parse program artifact");
+    let input = PathBuf::from(format!("{root}/../test_vectors/noir/{name}/Prover.toml"));
+    let inputs = Rep3CoSolver::<_, PartyTestNetwork>::partially_read_abi_bn254_fieldelement(
+        &input,
+        &program_artifact.abi,
+        &program_artifact.bytecode,
+    )
+    .expect("can share field elements for noir witness extension");
+
+    let mut rng = rand::thread_rng();
+    let shares = co_noir::split_input_rep3_with_ring_types::<Bn254, _>(
+        inputs,
+        &Default::default(),
+        &mut rng,
+    )
+    .unwrap();
+    let mut shares = shares.map(co_noir::Rep3InputShares::new);
+    let alice = co_noir::ClientSigningKey::generate(&mut rng);
+    let circuit_hash: [u8; 32] = rng.r#gen();
+    co_noir::sign_input_shares(&mut shares, "alice", Some(circuit_hash), &alice).unwrap();
+
+    // all inputs are signed, including the public ones
+    let inputs = shares[0]
+        .inputs
+        .keys()
+        .map(|name| (name.to_owned(), None))
+        .collect::<std::collections::BTreeMap<_, _>>();
+    assert!(!inputs.is_empty());
+    let mut policy = co_noir::InputPolicy::default();
+    policy.clients.insert(
+        "alice".to_owned(),
+        co_noir::ClientPolicy {
+            key: alice.verifying_key(),
+            inputs: inputs.clone(),
+        },
+    );
+    for (id, share) in shares.iter().enumerate() {
+        share
+            .verify_provenance(&policy, id.try_into().unwrap(), Some(circuit_hash))
+            .unwrap();
+    }
+    // the signature of one party does not verify for another party
+    assert!(shares[0]
+        .verify_provenance(&policy, co_noir::PartyID::ID1, Some(circuit_hash))
+        .is_err());
+    // the signature cannot be replayed for another circuit
+    assert!(shares[0]
+        .verify_provenance(&policy, co_noir::PartyID::ID0, Some(rng.r#gen()))
+        .is_err());
+    assert!(shares[0]
+        .verify_provenance(&policy, co_noir::PartyID::ID0, None)
+        .is_err());
+
+    // alice is not allowed to provide all inputs
+    let mut restricted = policy.clone();
+    let client = restricted.clients.get_mut("alice").unwrap();
+    client.inputs.pop_first();
+    assert!(shares[0]
+        .verify_provenance(&restricted, co_noir::PartyID::ID0, Some(circuit_hash))
+        .is_err());
+
+    // the signature of alice is not accepted for another client
+    let mut impostor = shares[0].clone();
+    impostor.provenance[0].client = "bob".to_owned();
+    policy.clients.insert(
+        "bob".to_owned(),
+        co_noir::ClientPolicy {
+            key: co_noir::ClientSigningKey::generate(&mut rng).verifying_key(),
+            inputs,
+        },
+    );
+    assert!(impostor
+        .verify_provenance(&policy, co_noir::PartyID::ID0, Some(circuit_hash))
+        .is_err());
+
+    // unsigned inputs are rejected
+    let unsigned = co_noir::Rep3InputShares::new(shares[0].inputs.clone());
+    assert!(unsigned
+        .verify_provenance(&policy, co_noir::PartyID::ID0, Some(circuit_hash))
+        .is_err());
+}
+
+#[test]
+fn test_rep3_input_commitments_merge_order() {
+    let root = std::env!("CARGO_MANIFEST_DIR");
+    let name = "add3u64";
+    let program = std::fs::read_to_string(format!(
+        "{root}/../test_vectors/noir/{name}/kat/{name}.json"
+    ))
+    .unwrap();
+    let program_artifact = serde_json::from_str::<ProgramArtifact>(&program)
+        .expect("failed to parse program artifact");
+    let input = PathBuf::from(format!("{root}/../test_vectors/noir/{name}/Prover.toml"));
+    let inputs = Rep3CoSolver::<_, PartyTestNetwork>::partially_read_abi_bn254_fieldelement(
+        &input,
+        &program_artifact.abi,
+        &program_artifact.bytecode,
+    )
+    .expect("can share field elements for noir witness extension");
+
+    // two dealers, each providing some of the inputs
+    let (inputs_a, inputs_b): (
+        std::collections::BTreeMap<_, _>,
+        std::collections::BTreeMap<_, _>,
+    ) = inputs.into_iter().partition(|(name, _)| name == "x");
+    let mut rng = rand::thread_rng();
+    let mut dealer = |inputs| {
+        let shares = co_noir::split_input_rep3_with_ring_types::<Bn254, _>(
+            inputs,
+            &Default::default(),
+            &mut rng,
+        )
+        .unwrap();
+        co_noir::commit_input_shares(shares, &mut rng).unwrap()
+    };
+    let [a0, a1, a2] = dealer(inputs_a);
+    let [b0, b1, b2] = dealer(inputs_b);
+
+    // the parties merge the input shares in different orders
+    let merged = [
+        co_noir::merge_typed_input_shares::<Bn254>(vec![a0, b0]).unwrap(),
+        co_noir::merge_typed_input_shares::<Bn254>(vec![b1, a1]).unwrap(),
+        co_noir::merge_typed_input_shares::<Bn254>(vec![a2, b2]).unwrap(),
+    ];
+    let test_network = Rep3TestNetwork::default();
+    let mut threads = vec![];
+    for (mut net, share) in izip!(test_network.get_party_networks(), merged) {
+        threads.push(thread::spawn(move || {
+            co_noir::verify_input_commitments(&share, &mut net)
+        }));
+    }
+    for thread in threads {
+        thread.join().unwrap().unwrap();
+    }
+}
+
+#[test]
+fn test_rep3_input_commitments_coverage() {
+    let root = std::env!("CARGO_MANIFEST_DIR");
+    let name = "add3u64";
+    let program = std::fs::read_to_string(format!(
+        "{root}/../test_vectors/noir/{name}/kat/{name}.json"
+    ))
+    .unwrap();
+    let program_artifact = serde_json::from_str::<ProgramArtifact>(&program)
+        .expect("failed to parse program artifact");
+    let input = PathBuf::from(format!("{root}/../test_vectors/noir/{name}/Prover.toml"));
+    let inputs = Rep3CoSolver::<_, PartyTestNetwork>::partially_read_abi_bn254_fieldelement(
+        &input,
+        &program_artifact.abi,
+        &program_artifact.bytecode,
+    )
+    .expect("can share field elements for noir witness extension");
+
+    // x is the only private input, y and z are public
+    let (inputs_x, inputs_yz): (
+        std::collections::BTreeMap<_, _>,
+        std::collections::BTreeMap<_, _>,
+    ) = inputs.into_iter().partition(|(name, _)| name == "x");
+    let mut rng = rand::thread_rng();
+    let shares_x = co_noir::split_input_rep3_with_ring_types::<Bn254, _>(
+        inputs_x,
+        &Default::default(),
+        &mut rng,
+    )
+    .unwrap();
+    let shares_yz = co_noir::split_input_rep3_with_ring_types::<Bn254, _>(
+        inputs_yz,
+        &Default::default(),
+        &mut rng,
+    )
+    .unwrap();
+    let [x0, _, _] = co_noir::commit_input_shares(shares_x.clone(), &mut rng).unwrap();
+    let [yz0, _, _] = co_noir::commit_input_shares(shares_yz.clone(), &mut rng).unwrap();
+
+    // the public inputs do not need to be committed
+    let merged = co_noir::merge_typed_input_shares::<Bn254>(vec![
+        x0.clone(),
+        co_noir::Rep3InputShares::new(shares_yz[0].clone()),
+    ])
+    .unwrap();
+    merged.verify_commitments(co_noir::PartyID::ID0).unwrap();
+    merged.check_commitment_coverage().unwrap();
+
+    // the private input is not committed
+    let merged = co_noir::merge_typed_input_shares::<Bn254>(vec![
+        co_noir::Rep3InputShares::new(shares_x[0].clone()),
+        yz0,
+    ])
+    .unwrap();
+    merged.verify_commitments(co_noir::PartyID::ID0).unwrap();
+    assert!(merged.check_commitment_coverage().is_err());
+
+    // the private input is committed twice
+    let mut duplicated = x0.clone();
+    duplicated.commitments.push(x0.commitments[0].clone());
+    assert!(duplicated
+        .verify_commitments(co_noir::PartyID::ID0)
+        .is_err());
+    assert!(duplicated.check_commitment_coverage().is_err());
+}